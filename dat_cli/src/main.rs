@@ -0,0 +1,509 @@
+use l2_rw::schema::Schema;
+use l2_rw::table::Table;
+use l2_rw::{encode_bytes, write_encoded_file, DatEnvelope, DatReader, EncVersion};
+use std::io::{Cursor, Read};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage:
+    l2w_dat_cli decode <input.dat> <output>
+        Decrypts and inflates .dat into raw bytes
+    l2w_dat_cli encode <input> <output.dat> [--version <111|121|411|412|413|414|plain>]
+        Encodes raw bytes into .dat, 413 by default
+    l2w_dat_cli info <file.dat>... [--schema <schema>]
+        Prints header version, sizes and the leading count, which is the record count unless
+        the dat starts with a string dictionary. With schema records are decoded and counted
+    l2w_dat_cli verify <file.dat>...
+        Decodes, encodes back with the same version and checks that payload is unchanged
+    l2w_dat_cli export <file.dat> <output.csv|tsv> --schema <schema>
+        Writes records as a table, one row per record
+    l2w_dat_cli import <input.csv|tsv> <file.dat> --schema <schema>
+        Replaces records of existing .dat with table rows, string dictionary and encryption
+        are kept";
+
+enum Command {
+    Decode {
+        input: PathBuf,
+        output: PathBuf,
+    },
+    Encode {
+        input: PathBuf,
+        output: PathBuf,
+        version: Option<EncVersion>,
+    },
+    Info {
+        files: Vec<PathBuf>,
+        schema: Option<PathBuf>,
+    },
+    Verify {
+        files: Vec<PathBuf>,
+    },
+    Export {
+        input: PathBuf,
+        output: PathBuf,
+        schema: PathBuf,
+    },
+    Import {
+        input: PathBuf,
+        output: PathBuf,
+        schema: PathBuf,
+    },
+}
+
+impl Command {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let Some(command) = args.next() else {
+            return Err("command is missing".to_string());
+        };
+
+        let mut paths = vec![];
+        let mut version = None;
+        let mut schema = None;
+
+        while let Some(arg) = args.next() {
+            if arg == "--version" {
+                let Some(v) = args.next() else {
+                    return Err("--version needs a value".to_string());
+                };
+
+                version = Some(if v == "plain" { None } else { Some(v.parse()?) });
+            } else if arg == "--schema" {
+                let Some(v) = args.next() else {
+                    return Err("--schema needs a value".to_string());
+                };
+
+                schema = Some(PathBuf::from(v));
+            } else if arg.starts_with("--") {
+                return Err(format!("unknown option {arg}"));
+            } else {
+                paths.push(PathBuf::from(arg));
+            }
+        }
+
+        let command = match command.as_str() {
+            "export" | "import" => {
+                let [input, output]: [PathBuf; 2] = paths
+                    .try_into()
+                    .map_err(|_| format!("{command} needs input and output paths"))?;
+
+                let Some(schema) = schema.take() else {
+                    return Err(format!("{command} needs --schema"));
+                };
+
+                if command == "export" {
+                    Command::Export {
+                        input,
+                        output,
+                        schema,
+                    }
+                } else {
+                    Command::Import {
+                        input,
+                        output,
+                        schema,
+                    }
+                }
+            }
+            "decode" | "encode" => {
+                let [input, output]: [PathBuf; 2] = paths
+                    .try_into()
+                    .map_err(|_| format!("{command} needs input and output paths"))?;
+
+                if command == "decode" {
+                    Command::Decode { input, output }
+                } else {
+                    Command::Encode {
+                        input,
+                        output,
+                        version: version.unwrap_or(Some(EncVersion::V413)),
+                    }
+                }
+            }
+            "info" | "verify" if paths.is_empty() => {
+                return Err(format!("{command} needs at least one file"));
+            }
+            "info" => Command::Info {
+                files: paths,
+                schema: schema.take(),
+            },
+            "verify" => Command::Verify { files: paths },
+
+            _ => return Err(format!("unknown command {command}")),
+        };
+
+        if version.is_some() && !matches!(command, Command::Encode { .. }) {
+            return Err("--version is used only with encode".to_string());
+        }
+
+        if schema.is_some() {
+            return Err("--schema is used only with info, export and import".to_string());
+        }
+
+        Ok(command)
+    }
+
+    /**Returns `false` if any of the files failed
+     */
+    fn run(self) -> bool {
+        match self {
+            Command::Decode { input, output } => report(&input, decode(&input, &output)),
+            Command::Encode {
+                input,
+                output,
+                version,
+            } => report(&input, encode(&input, &output, version)),
+            Command::Info { files, schema } => {
+                let schema = match schema.as_deref().map(load_schema).transpose() {
+                    Ok(v) => v,
+                    Err(e) => {
+                        eprintln!("{e}");
+
+                        return false;
+                    }
+                };
+
+                files
+                    .iter()
+                    .filter(|v| !report(v, info(v, schema.as_ref())))
+                    .count()
+                    == 0
+            }
+            Command::Verify { files } => {
+                files.iter().filter(|v| !report(v, verify(v))).count() == 0
+            }
+            Command::Export {
+                input,
+                output,
+                schema,
+            } => report(&input, export(&input, &output, &schema)),
+            Command::Import {
+                input,
+                output,
+                schema,
+            } => report(&output, import(&input, &output, &schema)),
+        }
+    }
+}
+
+/**Errors are expected to already have the path, like [`l2_rw::Error`] does
+ */
+fn report(path: &Path, res: Result<String, String>) -> bool {
+    match res {
+        Ok(v) => {
+            println!("{}: {v}", path.display());
+
+            true
+        }
+        Err(e) => {
+            eprintln!("{e}");
+
+            false
+        }
+    }
+}
+
+fn version_name(version: Option<EncVersion>) -> String {
+    version.map_or_else(|| "plain".to_string(), |v| v.to_string())
+}
+
+fn decode(input: &Path, output: &Path) -> Result<String, String> {
+    let mut reader = DatReader::open(input).map_err(|e| e.to_string())?;
+    let data = reader.read_all().map_err(|e| e.to_string())?;
+
+    std::fs::write(output, &data).map_err(|e| format!("{output:?}: can't write: {e}"))?;
+
+    Ok(format!(
+        "decoded {} bytes ({}) into {}",
+        data.len(),
+        version_name(reader.version()),
+        output.display()
+    ))
+}
+
+fn encode(input: &Path, output: &Path, version: Option<EncVersion>) -> Result<String, String> {
+    let data = std::fs::read(input).map_err(|e| format!("{input:?}: can't read: {e}"))?;
+    let len = data.len();
+
+    let written = write_encoded_file(output, data, version).map_err(|e| e.to_string())?;
+
+    Ok(format!(
+        "encoded {len} bytes ({}) into {}, {written} bytes written",
+        version_name(version),
+        output.display()
+    ))
+}
+
+/**Without schema only the leading `u32` is read, dats with string dictionary start with its
+compact int size instead of the record count
+ */
+fn info(path: &Path, schema: Option<&Schema>) -> Result<String, String> {
+    let file_size = std::fs::metadata(path)
+        .map_err(|e| format!("{path:?}: can't read: {e}"))?
+        .len();
+
+    let mut reader = DatReader::open(path).map_err(|e| e.to_string())?;
+    let version = reader.version();
+
+    let mut count = [0u8; 4];
+    let decoded_size = reader
+        .read_exact(&mut count)
+        .and_then(|_| std::io::copy(&mut reader, &mut std::io::sink()))
+        .map_err(|e| e.to_string())?
+        + 4;
+
+    let counts = if let Some(schema) = schema {
+        let dat = schema.decode(path).map_err(|e| e.to_string())?;
+
+        let dict = dat
+            .dict
+            .map(|v| format!("\n\tstring dictionary: {}", v.len()))
+            .unwrap_or_default();
+
+        format!("{dict}\n\trecords: {}", dat.records.len())
+    } else {
+        format!("\n\tleading count: {}", u32::from_le_bytes(count))
+    };
+
+    Ok(format!(
+        "\n\tversion: {}\n\tfile size: {file_size}\n\tdecoded size: {decoded_size}{counts}",
+        version_name(version),
+    ))
+}
+
+/**Versions without private key are encoded as 413, payload is still checked
+ */
+fn verify(path: &Path) -> Result<String, String> {
+    let mut reader = DatReader::open(path).map_err(|e| e.to_string())?;
+    let payload = reader.read_all().map_err(|e| e.to_string())?;
+    let mut envelope = reader.finish().map_err(|e| e.to_string())?;
+
+    let mut note = "";
+
+    if envelope.version.is_some_and(|v| !v.can_encode()) {
+        envelope = DatEnvelope::new(Some(EncVersion::V413));
+        note = ", re-encoded as 413";
+    }
+
+    let encoded = encode_bytes(path, payload.clone(), &envelope).map_err(|e| e.to_string())?;
+
+    let mut reader = DatReader::new(path, Cursor::new(encoded)).map_err(|e| e.to_string())?;
+    let decoded = reader.read_all().map_err(|e| e.to_string())?;
+
+    if let Some(offset) = payload.iter().zip(&decoded).position(|(a, b)| a != b) {
+        return Err(format!("{path:?}: payload differs at offset {offset}"));
+    }
+
+    if payload.len() != decoded.len() {
+        return Err(format!(
+            "{path:?}: payload size differs: {} != {}",
+            payload.len(),
+            decoded.len()
+        ));
+    }
+
+    Ok(format!(
+        "OK ({}, {} bytes{note})",
+        version_name(envelope.version),
+        payload.len()
+    ))
+}
+
+fn load_schema(path: &Path) -> Result<Schema, String> {
+    Schema::load(path).map_err(|e| format!("{path:?}: {e}"))
+}
+
+fn export(input: &Path, output: &Path, schema: &Path) -> Result<String, String> {
+    let schema = load_schema(schema)?;
+    let dat = schema.decode(input).map_err(|e| e.to_string())?;
+
+    schema
+        .to_table(&dat)
+        .write(output)
+        .map_err(|e| e.to_string())?;
+
+    Ok(format!(
+        "exported {} records into {}",
+        dat.records.len(),
+        output.display()
+    ))
+}
+
+fn import(input: &Path, output: &Path, schema: &Path) -> Result<String, String> {
+    let schema = load_schema(schema)?;
+    let table = Table::read(input).map_err(|e| e.to_string())?;
+    let records = schema
+        .records_from_table(&table)
+        .map_err(|e| format!("{input:?}: {e}"))?;
+
+    let mut dat = schema.decode(output).map_err(|e| e.to_string())?;
+    dat.records = records;
+
+    let written = schema.save(output, &dat).map_err(|e| e.to_string())?;
+
+    Ok(format!(
+        "imported {} records from {}, {written} bytes written",
+        dat.records.len(),
+        input.display()
+    ))
+}
+
+fn main() -> ExitCode {
+    let command = match Command::parse(std::env::args().skip(1)) {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("{e}\n\n{USAGE}");
+
+            return ExitCode::from(2);
+        }
+    };
+
+    if command.run() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use l2_rw::{save_dat, DatVariant};
+
+    fn parse(args: &str) -> Result<Command, String> {
+        Command::parse(args.split_whitespace().map(String::from))
+    }
+
+    fn parse_err(args: &str) -> String {
+        parse(args).err().unwrap()
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("l2w_dat_cli_{}_{name}", std::process::id()))
+    }
+
+    #[test]
+    fn version_is_only_for_encode() {
+        let version = |args: &str| match parse(args) {
+            Ok(Command::Encode { version, .. }) => version,
+            _ => panic!("{args}"),
+        };
+
+        assert_eq!(version("encode a b"), Some(EncVersion::V413));
+        assert_eq!(version("encode a b --version 111"), Some(EncVersion::V111));
+        assert_eq!(version("encode --version plain a b"), None);
+
+        for args in [
+            "decode a b --version 413",
+            "decode a b --version plain",
+            "info a --version 413",
+            "verify a --version 111",
+            "export a b --schema s --version 413",
+        ] {
+            assert_eq!(
+                parse_err(args),
+                "--version is used only with encode",
+                "{args}"
+            );
+        }
+
+        assert_eq!(parse_err("encode a b --version"), "--version needs a value");
+        assert!(parse("encode a b --version 999").is_err());
+    }
+
+    #[test]
+    fn schema_is_only_for_info_export_and_import() {
+        assert!(matches!(
+            parse("info a b --schema s"),
+            Ok(Command::Info { files, schema: Some(_) }) if files.len() == 2
+        ));
+        assert!(matches!(
+            parse("info a"),
+            Ok(Command::Info { schema: None, .. })
+        ));
+        assert!(matches!(
+            parse("import a.csv b.dat --schema s"),
+            Ok(Command::Import { .. })
+        ));
+
+        assert_eq!(parse_err("export a b"), "export needs --schema");
+        assert_eq!(
+            parse_err("verify a --schema s"),
+            "--schema is used only with info, export and import"
+        );
+        assert_eq!(
+            parse_err("decode a b --schema s"),
+            "--schema is used only with info, export and import"
+        );
+    }
+
+    #[test]
+    fn paths_and_commands_are_checked() {
+        assert_eq!(parse_err(""), "command is missing");
+        assert_eq!(parse_err("pack a b"), "unknown command pack");
+        assert_eq!(parse_err("decode a"), "decode needs input and output paths");
+        assert_eq!(
+            parse_err("encode a b c"),
+            "encode needs input and output paths"
+        );
+        assert_eq!(parse_err("verify"), "verify needs at least one file");
+        assert_eq!(parse_err("info a --force"), "unknown option --force");
+    }
+
+    #[test]
+    fn encode_decode_round_trip() {
+        let raw = temp_path("round_trip.bin");
+        let dat = temp_path("round_trip.dat");
+        let decoded = temp_path("round_trip_decoded.bin");
+
+        let payload: Vec<u8> = [3u32, 10, 20, 30]
+            .iter()
+            .flat_map(|v| v.to_le_bytes())
+            .collect();
+
+        std::fs::write(&raw, &payload).unwrap();
+
+        for version in [Some(EncVersion::V413), Some(EncVersion::V111), None] {
+            encode(&raw, &dat, version).unwrap();
+            decode(&dat, &decoded).unwrap();
+
+            assert_eq!(std::fs::read(&decoded).unwrap(), payload, "{version:?}");
+
+            let info = info(&dat, None).unwrap();
+
+            assert!(info.contains("leading count: 3"), "{info}");
+            assert!(info.contains(&format!("version: {}", version_name(version))));
+            assert!(verify(&dat).unwrap().starts_with("OK"));
+        }
+
+        for v in [raw, dat, decoded] {
+            std::fs::remove_file(v).unwrap();
+        }
+    }
+
+    #[test]
+    fn info_with_schema_counts_string_dictionary() {
+        let dat = temp_path("dict.dat");
+
+        save_dat(
+            &dat,
+            DatVariant::DoubleArray(
+                vec!["a".to_string(), "b".to_string(), "c".to_string()],
+                vec![1u32, 2],
+            ),
+            Some(EncVersion::V413),
+        )
+        .unwrap();
+
+        let schema = Schema::parse("dat STR, DWORD;").unwrap();
+
+        let without = info(&dat, None).unwrap();
+        let with = info(&dat, Some(&schema)).unwrap();
+
+        std::fs::remove_file(dat).unwrap();
+
+        assert!(!without.contains("records"), "{without}");
+        assert!(with.contains("string dictionary: 3"), "{with}");
+        assert!(with.contains("records: 2"), "{with}");
+        assert!(!with.contains("leading count"), "{with}");
+    }
+}
@@ -0,0 +1,241 @@
+package net.sf.l2j.gameserver.scripting.quest;
+
+{{#has_classes}}
+import net.sf.l2j.gameserver.enums.actors.ClassId;
+{{/has_classes}}
+import net.sf.l2j.gameserver.model.actor.Creature;
+import net.sf.l2j.gameserver.model.actor.Npc;
+import net.sf.l2j.gameserver.model.actor.Player;
+import net.sf.l2j.gameserver.scripting.Quest;
+import net.sf.l2j.gameserver.scripting.QuestState;
+
+public class {{class_name}} extends Quest
+{
+	private static final String QUEST_NAME = "{{class_name}}";
+	
+	// NPCs
+{{#start_npcs}}
+{{#declare}}
+	private static final int {{const}} = {{id}};
+{{/declare}}
+{{/start_npcs}}
+	
+	// Monsters
+{{#kill_npcs}}
+{{#declare}}
+	private static final int {{const}} = {{id}};
+{{/declare}}
+{{/kill_npcs}}
+	
+	// Items
+{{#quest_items}}
+{{#declare}}
+	private static final int {{const}} = {{id}};
+{{/declare}}
+{{/quest_items}}
+	
+	// Rewards
+{{#rewards}}
+{{#declare}}
+	private static final int {{const}} = {{id}};
+{{/declare}}
+{{/rewards}}
+	
+	private static final int MIN_LEVEL = {{min_level}};
+{{#has_max_level}}
+	private static final int MAX_LEVEL = {{max_level}};
+{{/has_max_level}}
+	
+	public {{class_name}}()
+	{
+		super({{quest_id}}, "{{quest_title}}");
+		
+{{#has_quest_items}}
+		setItemsIds({{#quest_items}}{{const}}{{comma}}{{/quest_items}});
+		
+{{/has_quest_items}}
+{{#start_npcs}}
+		addStartNpc({{const}});
+		addTalkId({{const}});
+{{/start_npcs}}
+{{#has_kill_npcs}}
+		addKillId({{#kill_npcs}}{{const}}{{comma}}{{/kill_npcs}});
+{{/has_kill_npcs}}
+	}
+	
+	@Override
+	public String onAdvEvent(String event, Npc npc, Player player)
+	{
+		String htmltext = event;
+		QuestState st = player.getQuestState(QUEST_NAME);
+		if (st == null)
+			return htmltext;
+		
+		if (event.equalsIgnoreCase("accept"))
+		{
+			st.setState(STATE_STARTED);
+			st.setCond({{first_cond}});
+			st.playSound(QuestState.SOUND_ACCEPT);
+			htmltext = "accepted.htm";
+		}
+		
+		return htmltext;
+	}
+	
+	@Override
+	public String onTalk(Npc npc, Player player)
+	{
+		String htmltext = getNoQuestMsg();
+		QuestState st = player.getQuestState(QUEST_NAME);
+		if (st == null)
+			return htmltext;
+		
+		switch (st.getState())
+		{
+			case STATE_CREATED:
+				if (player.getLevel() < MIN_LEVEL{{#has_max_level}} || player.getLevel() > MAX_LEVEL{{/has_max_level}})
+					htmltext = "no_level.htm";
+{{#has_classes}}
+				else if (!isAllowedClass(player))
+					htmltext = "no_class.htm";
+{{/has_classes}}
+				else
+					htmltext = "start.htm";
+				break;
+			
+			case STATE_STARTED:
+				switch (st.getCond())
+				{
+{{#steps}}
+					// {{title}}
+					case {{cond}}:
+						if (isStepDone(st))
+						{
+{{#has_next}}
+							st.setCond({{next_cond}});
+							st.playSound(QuestState.SOUND_MIDDLE);
+							htmltext = "{{cond}}-done.htm";
+{{/has_next}}
+{{#is_last}}
+							finish(st);
+							htmltext = "finish.htm";
+{{/is_last}}
+						}
+						else
+							htmltext = "{{cond}}-progress.htm";
+						break;
+					
+{{/steps}}
+				}
+				break;
+			
+			case STATE_COMPLETED:
+				htmltext = getAlreadyCompletedMsg();
+				break;
+		}
+		
+		return htmltext;
+	}
+	
+	@Override
+	public String onKill(Npc npc, Creature killer)
+	{
+		final Player player = killer.getActingPlayer();
+		if (player == null)
+			return null;
+		
+		QuestState st = player.getQuestState(QUEST_NAME);
+		if (st == null || !st.isStarted())
+			return null;
+		
+		final int npcId = npc.getNpcId();
+		switch (st.getCond())
+		{
+{{#steps}}
+{{#is_hunt}}
+			case {{cond}}:
+{{#goals}}
+{{#is_kill}}
+				if (npcId == {{const}} && st.getInt("{{var}}") < {{count}})
+				{
+					st.set("{{var}}", String.valueOf(st.getInt("{{var}}") + 1));
+					st.playSound(QuestState.SOUND_ITEMGET);
+				}
+{{/is_kill}}
+{{#is_collect}}
+{{#has_drop_npcs}}
+				if (({{#drop_npcs}}npcId == {{const}}{{or}}{{/drop_npcs}}) && st.getQuestItemsCount({{const}}) < {{count}})
+				{
+					st.giveItems({{const}}, 1);
+					st.playSound(QuestState.SOUND_ITEMGET);
+				}
+{{/has_drop_npcs}}
+{{^has_drop_npcs}}
+				// TODO: nothing drops {{const}}
+{{/has_drop_npcs}}
+{{/is_collect}}
+{{/goals}}
+{{#has_next}}
+				if (isStepDone(st))
+				{
+					st.setCond({{next_cond}});
+					st.playSound(QuestState.SOUND_MIDDLE);
+				}
+{{/has_next}}
+				break;
+			
+{{/is_hunt}}
+{{/steps}}
+		}
+		
+		return null;
+	}
+	
+	private static boolean isStepDone(QuestState st)
+	{
+		switch (st.getCond())
+		{
+{{#steps}}
+{{#has_goals}}
+			case {{cond}}:
+				return {{#goals}}{{#is_kill}}st.getInt("{{var}}") >= {{count}}{{/is_kill}}{{#is_collect}}st.getQuestItemsCount({{const}}) >= {{count}}{{/is_collect}}{{#is_other}}true{{/is_other}}{{and}}{{/goals}};
+{{/has_goals}}
+{{/steps}}
+		}
+		
+		return true;
+	}
+{{#has_classes}}
+	
+	private static boolean isAllowedClass(Player player)
+	{
+		switch (player.getClassId())
+		{
+{{#classes}}
+			case {{const}}:
+{{/classes}}
+				return true;
+			
+			default:
+				return false;
+		}
+	}
+{{/has_classes}}
+	
+	private static void finish(QuestState st)
+	{
+{{#quest_items}}
+		st.takeItems({{const}}, -1);
+{{/quest_items}}
+		giveRewards(st);
+		st.playSound(QuestState.SOUND_FINISH);
+		st.exitQuest(false);
+	}
+	
+	private static void giveRewards(QuestState st)
+	{
+{{#rewards}}
+		st.rewardItems({{const}}, {{count}});
+{{/rewards}}
+	}
+}
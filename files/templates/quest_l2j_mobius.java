@@ -0,0 +1,226 @@
+package quests.{{class_name}};
+
+{{#has_classes}}
+import java.util.List;
+
+import org.l2jmobius.gameserver.model.base.ClassId;
+{{/has_classes}}
+import org.l2jmobius.gameserver.enums.QuestSound;
+import org.l2jmobius.gameserver.model.actor.Npc;
+import org.l2jmobius.gameserver.model.actor.Player;
+import org.l2jmobius.gameserver.model.quest.Quest;
+import org.l2jmobius.gameserver.model.quest.QuestState;
+import org.l2jmobius.gameserver.model.quest.State;
+
+/**
+ * {{quest_title}} ({{quest_id}})
+ */
+public class {{class_name}} extends Quest
+{
+	// NPCs
+{{#start_npcs}}
+{{#declare}}
+	private static final int {{const}} = {{id}};
+{{/declare}}
+{{/start_npcs}}
+	// Monsters
+{{#kill_npcs}}
+{{#declare}}
+	private static final int {{const}} = {{id}};
+{{/declare}}
+{{/kill_npcs}}
+	// Items
+{{#quest_items}}
+{{#declare}}
+	private static final int {{const}} = {{id}};
+{{/declare}}
+{{/quest_items}}
+	// Rewards
+{{#rewards}}
+{{#declare}}
+	private static final int {{const}} = {{id}};
+{{/declare}}
+{{/rewards}}
+	// Misc
+	private static final int MIN_LEVEL = {{min_level}};
+{{#has_max_level}}
+	private static final int MAX_LEVEL = {{max_level}};
+{{/has_max_level}}
+	
+	public {{class_name}}()
+	{
+		super({{quest_id}});
+{{#start_npcs}}
+		addStartNpc({{const}});
+		addTalkId({{const}});
+{{/start_npcs}}
+{{#has_kill_npcs}}
+		addKillId({{#kill_npcs}}{{const}}{{comma}}{{/kill_npcs}});
+{{/has_kill_npcs}}
+{{#has_quest_items}}
+		registerQuestItems({{#quest_items}}{{const}}{{comma}}{{/quest_items}});
+{{/has_quest_items}}
+		addCondMinLevel(MIN_LEVEL, "no_level.htm");
+{{#has_max_level}}
+		addCondMaxLevel(MAX_LEVEL, "no_level.htm");
+{{/has_max_level}}
+{{#has_classes}}
+		addCondClassIds(List.of({{#classes}}ClassId.{{const}}{{comma}}{{/classes}}), "no_class.htm");
+{{/has_classes}}
+	}
+	
+	@Override
+	public String onEvent(String event, Npc npc, Player player)
+	{
+		final QuestState qs = getQuestState(player, false);
+		if (qs == null)
+		{
+			return null;
+		}
+		
+		String htmltext = event;
+		switch (event)
+		{
+			case "accept":
+			{
+				qs.startQuest();
+				qs.setCond({{first_cond}});
+				htmltext = "accepted.htm";
+				break;
+			}
+		}
+		return htmltext;
+	}
+	
+	@Override
+	public String onTalk(Npc npc, Player player)
+	{
+		final QuestState qs = getQuestState(player, true);
+		String htmltext = getNoQuestMsg(player);
+		
+		switch (qs.getState())
+		{
+			case State.CREATED:
+			{
+				htmltext = "start.htm";
+				break;
+			}
+			case State.STARTED:
+			{
+				switch (qs.getCond())
+				{
+{{#steps}}
+					// {{title}}
+					case {{cond}}:
+					{
+						if (isStepDone(player, qs))
+						{
+{{#has_next}}
+							qs.setCond({{next_cond}}, true);
+							htmltext = "{{cond}}-done.htm";
+{{/has_next}}
+{{#is_last}}
+							finish(player, qs);
+							htmltext = "finish.htm";
+{{/is_last}}
+						}
+						else
+						{
+							htmltext = "{{cond}}-progress.htm";
+						}
+						break;
+					}
+{{/steps}}
+				}
+				break;
+			}
+			case State.COMPLETED:
+			{
+				htmltext = getAlreadyCompletedMsg(player);
+				break;
+			}
+		}
+		
+		return htmltext;
+	}
+	
+	@Override
+	public String onKill(Npc npc, Player killer, boolean isSummon)
+	{
+		final QuestState qs = getQuestState(killer, false);
+		if ((qs == null) || !qs.isStarted())
+		{
+			return super.onKill(npc, killer, isSummon);
+		}
+		
+		final int npcId = npc.getId();
+		switch (qs.getCond())
+		{
+{{#steps}}
+{{#is_hunt}}
+			case {{cond}}:
+			{
+{{#goals}}
+{{#is_kill}}
+				if ((npcId == {{const}}) && (qs.getInt("{{var}}") < {{count}}))
+				{
+					qs.set("{{var}}", qs.getInt("{{var}}") + 1);
+					playSound(killer, QuestSound.ITEMSOUND_QUEST_ITEMGET);
+				}
+{{/is_kill}}
+{{#is_collect}}
+{{#has_drop_npcs}}
+				if (({{#drop_npcs}}(npcId == {{const}}){{or}}{{/drop_npcs}}) && (getQuestItemsCount(killer, {{const}}) < {{count}}))
+				{
+					giveItems(killer, {{const}}, 1);
+					playSound(killer, QuestSound.ITEMSOUND_QUEST_ITEMGET);
+				}
+{{/has_drop_npcs}}
+{{^has_drop_npcs}}
+				// TODO: nothing drops {{const}}
+{{/has_drop_npcs}}
+{{/is_collect}}
+{{/goals}}
+{{#has_next}}
+				if (isStepDone(killer, qs))
+				{
+					qs.setCond({{next_cond}}, true);
+				}
+{{/has_next}}
+				break;
+			}
+{{/is_hunt}}
+{{/steps}}
+		}
+		return super.onKill(npc, killer, isSummon);
+	}
+	
+	private boolean isStepDone(Player player, QuestState qs)
+	{
+		switch (qs.getCond())
+		{
+{{#steps}}
+{{#has_goals}}
+			case {{cond}}:
+			{
+				return {{#goals}}{{#is_kill}}(qs.getInt("{{var}}") >= {{count}}){{/is_kill}}{{#is_collect}}(getQuestItemsCount(player, {{const}}) >= {{count}}){{/is_collect}}{{#is_other}}true{{/is_other}}{{and}}{{/goals}};
+			}
+{{/has_goals}}
+{{/steps}}
+		}
+		return true;
+	}
+	
+	private void finish(Player player, QuestState qs)
+	{
+		giveRewards(player);
+		qs.exitQuest(false, true);
+	}
+	
+	private void giveRewards(Player player)
+	{
+{{#rewards}}
+		giveItems(player, {{const}}, {{count}});
+{{/rewards}}
+	}
+}
@@ -0,0 +1,201 @@
+package quests;
+
+{{#has_classes}}
+import org.mmocore.gameserver.model.base.ClassId;
+{{/has_classes}}
+import org.mmocore.gameserver.model.instances.NpcInstance;
+import org.mmocore.gameserver.model.quest.Quest;
+import org.mmocore.gameserver.model.quest.QuestState;
+import org.mmocore.gameserver.utils.ItemFunctions;
+
+public class {{class_name}} extends Quest {
+    //START NPC
+{{#start_npcs}}
+{{#declare}}
+    private static final int {{const}} = {{id}};
+{{/declare}}
+{{/start_npcs}}
+
+    //KILL NPC
+{{#kill_npcs}}
+{{#declare}}
+    private static final int {{const}} = {{id}};
+{{/declare}}
+{{/kill_npcs}}
+
+    //QUEST ITEMS
+{{#quest_items}}
+{{#declare}}
+    private static final int {{const}} = {{id}};
+{{/declare}}
+{{/quest_items}}
+
+    //REWARDS
+{{#rewards}}
+{{#declare}}
+    private static final int {{const}} = {{id}};
+{{/declare}}
+{{/rewards}}
+
+
+    public {{class_name}}() {
+        super({{is_party}});
+{{#start_npcs}}
+        addStartNpc({{const}});
+{{/start_npcs}}
+{{#has_kill_npcs}}
+        addKillId({{#kill_npcs}}{{const}}{{comma}}{{/kill_npcs}});
+{{/has_kill_npcs}}
+{{#has_quest_items}}
+        addQuestItem({{#quest_items}}{{const}}{{comma}}{{/quest_items}});
+{{/has_quest_items}}
+        addLevelCheck({{min_level}}{{#has_max_level}}, {{max_level}}{{/has_max_level}});
+{{#has_classes}}
+        addClassIdCheck({{#classes}}ClassId.{{name}}{{comma}}{{/classes}});
+{{/has_classes}}
+    }
+
+    @Override
+    public String onEvent(String event, QuestState st, NpcInstance npc) {
+        String htmltext = event;
+
+        if (event.equalsIgnoreCase("accept")) {
+            st.setState(STARTED);
+            st.setCond({{first_cond}});
+            st.playSound(SOUND_ACCEPT);
+            htmltext = "accepted.htm";
+        }
+
+        return htmltext;
+    }
+
+    @Override
+    public String onTalk(NpcInstance npc, QuestState st) {
+        String htmltext = NO_QUEST_DIALOG;
+        int npcId = npc.getNpcId();
+        int current_state = st.getState();
+        int step = st.getCond();
+
+        if (!isStartNpc(npcId)) {
+            return htmltext;
+        }
+
+        switch (current_state) {
+            case CREATED:
+                int level = st.getPlayer().getLevel();
+
+                if (level < {{min_level}}{{#has_max_level}} || level > {{max_level}}{{/has_max_level}}) {
+                    htmltext = "no_level.htm";
+                    st.exitCurrentQuest(true);
+                } else {
+                    htmltext = "start.htm";
+                }
+
+                break;
+            case STARTED:
+                switch (step) {
+{{#steps}}
+                    //{{title}}
+                    case {{cond}}:
+                        if (isStepDone(st)) {
+{{#has_next}}
+                            st.setCond({{next_cond}});
+                            st.playSound(SOUND_MIDDLE);
+                            htmltext = "{{cond}}-done.htm";
+{{/has_next}}
+{{#is_last}}
+                            finish(st);
+                            htmltext = "finish.htm";
+{{/is_last}}
+                        } else {
+                            htmltext = "{{cond}}-progress.htm";
+                        }
+
+                        break;
+{{/steps}}
+                }
+
+                break;
+
+            case COMPLETED:
+                htmltext = "completed.htm";
+                break;
+        }
+
+        return htmltext;
+    }
+
+    @Override
+    public String onKill(NpcInstance npc, QuestState st) {
+        int npcId = npc.getNpcId();
+
+        switch (st.getCond()) {
+{{#steps}}
+{{#is_hunt}}
+            case {{cond}}:
+{{#goals}}
+{{#is_kill}}
+                if (npcId == {{const}} && st.getInt("{{var}}") < {{count}}) {
+                    st.set("{{var}}", st.getInt("{{var}}") + 1);
+                    st.playSound(SOUND_ITEMGET);
+                }
+{{/is_kill}}
+{{#is_collect}}
+{{#has_drop_npcs}}
+                if (({{#drop_npcs}}npcId == {{const}}{{or}}{{/drop_npcs}}) && st.getQuestItemsCount({{const}}) < {{count}}) {
+                    st.giveItems({{const}}, 1);
+                    st.playSound(SOUND_ITEMGET);
+                }
+{{/has_drop_npcs}}
+{{^has_drop_npcs}}
+                //TODO: nothing drops {{const}}
+{{/has_drop_npcs}}
+{{/is_collect}}
+{{/goals}}
+{{#has_next}}
+                if (isStepDone(st)) {
+                    st.setCond({{next_cond}});
+                    st.playSound(SOUND_MIDDLE);
+                }
+{{/has_next}}
+
+                break;
+{{/is_hunt}}
+{{/steps}}
+        }
+
+        return null;
+    }
+
+    private boolean isStartNpc(int npc_id) {
+        return {{start_npc_check}};
+    }
+
+    private boolean isStepDone(QuestState st) {
+        switch (st.getCond()) {
+{{#steps}}
+{{#has_goals}}
+            case {{cond}}:
+                return {{#goals}}{{#is_kill}}st.getInt("{{var}}") >= {{count}}{{/is_kill}}{{#is_collect}}st.getQuestItemsCount({{const}}) >= {{count}}{{/is_collect}}{{#is_other}}true{{/is_other}}{{and}}{{/goals}};
+{{/has_goals}}
+{{/steps}}
+        }
+
+        return true;
+    }
+
+    private void finish(QuestState st) {
+{{#quest_items}}
+        st.takeItems({{const}}, -1);
+{{/quest_items}}
+        giveRewards(st);
+        st.playSound(SOUND_FINISH);
+        st.exitCurrentQuest(false);
+    }
+
+    private void giveRewards(QuestState quest) {
+{{#rewards}}
+        quest.giveItems({{const}}, {{count}});
+{{/rewards}}
+    }
+}
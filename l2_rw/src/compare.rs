@@ -0,0 +1,374 @@
+use crate::stream::DatReader;
+use crate::ue2_rw::ReadUnreal;
+use crate::DatRecords;
+use std::fmt::{Display, Formatter};
+use std::io::{Cursor, Read};
+use std::path::Path;
+
+/**Part of decoded payload where serialized data went different from the loaded one
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PayloadPart {
+    DictCount,
+    DictRecord {
+        record: usize,
+        field: Option<String>,
+    },
+    RecordCount,
+    Record {
+        record: usize,
+        field: Option<String>,
+    },
+    /**Whatever follows the records, usually "SafePackage". Also records `_baseinfo` files
+    declare but don't have
+     */
+    Tail,
+}
+
+impl Display for PayloadPart {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (name, record, field) = match self {
+            PayloadPart::DictCount => return f.write_str("string dictionary count"),
+            PayloadPart::RecordCount => return f.write_str("records count"),
+            PayloadPart::Tail => return f.write_str("data after records"),
+
+            PayloadPart::DictRecord { record, field } => {
+                ("string dictionary record", record, field)
+            }
+            PayloadPart::Record { record, field } => ("record", record, field),
+        };
+
+        write!(f, "{name} {record}")?;
+
+        if let Some(field) = field {
+            write!(f, " ({field})")?;
+        }
+
+        Ok(())
+    }
+}
+
+/**First difference between decoded payload of a file and payload serialized from its records
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PayloadMismatch {
+    pub offset: usize,
+    pub part: PayloadPart,
+    pub original_len: usize,
+    pub serialized_len: usize,
+}
+
+impl Display for PayloadMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} differs at offset {} (original {} bytes, serialized {} bytes)",
+            self.part, self.offset, self.original_len, self.serialized_len
+        )
+    }
+}
+
+/**Compares decoded payload of a file (see [`crate::read_encoded_file`]) with `serialized` one,
+both with envelope tail. Record and field of the first different byte are found by reading
+`original` as `T` records, `path` is needed only to read `_baseinfo` files the same way loader
+does
+ */
+pub fn compare_payload<T: ReadUnreal>(
+    path: &Path,
+    original: &[u8],
+    serialized: &[u8],
+) -> Option<PayloadMismatch> {
+    let offset = first_difference(original, serialized)?;
+
+    Some(PayloadMismatch {
+        offset,
+        part: locate::<(), T>(path, original, offset, false),
+        original_len: original.len(),
+        serialized_len: serialized.len(),
+    })
+}
+
+/**Same as [`compare_payload`] for files with string dictionary before the records
+ */
+pub fn compare_payload_with_string_dict<S: ReadUnreal, T: ReadUnreal>(
+    path: &Path,
+    original: &[u8],
+    serialized: &[u8],
+) -> Option<PayloadMismatch> {
+    let offset = first_difference(original, serialized)?;
+
+    Some(PayloadMismatch {
+        offset,
+        part: locate::<S, T>(path, original, offset, true),
+        original_len: original.len(),
+        serialized_len: serialized.len(),
+    })
+}
+
+fn first_difference(original: &[u8], serialized: &[u8]) -> Option<usize> {
+    if original == serialized {
+        return None;
+    }
+
+    Some(
+        original
+            .iter()
+            .zip(serialized)
+            .position(|(a, b)| a != b)
+            .unwrap_or(original.len().min(serialized.len())),
+    )
+}
+
+fn locate<S: ReadUnreal, T: ReadUnreal>(
+    path: &Path,
+    original: &[u8],
+    offset: usize,
+    with_dict: bool,
+) -> PayloadPart {
+    let Ok(mut reader) = DatReader::new(path, Cursor::new(original)) else {
+        return PayloadPart::Tail;
+    };
+
+    if with_dict {
+        let Ok(dict) = reader.string_dict::<S>() else {
+            return PayloadPart::DictCount;
+        };
+
+        reader = match find_record(dict, original, offset) {
+            Ok(v) => v,
+            Err(Found::Count) => return PayloadPart::DictCount,
+            Err(Found::Record { record, field }) => {
+                return PayloadPart::DictRecord { record, field };
+            }
+        };
+    }
+
+    let Ok(records) = reader.records::<T>() else {
+        return PayloadPart::RecordCount;
+    };
+
+    match find_record(records, original, offset) {
+        Ok(_) => PayloadPart::Tail,
+        Err(Found::Count) => PayloadPart::RecordCount,
+        Err(Found::Record { record, field }) => PayloadPart::Record { record, field },
+    }
+}
+
+enum Found {
+    Count,
+    Record {
+        record: usize,
+        field: Option<String>,
+    },
+}
+
+/**Goes through the records until the one that ends after `offset`. Its field is the one that
+can't be read when record bytes are cut at `offset`. Reader is returned if `offset` is after
+the records
+ */
+fn find_record<V: ReadUnreal, R: Read>(
+    mut records: DatRecords<V, R>,
+    original: &[u8],
+    offset: usize,
+) -> Result<DatReader<R>, Found> {
+    let mut start = records.position() as usize;
+
+    if offset < start {
+        return Err(Found::Count);
+    }
+
+    let mut record = 0;
+
+    while let Some(v) = records.next() {
+        let end = records.position() as usize;
+
+        if v.is_err() || end > offset {
+            let field = V::read_unreal(&mut Cursor::new(&original[start..offset]))
+                .err()
+                .and_then(|e| e.field_path());
+
+            return Err(Found::Record { record, field });
+        }
+
+        start = end;
+        record += 1;
+    }
+
+    records.into_reader().map_err(|_| Found::Record {
+        record,
+        field: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ue2_rw::MTX;
+    use crate::{serialize_payload, DatEnvelope, DatVariant};
+
+    fn mtx(a: u32, b: u32) -> MTX {
+        MTX {
+            vec_1: vec![a].into(),
+            vec_2: vec![b].into(),
+        }
+    }
+
+    fn envelope(tail: &[u8]) -> DatEnvelope {
+        let mut res = DatEnvelope::new(None);
+        res.tail = tail.to_vec();
+
+        res
+    }
+
+    fn payload(dict: Option<Vec<MTX>>, records: Vec<MTX>, tail: &[u8]) -> Vec<u8> {
+        let data = match dict {
+            Some(dict) => DatVariant::DoubleArray(dict, records),
+            None => DatVariant::Array(records),
+        };
+
+        serialize_payload(data, &envelope(tail)).unwrap()
+    }
+
+    #[test]
+    fn same_payload() {
+        let original = payload(None, vec![mtx(1, 2)], b"SafePackage");
+
+        assert_eq!(
+            compare_payload::<MTX>(Path::new("mtx.dat"), &original, &original),
+            None
+        );
+    }
+
+    #[test]
+    fn tail_difference() {
+        let original = payload(None, vec![mtx(1, 2), mtx(3, 4)], b"SafePackage");
+        let serialized = payload(None, vec![mtx(1, 2), mtx(3, 4)], b"SafePackagf");
+
+        let mismatch = compare_payload::<MTX>(Path::new("mtx.dat"), &original, &serialized);
+
+        assert_eq!(
+            mismatch,
+            Some(PayloadMismatch {
+                offset: original.len() - 1,
+                part: PayloadPart::Tail,
+                original_len: original.len(),
+                serialized_len: serialized.len(),
+            })
+        );
+    }
+
+    #[test]
+    fn missing_tail() {
+        let original = payload(None, vec![mtx(1, 2)], b"SafePackage");
+        let serialized = payload(None, vec![mtx(1, 2)], b"");
+
+        let mismatch =
+            compare_payload::<MTX>(Path::new("mtx.dat"), &original, &serialized).unwrap();
+
+        assert_eq!(mismatch.offset, serialized.len());
+        assert_eq!(mismatch.part, PayloadPart::Tail);
+    }
+
+    #[test]
+    fn records_count_difference() {
+        let original = payload(None, vec![mtx(1, 2), mtx(3, 4)], b"");
+        let serialized = payload(None, vec![mtx(1, 2)], b"");
+
+        let mismatch =
+            compare_payload::<MTX>(Path::new("mtx.dat"), &original, &serialized).unwrap();
+
+        assert_eq!(mismatch.offset, 0);
+        assert_eq!(mismatch.part, PayloadPart::RecordCount);
+    }
+
+    #[test]
+    fn record_difference_after_dict() {
+        let dict = vec![mtx(1, 2), mtx(3, 4)];
+        let original = payload(Some(dict.clone()), vec![mtx(5, 6), mtx(7, 8)], b"");
+        let serialized = payload(Some(dict), vec![mtx(5, 6), mtx(7, 9)], b"");
+
+        let mismatch = compare_payload_with_string_dict::<MTX, MTX>(
+            Path::new("mtx.dat"),
+            &original,
+            &serialized,
+        )
+        .unwrap();
+
+        assert_eq!(
+            mismatch.part,
+            PayloadPart::Record {
+                record: 1,
+                field: Some("MTX.vec_2".to_string())
+            }
+        );
+        assert_eq!(mismatch.offset, original.len() - 4);
+    }
+
+    #[test]
+    fn dict_record_difference() {
+        let records = vec![mtx(5, 6)];
+        let original = payload(Some(vec![mtx(1, 2), mtx(3, 4)]), records.clone(), b"");
+        let serialized = payload(Some(vec![mtx(1, 2), mtx(0, 4)]), records, b"");
+
+        let mismatch = compare_payload_with_string_dict::<MTX, MTX>(
+            Path::new("mtx.dat"),
+            &original,
+            &serialized,
+        )
+        .unwrap();
+
+        assert_eq!(
+            mismatch.part,
+            PayloadPart::DictRecord {
+                record: 1,
+                field: Some("MTX.vec_1".to_string())
+            }
+        );
+    }
+
+    #[test]
+    fn dict_count_difference() {
+        let original = payload(Some(vec![mtx(1, 2), mtx(3, 4)]), vec![], b"");
+        let serialized = payload(Some(vec![mtx(1, 2)]), vec![], b"");
+
+        let mismatch = compare_payload_with_string_dict::<MTX, MTX>(
+            Path::new("mtx.dat"),
+            &original,
+            &serialized,
+        )
+        .unwrap();
+
+        assert_eq!(mismatch.offset, 0);
+        assert_eq!(mismatch.part, PayloadPart::DictCount);
+    }
+
+    #[test]
+    fn mismatch_display() {
+        let mismatch = PayloadMismatch {
+            offset: 42,
+            part: PayloadPart::Record {
+                record: 3,
+                field: Some("MTX.vec_2".to_string()),
+            },
+            original_len: 100,
+            serialized_len: 101,
+        };
+
+        assert_eq!(
+            mismatch.to_string(),
+            "record 3 (MTX.vec_2) differs at offset 42 (original 100 bytes, serialized 101 bytes)"
+        );
+        assert_eq!(
+            PayloadPart::DictCount.to_string(),
+            "string dictionary count"
+        );
+        assert_eq!(
+            PayloadPart::DictRecord {
+                record: 0,
+                field: None
+            }
+            .to_string(),
+            "string dictionary record 0"
+        );
+    }
+}
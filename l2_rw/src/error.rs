@@ -0,0 +1,118 @@
+use crate::ue2_rw::ReadError;
+use crate::EncVersion;
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+
+/**Everything that can go wrong while reading or writing .dat file.
+
+Each variant keeps path of the file and, where it makes sense, byte offset in decoded (or raw
+for [`Error::RsaBlock`]) stream and index of the record being read. For record errors offset is
+position where reading stopped, field is taken from [`ReadError`] trace.
+ */
+#[derive(Debug)]
+pub enum Error {
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    UnknownHeader {
+        path: PathBuf,
+    },
+    UnknownEncVersion {
+        path: PathBuf,
+        version: String,
+    },
+    RsaBlock {
+        path: PathBuf,
+        offset: u64,
+    },
+    MissingPrivateKey {
+        path: PathBuf,
+        version: EncVersion,
+    },
+    Inflate {
+        path: PathBuf,
+        reason: String,
+    },
+    TruncatedRecord {
+        path: PathBuf,
+        offset: u64,
+        record: usize,
+        field: Option<String>,
+    },
+    InvalidRecord {
+        path: PathBuf,
+        offset: u64,
+        record: usize,
+        source: ReadError,
+    },
+}
+
+impl Error {
+    pub fn path(&self) -> &PathBuf {
+        match self {
+            Error::Io { path, .. }
+            | Error::UnknownHeader { path }
+            | Error::UnknownEncVersion { path, .. }
+            | Error::RsaBlock { path, .. }
+            | Error::MissingPrivateKey { path, .. }
+            | Error::Inflate { path, .. }
+            | Error::TruncatedRecord { path, .. }
+            | Error::InvalidRecord { path, .. } => path,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "{path:?}: io error: {source}"),
+            Error::UnknownHeader { path } => write!(f, "{path:?}: unknown file header"),
+            Error::UnknownEncVersion { path, version } => {
+                write!(f, "{path:?}: unknown encryption version {version}")
+            }
+            Error::RsaBlock { path, offset } => {
+                write!(f, "{path:?}: can't decrypt RSA block at offset {offset}")
+            }
+            Error::MissingPrivateKey { path, version } => {
+                write!(f, "{path:?}: no private key to encode {version:?}")
+            }
+            Error::Inflate { path, reason } => write!(f, "{path:?}: inflate failed: {reason}"),
+            Error::TruncatedRecord {
+                path,
+                offset,
+                record,
+                field,
+            } => {
+                write!(f, "{path:?}: record {record} is truncated at offset {offset}")?;
+
+                if let Some(field) = field {
+                    write!(f, " ({field})")?;
+                }
+
+                Ok(())
+            }
+            Error::InvalidRecord {
+                path,
+                offset,
+                record,
+                source,
+            } => write!(
+                f,
+                "{path:?}: can't read record {record} at offset {offset}: {source}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::InvalidRecord { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
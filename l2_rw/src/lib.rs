@@ -1,16 +1,28 @@
-use crate::ue2_rw::{CompactInt, ReadUnreal, UnrealWriter, WriteUnreal, INDEX};
+use crate::ue2_rw::{CompactInt, ReadUnreal, UnrealWriter, WriteUnreal};
 use byteorder::WriteBytesExt;
-use inflate::inflate_bytes_zlib_no_checksum;
 use miniz_oxide::deflate::compress_to_vec_zlib;
-use openssl::bn::BigNum;
-use openssl::rsa::Padding;
-use std::fmt::Debug;
-use std::fs::File;
-use std::io::{BufReader, Cursor, Read, Seek, Write};
-use std::path::Path;
-
+use num_bigint::BigUint;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::Hasher;
+use std::io::{Cursor, Read, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+mod compare;
+pub mod error;
+mod rsa;
+pub mod schema;
+mod stream;
+pub mod table;
 pub mod ue2_rw;
 
+pub use compare::{
+    compare_payload, compare_payload_with_string_dict, PayloadMismatch, PayloadPart,
+};
+pub use error::Error;
+pub use stream::{DatReader, DatRecords};
+
 pub const PACKAGE_FILE_TAG: u32 = 0x9E2A83C1;
 pub const LINEAGE_HEADER: &[u8; 22] = b"L\x00i\x00n\x00e\x00a\x00g\x00e\x002\x00V\x00e\x00r\x00";
 pub const END_BYTES: &[u8; 20] = &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 100];
@@ -21,6 +33,12 @@ pub const V412: &[u8; 6] = b"4\x001\x002\x00";
 pub const V413: &[u8; 6] = b"4\x001\x003\x00";
 pub const V414: &[u8; 6] = b"4\x001\x004\x00";
 
+pub const XOR_KEY_111: u8 = 0xAC;
+pub const SAFE_PACKAGE_TAIL: &[u8; 13] = &[12, 83, 97, 102, 101, 80, 97, 99, 107, 97, 103, 101, 0];
+
+const V413_PRIVATE_EXPONENT: &str = "30b4c2d798d47086145c75063c8e841e719776e400291d7838d3e6c4405b504c6a07f8fca27f32b86643d2649d1d5f124cdd0bf272f0909dd7352fe10a77b34d831043d9ae541f8263c6fe3d1c14c2f04e43a7253a6dda9a8c1562cbd493c1b631a1957618ad5dfe5ca28553f746e2fc6f2db816c7db223ec91e955081c1de65";
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum EncVersion {
     V111,
     V121,
@@ -31,7 +49,66 @@ pub enum EncVersion {
 }
 
 impl EncVersion {
-    fn get_modulus(&self) -> Option<BigNum> {
+    pub const ALL: [EncVersion; 6] = [
+        EncVersion::V111,
+        EncVersion::V121,
+        EncVersion::V411,
+        EncVersion::V412,
+        EncVersion::V413,
+        EncVersion::V414,
+    ];
+
+    pub fn from_header(header: &[u8]) -> Option<Self> {
+        Self::ALL.into_iter().find(|v| v.header() == header)
+    }
+
+    pub fn header(&self) -> &'static [u8; 6] {
+        match self {
+            EncVersion::V111 => V111,
+            EncVersion::V121 => V121,
+            EncVersion::V411 => V411,
+            EncVersion::V412 => V412,
+            EncVersion::V413 => V413,
+            EncVersion::V414 => V414,
+        }
+    }
+
+    pub fn is_rsa(&self) -> bool {
+        self.get_modulus().is_some()
+    }
+
+    /**Only 413 key pair (the one l2encdec uses) has known private exponent, so only 413 can be
+    written from RSA versions
+     */
+    pub fn can_encode(&self) -> bool {
+        !self.is_rsa() || self.get_private_exponent().is_some()
+    }
+
+    /**111 uses constant key, 121 key is calculated from lowercase file name
+     */
+    fn get_xor_key(&self, file_name: &str) -> Option<u8> {
+        match self {
+            EncVersion::V111 => Some(XOR_KEY_111),
+            EncVersion::V121 => Some(
+                file_name
+                    .to_lowercase()
+                    .chars()
+                    .fold(0u32, |acc, c| acc.wrapping_add(c as u32)) as u8,
+            ),
+
+            _ => None,
+        }
+    }
+
+    fn get_private_exponent(&self) -> Option<BigUint> {
+        match self {
+            EncVersion::V413 => Some(hex_to_big_uint(V413_PRIVATE_EXPONENT)),
+
+            _ => None,
+        }
+    }
+
+    fn get_modulus(&self) -> Option<BigUint> {
         match self {
             EncVersion::V111 => {
                 None
@@ -41,152 +118,187 @@ impl EncVersion {
             }
 
             EncVersion::V411 => {
-                Some(BigNum::from_hex_str(
+                Some(hex_to_big_uint(
                     "8c9d5da87b30f5d7cd9dc88c746eaac5bb180267fa11737358c4c95d9adf59dd37689f9befb251508759555d6fe0eca87bebe0a10712cf0ec245af84cd22eb4cb675e98eaf5799fca62a20a2baa4801d5d70718dcd43283b8428f1387aec6600f937bfc7bb72404d187d3a9c438f1ffce9ce365dccf754232ff6def038a41385",
-                ).unwrap())
+                ))
             }
             EncVersion::V412 => {
-                Some(BigNum::from_hex_str(
+                Some(hex_to_big_uint(
                     "a465134799cf2c45087093e7d0f0f144e6d528110c08f674730d436e40827330eccea46e70acf10cdda7d8f710e3b44dcca931812d76cd7494289bca8b73823f57efc0515b97e4a2a02612ccfa719cf7885104b06f2e7e2cc967b62e3d3b1aadb925db94cbc8cd3070a4bb13f7e202c7733a67b1b94c1ebc0afcbe1a63b448cf",
-                ).unwrap())
+                ))
             }
             EncVersion::V413 => {
-                Some(BigNum::from_hex_str(
+                Some(hex_to_big_uint(
                     "75b4d6de5c016544068a1acf125869f43d2e09fc55b8b1e289556daf9b8757635593446288b3653da1ce91c87bb1a5c18f16323495c55d7d72c0890a83f69bfd1fd9434eb1c02f3e4679edfa43309319070129c267c85604d87bb65bae205de3707af1d2108881abb567c3b3d069ae67c3a4c6a3aa93d26413d4c66094ae2039",
-                ).unwrap())
+                ))
             }
             EncVersion::V414 => {
-                Some(BigNum::from_hex_str(
+                Some(hex_to_big_uint(
                     "ad70257b2316ce09dfaf2ebc3f63b3d673b0c98a403950e26bb87379b11e17aed0e45af23e7171e5ec1fbc8d1ae32ffb7801b31266eef9c334b53469d4b7cbe83284273d35a9aab49b453e7012f374496c65f8089f5d134b0eb3d1e3b22051ed5977a6dd68c4f85785dfcc9f4412c81681944fc4b8ce27caf0242deaa5762e8d"
-                ).unwrap())
+                ))
             }
         }
     }
 
-    fn get_exponent(&self) -> Option<BigNum> {
+    fn get_exponent(&self) -> Option<BigUint> {
         match self {
             EncVersion::V111 => None,
             EncVersion::V121 => None,
 
-            EncVersion::V411 => Some(BigNum::from_hex_str("1d").unwrap()),
-            EncVersion::V412 => Some(BigNum::from_hex_str("25").unwrap()),
-            EncVersion::V413 => Some(BigNum::from_hex_str("1d").unwrap()),
-            EncVersion::V414 => Some(BigNum::from_hex_str("25").unwrap()),
+            EncVersion::V411 => Some(hex_to_big_uint("1d")),
+            EncVersion::V412 => Some(hex_to_big_uint("25")),
+            EncVersion::V413 => Some(hex_to_big_uint("1d")),
+            EncVersion::V414 => Some(hex_to_big_uint("25")),
         }
     }
 }
 
-struct Decoder<'a, T: Read> {
-    data: T,
-    output: &'a mut Vec<u8>,
+impl Display for EncVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&String::from_utf8_lossy(self.header()).replace('\0', ""))
+    }
 }
 
-impl<'a, T: Read> Decoder<'a, T> {
-    fn byte_to_int(b: u8) -> i32 {
-        if b > 128 {
-            b as i32 - 256
-        } else {
-            b as i32
-        }
-    }
+/**Accepts header value, like `413`, with optional `v` prefix
+ */
+impl FromStr for EncVersion {
+    type Err = String;
 
-    pub fn decode(&mut self, modulus: BigNum, exp: BigNum) {
-        let mut buff = [0u8; 128];
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let v = s.trim_start_matches(['v', 'V']);
 
-        let mut ct = self.data.read(&mut buff).unwrap();
+        Self::ALL
+            .into_iter()
+            .find(|e| e.to_string() == v)
+            .ok_or_else(|| format!("unknown encryption version {s}"))
+    }
+}
 
-        let rsa = openssl::rsa::Rsa::from_public_components(modulus, exp).unwrap();
+fn hex_to_big_uint(hex: &str) -> BigUint {
+    BigUint::parse_bytes(hex.as_bytes(), 16).unwrap()
+}
 
-        let mut chunk = [0u8; 128];
+/**Everything around the records that is needed to write file back the way it was read.
+
+`version` is `None` for plain files, `tail` is whatever follows the last record (usually
+"SafePackage" ASCF) and `footer` follows encrypted data (only RSA versions have it).
+
+Deflate output of the client encoder differs from ours, so envelope of a loaded file also keeps
+where it came from with hashes of the file and its decoded payload. If serialized payload didn't
+change and original file is still the same, its bytes are written as is.
+ */
+#[derive(Debug, Clone)]
+pub struct DatEnvelope {
+    pub version: Option<EncVersion>,
+    pub tail: Vec<u8>,
+    pub footer: Vec<u8>,
+    original: Option<OriginalFile>,
+}
 
-        while ct != 0 {
-            rsa.public_decrypt(&buff, &mut chunk, Padding::NONE)
-                .unwrap();
+#[derive(Debug, Clone)]
+struct OriginalFile {
+    payload_hash: u64,
+    file_hash: u64,
+    path: PathBuf,
+}
 
-            let size = Self::byte_to_int(chunk[3]) & 0xFF;
-            let pad = (-size & 0x1) + (-size & 0x2);
-            let start = (128 - size - pad) as usize;
-            let end = start + size as usize;
+impl DatEnvelope {
+    /**Envelope for a new file: "SafePackage" tail and default footer for RSA versions
+     */
+    pub fn new(version: Option<EncVersion>) -> Self {
+        Self {
+            version,
+            tail: SAFE_PACKAGE_TAIL.to_vec(),
+            footer: if version.is_some_and(|v| v.is_rsa()) {
+                END_BYTES.to_vec()
+            } else {
+                vec![]
+            },
+            original: None,
+        }
+    }
 
-            self.output.extend_from_slice(&chunk[start..end]);
+    fn original_bytes(&self, payload: &[u8]) -> Option<Vec<u8>> {
+        let original = self.original.as_ref()?;
 
-            ct = self.data.read(&mut buff).unwrap();
-            chunk = [0u8; 128];
+        if original.payload_hash != hash_bytes(payload) {
+            return None;
         }
 
-        let res = inflate_bytes_zlib_no_checksum(&self.output[4..self.output.len() - 4]).unwrap();
-
-        *self.output = res;
+        std::fs::read(&original.path)
+            .ok()
+            .filter(|v| hash_bytes(v) == original.file_hash)
     }
 }
 
+/**Same as hashing with [`std::hash::Hasher::write`] in chunks, so it can be done while streaming
+ */
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    hasher.write(bytes);
+
+    hasher.finish()
+}
+
 pub fn deserialize_dat_with_string_dict<S: ReadUnreal + Debug, T: ReadUnreal + Debug>(
     file_path: &Path,
-) -> Result<(Vec<S>, Vec<T>), ()> {
-    println!("Loading {file_path:?}...");
-    let Ok(bytes) = read_encoded_file(file_path) else {
-        return Err(());
-    };
-
-    let mut reader = BufReader::new(Cursor::new(bytes));
+) -> error::Result<(Vec<S>, Vec<T>)> {
+    deserialize_dat_with_string_dict_and_envelope(file_path).map(|(dict, res, _)| (dict, res))
+}
 
-    let count = INDEX::read_unreal(&mut reader);
-    let mut string_dict = Vec::with_capacity(count.0 as usize);
+/**Same as [`deserialize_dat_with_string_dict`], but also returns [`DatEnvelope`] of the file
+ */
+pub fn deserialize_dat_with_string_dict_and_envelope<
+    S: ReadUnreal + Debug,
+    T: ReadUnreal + Debug,
+>(
+    file_path: &Path,
+) -> error::Result<(Vec<S>, Vec<T>, DatEnvelope)> {
+    println!("Loading {file_path:?}...");
+    let mut dict_records = DatReader::open(file_path)?.string_dict::<S>()?;
 
-    println!("\tDict elements count: {}", count.0);
+    println!("\tDict elements count: {}", dict_records.declared_count());
 
-    for _ in 0..count.0 {
-        let t = S::read_unreal(&mut reader);
-        string_dict.push(t);
-    }
+    let string_dict = collect_records(&mut dict_records)?;
 
-    let count = u32::read_unreal(&mut reader);
+    let mut records = dict_records.into_reader()?.records::<T>()?;
 
-    println!("\tElements count: {count}");
+    println!("\tElements count: {}", records.declared_count());
 
-    let mut res = Vec::with_capacity(count as usize);
+    let res = collect_records(&mut records)?;
 
-    for _ in 0..count {
-        let t = T::read_unreal(&mut reader);
-        res.push(t);
-    }
+    Ok((string_dict, res, records.into_reader()?.finish()?))
+}
 
-    Ok((string_dict, res))
+pub fn deserialize_dat<T: ReadUnreal + Debug>(file_path: &Path) -> error::Result<Vec<T>> {
+    deserialize_dat_with_envelope(file_path).map(|(res, _)| res)
 }
 
-pub fn deserialize_dat<T: ReadUnreal + Debug>(file_path: &Path) -> Result<Vec<T>, ()> {
+/**Same as [`deserialize_dat`], but also returns [`DatEnvelope`] of the file
+ */
+pub fn deserialize_dat_with_envelope<T: ReadUnreal + Debug>(
+    file_path: &Path,
+) -> error::Result<(Vec<T>, DatEnvelope)> {
     println!("Loading {file_path:?}");
-    let Ok(bytes) = read_encoded_file(file_path) else {
-        println!("Err!");
-        return Err(());
-    };
-
-    let bugged = file_path
-        .to_str()
-        .unwrap()
-        .to_lowercase()
-        .ends_with("_baseinfo.dat");
-
-    let bytes_count = bytes.len();
-
-    let mut reader = BufReader::new(Cursor::new(bytes));
-    let count = u32::read_unreal(&mut reader);
+    let mut records = DatRecords::<T>::open(file_path)?;
 
-    println!("\tElements count: {count}");
+    println!("\tElements count: {}", records.declared_count());
 
-    let mut res = Vec::with_capacity(count as usize);
+    let res = collect_records(&mut records)?;
 
-    for _ in 0..count {
-        if bugged && bytes_count - (reader.stream_position().unwrap() as usize) < 16 {
-            break;
-        }
+    println!("\tLoaded: {}", res.len());
+    Ok((res, records.into_reader()?.finish()?))
+}
 
-        let t = T::read_unreal(&mut reader);
+fn collect_records<T: ReadUnreal, R: Read>(
+    records: &mut DatRecords<T, R>,
+) -> error::Result<Vec<T>> {
+    let mut res = Vec::with_capacity(records.declared_count());
 
-        res.push(t);
+    for v in records {
+        res.push(v?);
     }
 
-    println!("\tLoaded: {}", res.len());
     Ok(res)
 }
 
@@ -203,40 +315,34 @@ fn int_to_byte(i: i32) -> u8 {
     }
 }
 
-fn encode<D: Write>(data: Vec<u8>, output: &mut D, modulus: BigNum, exp: BigNum) {
+fn encode<D: Write>(
+    data: Vec<u8>,
+    output: &mut D,
+    modulus: BigUint,
+    exp: BigUint,
+) -> std::io::Result<()> {
     let compressed = compress_to_vec_zlib(&data, 6);
 
     let res = Vec::with_capacity(compressed.len() + 10);
     let mut cursor = Cursor::new(res);
 
-    cursor
-        .write_u8(int_to_byte(data.len() as i32 & 0xFF))
-        .unwrap();
-    cursor
-        .write_u8(int_to_byte(
-            (data.len() as i32 & 0xFF00).overflowing_shr(8).0,
-        ))
-        .unwrap();
-    cursor
-        .write_u8(int_to_byte(
-            (data.len() as i32 & 0xFF0000).overflowing_shr(16).0,
-        ))
-        .unwrap();
-    cursor
-        .write_u8(int_to_byte(
-            (data.len() as i32 & -16777216i32).overflowing_shr(24).0,
-        ))
-        .unwrap();
+    cursor.write_u8(int_to_byte(data.len() as i32 & 0xFF))?;
+    cursor.write_u8(int_to_byte(
+        (data.len() as i32 & 0xFF00).overflowing_shr(8).0,
+    ))?;
+    cursor.write_u8(int_to_byte(
+        (data.len() as i32 & 0xFF0000).overflowing_shr(16).0,
+    ))?;
+    cursor.write_u8(int_to_byte(
+        (data.len() as i32 & -16777216i32).overflowing_shr(24).0,
+    ))?;
 
-    cursor.write_all(&compressed).unwrap();
+    cursor.write_all(&compressed)?;
 
     let mut cursor = Cursor::new(cursor.into_inner());
 
     let mut buff = [0u8; 124];
     let mut block = [0u8; 128];
-    let mut chunk = [0u8; 128];
-
-    let rsa = openssl::rsa::Rsa::from_public_components(modulus, exp).unwrap();
 
     while let Ok(len) = cursor.read(&mut buff) {
         if len == 0 {
@@ -249,20 +355,77 @@ fn encode<D: Write>(data: Vec<u8>, output: &mut D, modulus: BigNum, exp: BigNum)
 
         block[start..(len + start)].copy_from_slice(&buff[..len]);
 
-        rsa.public_encrypt(&block, &mut chunk, Padding::NONE)
-            .unwrap();
+        //First byte of the block is always zero, so it can't be greater than modulus
+        let chunk = rsa::transform_block(&block, &modulus, &exp).unwrap();
 
-        output.write_all(&chunk).unwrap();
+        output.write_all(&chunk)?;
 
         block = [0u8; 128];
-        chunk = [0u8; 128];
     }
+
+    Ok(())
 }
 
+/**Serializes `data` and writes it with `version` encryption, `None` writes plain unencrypted file
+ */
 pub fn save_dat<S: WriteUnreal + Debug, T: WriteUnreal + Debug>(
     file_path: &Path,
     data: DatVariant<S, T>,
-) -> std::io::Result<usize> {
+    version: Option<EncVersion>,
+) -> error::Result<usize> {
+    save_dat_with_envelope(file_path, data, &DatEnvelope::new(version))
+}
+
+/**Serializes `data` and writes it wrapped into `envelope`.
+
+If `envelope` was loaded from a file and payload is unchanged, original file bytes are written
+ */
+pub fn save_dat_with_envelope<S: WriteUnreal + Debug, T: WriteUnreal + Debug>(
+    file_path: &Path,
+    data: DatVariant<S, T>,
+    envelope: &DatEnvelope,
+) -> error::Result<usize> {
+    let serialized_data = serialize_dat(data).map_err(|source| Error::Io {
+        path: file_path.to_path_buf(),
+        source,
+    })?;
+
+    write_with_envelope(file_path, serialized_data, envelope)
+}
+
+/**Appends envelope tail to serialized `data` and writes it, see [`save_dat_with_envelope`]
+ */
+pub(crate) fn write_with_envelope(
+    file_path: &Path,
+    mut data: Vec<u8>,
+    envelope: &DatEnvelope,
+) -> error::Result<usize> {
+    data.extend_from_slice(&envelope.tail);
+
+    let out = match envelope.original_bytes(&data) {
+        Some(original) => original,
+        None => encode_bytes(file_path, data, envelope)?,
+    };
+
+    write_file(file_path, out)
+}
+
+/**Payload [`save_dat_with_envelope`] encodes: serialized `data` followed by envelope tail.
+Nothing is written, so it can be compared with payload of the loaded file
+ */
+pub fn serialize_payload<S: WriteUnreal + Debug, T: WriteUnreal + Debug>(
+    data: DatVariant<S, T>,
+    envelope: &DatEnvelope,
+) -> std::io::Result<Vec<u8>> {
+    let mut res = serialize_dat(data)?;
+    res.extend_from_slice(&envelope.tail);
+
+    Ok(res)
+}
+
+fn serialize_dat<S: WriteUnreal + Debug, T: WriteUnreal + Debug>(
+    data: DatVariant<S, T>,
+) -> std::io::Result<Vec<u8>> {
     let mut serialized_data = Vec::new();
 
     match data {
@@ -285,60 +448,359 @@ pub fn save_dat<S: WriteUnreal + Debug, T: WriteUnreal + Debug>(
         }
     }
 
-    serialized_data.write_all(&[12, 83, 97, 102, 101, 80, 97, 99, 107, 97, 103, 101, 0])?;
+    Ok(serialized_data)
+}
 
-    let mut out = File::create(file_path)?;
+/**Writes already serialized `data` with `version` encryption, `None` writes it as is
+ */
+pub fn write_encoded_file(
+    file_path: &Path,
+    data: Vec<u8>,
+    version: Option<EncVersion>,
+) -> error::Result<usize> {
+    let out = encode_bytes(file_path, data, &DatEnvelope::new(version))?;
 
-    out.write_all(LINEAGE_HEADER)?;
-    out.write_all(V413)?;
+    write_file(file_path, out)
+}
 
-    encode(
-        serialized_data,
-        &mut out,
-        BigNum::from_hex_str("75b4d6de5c016544068a1acf125869f43d2e09fc55b8b1e289556daf9b8757635593446288b3653da1ce91c87bb1a5c18f16323495c55d7d72c0890a83f69bfd1fd9434eb1c02f3e4679edfa43309319070129c267c85604d87bb65bae205de3707af1d2108881abb567c3b3d069ae67c3a4c6a3aa93d26413d4c66094ae2039",).unwrap(),
-        BigNum::from_hex_str("30b4c2d798d47086145c75063c8e841e719776e400291d7838d3e6c4405b504c6a07f8fca27f32b86643d2649d1d5f124cdd0bf272f0909dd7352fe10a77b34d831043d9ae541f8263c6fe3d1c14c2f04e43a7253a6dda9a8c1562cbd493c1b631a1957618ad5dfe5ca28553f746e2fc6f2db816c7db223ec91e955081c1de65",).unwrap()
-    );
+fn write_file(file_path: &Path, out: Vec<u8>) -> error::Result<usize> {
+    let len = out.len();
 
-    out.write_all(END_BYTES)?;
+    std::fs::write(file_path, out).map_err(|source| Error::Io {
+        path: file_path.to_path_buf(),
+        source,
+    })?;
 
-    Ok(0)
+    Ok(len)
 }
 
-pub fn read_encoded_file(path: &Path) -> Result<Vec<u8>, ()> {
-    let mut file;
-
-    let mut f_file = File::open(path).unwrap();
-    file = Vec::with_capacity(f_file.metadata().unwrap().len() as usize);
-    f_file.read_to_end(&mut file).unwrap();
-
-    if &file[0..22] == LINEAGE_HEADER {
-        let enc = &file[22..28];
-        let enc_version = if enc == V111 {
-            EncVersion::V111
-        } else if enc == V121 {
-            EncVersion::V121
-        } else if enc == V413 {
-            EncVersion::V413
-        } else {
-            unreachable!("Unknown enc version: {}", String::from_utf8_lossy(enc))
+/**Encodes serialized `data` in memory. `file_path` is needed only for 121 XOR key, `tail` of the
+envelope is expected to be already in `data`
+ */
+pub fn encode_bytes(
+    file_path: &Path,
+    data: Vec<u8>,
+    envelope: &DatEnvelope,
+) -> error::Result<Vec<u8>> {
+    let Some(version) = envelope.version else {
+        return Ok(data);
+    };
+
+    let mut out = Vec::with_capacity(data.len() + 48);
+
+    out.extend_from_slice(LINEAGE_HEADER);
+    out.extend_from_slice(version.header());
+
+    if let Some(xor) = version.get_xor_key(&file_name(file_path)) {
+        out.extend(data.iter().map(|b| b ^ xor));
+        out.extend_from_slice(&envelope.footer);
+
+        return Ok(out);
+    }
+
+    let Some(exp) = version.get_private_exponent() else {
+        return Err(Error::MissingPrivateKey {
+            path: file_path.to_path_buf(),
+            version,
+        });
+    };
+
+    encode(data, &mut out, version.get_modulus().unwrap(), exp).map_err(|source| {
+        Error::Io {
+            path: file_path.to_path_buf(),
+            source,
+        }
+    })?;
+
+    out.extend_from_slice(&envelope.footer);
+
+    Ok(out)
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|v| v.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+pub fn read_encoded_file(path: &Path) -> error::Result<Vec<u8>> {
+    DatReader::open(path)?.read_all()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ue2_rw::MTX;
+    use std::path::PathBuf;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("l2_rw_test_{}_{name}", std::process::id()))
+    }
+
+    #[test]
+    fn save_and_read_round_trip() {
+        let path = temp_path("round_trip.dat");
+        let values: Vec<u32> = (0..1000).collect();
+
+        save_dat(
+            &path,
+            DatVariant::<(), u32>::Array(values.clone()),
+            Some(EncVersion::V413),
+        )
+        .unwrap();
+        let loaded = deserialize_dat::<u32>(&path).unwrap();
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(values, loaded);
+    }
+
+    #[test]
+    fn encode_decode_all_versions() {
+        let path = Path::new("SkillGrp.dat");
+        let data: Vec<u8> = (0..5000u32).map(|v| (v * 7 % 251) as u8).collect();
+
+        for version in EncVersion::ALL.into_iter().map(Some).chain([None]) {
+            match encode_bytes(path, data.clone(), &DatEnvelope::new(version)) {
+                Ok(encoded) => {
+                    let mut reader = DatReader::new(path, Cursor::new(encoded)).unwrap();
+
+                    assert_eq!(reader.read_all().unwrap(), data, "{version:?}");
+                    assert_eq!(reader.version(), version);
+                }
+                Err(Error::MissingPrivateKey { .. }) => {
+                    assert!(!version.unwrap().can_encode())
+                }
+                Err(e) => panic!("{e}"),
+            }
+        }
+    }
+
+    #[test]
+    fn unchanged_file_is_byte_identical() {
+        let path = temp_path("envelope.dat");
+
+        //Payload with custom tail and custom footer
+        let mut payload = vec![];
+        payload.write_unreal_value(3u32).unwrap();
+        for v in [7u32, 8, 9] {
+            payload.write_unreal_value(v).unwrap();
+        }
+        payload.extend_from_slice(b"custom tail");
+
+        let mut original = vec![];
+        let modulus = EncVersion::V413.get_modulus().unwrap();
+        let exp = EncVersion::V413.get_private_exponent().unwrap();
+        original.extend_from_slice(LINEAGE_HEADER);
+        original.extend_from_slice(V413);
+        encode(payload, &mut original, modulus, exp).unwrap();
+        original.extend_from_slice(&[1; 20]);
+
+        std::fs::write(&path, &original).unwrap();
+
+        let (values, envelope) = deserialize_dat_with_envelope::<u32>(&path).unwrap();
+
+        assert_eq!(envelope.version, Some(EncVersion::V413));
+        assert_eq!(envelope.tail, b"custom tail");
+        assert_eq!(envelope.footer, [1; 20]);
+
+        save_dat_with_envelope(&path, DatVariant::<(), u32>::Array(values), &envelope).unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), original);
+
+        save_dat_with_envelope(&path, DatVariant::<(), u32>::Array(vec![7, 8]), &envelope)
+            .unwrap();
+        let (values, changed) = deserialize_dat_with_envelope::<u32>(&path).unwrap();
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(values, [7, 8]);
+        assert_eq!(changed.tail, envelope.tail);
+        assert_eq!(changed.footer, envelope.footer);
+    }
+
+    #[test]
+    fn records_are_streamed() {
+        let path = temp_path("streamed.dat");
+        let values: Vec<u32> = (0..1000).collect();
+
+        save_dat(
+            &path,
+            DatVariant::<(), u32>::Array(values.clone()),
+            Some(EncVersion::V413),
+        )
+        .unwrap();
+
+        let mut records = DatRecords::<u32>::open(&path).unwrap();
+
+        assert_eq!(records.declared_count(), values.len());
+        assert_eq!(records.next().unwrap().unwrap(), 0);
+
+        let sum: u64 = records.by_ref().take(9).map(|v| v.unwrap() as u64).sum();
+        assert_eq!(sum, (1..10).sum());
+
+        let envelope = records.into_reader().unwrap().finish().unwrap();
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(envelope.tail, SAFE_PACKAGE_TAIL);
+        assert_eq!(envelope.footer, END_BYTES);
+    }
+
+    #[test]
+    fn xor_121_key_depends_on_file_name() {
+        assert_eq!(
+            EncVersion::V121.get_xor_key("SkillGrp.dat"),
+            EncVersion::V121.get_xor_key("skillgrp.dat")
+        );
+        assert_ne!(
+            EncVersion::V121.get_xor_key("skillgrp.dat"),
+            EncVersion::V121.get_xor_key("npcgrp.dat")
+        );
+    }
+
+    #[test]
+    fn enc_version_from_str() {
+        for v in EncVersion::ALL {
+            assert_eq!(v.to_string().parse(), Ok(v));
+        }
+
+        assert_eq!("v413".parse(), Ok(EncVersion::V413));
+        assert!("415".parse::<EncVersion>().is_err());
+    }
+
+    #[test]
+    fn unknown_enc_version() {
+        let path = temp_path("unknown_version.dat");
+
+        let mut file = LINEAGE_HEADER.to_vec();
+        file.extend_from_slice(b"9\x009\x009\x00");
+        file.extend_from_slice(&[0; 128]);
+        std::fs::write(&path, file).unwrap();
+
+        let res = read_encoded_file(&path);
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(res, Err(Error::UnknownEncVersion { version, .. }) if version == "999"));
+    }
+
+    #[test]
+    fn truncated_record() {
+        let path = temp_path("truncated.dat");
+
+        let mut file = vec![];
+        file.write_unreal_value(3u32).unwrap();
+        file.write_unreal_value(1u32).unwrap();
+        std::fs::write(&path, file).unwrap();
+
+        let res = deserialize_dat::<u32>(&path);
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(
+            res,
+            Err(Error::TruncatedRecord {
+                offset: 8,
+                record: 1,
+                field: None,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn truncated_field() {
+        let path = temp_path("truncated_field.dat");
+
+        let mut file = vec![];
+        file.write_unreal_value(1u32).unwrap();
+        file.write_unreal_value(MTX {
+            vec_1: vec![1, 2].into(),
+            vec_2: vec![3].into(),
+        })
+        .unwrap();
+        file.truncate(file.len() - 2);
+        std::fs::write(&path, file).unwrap();
+
+        let res = deserialize_dat::<MTX>(&path);
+
+        std::fs::remove_file(&path).unwrap();
+
+        let Err(Error::TruncatedRecord { field, record, .. }) = res else {
+            panic!("{res:?}")
         };
 
-        if let Some(modulus) = enc_version.get_modulus() {
-            let exp = enc_version.get_exponent().unwrap();
-            let mut output = vec![];
-            let mut decoder = Decoder {
-                data: BufReader::new(Cursor::new(&file[28..file.len() - 20])),
-                output: &mut output,
-            };
+        assert_eq!(record, 0);
+        assert_eq!(field.as_deref(), Some("MTX.vec_2"));
+    }
+
+    #[test]
+    fn payload_mismatch_field() {
+        let path = Path::new("mtx.dat");
+        let records = |v: u32| {
+            DatVariant::<(), MTX>::Array(vec![
+                MTX {
+                    vec_1: vec![1].into(),
+                    vec_2: vec![2].into(),
+                },
+                MTX {
+                    vec_1: vec![3].into(),
+                    vec_2: vec![4, v].into(),
+                },
+            ])
+        };
+
+        let envelope = DatEnvelope::new(None);
+        let original = serialize_payload(records(5), &envelope).unwrap();
+        let serialized = serialize_payload(records(6), &envelope).unwrap();
+
+        assert_eq!(compare_payload::<MTX>(path, &original, &original), None);
+
+        let mismatch = compare_payload::<MTX>(path, &original, &serialized).unwrap();
 
-            decoder.decode(modulus, exp);
+        assert_eq!(
+            mismatch.part,
+            PayloadPart::Record {
+                record: 1,
+                field: Some("MTX.vec_2".to_string())
+            }
+        );
+        assert_eq!(mismatch.offset, original.len() - envelope.tail.len() - 4);
+    }
+
+    #[test]
+    fn baseinfo_extra_records_change_count() {
+        let path = Path::new("item_baseinfo.dat");
 
-            file = output;
-        } else {
-            let xor = file[28] ^ (PACKAGE_FILE_TAG & 0xFF) as u8;
-            file = file[28..].iter_mut().map(|b| *b ^ xor).collect();
+        let mut original = vec![];
+        original.write_unreal_value(10u32).unwrap();
+        for v in 0..8u32 {
+            original.write_unreal_value(v).unwrap();
         }
+
+        let (values, envelope) = DatReader::new(path, Cursor::new(&original))
+            .map(|v| {
+                let mut records = v.records::<u32>().unwrap();
+                let values: Vec<_> = records.by_ref().map(|v| v.unwrap()).collect();
+
+                (values, records.into_reader().unwrap().finish().unwrap())
+            })
+            .unwrap();
+
+        assert_eq!(values.len(), 5);
+
+        let serialized =
+            serialize_payload(DatVariant::<(), u32>::Array(values), &envelope).unwrap();
+        let mismatch = compare_payload::<u32>(path, &original, &serialized).unwrap();
+
+        assert_eq!(mismatch.offset, 0);
+        assert_eq!(mismatch.part, PayloadPart::RecordCount);
     }
 
-    Ok(file)
+    #[test]
+    fn missing_file() {
+        let res = read_encoded_file(&temp_path("not_exists.dat"));
+
+        assert!(matches!(res, Err(Error::Io { .. })));
+    }
 }
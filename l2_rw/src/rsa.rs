@@ -0,0 +1,155 @@
+use num_bigint::BigUint;
+
+pub const BLOCK_SIZE: usize = 128;
+
+/**Raw RSA over one 128 byte block, without any padding.
+
+Same operation is used for both directions: `public_decrypt` with public exponent on load and
+`public_encrypt` with private exponent on save. Result is big endian, left padded with zeros.
+Returns `None` if block is not less than modulus.
+ */
+#[cfg(not(feature = "openssl"))]
+pub fn transform_block(
+    block: &[u8; BLOCK_SIZE],
+    modulus: &BigUint,
+    exp: &BigUint,
+) -> Option<[u8; BLOCK_SIZE]> {
+    pure_transform_block(block, modulus, exp)
+}
+
+#[cfg(feature = "openssl")]
+pub fn transform_block(
+    block: &[u8; BLOCK_SIZE],
+    modulus: &BigUint,
+    exp: &BigUint,
+) -> Option<[u8; BLOCK_SIZE]> {
+    openssl_transform_block(block, modulus, exp)
+}
+
+#[cfg_attr(feature = "openssl", allow(unused))]
+fn pure_transform_block(
+    block: &[u8; BLOCK_SIZE],
+    modulus: &BigUint,
+    exp: &BigUint,
+) -> Option<[u8; BLOCK_SIZE]> {
+    let value = BigUint::from_bytes_be(block);
+
+    if &value >= modulus {
+        return None;
+    }
+
+    let res = value.modpow(exp, modulus).to_bytes_be();
+
+    let mut out = [0u8; BLOCK_SIZE];
+    out[BLOCK_SIZE - res.len()..].copy_from_slice(&res);
+
+    Some(out)
+}
+
+#[cfg(feature = "openssl")]
+fn openssl_transform_block(
+    block: &[u8; BLOCK_SIZE],
+    modulus: &BigUint,
+    exp: &BigUint,
+) -> Option<[u8; BLOCK_SIZE]> {
+    use openssl::bn::BigNum;
+    use openssl::rsa::{Padding, Rsa};
+
+    let rsa = Rsa::from_public_components(
+        BigNum::from_slice(&modulus.to_bytes_be()).ok()?,
+        BigNum::from_slice(&exp.to_bytes_be()).ok()?,
+    )
+    .ok()?;
+
+    let mut out = [0u8; BLOCK_SIZE];
+    rsa.public_decrypt(block, &mut out, Padding::NONE).ok()?;
+
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EncVersion, V413_PRIVATE_EXPONENT};
+
+    /**Deterministic blocks which are always less than modulus (first byte is zero)
+     */
+    fn test_blocks() -> Vec<[u8; BLOCK_SIZE]> {
+        let mut seed = 0x2545F4914F6CDD1Du64;
+        let mut res = vec![[0u8; BLOCK_SIZE]];
+
+        for _ in 0..32 {
+            let mut block = [0u8; BLOCK_SIZE];
+
+            for b in block.iter_mut().skip(1) {
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                *b = seed as u8;
+            }
+
+            res.push(block);
+        }
+
+        res
+    }
+
+    #[test]
+    fn rsa_versions_have_keys() {
+        for v in EncVersion::ALL {
+            let rsa = !matches!(v, EncVersion::V111 | EncVersion::V121);
+
+            assert_eq!(v.get_modulus().is_some(), rsa);
+            assert_eq!(v.get_exponent().is_some(), rsa);
+        }
+    }
+
+    #[test]
+    fn block_above_modulus_fails() {
+        let modulus = EncVersion::V413.get_modulus().unwrap();
+        let exp = EncVersion::V413.get_exponent().unwrap();
+
+        assert!(pure_transform_block(&[0xFF; BLOCK_SIZE], &modulus, &exp).is_none());
+    }
+
+    #[test]
+    fn v413_round_trip() {
+        let modulus = EncVersion::V413.get_modulus().unwrap();
+        let public = EncVersion::V413.get_exponent().unwrap();
+        let private = crate::hex_to_big_uint(V413_PRIVATE_EXPONENT);
+
+        for block in test_blocks() {
+            let encoded = pure_transform_block(&block, &modulus, &private).unwrap();
+            let decoded = pure_transform_block(&encoded, &modulus, &public).unwrap();
+
+            assert_eq!(block, decoded);
+        }
+    }
+
+    #[cfg(feature = "openssl")]
+    #[test]
+    fn pure_matches_openssl() {
+        for v in EncVersion::ALL {
+            let (Some(modulus), Some(exp)) = (v.get_modulus(), v.get_exponent()) else {
+                continue;
+            };
+
+            for block in test_blocks() {
+                assert_eq!(
+                    pure_transform_block(&block, &modulus, &exp),
+                    openssl_transform_block(&block, &modulus, &exp)
+                );
+            }
+        }
+
+        let modulus = EncVersion::V413.get_modulus().unwrap();
+        let private = crate::hex_to_big_uint(V413_PRIVATE_EXPONENT);
+
+        for block in test_blocks() {
+            assert_eq!(
+                pure_transform_block(&block, &modulus, &private),
+                openssl_transform_block(&block, &modulus, &private)
+            );
+        }
+    }
+}
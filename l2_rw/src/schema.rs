@@ -0,0 +1,1361 @@
+use crate::error::{self, Error};
+use crate::table::{field_path, item_path, Table, TableError, TableReader};
+use crate::ue2_rw::{
+    CompactInt, ReadError, ReadUnreal, WriteUnreal, ASCF, BYTE, DOUBLE, DWORD, FLOAT, GUID, INDEX,
+    INT, LONG, MTX, MTX3, SHORT, STR, WORD,
+};
+use crate::{write_with_envelope, DatEnvelope, DatReader};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::io::{ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/**Counts are read from the file, so they are not trusted for preallocation
+ */
+const MAX_PREALLOC: usize = 1024;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Type {
+    Byte,
+    Word,
+    Short,
+    Dword,
+    Int,
+    Long,
+    Float,
+    Double,
+    Guid,
+    Index,
+    Str,
+    Ascf,
+    Mtx,
+    Mtx3,
+    /**Prefixed with compact int count
+     */
+    Vec(Box<Type>),
+    /**Count type and item type
+     */
+    UVec(Box<Type>, Box<Type>),
+    /**Count type and item types, all first items go before all second ones
+     */
+    DVec(Box<Type>, Box<Type>, Box<Type>),
+    /**Index in [`Schema::structs`]
+     */
+    Struct(usize),
+}
+
+impl Type {
+    fn is_count(&self) -> bool {
+        matches!(
+            self,
+            Type::Byte | Type::Word | Type::Short | Type::Dword | Type::Int | Type::Long
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub name: String,
+    pub ty: Type,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructDef {
+    pub name: String,
+    pub fields: Vec<Field>,
+}
+
+/**Dynamic counterpart of the typed dat structs
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Byte(BYTE),
+    Word(WORD),
+    Short(SHORT),
+    Dword(DWORD),
+    Int(INT),
+    Long(LONG),
+    Float(FLOAT),
+    Double(DOUBLE),
+    Guid(GUID),
+    Index(i32),
+    Str(STR),
+    Ascf(ASCF),
+    Mtx(MTX),
+    Mtx3(MTX3),
+    /**`Vec` and `UVEC`
+     */
+    List(Vec<Value>),
+    /**`DVEC`
+     */
+    Pairs(Vec<(Value, Value)>),
+    /**Fields in schema order
+     */
+    Struct(Vec<Value>),
+}
+
+impl Value {
+    fn kind(&self) -> &'static str {
+        match self {
+            Value::Byte(_) => "BYTE",
+            Value::Word(_) => "WORD",
+            Value::Short(_) => "SHORT",
+            Value::Dword(_) => "DWORD",
+            Value::Int(_) => "INT",
+            Value::Long(_) => "LONG",
+            Value::Float(_) => "FLOAT",
+            Value::Double(_) => "DOUBLE",
+            Value::Guid(_) => "GUID",
+            Value::Index(_) => "INDEX",
+            Value::Str(_) => "STR",
+            Value::Ascf(_) => "ASCF",
+            Value::Mtx(_) => "MTX",
+            Value::Mtx3(_) => "MTX3",
+            Value::List(_) => "list",
+            Value::Pairs(_) => "pairs",
+            Value::Struct(_) => "struct",
+        }
+    }
+}
+
+/**Scalars are shown as is, `ASCF` without trailing zero. Lists and structs are shown for
+debugging only, tables use [`Schema::to_table`] to flatten them
+ */
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Byte(v) => write!(f, "{v}"),
+            Value::Word(v) => write!(f, "{v}"),
+            Value::Short(v) => write!(f, "{v}"),
+            Value::Dword(v) => write!(f, "{v}"),
+            Value::Int(v) => write!(f, "{v}"),
+            Value::Long(v) => write!(f, "{v}"),
+            Value::Float(v) => write!(f, "{v}"),
+            Value::Double(v) => write!(f, "{v}"),
+            Value::Guid(v) => write!(f, "{v}"),
+            Value::Index(v) => write!(f, "{v}"),
+            Value::Str(v) => write!(f, "{v}"),
+            Value::Ascf(v) => write!(f, "{v}"),
+            v => write!(f, "{v:?}"),
+        }
+    }
+}
+
+/**Records of a dat decoded with [`Schema`]
+ */
+#[derive(Debug, Clone)]
+pub struct DynamicDat {
+    pub dict: Option<Vec<Value>>,
+    pub records: Vec<Value>,
+    pub envelope: DatEnvelope,
+}
+
+#[derive(Debug)]
+pub enum SchemaError {
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    Syntax {
+        line: usize,
+        message: String,
+    },
+}
+
+impl Display for SchemaError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaError::Io { path, source } => write!(f, "{path:?}: io error: {source}"),
+            SchemaError::Syntax { line, message } => write!(f, "line {line}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for SchemaError {}
+
+/**Text description of a dat layout, used to read files without typed model.
+
+Syntax is a subset of Rust, so structs from `dat_loader` can be copied as is:
+
+```text
+// comment
+struct CoordsXYZ {
+    x: FLOAT,
+    y: FLOAT,
+    z: FLOAT,
+}
+
+#[derive(Debug, ReadUnreal, WriteUnreal)]
+pub struct HuntingZoneDat {
+    id: DWORD,
+    start_npc_loc: CoordsXYZ,
+    name: ASCF,
+    quests: Vec<USHORT>,
+    npcs: UVEC<BYTE, DWORD>,
+}
+
+dat HuntingZoneDat;
+```
+
+`dat Record;` declares records prefixed with `u32` count, `dat Dict, Record;` adds string
+dictionary prefixed with compact int count before them. Types are the ones from
+[`crate::ue2_rw`] (`CompactInt` and Rust primitive names are accepted too), `Vec<T>`,
+`UVEC<Count, T>`, `DVEC<Count, T1, T2>` and structs declared in the same schema
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Schema {
+    structs: Vec<StructDef>,
+    dict: Option<Type>,
+    record: Type,
+}
+
+impl Schema {
+    pub fn load(path: &Path) -> Result<Self, SchemaError> {
+        let text = std::fs::read_to_string(path).map_err(|source| SchemaError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        Self::parse(&text)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, SchemaError> {
+        Parser::new(text)?.parse()
+    }
+
+    pub fn structs(&self) -> &[StructDef] {
+        &self.structs
+    }
+
+    pub fn dict(&self) -> Option<&Type> {
+        self.dict.as_ref()
+    }
+
+    pub fn record(&self) -> &Type {
+        &self.record
+    }
+
+    pub fn type_name(&self, ty: &Type) -> String {
+        match ty {
+            Type::Byte => "BYTE".to_string(),
+            Type::Word => "WORD".to_string(),
+            Type::Short => "SHORT".to_string(),
+            Type::Dword => "DWORD".to_string(),
+            Type::Int => "INT".to_string(),
+            Type::Long => "LONG".to_string(),
+            Type::Float => "FLOAT".to_string(),
+            Type::Double => "DOUBLE".to_string(),
+            Type::Guid => "GUID".to_string(),
+            Type::Index => "INDEX".to_string(),
+            Type::Str => "STR".to_string(),
+            Type::Ascf => "ASCF".to_string(),
+            Type::Mtx => "MTX".to_string(),
+            Type::Mtx3 => "MTX3".to_string(),
+            Type::Vec(t) => format!("Vec<{}>", self.type_name(t)),
+            Type::UVec(i, t) => format!("UVEC<{}, {}>", self.type_name(i), self.type_name(t)),
+            Type::DVec(i, t1, t2) => format!(
+                "DVEC<{}, {}, {}>",
+                self.type_name(i),
+                self.type_name(t1),
+                self.type_name(t2)
+            ),
+            Type::Struct(i) => self.structs[*i].name.clone(),
+        }
+    }
+
+    pub fn decode(&self, path: &Path) -> error::Result<DynamicDat> {
+        self.decode_from(DatReader::open(path)?)
+    }
+
+    pub fn decode_from<R: Read>(&self, mut reader: DatReader<R>) -> error::Result<DynamicDat> {
+        let dict = if let Some(ty) = &self.dict {
+            let count = reader.read_record_with(0, INDEX::read_unreal)?.0.max(0) as usize;
+            let mut dict = Vec::with_capacity(count.min(MAX_PREALLOC));
+
+            for i in 0..count {
+                dict.push(reader.read_record_with(i, |r| self.read_value(ty, r))?);
+            }
+
+            Some(dict)
+        } else {
+            None
+        };
+
+        let count = reader.read_record_with(0, u32::read_unreal)? as usize;
+        let stop_short = reader.declares_extra_records();
+        let mut records = Vec::with_capacity(count.min(MAX_PREALLOC));
+
+        for i in 0..count {
+            if stop_short && !reader.has_at_least(16)? {
+                break;
+            }
+
+            records.push(reader.read_record_with(i, |r| self.read_value(&self.record, r))?);
+        }
+
+        Ok(DynamicDat {
+            dict,
+            records,
+            envelope: reader.finish()?,
+        })
+    }
+
+    /**Same as [`crate::save_dat_with_envelope`], unchanged data is written as original bytes
+     */
+    pub fn save(&self, path: &Path, dat: &DynamicDat) -> error::Result<usize> {
+        let data = self.serialize(dat).map_err(|source| Error::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        write_with_envelope(path, data, &dat.envelope)
+    }
+
+    /**Payload without envelope tail
+     */
+    pub fn serialize(&self, dat: &DynamicDat) -> std::io::Result<Vec<u8>> {
+        let mut out = vec![];
+
+        match (&self.dict, &dat.dict) {
+            (Some(ty), Some(dict)) => {
+                CompactInt(dict.len() as i32).write_unreal(&mut out)?;
+
+                for (i, v) in dict.iter().enumerate() {
+                    self.write_value(ty, v, &mut out)
+                        .map_err(|e| in_context(e, format!("dict record {i}")))?;
+                }
+            }
+            (None, None) => {}
+            _ => {
+                return Err(invalid_input(
+                    "string dictionary doesn't match schema".to_string(),
+                ))
+            }
+        }
+
+        (dat.records.len() as u32).write_unreal(&mut out)?;
+
+        for (i, v) in dat.records.iter().enumerate() {
+            self.write_value(&self.record, v, &mut out)
+                .map_err(|e| in_context(e, format!("record {i}")))?;
+        }
+
+        Ok(out)
+    }
+
+    pub fn read_value<R: Read>(&self, ty: &Type, reader: &mut R) -> Result<Value, ReadError> {
+        Ok(match ty {
+            Type::Byte => Value::Byte(BYTE::read_unreal(reader)?),
+            Type::Word => Value::Word(WORD::read_unreal(reader)?),
+            Type::Short => Value::Short(SHORT::read_unreal(reader)?),
+            Type::Dword => Value::Dword(DWORD::read_unreal(reader)?),
+            Type::Int => Value::Int(INT::read_unreal(reader)?),
+            Type::Long => Value::Long(LONG::read_unreal(reader)?),
+            Type::Float => Value::Float(FLOAT::read_unreal(reader)?),
+            Type::Double => Value::Double(DOUBLE::read_unreal(reader)?),
+            Type::Guid => Value::Guid(GUID::read_unreal(reader)?),
+            Type::Index => Value::Index(INDEX::read_unreal(reader)?.0),
+            Type::Str => Value::Str(STR::read_unreal(reader)?),
+            Type::Ascf => Value::Ascf(ASCF::read_unreal(reader)?),
+            Type::Mtx => Value::Mtx(MTX::read_unreal(reader)?),
+            Type::Mtx3 => Value::Mtx3(MTX3::read_unreal(reader)?),
+            Type::Vec(t) => {
+                let len = INDEX::read_unreal(reader)?.0;
+
+                self.read_list(t, len.into(), reader)?
+            }
+            Type::UVec(i, t) => {
+                let len = self.read_count(i, reader)?;
+
+                self.read_list(t, len, reader)?
+            }
+            Type::DVec(i, t1, t2) => {
+                let len = self.read_count(i, reader)?;
+                let Value::List(first) = self.read_list(t1, len, reader)? else {
+                    unreachable!()
+                };
+                let Value::List(second) = self.read_list(t2, len, reader)? else {
+                    unreachable!()
+                };
+
+                Value::Pairs(first.into_iter().zip(second).collect())
+            }
+            Type::Struct(i) => {
+                let def = &self.structs[*i];
+                let mut values = Vec::with_capacity(def.fields.len());
+
+                for f in &def.fields {
+                    values.push(
+                        self.read_value(&f.ty, reader)
+                            .map_err(|e| e.in_field(def.name.clone(), f.name.clone()))?,
+                    );
+                }
+
+                Value::Struct(values)
+            }
+        })
+    }
+
+    fn read_count<R: Read>(&self, ty: &Type, reader: &mut R) -> Result<i64, ReadError> {
+        Ok(match self.read_value(ty, reader)? {
+            Value::Byte(v) => v.into(),
+            Value::Word(v) => v.into(),
+            Value::Short(v) => v.into(),
+            Value::Dword(v) => v.into(),
+            Value::Int(v) => v.into(),
+            Value::Long(v) => v,
+            _ => unreachable!("count type is checked by parser"),
+        })
+    }
+
+    fn read_list<R: Read>(&self, ty: &Type, len: i64, reader: &mut R) -> Result<Value, ReadError> {
+        if len < 0 {
+            return Err(ReadError::invalid_data("negative count"));
+        }
+
+        let mut res = Vec::with_capacity((len as usize).min(MAX_PREALLOC));
+
+        for _ in 0..len {
+            res.push(self.read_value(ty, reader)?);
+        }
+
+        Ok(Value::List(res))
+    }
+
+    pub fn write_value<W: Write>(
+        &self,
+        ty: &Type,
+        value: &Value,
+        writer: &mut W,
+    ) -> std::io::Result<()> {
+        match (ty, value) {
+            (Type::Byte, Value::Byte(v)) => v.write_unreal(writer),
+            (Type::Word, Value::Word(v)) => v.write_unreal(writer),
+            (Type::Short, Value::Short(v)) => v.write_unreal(writer),
+            (Type::Dword, Value::Dword(v)) => v.write_unreal(writer),
+            (Type::Int, Value::Int(v)) => v.write_unreal(writer),
+            (Type::Long, Value::Long(v)) => v.write_unreal(writer),
+            (Type::Float, Value::Float(v)) => v.write_unreal(writer),
+            (Type::Double, Value::Double(v)) => v.write_unreal(writer),
+            (Type::Guid, Value::Guid(v)) => v.write_unreal(writer),
+            (Type::Index, Value::Index(v)) => CompactInt(*v).write_unreal(writer),
+            (Type::Str, Value::Str(v)) => v.write_unreal(writer),
+            (Type::Ascf, Value::Ascf(v)) => v.write_unreal(writer),
+            (Type::Mtx, Value::Mtx(v)) => v.write_unreal(writer),
+            (Type::Mtx3, Value::Mtx3(v)) => v.write_unreal(writer),
+            (Type::Vec(t), Value::List(items)) => {
+                CompactInt(items.len() as i32).write_unreal(writer)?;
+
+                for v in items {
+                    self.write_value(t, v, writer)?;
+                }
+
+                Ok(())
+            }
+            (Type::UVec(i, t), Value::List(items)) => {
+                self.write_count(i, items.len(), writer)?;
+
+                for v in items {
+                    self.write_value(t, v, writer)?;
+                }
+
+                Ok(())
+            }
+            (Type::DVec(i, t1, t2), Value::Pairs(items)) => {
+                self.write_count(i, items.len(), writer)?;
+
+                for (v, _) in items {
+                    self.write_value(t1, v, writer)?;
+                }
+
+                for (_, v) in items {
+                    self.write_value(t2, v, writer)?;
+                }
+
+                Ok(())
+            }
+            (Type::Struct(i), Value::Struct(values))
+                if values.len() == self.structs[*i].fields.len() =>
+            {
+                let def = &self.structs[*i];
+
+                for (f, v) in def.fields.iter().zip(values) {
+                    self.write_value(&f.ty, v, writer)
+                        .map_err(|e| in_context(e, format!("{}.{}", def.name, f.name)))?;
+                }
+
+                Ok(())
+            }
+            (Type::Struct(i), Value::Struct(values)) => Err(invalid_input(format!(
+                "{} has {} fields, got {}",
+                self.structs[*i].name,
+                self.structs[*i].fields.len(),
+                values.len()
+            ))),
+            (ty, v) => Err(invalid_input(format!(
+                "expected {}, got {}",
+                self.type_name(ty),
+                v.kind()
+            ))),
+        }
+    }
+
+    fn write_count<W: Write>(&self, ty: &Type, len: usize, writer: &mut W) -> std::io::Result<()> {
+        let count = match ty {
+            Type::Byte => BYTE::try_from(len).ok().map(Value::Byte),
+            Type::Word => WORD::try_from(len).ok().map(Value::Word),
+            Type::Short => SHORT::try_from(len).ok().map(Value::Short),
+            Type::Dword => DWORD::try_from(len).ok().map(Value::Dword),
+            Type::Int => INT::try_from(len).ok().map(Value::Int),
+            Type::Long => LONG::try_from(len).ok().map(Value::Long),
+            _ => unreachable!("count type is checked by parser"),
+        };
+
+        let Some(count) = count else {
+            return Err(invalid_input(format!(
+                "{len} items don't fit into {} count",
+                self.type_name(ty)
+            )));
+        };
+
+        self.write_value(ty, &count, writer)
+    }
+
+    /**One row per record, see [`Table`] for column names. String dictionary is not exported
+     */
+    pub fn to_table(&self, dat: &DynamicDat) -> Table {
+        Table::from_flat_rows(
+            dat.records
+                .iter()
+                .map(|v| {
+                    let mut row = vec![];
+                    self.flatten(&self.record, v, self.root_path(), &mut row);
+
+                    row
+                })
+                .collect(),
+        )
+    }
+
+    /**Records from rows of [`Schema::to_table`] output, every column must be known
+     */
+    pub fn records_from_table(&self, table: &Table) -> Result<Vec<Value>, TableError> {
+        let mut reader = TableReader::new(table)?;
+        let mut res = Vec::with_capacity(reader.len());
+
+        for row in 0..reader.len() {
+            res.push(self.unflatten(&self.record, self.root_path(), row, &mut reader)?);
+        }
+
+        reader.check_unused()?;
+
+        Ok(res)
+    }
+
+    fn root_path(&self) -> &'static str {
+        if matches!(self.record, Type::Struct(_)) {
+            ""
+        } else {
+            "value"
+        }
+    }
+
+    fn flatten(&self, ty: &Type, value: &Value, path: &str, out: &mut Vec<(String, String)>) {
+        match (ty, value) {
+            (Type::Struct(i), Value::Struct(values)) => {
+                for (f, v) in self.structs[*i].fields.iter().zip(values) {
+                    self.flatten(&f.ty, v, &field_path(path, &f.name), out);
+                }
+            }
+            (Type::Vec(t) | Type::UVec(_, t), Value::List(items)) => {
+                for (i, v) in items.iter().enumerate() {
+                    self.flatten(t, v, &item_path(path, i), out);
+                }
+            }
+            (Type::DVec(_, t1, t2), Value::Pairs(items)) => {
+                for (i, (v1, v2)) in items.iter().enumerate() {
+                    let item = item_path(path, i);
+
+                    self.flatten(t1, v1, &field_path(&item, "0"), out);
+                    self.flatten(t2, v2, &field_path(&item, "1"), out);
+                }
+            }
+            (_, Value::Mtx(v)) => {
+                flatten_list(&v.vec_1.inner, &field_path(path, "vec_1"), out);
+                flatten_list(&v.vec_2.inner, &field_path(path, "vec_2"), out);
+            }
+            (_, Value::Mtx3(v)) => {
+                flatten_list(&v.vec_1, &field_path(path, "vec_1"), out);
+
+                for (i, (v1, v2)) in v.vec_1_f.iter().enumerate() {
+                    let item = item_path(&field_path(path, "vec_1_f"), i);
+
+                    out.push((field_path(&item, "0"), v1.to_string()));
+                    out.push((field_path(&item, "1"), v2.to_string()));
+                }
+
+                flatten_list(&v.vec_2, &field_path(path, "vec_2"), out);
+                out.push((field_path(path, "val"), v.val.to_string()));
+            }
+            (_, v) => out.push((path.to_string(), v.to_string())),
+        }
+    }
+
+    fn unflatten(
+        &self,
+        ty: &Type,
+        path: &str,
+        row: usize,
+        reader: &mut TableReader,
+    ) -> Result<Value, TableError> {
+        Ok(match ty {
+            Type::Byte => Value::Byte(parse_cell(reader, row, path, "BYTE")?),
+            Type::Word => Value::Word(parse_cell(reader, row, path, "WORD")?),
+            Type::Short => Value::Short(parse_cell(reader, row, path, "SHORT")?),
+            Type::Dword => Value::Dword(parse_cell(reader, row, path, "DWORD")?),
+            Type::Int => Value::Int(parse_cell(reader, row, path, "INT")?),
+            Type::Long => Value::Long(parse_cell(reader, row, path, "LONG")?),
+            Type::Float => Value::Float(parse_cell(reader, row, path, "FLOAT")?),
+            Type::Double => Value::Double(parse_cell(reader, row, path, "DOUBLE")?),
+            Type::Guid => Value::Guid(parse_cell(reader, row, path, "GUID")?),
+            Type::Index => Value::Index(parse_cell(reader, row, path, "INDEX")?),
+            Type::Str => Value::Str(reader.cell(row, path)?.to_string()),
+            Type::Ascf => Value::Ascf(ASCF::from(reader.cell(row, path)?.to_string())),
+            Type::Mtx => Value::Mtx(MTX {
+                vec_1: unflatten_list(reader, row, &field_path(path, "vec_1"), "DWORD")?.into(),
+                vec_2: unflatten_list(reader, row, &field_path(path, "vec_2"), "DWORD")?.into(),
+            }),
+            Type::Mtx3 => {
+                let vec_1 = unflatten_list(reader, row, &field_path(path, "vec_1"), "DWORD")?;
+                let f_path = field_path(path, "vec_1_f");
+                let len = reader.list_len(row, &f_path).max(vec_1.len());
+                let mut vec_1_f = Vec::with_capacity(len);
+
+                for i in 0..len {
+                    let item = item_path(&f_path, i);
+
+                    vec_1_f.push((
+                        parse_cell(reader, row, &field_path(&item, "0"), "BYTE")?,
+                        parse_cell(reader, row, &field_path(&item, "1"), "BYTE")?,
+                    ));
+                }
+
+                Value::Mtx3(MTX3 {
+                    vec_1,
+                    vec_1_f,
+                    vec_2: unflatten_list(reader, row, &field_path(path, "vec_2"), "DWORD")?,
+                    val: parse_cell(reader, row, &field_path(path, "val"), "DWORD")?,
+                })
+            }
+            Type::Vec(t) | Type::UVec(_, t) => {
+                let len = reader.list_len(row, path);
+                let mut items = Vec::with_capacity(len);
+
+                for i in 0..len {
+                    items.push(self.unflatten(t, &item_path(path, i), row, reader)?);
+                }
+
+                Value::List(items)
+            }
+            Type::DVec(_, t1, t2) => {
+                let len = reader.list_len(row, path);
+                let mut items = Vec::with_capacity(len);
+
+                for i in 0..len {
+                    let item = item_path(path, i);
+
+                    items.push((
+                        self.unflatten(t1, &field_path(&item, "0"), row, reader)?,
+                        self.unflatten(t2, &field_path(&item, "1"), row, reader)?,
+                    ));
+                }
+
+                Value::Pairs(items)
+            }
+            Type::Struct(i) => {
+                let def = &self.structs[*i];
+                let mut values = Vec::with_capacity(def.fields.len());
+
+                for f in &def.fields {
+                    values.push(self.unflatten(&f.ty, &field_path(path, &f.name), row, reader)?);
+                }
+
+                Value::Struct(values)
+            }
+        })
+    }
+
+    /**Structs which contain themselves not through a list can't be read
+     */
+    fn check_recursion(&self) -> Result<(), String> {
+        fn visit(
+            schema: &Schema,
+            ty: &Type,
+            state: &mut [u8],
+            path: &mut Vec<usize>,
+        ) -> Result<(), String> {
+            let Type::Struct(i) = ty else {
+                return Ok(());
+            };
+
+            match state[*i] {
+                1 => {
+                    path.push(*i);
+
+                    return Err(path
+                        .iter()
+                        .map(|v| schema.structs[*v].name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(" > "));
+                }
+                2 => return Ok(()),
+                _ => {}
+            }
+
+            state[*i] = 1;
+            path.push(*i);
+
+            for f in &schema.structs[*i].fields {
+                visit(schema, &f.ty, state, path)?;
+            }
+
+            path.pop();
+            state[*i] = 2;
+
+            Ok(())
+        }
+
+        let mut state = vec![0u8; self.structs.len()];
+
+        for i in 0..self.structs.len() {
+            visit(self, &Type::Struct(i), &mut state, &mut vec![])?;
+        }
+
+        Ok(())
+    }
+}
+
+fn flatten_list<T: Display>(items: &[T], path: &str, out: &mut Vec<(String, String)>) {
+    for (i, v) in items.iter().enumerate() {
+        out.push((item_path(path, i), v.to_string()));
+    }
+}
+
+fn unflatten_list<T: FromStr>(
+    reader: &mut TableReader,
+    row: usize,
+    path: &str,
+    type_name: &str,
+) -> Result<Vec<T>, TableError> {
+    let len = reader.list_len(row, path);
+    let mut res = Vec::with_capacity(len);
+
+    for i in 0..len {
+        res.push(parse_cell(reader, row, &item_path(path, i), type_name)?);
+    }
+
+    Ok(res)
+}
+
+fn parse_cell<T: FromStr>(
+    reader: &mut TableReader,
+    row: usize,
+    column: &str,
+    type_name: &str,
+) -> Result<T, TableError> {
+    let cell = reader.cell(row, column)?;
+
+    cell.trim()
+        .parse()
+        .map_err(|_| TableError::invalid(Some(row), column, format!("{cell:?} is not {type_name}")))
+}
+
+fn invalid_input(msg: String) -> std::io::Error {
+    std::io::Error::new(ErrorKind::InvalidInput, msg)
+}
+
+fn in_context(e: std::io::Error, context: String) -> std::io::Error {
+    std::io::Error::new(e.kind(), format!("{context}: {e}"))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Punct(char),
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    names: HashMap<String, usize>,
+}
+
+impl Parser {
+    fn new(text: &str) -> Result<Self, SchemaError> {
+        let mut tokens = vec![];
+
+        for (n, line) in text.lines().enumerate() {
+            let line_n = n + 1;
+            let line = line.split("//").next().unwrap();
+            let mut chars = line.char_indices().peekable();
+
+            while let Some((start, c)) = chars.next() {
+                if c.is_whitespace() {
+                    continue;
+                }
+
+                if c.is_alphanumeric() || c == '_' {
+                    let mut end = start + c.len_utf8();
+
+                    while let Some((i, c)) = chars.peek() {
+                        if !(c.is_alphanumeric() || *c == '_') {
+                            break;
+                        }
+
+                        end = i + c.len_utf8();
+                        chars.next();
+                    }
+
+                    tokens.push((Token::Ident(line[start..end].to_string()), line_n));
+                } else if "{}<>(),:;#[]".contains(c) {
+                    tokens.push((Token::Punct(c), line_n));
+                } else {
+                    return Err(SchemaError::Syntax {
+                        line: line_n,
+                        message: format!("unexpected character '{c}'"),
+                    });
+                }
+            }
+        }
+
+        let mut names = HashMap::new();
+
+        for w in tokens.windows(2) {
+            if let [(Token::Ident(kw), _), (Token::Ident(name), line)] = w {
+                if kw == "struct" && names.insert(name.clone(), names.len()).is_some() {
+                    return Err(SchemaError::Syntax {
+                        line: *line,
+                        message: format!("struct {name} is declared twice"),
+                    });
+                }
+            }
+        }
+
+        Ok(Self {
+            tokens,
+            pos: 0,
+            names,
+        })
+    }
+
+    fn parse(mut self) -> Result<Schema, SchemaError> {
+        let mut structs = vec![];
+        let mut dat = None;
+
+        while self.pos < self.tokens.len() {
+            self.skip_attributes()?;
+
+            let line = self.line();
+            let keyword = self.ident()?;
+
+            match keyword.as_str() {
+                "pub" => {
+                    self.skip_visibility()?;
+                }
+                "struct" => structs.push(self.parse_struct()?),
+                "dat" if dat.is_some() => return Err(self.error_at(line, "dat is declared twice")),
+                "dat" => {
+                    let first = self.parse_type()?;
+
+                    dat = if self.eat(',') {
+                        Some((Some(first), self.parse_type()?))
+                    } else {
+                        Some((None, first))
+                    };
+
+                    self.expect(';')?;
+                }
+                _ => {
+                    return Err(
+                        self.error_at(line, &format!("expected struct or dat, got {keyword}"))
+                    )
+                }
+            }
+        }
+
+        let Some((dict, record)) = dat else {
+            return Err(self.error_at(self.line(), "dat declaration is missing"));
+        };
+
+        let schema = Schema {
+            structs,
+            dict,
+            record,
+        };
+
+        schema
+            .check_recursion()
+            .map_err(|path| SchemaError::Syntax {
+                line: 0,
+                message: format!("recursive struct {path}"),
+            })?;
+
+        Ok(schema)
+    }
+
+    fn parse_struct(&mut self) -> Result<StructDef, SchemaError> {
+        let name = self.ident()?;
+        let mut fields: Vec<Field> = vec![];
+
+        self.expect('{')?;
+
+        while !self.eat('}') {
+            self.skip_attributes()?;
+
+            let line = self.line();
+            let mut field_name = self.ident()?;
+
+            if field_name == "pub" {
+                self.skip_visibility()?;
+                field_name = self.ident()?;
+            }
+
+            if fields.iter().any(|v| v.name == field_name) {
+                return Err(self.error_at(line, &format!("field {field_name} is declared twice")));
+            }
+
+            self.expect(':')?;
+
+            fields.push(Field {
+                name: field_name,
+                ty: self.parse_type()?,
+            });
+
+            if !self.eat(',') {
+                self.expect('}')?;
+
+                break;
+            }
+        }
+
+        Ok(StructDef { name, fields })
+    }
+
+    fn parse_type(&mut self) -> Result<Type, SchemaError> {
+        let line = self.line();
+        let name = self.ident()?;
+
+        Ok(match name.as_str() {
+            "BYTE" | "u8" => Type::Byte,
+            "WORD" | "USHORT" | "u16" => Type::Word,
+            "SHORT" | "i16" => Type::Short,
+            "DWORD" | "u32" => Type::Dword,
+            "INT" | "i32" => Type::Int,
+            "LONG" | "i64" => Type::Long,
+            "FLOAT" | "f32" => Type::Float,
+            "DOUBLE" | "f64" => Type::Double,
+            "GUID" | "u128" => Type::Guid,
+            "INDEX" | "CompactInt" => Type::Index,
+            "STR" | "String" => Type::Str,
+            "ASCF" => Type::Ascf,
+            "MTX" => Type::Mtx,
+            "MTX3" => Type::Mtx3,
+            "Vec" => {
+                self.expect('<')?;
+                let t = self.parse_type()?;
+                self.expect('>')?;
+
+                Type::Vec(Box::new(t))
+            }
+            "UVEC" => {
+                self.expect('<')?;
+                let i = self.parse_count_type()?;
+                self.expect(',')?;
+                let t = self.parse_type()?;
+                self.expect('>')?;
+
+                Type::UVec(Box::new(i), Box::new(t))
+            }
+            "DVEC" => {
+                self.expect('<')?;
+                let i = self.parse_count_type()?;
+                self.expect(',')?;
+                let t1 = self.parse_type()?;
+                self.expect(',')?;
+                let t2 = self.parse_type()?;
+                self.expect('>')?;
+
+                Type::DVec(Box::new(i), Box::new(t1), Box::new(t2))
+            }
+            _ => match self.names.get(&name) {
+                Some(i) => Type::Struct(*i),
+                None => return Err(self.error_at(line, &format!("unknown type {name}"))),
+            },
+        })
+    }
+
+    fn parse_count_type(&mut self) -> Result<Type, SchemaError> {
+        let line = self.line();
+        let ty = self.parse_type()?;
+
+        if !ty.is_count() {
+            return Err(self.error_at(line, "count type must be an integer"));
+        }
+
+        Ok(ty)
+    }
+
+    /**`#[...]` before structs and fields
+     */
+    fn skip_attributes(&mut self) -> Result<(), SchemaError> {
+        while self.eat('#') {
+            self.expect('[')?;
+
+            let mut depth = 1;
+
+            while depth > 0 {
+                match self.next()? {
+                    Token::Punct('[') => depth += 1,
+                    Token::Punct(']') => depth -= 1,
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /**`(crate)` after `pub`
+     */
+    fn skip_visibility(&mut self) -> Result<(), SchemaError> {
+        if self.eat('(') {
+            self.ident()?;
+            self.expect(')')?;
+        }
+
+        Ok(())
+    }
+
+    fn line(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .or(self.tokens.last())
+            .map_or(0, |v| v.1)
+    }
+
+    fn next(&mut self) -> Result<Token, SchemaError> {
+        let Some((token, _)) = self.tokens.get(self.pos) else {
+            return Err(self.error_at(self.line(), "unexpected end of schema"));
+        };
+
+        self.pos += 1;
+
+        Ok(token.clone())
+    }
+
+    fn ident(&mut self) -> Result<String, SchemaError> {
+        let line = self.line();
+
+        match self.next()? {
+            Token::Ident(v) => Ok(v),
+            Token::Punct(c) => Err(self.error_at(line, &format!("expected name, got '{c}'"))),
+        }
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self
+            .tokens
+            .get(self.pos)
+            .is_some_and(|v| v.0 == Token::Punct(c))
+        {
+            self.pos += 1;
+
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), SchemaError> {
+        if self.eat(c) {
+            return Ok(());
+        }
+
+        let line = self.line();
+
+        let got = match self.tokens.get(self.pos) {
+            Some((Token::Ident(v), _)) => v.clone(),
+            Some((Token::Punct(v), _)) => format!("'{v}'"),
+            None => "end of schema".to_string(),
+        };
+
+        Err(self.error_at(line, &format!("expected '{c}', got {got}")))
+    }
+
+    fn error_at(&self, line: usize, message: &str) -> SchemaError {
+        SchemaError::Syntax {
+            line,
+            message: message.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ue2_rw::{UnrealReader, UnrealWriter, DVEC, UVEC};
+    use crate::{save_dat, DatVariant, EncVersion};
+    use r#macro::{ReadUnreal, WriteUnreal};
+
+    #[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal)]
+    struct Coords {
+        x: FLOAT,
+        y: FLOAT,
+        z: FLOAT,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal)]
+    struct Record {
+        id: DWORD,
+        name: ASCF,
+        loc: Coords,
+        level: INDEX,
+        quests: Vec<WORD>,
+        npcs: UVEC<BYTE, DWORD>,
+        effects: DVEC<DWORD, DWORD, SHORT>,
+        mtx: MTX,
+        mtx3: MTX3,
+    }
+
+    const SCHEMA: &str = "
+        // Same layout as Record
+        struct Coords { x: FLOAT, y: FLOAT, z: FLOAT }
+
+        #[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal)]
+        pub struct Record {
+            pub(crate) id: DWORD,
+            name: ASCF,
+            loc: Coords,
+            level: CompactInt,
+            quests: Vec<USHORT>,
+            npcs: UVEC<BYTE, DWORD>,
+            effects: DVEC<DWORD, DWORD, SHORT>,
+            mtx: MTX,
+            mtx3: MTX3,
+        }
+
+        dat STR, Record;
+    ";
+
+    fn records() -> Vec<Record> {
+        (0..50u32)
+            .map(|i| Record {
+                id: i,
+                name: ASCF::from(format!("name {i}")),
+                loc: Coords {
+                    x: i as f32,
+                    y: 1.5,
+                    z: -2.0,
+                },
+                level: CompactInt(i as i32 * 1000 - 3000),
+                quests: (0..i % 4).map(|v| v as u16).collect(),
+                npcs: (0..i % 3).collect::<Vec<_>>().into(),
+                effects: (0..i % 5)
+                    .map(|v| (v, -(v as i16)))
+                    .collect::<Vec<_>>()
+                    .into(),
+                mtx: MTX {
+                    vec_1: vec![i].into(),
+                    vec_2: vec![].into(),
+                },
+                mtx3: MTX3 {
+                    vec_1: vec![1, 2],
+                    vec_1_f: vec![(3, 4), (5, 6)],
+                    vec_2: vec![i],
+                    val: 7,
+                },
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = |text: &str| match Schema::parse(text) {
+            Err(SchemaError::Syntax { line, message }) => (line, message),
+            v => panic!("{v:?}"),
+        };
+
+        assert_eq!(
+            err("struct A { a: DWORD }"),
+            (1, "dat declaration is missing".into())
+        );
+        assert_eq!(
+            err("struct A {\n a: B,\n}\ndat A;"),
+            (2, "unknown type B".into())
+        );
+        assert_eq!(
+            err("struct A { a: UVEC<FLOAT, BYTE> }\ndat A;"),
+            (1, "count type must be an integer".into())
+        );
+        assert_eq!(
+            err("struct A { a: B }\nstruct B { b: A }\ndat A;").1,
+            "recursive struct A > B > A"
+        );
+
+        let schema = Schema::parse("struct A { a: Vec<A> }\ndat UVEC<DWORD, A>;").unwrap();
+        assert_eq!(
+            schema.type_name(schema.record()),
+            "UVEC<DWORD, A>".to_string()
+        );
+    }
+
+    #[test]
+    fn matches_typed_structs() {
+        let path = std::env::temp_dir().join(format!("l2_rw_schema_{}.dat", std::process::id()));
+        let dict = vec!["a".to_string(), "b".to_string()];
+        let records = records();
+
+        save_dat(
+            &path,
+            DatVariant::DoubleArray(dict.clone(), records.clone()),
+            Some(EncVersion::V413),
+        )
+        .unwrap();
+        let original = std::fs::read(&path).unwrap();
+
+        let schema = Schema::parse(SCHEMA).unwrap();
+        let mut dat = schema.decode(&path).unwrap();
+
+        assert_eq!(
+            dat.dict,
+            Some(dict.iter().map(|v| Value::Str(v.clone())).collect())
+        );
+        assert_eq!(dat.records.len(), records.len());
+
+        let Value::Struct(fields) = &dat.records[3] else {
+            panic!()
+        };
+        assert_eq!(fields[0], Value::Dword(3));
+        assert_eq!(fields[3], Value::Index(0));
+        assert_eq!(
+            fields[2],
+            Value::Struct(vec![
+                Value::Float(3.0),
+                Value::Float(1.5),
+                Value::Float(-2.0)
+            ])
+        );
+
+        schema.save(&path, &dat).unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), original);
+
+        let Value::Struct(fields) = &mut dat.records[3] else {
+            panic!()
+        };
+        fields[0] = Value::Dword(100);
+        fields[1] = Value::Dword(1);
+        let err = schema.save(&path, &dat).unwrap_err().to_string();
+
+        assert!(
+            err.contains("record 3: Record.name: expected ASCF, got DWORD"),
+            "{err}"
+        );
+
+        let Value::Struct(fields) = &mut dat.records[3] else {
+            panic!()
+        };
+        fields[1] = Value::Ascf(ASCF::from("name 3".to_string()));
+        schema.save(&path, &dat).unwrap();
+
+        let (_, loaded, _) =
+            crate::deserialize_dat_with_string_dict_and_envelope::<STR, Record>(&path).unwrap();
+        assert_eq!(loaded[3].id, 100);
+        assert_eq!(loaded[4], records[4]);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn table_round_trip() {
+        let schema = Schema::parse(SCHEMA).unwrap();
+        let mut dat = DynamicDat {
+            dict: Some(vec![]),
+            records: vec![],
+            envelope: DatEnvelope::new(None),
+        };
+
+        let mut data = vec![];
+        CompactInt(0).write_unreal(&mut data).unwrap();
+        data.write_unreal_value(records().len() as u32).unwrap();
+        for v in records() {
+            data.write_unreal_value(v).unwrap();
+        }
+
+        let reader = DatReader::new(Path::new("test.dat"), std::io::Cursor::new(data)).unwrap();
+        dat.records = schema.decode_from(reader).unwrap().records;
+
+        let path = std::env::temp_dir().join(format!("l2_rw_table_{}.tsv", std::process::id()));
+        let table = schema.to_table(&dat);
+        table.write(&path).unwrap();
+
+        assert_eq!(table.columns[..3], ["id", "name", "loc.x"]);
+        assert!(table.columns.contains(&"effects[3].1".to_string()));
+        assert!(table.columns.contains(&"mtx3.vec_1_f[1].0".to_string()));
+
+        let loaded = Table::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, table);
+        assert_eq!(schema.records_from_table(&loaded).unwrap(), dat.records);
+
+        let mut broken = loaded.clone();
+        broken.columns[0] = "ids".to_string();
+        assert_eq!(
+            schema.records_from_table(&broken).unwrap_err().to_string(),
+            "row 1, column id: column is missing"
+        );
+
+        let mut broken = loaded.clone();
+        broken.columns.push("unknown".to_string());
+        broken.rows[0].push("1".to_string());
+        assert_eq!(
+            schema.records_from_table(&broken).unwrap_err().to_string(),
+            "column unknown: unknown column"
+        );
+
+        let mut broken = loaded;
+        broken.rows[2][0] = "x".to_string();
+        assert_eq!(
+            schema.records_from_table(&broken).unwrap_err().to_string(),
+            "row 3, column id: \"x\" is not DWORD"
+        );
+    }
+
+    #[test]
+    fn truncated_field_has_schema_path() {
+        let schema = Schema::parse(SCHEMA).unwrap();
+        let dat = DynamicDat {
+            dict: Some(vec![]),
+            records: vec![],
+            envelope: DatEnvelope::new(None),
+        };
+
+        let mut data = schema.serialize(&dat).unwrap();
+        data[1] = 1;
+        data.extend_from_slice(&[1, 0, 0, 0, 3]);
+
+        let path = Path::new("test.dat");
+        let err = schema
+            .decode_from(DatReader::new(path, std::io::Cursor::new(data)).unwrap())
+            .unwrap_err();
+
+        match err {
+            Error::TruncatedRecord { record, field, .. } => {
+                assert_eq!(record, 0);
+                assert_eq!(field.as_deref(), Some("Record.name"));
+            }
+            e => panic!("{e}"),
+        }
+    }
+}
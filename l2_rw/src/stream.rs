@@ -0,0 +1,568 @@
+use crate::error::{self, Error};
+use crate::rsa::{self, BLOCK_SIZE};
+use crate::ue2_rw::{ReadError, ReadUnreal, INDEX};
+use crate::{file_name, DatEnvelope, EncVersion, OriginalFile, END_BYTES, LINEAGE_HEADER};
+use inflate::DeflateDecoderBuf;
+use num_bigint::BigUint;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::VecDeque;
+use std::fs::File;
+use std::hash::Hasher;
+use std::io::{BufReader, Chain, Cursor, Read};
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+
+/**Passes raw file bytes through, hashing them on the way
+ */
+struct HashingReader<R: Read> {
+    inner: R,
+    hasher: DefaultHasher,
+}
+
+impl<R: Read> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let ct = self.inner.read(buf)?;
+        self.hasher.write(&buf[..ct]);
+
+        Ok(ct)
+    }
+}
+
+/**Decrypts RSA blocks one at a time. Partial block at the end of the file is the footer.
+
+Errors are returned as [`Error`] wrapped into `io::Error`, see [`unwrap_dat_error`]
+ */
+struct RsaBlockReader<R: Read> {
+    inner: R,
+    path: PathBuf,
+    offset: u64,
+    modulus: BigUint,
+    exp: BigUint,
+    block: [u8; BLOCK_SIZE],
+    start: usize,
+    end: usize,
+    footer: Option<Vec<u8>>,
+}
+
+impl<R: Read> RsaBlockReader<R> {
+    fn byte_to_int(b: u8) -> i32 {
+        if b > 128 {
+            b as i32 - 256
+        } else {
+            b as i32
+        }
+    }
+
+    /**Returns `false` when footer is reached
+     */
+    fn next_block(&mut self) -> error::Result<bool> {
+        let mut buff = [0u8; BLOCK_SIZE];
+
+        let ct = read_full(&mut self.inner, &mut buff).map_err(|source| Error::Io {
+            path: self.path.clone(),
+            source,
+        })?;
+
+        let rsa_error = || Error::RsaBlock {
+            path: self.path.clone(),
+            offset: self.offset,
+        };
+
+        if ct != BLOCK_SIZE {
+            if ct != END_BYTES.len() {
+                return Err(rsa_error());
+            }
+
+            self.footer = Some(buff[..ct].to_vec());
+
+            return Ok(false);
+        }
+
+        let Some(chunk) = rsa::transform_block(&buff, &self.modulus, &self.exp) else {
+            return Err(rsa_error());
+        };
+
+        let size = Self::byte_to_int(chunk[3]) & 0xFF;
+
+        if size > 124 {
+            return Err(rsa_error());
+        }
+
+        let pad = (-size & 0x1) + (-size & 0x2);
+
+        self.block = chunk;
+        self.start = (128 - size - pad) as usize;
+        self.end = self.start + size as usize;
+        self.offset += BLOCK_SIZE as u64;
+
+        Ok(true)
+    }
+}
+
+impl<R: Read> Read for RsaBlockReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.start == self.end {
+            if self.footer.is_some() {
+                return Ok(0);
+            }
+
+            match self.next_block() {
+                Ok(true) => {}
+                Ok(false) => return Ok(0),
+                Err(e) => return Err(std::io::Error::other(e)),
+            }
+        }
+
+        let ct = buf.len().min(self.end - self.start);
+        buf[..ct].copy_from_slice(&self.block[self.start..self.start + ct]);
+        self.start += ct;
+
+        Ok(ct)
+    }
+}
+
+fn read_full<R: Read>(reader: &mut R, buf: &mut [u8]) -> std::io::Result<usize> {
+    let mut read = 0;
+
+    while read < buf.len() {
+        let ct = reader.read(&mut buf[read..])?;
+
+        if ct == 0 {
+            break;
+        }
+
+        read += ct;
+    }
+
+    Ok(read)
+}
+
+/**Extracts [`Error`] that was passed through `Read` implementations, other errors are left as is
+ */
+fn unwrap_dat_error(e: std::io::Error) -> Result<Error, std::io::Error> {
+    if e.get_ref().is_some_and(|v| v.is::<Error>()) {
+        Ok(*e.into_inner().unwrap().downcast::<Error>().unwrap())
+    } else {
+        Err(e)
+    }
+}
+
+type RsaPayload<R> = DeflateDecoderBuf<BufReader<RsaBlockReader<HashingReader<R>>>>;
+
+enum Payload<R: Read> {
+    Plain(Chain<Cursor<Vec<u8>>, HashingReader<R>>),
+    Xor { inner: HashingReader<R>, key: u8 },
+    Rsa(Box<RsaPayload<R>>),
+}
+
+/**Decoded payload of a .dat file as a stream.
+
+Header is parsed on creation, everything else (RSA blocks, inflate, XOR) is done while reading,
+so only small buffers are kept in memory regardless of file size
+ */
+pub struct DatReader<R: Read = BufReader<File>> {
+    path: PathBuf,
+    version: Option<EncVersion>,
+    payload: Payload<R>,
+    peeked: VecDeque<u8>,
+    pos: u64,
+    hasher: DefaultHasher,
+}
+
+impl DatReader {
+    pub fn open(path: &Path) -> error::Result<Self> {
+        let file = File::open(path).map_err(|source| Error::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        Self::new(path, BufReader::new(file))
+    }
+}
+
+impl<R: Read> DatReader<R> {
+    /**`path` is used for error reporting and, for 121 version, to get XOR key
+     */
+    pub fn new(path: &Path, reader: R) -> error::Result<Self> {
+        let mut raw = HashingReader {
+            inner: reader,
+            hasher: DefaultHasher::new(),
+        };
+
+        let mut header = [0u8; 28];
+        let ct = read_full(&mut raw, &mut header).map_err(|source| Error::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        let (version, payload) = if header[..ct].starts_with(LINEAGE_HEADER) {
+            if ct < 28 {
+                return Err(Error::UnknownHeader {
+                    path: path.to_path_buf(),
+                });
+            }
+
+            let enc = &header[22..28];
+            let Some(version) = EncVersion::from_header(enc) else {
+                return Err(Error::UnknownEncVersion {
+                    path: path.to_path_buf(),
+                    version: String::from_utf8_lossy(enc).replace('\0', ""),
+                });
+            };
+
+            let payload = if let Some(modulus) = version.get_modulus() {
+                Self::rsa_payload(path, raw, modulus, version.get_exponent().unwrap())?
+            } else {
+                Payload::Xor {
+                    inner: raw,
+                    key: version.get_xor_key(&file_name(path)).unwrap(),
+                }
+            };
+
+            (Some(version), payload)
+        } else if header[..ct].starts_with(&LINEAGE_HEADER[..14]) {
+            return Err(Error::UnknownHeader {
+                path: path.to_path_buf(),
+            });
+        } else {
+            (
+                None,
+                Payload::Plain(Cursor::new(header[..ct].to_vec()).chain(raw)),
+            )
+        };
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            version,
+            payload,
+            peeked: VecDeque::new(),
+            pos: 0,
+            hasher: DefaultHasher::new(),
+        })
+    }
+
+    /**Skips 4 bytes of uncompressed size, zlib checksum at the end is not checked
+     */
+    fn rsa_payload(
+        path: &Path,
+        raw: HashingReader<R>,
+        modulus: BigUint,
+        exp: BigUint,
+    ) -> error::Result<Payload<R>> {
+        let mut decrypted = BufReader::new(RsaBlockReader {
+            inner: raw,
+            path: path.to_path_buf(),
+            offset: LINEAGE_HEADER.len() as u64 + 6,
+            modulus,
+            exp,
+            block: [0u8; BLOCK_SIZE],
+            start: 0,
+            end: 0,
+            footer: None,
+        });
+
+        let mut size = [0u8; 4];
+
+        match read_full(&mut decrypted, &mut size) {
+            Ok(4) => {}
+            Ok(_) => {
+                return Err(Error::Inflate {
+                    path: path.to_path_buf(),
+                    reason: "decrypted data is too short".to_string(),
+                })
+            }
+            Err(e) => return Err(Self::to_error(path, e)),
+        }
+
+        Ok(Payload::Rsa(Box::new(
+            DeflateDecoderBuf::from_zlib_no_checksum(decrypted),
+        )))
+    }
+
+    fn to_error(path: &Path, e: std::io::Error) -> Error {
+        unwrap_dat_error(e).unwrap_or_else(|source| Error::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn version(&self) -> Option<EncVersion> {
+        self.version
+    }
+
+    /**Position in decoded stream
+     */
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+
+    /**Records prefixed with `u32` count
+     */
+    pub fn records<T: ReadUnreal>(mut self) -> error::Result<DatRecords<T, R>> {
+        let count: u32 = self.read_record(0)?;
+        let stop_short = self.declares_extra_records();
+
+        Ok(DatRecords::new(self, count as usize, stop_short))
+    }
+
+    /**Records prefixed with compact int count, used for string dictionaries
+     */
+    pub fn string_dict<S: ReadUnreal>(mut self) -> error::Result<DatRecords<S, R>> {
+        let count: INDEX = self.read_record(0)?;
+
+        Ok(DatRecords::new(self, count.0.max(0) as usize, false))
+    }
+
+    /**Reads everything that is left as envelope tail and drains the file to get the footer
+     */
+    pub fn finish(mut self) -> error::Result<DatEnvelope> {
+        let mut tail = vec![];
+        self.read_to_end(&mut tail)
+            .map_err(|e| Self::to_error(&self.path, e))?;
+
+        let (footer, mut raw) = match self.payload {
+            Payload::Plain(v) => (vec![], v.into_inner().1),
+            Payload::Xor { inner, .. } => (vec![], inner),
+            Payload::Rsa(v) => {
+                let mut rsa = v.into_inner().into_inner();
+
+                std::io::copy(&mut rsa, &mut std::io::sink())
+                    .map_err(|e| Self::to_error(&self.path, e))?;
+
+                (rsa.footer.unwrap_or_default(), rsa.inner)
+            }
+        };
+
+        std::io::copy(&mut raw, &mut std::io::sink()).map_err(|source| Error::Io {
+            path: self.path.clone(),
+            source,
+        })?;
+
+        Ok(DatEnvelope {
+            version: self.version,
+            tail,
+            footer,
+            original: Some(OriginalFile {
+                payload_hash: self.hasher.finish(),
+                file_hash: raw.hasher.finish(),
+                path: self.path,
+            }),
+        })
+    }
+
+    /**Decoded payload as a whole
+     */
+    pub fn read_all(&mut self) -> error::Result<Vec<u8>> {
+        let mut res = vec![];
+        self.read_to_end(&mut res)
+            .map_err(|e| Self::to_error(&self.path, e))?;
+
+        Ok(res)
+    }
+
+    /**_baseinfo files declare more records than they have, reading should stop when less than
+16 bytes are left
+     */
+    pub(crate) fn declares_extra_records(&self) -> bool {
+        self.path
+            .to_string_lossy()
+            .to_lowercase()
+            .ends_with("_baseinfo.dat")
+    }
+
+    fn read_record<V: ReadUnreal>(&mut self, record: usize) -> error::Result<V> {
+        self.read_record_with(record, V::read_unreal)
+    }
+
+    /**Same as `read_record` for values without [`ReadUnreal`] impl
+     */
+    pub(crate) fn read_record_with<V>(
+        &mut self,
+        record: usize,
+        read: impl FnOnce(&mut Self) -> Result<V, ReadError>,
+    ) -> error::Result<V> {
+        read(self).map_err(|e| self.record_error(record, e))
+    }
+
+    fn record_error(&self, record: usize, e: ReadError) -> Error {
+        if e.is_eof() {
+            return Error::TruncatedRecord {
+                path: self.path.clone(),
+                offset: self.pos,
+                record,
+                field: e.field_path(),
+            };
+        }
+
+        let ReadError { source, trace } = e;
+
+        match unwrap_dat_error(source) {
+            Ok(e) => e,
+            Err(source) => Error::InvalidRecord {
+                path: self.path.clone(),
+                offset: self.pos,
+                record,
+                source: ReadError { source, trace },
+            },
+        }
+    }
+
+    /**True if at least `n` bytes are left in decoded stream
+     */
+    pub(crate) fn has_at_least(&mut self, n: usize) -> error::Result<bool> {
+        let mut buff = [0u8; 64];
+
+        while self.peeked.len() < n {
+            let ct = self
+                .read_payload(&mut buff)
+                .map_err(|e| Self::to_error(&self.path, e))?;
+
+            if ct == 0 {
+                return Ok(false);
+            }
+
+            self.peeked.extend(&buff[..ct]);
+        }
+
+        Ok(true)
+    }
+
+    fn read_payload(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let ct = match &mut self.payload {
+            Payload::Plain(v) => v.read(buf)?,
+            Payload::Xor { inner, key } => {
+                let ct = inner.read(buf)?;
+
+                for b in &mut buf[..ct] {
+                    *b ^= *key;
+                }
+
+                ct
+            }
+            Payload::Rsa(v) => v.read(buf).map_err(|e| match unwrap_dat_error(e) {
+                Ok(e) => std::io::Error::other(e),
+                Err(e) => std::io::Error::other(Error::Inflate {
+                    path: self.path.clone(),
+                    reason: e.to_string(),
+                }),
+            })?,
+        };
+
+        self.hasher.write(&buf[..ct]);
+
+        Ok(ct)
+    }
+}
+
+impl<R: Read> Read for DatReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let ct = if self.peeked.is_empty() {
+            self.read_payload(buf)?
+        } else {
+            self.peeked.read(buf)?
+        };
+
+        self.pos += ct as u64;
+
+        Ok(ct)
+    }
+}
+
+/**Lazy iterator over .dat records, stops after the first error.
+
+Use [`DatRecords::into_reader`] to continue with the data that follows the records
+ */
+pub struct DatRecords<T: ReadUnreal, R: Read = BufReader<File>> {
+    reader: DatReader<R>,
+    count: usize,
+    next: usize,
+    stop_short: bool,
+    failed: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<T: ReadUnreal> DatRecords<T> {
+    pub fn open(path: &Path) -> error::Result<Self> {
+        DatReader::open(path)?.records()
+    }
+}
+
+impl<T: ReadUnreal, R: Read> DatRecords<T, R> {
+    fn new(reader: DatReader<R>, count: usize, stop_short: bool) -> Self {
+        Self {
+            reader,
+            count,
+            next: 0,
+            stop_short,
+            failed: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /**Records count declared in the file
+     */
+    pub fn declared_count(&self) -> usize {
+        self.count
+    }
+
+    /**Position in decoded stream after the last read record
+     */
+    pub(crate) fn position(&self) -> u64 {
+        self.reader.position()
+    }
+
+    /**Skips records that weren't read yet
+     */
+    pub fn into_reader(mut self) -> error::Result<DatReader<R>> {
+        for v in self.by_ref() {
+            v?;
+        }
+
+        Ok(self.reader)
+    }
+}
+
+impl<T: ReadUnreal, R: Read> Iterator for DatRecords<T, R> {
+    type Item = error::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.next >= self.count {
+            return None;
+        }
+
+        if self.stop_short {
+            match self.reader.has_at_least(16) {
+                Ok(true) => {}
+                Ok(false) => {
+                    self.count = self.next;
+
+                    return None;
+                }
+                Err(e) => {
+                    self.failed = true;
+
+                    return Some(Err(e));
+                }
+            }
+        }
+
+        let res = self.reader.read_record(self.next);
+
+        self.next += 1;
+        self.failed = res.is_err();
+
+        Some(res)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.failed {
+            (0, Some(0))
+        } else {
+            (0, Some(self.count - self.next))
+        }
+    }
+}
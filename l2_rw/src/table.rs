@@ -0,0 +1,309 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+/**Records flattened into text columns, one row per record.
+
+Column names follow the same convention everywhere, so a table exported from a dat and from
+an entity holder look alike:
+
+* `field` for top level values, `parent.field` for nested struct fields
+* `list[0]`, `list[1].field` for list items, list length is the highest index with any
+  non-empty cell plus one, so rows with shorter lists just leave the rest empty
+* `pairs[0].0`, `pairs[0].1` for `DVEC` items and tuples
+ */
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Table {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+#[derive(Debug)]
+pub enum TableError {
+    Csv {
+        path: PathBuf,
+        source: csv::Error,
+    },
+    /**`row` is 1 based and doesn't count header, `None` for header errors
+     */
+    Invalid {
+        row: Option<usize>,
+        column: String,
+        message: String,
+    },
+}
+
+impl TableError {
+    pub fn invalid(row: Option<usize>, column: &str, message: impl Into<String>) -> Self {
+        TableError::Invalid {
+            row: row.map(|v| v + 1),
+            column: column.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for TableError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TableError::Csv { path, source } => write!(f, "{path:?}: {source}"),
+            TableError::Invalid {
+                row: Some(row),
+                column,
+                message,
+            } => write!(f, "row {row}, column {column}: {message}"),
+            TableError::Invalid {
+                row: None,
+                column,
+                message,
+            } => write!(f, "column {column}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for TableError {}
+
+/**Tab for `.tsv` files, comma for everything else
+ */
+pub fn delimiter(path: &Path) -> u8 {
+    if path
+        .extension()
+        .is_some_and(|v| v.eq_ignore_ascii_case("tsv"))
+    {
+        b'\t'
+    } else {
+        b','
+    }
+}
+
+impl Table {
+    /**Columns are merged keeping the order they come in, so list columns from longer rows stay
+    next to the shorter ones
+     */
+    pub fn from_flat_rows(flat: Vec<Vec<(String, String)>>) -> Self {
+        let mut columns: Vec<String> = vec![];
+        let mut index: HashMap<String, usize> = HashMap::new();
+
+        for row in &flat {
+            let mut next = 0;
+
+            for (column, _) in row {
+                if let Some(i) = index.get(column) {
+                    next = i + 1;
+
+                    continue;
+                }
+
+                columns.insert(next, column.clone());
+                next += 1;
+
+                index = columns
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| (v.clone(), i))
+                    .collect();
+            }
+        }
+
+        let rows = flat
+            .into_iter()
+            .map(|row| {
+                let mut res = vec![String::new(); columns.len()];
+
+                for (column, value) in row {
+                    res[index[&column]] = value;
+                }
+
+                res
+            })
+            .collect();
+
+        Self { columns, rows }
+    }
+
+    pub fn read(path: &Path) -> Result<Self, TableError> {
+        let to_error = |source| TableError::Csv {
+            path: path.to_path_buf(),
+            source,
+        };
+
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(delimiter(path))
+            .from_path(path)
+            .map_err(to_error)?;
+
+        let columns = reader
+            .headers()
+            .map_err(to_error)?
+            .iter()
+            .map(|v| v.trim().to_string())
+            .collect();
+
+        let mut rows = vec![];
+
+        for record in reader.records() {
+            rows.push(record.map_err(to_error)?.iter().map(String::from).collect());
+        }
+
+        Ok(Self { columns, rows })
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), TableError> {
+        let to_error = |source| TableError::Csv {
+            path: path.to_path_buf(),
+            source,
+        };
+
+        let mut writer = csv::WriterBuilder::new()
+            .delimiter(delimiter(path))
+            .from_path(path)
+            .map_err(to_error)?;
+
+        writer.write_record(&self.columns).map_err(to_error)?;
+
+        for row in &self.rows {
+            writer.write_record(row).map_err(to_error)?;
+        }
+
+        writer.flush().map_err(|e| to_error(e.into()))
+    }
+}
+
+/**Reads cells by column names and keeps track of the columns that were used, so columns which
+don't belong to the records can be reported after import
+ */
+pub struct TableReader<'a> {
+    table: &'a Table,
+    index: HashMap<&'a str, usize>,
+    used: Vec<bool>,
+    lists: HashSet<String>,
+}
+
+impl<'a> TableReader<'a> {
+    pub fn new(table: &'a Table) -> Result<Self, TableError> {
+        let mut index = HashMap::new();
+
+        for (i, column) in table.columns.iter().enumerate() {
+            if index.insert(column.as_str(), i).is_some() {
+                return Err(TableError::invalid(None, column, "duplicated column"));
+            }
+        }
+
+        Ok(Self {
+            table,
+            index,
+            used: vec![false; table.columns.len()],
+            lists: HashSet::new(),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.table.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.table.rows.is_empty()
+    }
+
+    /**Missing column is an error, missing cell in a short row is read as empty. Cell is not
+    trimmed, strings keep their spaces
+     */
+    pub fn cell(&mut self, row: usize, column: &str) -> Result<&'a str, TableError> {
+        let Some(i) = self.index.get(column) else {
+            return Err(TableError::invalid(Some(row), column, "column is missing"));
+        };
+
+        self.used[*i] = true;
+
+        Ok(self.table.rows[row].get(*i).map_or("", String::as_str))
+    }
+
+    /**Highest index with any non-empty cell under `list[i]` plus one
+     */
+    pub fn list_len(&mut self, row: usize, list: &str) -> usize {
+        let prefix = format!("{list}[");
+        let mut len = 0;
+
+        for (column, i) in &self.index {
+            let Some(rest) = column.strip_prefix(&prefix) else {
+                continue;
+            };
+
+            let Some(Ok(n)) = rest.split(']').next().map(str::parse::<usize>) else {
+                continue;
+            };
+
+            if n >= len
+                && self.table.rows[row]
+                    .get(*i)
+                    .is_some_and(|v| !v.trim().is_empty())
+            {
+                len = n + 1;
+            }
+        }
+
+        self.lists.insert(prefix);
+
+        len
+    }
+
+    /**True if any cell of `path` or of its nested fields and items is not empty, used for
+    optional values
+     */
+    pub fn has_value(&mut self, row: usize, path: &str) -> bool {
+        let mut res = false;
+
+        for (column, i) in &self.index {
+            let nested = column
+                .strip_prefix(path)
+                .is_some_and(|v| v.is_empty() || v.starts_with('.') || v.starts_with('['));
+
+            if nested {
+                self.used[*i] = true;
+                res |= self.table.rows[row]
+                    .get(*i)
+                    .is_some_and(|v| !v.trim().is_empty());
+            }
+        }
+
+        res
+    }
+
+    /**Columns that weren't read for any row. Empty columns of list items past the longest list
+    are fine
+     */
+    pub fn check_unused(&self) -> Result<(), TableError> {
+        for (i, column) in self.table.columns.iter().enumerate() {
+            if self.used[i] {
+                continue;
+            }
+
+            let in_list = self.lists.iter().any(|v| column.starts_with(v.as_str()));
+            let is_empty = self
+                .table
+                .rows
+                .iter()
+                .all(|row| row.get(i).is_none_or(|v| v.trim().is_empty()));
+
+            if !(in_list && is_empty) {
+                return Err(TableError::invalid(None, column, "unknown column"));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/**`parent.field`, or just `field` for the top level
+ */
+pub fn field_path(parent: &str, field: &str) -> String {
+    if parent.is_empty() {
+        field.to_string()
+    } else {
+        format!("{parent}.{field}")
+    }
+}
+
+pub fn item_path(parent: &str, index: usize) -> String {
+    format!("{parent}[{index}]")
+}
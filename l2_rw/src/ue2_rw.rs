@@ -1,7 +1,9 @@
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use num_traits::{AsPrimitive, FromPrimitive};
 use r#macro::{ReadUnreal, WriteUnreal};
-use std::io::{Read, Write};
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+use std::io::{ErrorKind, Read, Write};
 use std::marker::PhantomData;
 use std::slice;
 use std::slice::Iter;
@@ -41,9 +43,9 @@ impl ASCF {
     }
 }
 
-impl ToString for ASCF {
-    fn to_string(&self) -> String {
-        self.0.replace('\0', "").replace("\\n", "\n")
+impl Display for ASCF {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0.replace('\0', "").replace("\\n", "\n"))
     }
 }
 
@@ -121,36 +123,41 @@ pub struct MTX3 {
 }
 
 impl ReadUnreal for MTX3 {
-    fn read_unreal<T: Read>(reader: &mut T) -> Self {
-        let s1 = reader.read_u8().unwrap() as usize;
+    fn read_unreal<T: Read>(reader: &mut T) -> Result<Self, ReadError> {
+        let field = |name| move |e: std::io::Error| ReadError::from(e).in_field("MTX3", name);
+
+        let s1 = reader.read_u8().map_err(field("vec_1"))? as usize;
 
         let mut vec_1 = Vec::with_capacity(s1);
         let mut vec_1_f = Vec::with_capacity(s1);
 
         for _ in 0..s1 {
-            vec_1.push(reader.read_u32::<LittleEndian>().unwrap())
+            vec_1.push(reader.read_u32::<LittleEndian>().map_err(field("vec_1"))?)
         }
 
         for _ in 0..s1 {
-            vec_1_f.push((reader.read_u8().unwrap(), reader.read_u8().unwrap()))
+            vec_1_f.push((
+                reader.read_u8().map_err(field("vec_1_f"))?,
+                reader.read_u8().map_err(field("vec_1_f"))?,
+            ))
         }
 
-        let s2 = reader.read_u8().unwrap() as usize;
+        let s2 = reader.read_u8().map_err(field("vec_2"))? as usize;
 
         let mut vec_2 = Vec::with_capacity(s1);
 
         for _ in 0..s2 {
-            vec_2.push(reader.read_u32::<LittleEndian>().unwrap())
+            vec_2.push(reader.read_u32::<LittleEndian>().map_err(field("vec_2"))?)
         }
 
-        let val = reader.read_u32::<LittleEndian>().unwrap();
+        let val = reader.read_u32::<LittleEndian>().map_err(field("val"))?;
 
-        Self {
+        Ok(Self {
             vec_1,
             vec_1_f,
             vec_2,
             val,
-        }
+        })
     }
 }
 
@@ -254,13 +261,12 @@ impl WriteUnreal for ASCF {
         } else {
             let c = self.0.encode_utf16();
 
-            CompactInt(-(c.clone().count() as i32) - 1).write_unreal(writer)?;
+            //Terminating zero is already in the string, same as for ascii
+            CompactInt(-(c.clone().count() as i32)).write_unreal(writer)?;
 
             for v in c {
                 writer.write_all(&v.to_le_bytes())?;
             }
-
-            writer.write_all(&0u16.to_le_bytes())?;
         }
 
         Ok(())
@@ -408,17 +414,98 @@ impl<T: Write> UnrealWriter for T {
     }
 }
 
-pub trait ReadUnreal {
-    fn read_unreal<T: Read>(reader: &mut T) -> Self;
+/**Error produced by [`ReadUnreal`].
+
+`trace` holds struct and field names filled by `#[derive(ReadUnreal)]` (or by
+[`crate::schema::Schema`] for dynamic structs) while error goes up, innermost field first.
+ */
+#[derive(Debug)]
+pub struct ReadError {
+    pub source: std::io::Error,
+    pub trace: Vec<(Cow<'static, str>, Cow<'static, str>)>,
+}
+
+impl ReadError {
+    pub fn in_field(
+        mut self,
+        struct_name: impl Into<Cow<'static, str>>,
+        field_name: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.trace.push((struct_name.into(), field_name.into()));
+
+        self
+    }
+
+    pub fn is_eof(&self) -> bool {
+        self.source.kind() == ErrorKind::UnexpectedEof
+    }
+
+    /**`Outer.field > Inner.field`, `None` if error happened outside of derived struct
+     */
+    pub fn field_path(&self) -> Option<String> {
+        if self.trace.is_empty() {
+            return None;
+        }
+
+        Some(
+            self.trace
+                .iter()
+                .rev()
+                .map(|(s, f)| format!("{s}.{f}"))
+                .collect::<Vec<_>>()
+                .join(" > "),
+        )
+    }
+
+    pub(crate) fn invalid_data(msg: &str) -> Self {
+        std::io::Error::new(ErrorKind::InvalidData, msg.to_string()).into()
+    }
+}
+
+impl From<std::io::Error> for ReadError {
+    fn from(value: std::io::Error) -> Self {
+        Self {
+            source: value,
+            trace: vec![],
+        }
+    }
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(path) = self.field_path() {
+            write!(f, "{path}: {}", self.source)
+        } else {
+            write!(f, "{}", self.source)
+        }
+    }
+}
+
+impl std::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+pub trait ReadUnreal: Sized {
+    fn read_unreal<T: Read>(reader: &mut T) -> Result<Self, ReadError>;
+}
+
+/**Placeholder for files without string dictionary, same as [`WriteUnreal`] impl
+ */
+impl ReadUnreal for () {
+    fn read_unreal<T: Read>(_reader: &mut T) -> Result<Self, ReadError> {
+        Ok(())
+    }
 }
 
 impl ReadUnreal for INDEX {
-    fn read_unreal<T: Read>(reader: &mut T) -> Self {
+    fn read_unreal<T: Read>(reader: &mut T) -> Result<Self, ReadError> {
         let mut output: i32 = 0;
         let mut signed = false;
 
         for i in 0..4 {
-            let x = reader.read_u8().unwrap() as i32 & 255;
+            let x = reader.read_u8()? as i32 & 255;
 
             if i == 0 {
                 if x & 128 > 0 {
@@ -444,25 +531,25 @@ impl ReadUnreal for INDEX {
             output *= -1
         }
 
-        Self(output)
+        Ok(Self(output))
     }
 }
 
 impl ReadUnreal for STR {
-    fn read_unreal<T: Read>(reader: &mut T) -> Self {
-        let count = u32::read_unreal(reader);
+    fn read_unreal<T: Read>(reader: &mut T) -> Result<Self, ReadError> {
+        let count = u32::read_unreal(reader)?;
         let mut bytes: Vec<u8> = vec![0u8; count as usize];
-        reader.read_exact(&mut bytes).unwrap();
+        reader.read_exact(&mut bytes)?;
         let s: &[u16] =
             unsafe { slice::from_raw_parts(bytes.as_ptr() as *const _, bytes.len() / 2) };
 
-        String::from_utf16(s).unwrap()
+        String::from_utf16(s).map_err(|_| ReadError::invalid_data("invalid UTF-16 string"))
     }
 }
 
 impl ReadUnreal for ASCF {
-    fn read_unreal<T: Read>(reader: &mut T) -> Self {
-        let mut count = reader.read_unreal_value::<INDEX>().0;
+    fn read_unreal<T: Read>(reader: &mut T) -> Result<Self, ReadError> {
+        let mut count = reader.read_unreal_value::<INDEX>()?.0;
         let mut skip = 1;
 
         if count < 0 {
@@ -471,100 +558,102 @@ impl ReadUnreal for ASCF {
         }
 
         let mut bytes: Vec<u8> = vec![0u8; count as usize];
-        reader.read_exact(&mut bytes).unwrap();
+        reader.read_exact(&mut bytes)?;
 
         if skip == 2 {
             let s: &[u16] =
                 unsafe { slice::from_raw_parts(bytes.as_ptr() as *const _, count as usize / 2) };
-            ASCF(String::from_utf16(s).unwrap())
+            Ok(ASCF(String::from_utf16(s).map_err(|_| {
+                ReadError::invalid_data("invalid UTF-16 string")
+            })?))
         } else {
-            ASCF(CP1252.decode(&bytes).to_string())
+            Ok(ASCF(CP1252.decode(&bytes).to_string()))
         }
     }
 }
 
 impl ReadUnreal for u8 {
-    fn read_unreal<T: Read>(reader: &mut T) -> Self {
-        reader.read_u8().unwrap()
+    fn read_unreal<T: Read>(reader: &mut T) -> Result<Self, ReadError> {
+        Ok(reader.read_u8()?)
     }
 }
 
 impl ReadUnreal for u16 {
-    fn read_unreal<T: Read>(reader: &mut T) -> Self {
-        reader.read_u16::<LittleEndian>().unwrap()
+    fn read_unreal<T: Read>(reader: &mut T) -> Result<Self, ReadError> {
+        Ok(reader.read_u16::<LittleEndian>()?)
     }
 }
 
 impl ReadUnreal for i16 {
-    fn read_unreal<T: Read>(reader: &mut T) -> Self {
-        reader.read_i16::<LittleEndian>().unwrap()
+    fn read_unreal<T: Read>(reader: &mut T) -> Result<Self, ReadError> {
+        Ok(reader.read_i16::<LittleEndian>()?)
     }
 }
 
 impl ReadUnreal for u32 {
-    fn read_unreal<T: Read>(reader: &mut T) -> Self {
-        reader.read_u32::<LittleEndian>().unwrap()
+    fn read_unreal<T: Read>(reader: &mut T) -> Result<Self, ReadError> {
+        Ok(reader.read_u32::<LittleEndian>()?)
     }
 }
 
 impl ReadUnreal for f32 {
-    fn read_unreal<T: Read>(reader: &mut T) -> Self {
-        reader.read_f32::<LittleEndian>().unwrap()
+    fn read_unreal<T: Read>(reader: &mut T) -> Result<Self, ReadError> {
+        Ok(reader.read_f32::<LittleEndian>()?)
     }
 }
 
 impl ReadUnreal for f64 {
-    fn read_unreal<T: Read>(reader: &mut T) -> Self {
-        reader.read_f64::<LittleEndian>().unwrap()
+    fn read_unreal<T: Read>(reader: &mut T) -> Result<Self, ReadError> {
+        Ok(reader.read_f64::<LittleEndian>()?)
     }
 }
 
 impl ReadUnreal for i32 {
-    fn read_unreal<T: Read>(reader: &mut T) -> Self {
-        reader.read_i32::<LittleEndian>().unwrap()
+    fn read_unreal<T: Read>(reader: &mut T) -> Result<Self, ReadError> {
+        Ok(reader.read_i32::<LittleEndian>()?)
     }
 }
 
 impl ReadUnreal for i64 {
-    fn read_unreal<T: Read>(reader: &mut T) -> Self {
-        reader.read_i64::<LittleEndian>().unwrap()
+    fn read_unreal<T: Read>(reader: &mut T) -> Result<Self, ReadError> {
+        Ok(reader.read_i64::<LittleEndian>()?)
     }
 }
 
 impl ReadUnreal for u128 {
-    fn read_unreal<T: Read>(reader: &mut T) -> Self {
-        reader.read_u128::<LittleEndian>().unwrap()
+    fn read_unreal<T: Read>(reader: &mut T) -> Result<Self, ReadError> {
+        Ok(reader.read_u128::<LittleEndian>()?)
     }
 }
 
 impl<V: ReadUnreal> ReadUnreal for Vec<V> {
-    fn read_unreal<T: Read>(reader: &mut T) -> Self {
-        let len = INDEX::read_unreal(reader).0;
+    fn read_unreal<T: Read>(reader: &mut T) -> Result<Self, ReadError> {
+        let len = INDEX::read_unreal(reader)?.0;
 
-        let mut res = Vec::with_capacity(len as usize);
+        let mut res = Vec::with_capacity(len.max(0) as usize);
 
         for _ in 0..len {
-            res.push(V::read_unreal(reader))
+            res.push(V::read_unreal(reader)?)
         }
 
-        res
+        Ok(res)
     }
 }
 
 impl<I: ReadUnreal + AsPrimitive<usize>, V: ReadUnreal> ReadUnreal for UVEC<I, V> {
-    fn read_unreal<T: Read>(reader: &mut T) -> Self {
-        let len: usize = I::read_unreal(reader).as_();
+    fn read_unreal<T: Read>(reader: &mut T) -> Result<Self, ReadError> {
+        let len: usize = I::read_unreal(reader)?.as_();
 
         let mut res = Vec::with_capacity(len);
 
         for _ in 0..len {
-            res.push(V::read_unreal(reader))
+            res.push(V::read_unreal(reader)?)
         }
 
-        UVEC {
+        Ok(UVEC {
             _i: PhantomData,
             inner: res,
-        }
+        })
     }
 }
 
@@ -574,31 +663,31 @@ impl<
         V2: ReadUnreal + Default + Clone,
     > ReadUnreal for DVEC<I, V1, V2>
 {
-    fn read_unreal<T: Read>(reader: &mut T) -> Self {
-        let len: usize = I::read_unreal(reader).as_();
+    fn read_unreal<T: Read>(reader: &mut T) -> Result<Self, ReadError> {
+        let len: usize = I::read_unreal(reader)?.as_();
 
         let mut res = vec![(V1::default(), V2::default()); len];
 
         for v in res.iter_mut().take(len) {
-            v.0 = V1::read_unreal(reader);
+            v.0 = V1::read_unreal(reader)?;
         }
         for v in res.iter_mut().take(len) {
-            v.1 = V2::read_unreal(reader);
+            v.1 = V2::read_unreal(reader)?;
         }
 
-        DVEC {
+        Ok(DVEC {
             _i: PhantomData,
             inner: res,
-        }
+        })
     }
 }
 
 pub trait UnrealReader {
-    fn read_unreal_value<V: ReadUnreal>(&mut self) -> V;
+    fn read_unreal_value<V: ReadUnreal>(&mut self) -> Result<V, ReadError>;
 }
 
 impl<T: Read> UnrealReader for T {
-    fn read_unreal_value<Z: ReadUnreal>(&mut self) -> Z {
+    fn read_unreal_value<Z: ReadUnreal>(&mut self) -> Result<Z, ReadError> {
         Z::read_unreal(self)
     }
 }
@@ -624,3 +713,51 @@ impl UnrealCasts for bool {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    /**"Лектор" as stored in npcname-ru.dat: negative char count including terminating zero,
+    then UTF-16LE chars
+     */
+    const LECTOR: [u8; 15] = [
+        0x87, 0x1B, 0x04, 0x35, 0x04, 0x3A, 0x04, 0x42, 0x04, 0x3E, 0x04, 0x40, 0x04, 0x00, 0x00,
+    ];
+
+    #[test]
+    fn reads_utf16_ascf() {
+        let read = ASCF::read_unreal(&mut Cursor::new(&LECTOR)).unwrap();
+
+        assert_eq!(read, ASCF::from("Лектор".to_string()));
+        assert_eq!(read.to_string(), "Лектор");
+    }
+
+    #[test]
+    fn writes_utf16_ascf_as_read() {
+        let read = ASCF::read_unreal(&mut Cursor::new(&LECTOR)).unwrap();
+
+        let mut bytes = vec![];
+        read.write_unreal(&mut bytes).unwrap();
+
+        assert_eq!(bytes, LECTOR);
+    }
+
+    #[test]
+    fn utf16_ascf_round_trip() {
+        let value = ASCF::from("Гремлин".to_string());
+
+        let mut bytes = vec![];
+        value.write_unreal(&mut bytes).unwrap();
+
+        assert_eq!(bytes.len(), 1 + 8 * 2);
+
+        let read = ASCF::read_unreal(&mut Cursor::new(&bytes)).unwrap();
+        assert_eq!(read, value);
+
+        let mut again = vec![];
+        read.write_unreal(&mut again).unwrap();
+        assert_eq!(again, bytes);
+    }
+}
@@ -26,6 +26,7 @@ pub fn write_unreal_derive(input: proc_macro::TokenStream) -> proc_macro::TokenS
 
     let expanded = quote! {
         impl WriteUnreal for #struct_name {
+            #[allow(clippy::needless_borrows_for_generic_args)] //Copy fields are borrowed too
             fn write_unreal<T: std::io::Write>(&self, writer: &mut T) -> std::io::Result<()> {
                 #(#field_conversions)*
 
@@ -62,14 +63,14 @@ pub fn read_unreal_derive(input: proc_macro::TokenStream) -> proc_macro::TokenSt
         _ => panic!("ReadUnreal can only be derived for structs"),
     };
 
-    let field_conversions = generate_field_conversions(&struct_info);
+    let field_conversions = generate_field_conversions(&struct_name, &struct_info);
 
     let expanded = quote! {
         impl ReadUnreal for #struct_name {
-            fn read_unreal<T: std::io::Read>(reader: &mut T) -> Self {
-                Self {
+            fn read_unreal<T: std::io::Read>(reader: &mut T) -> std::result::Result<Self, ReadError> {
+                Ok(Self {
                     #(#field_conversions)*
-                }
+                })
             }
         }
     };
@@ -95,7 +96,7 @@ fn parse_struct(data: syn::DataStruct) -> StructInfo {
     StructInfo { fields: fields_inf }
 }
 
-fn generate_field_conversions(struct_info: &StructInfo) -> Vec<TokenStream> {
+fn generate_field_conversions(struct_name: &Ident, struct_info: &StructInfo) -> Vec<TokenStream> {
     struct_info
         .fields
         .iter()
@@ -103,7 +104,9 @@ fn generate_field_conversions(struct_info: &StructInfo) -> Vec<TokenStream> {
             let ident = &field.ident;
             let ty = &field.ty;
             quote_spanned! {
-                ident.span() => #ident: reader.read_unreal_value::<#ty>(),
+                ident.span() => #ident: reader
+                    .read_unreal_value::<#ty>()
+                    .map_err(|e| e.in_field(stringify!(#struct_name), stringify!(#ident)))?,
             }
         })
         .collect()
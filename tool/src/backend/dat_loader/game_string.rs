@@ -0,0 +1,158 @@
+use crate::backend::dat_loader::translation::LocalizedRecord;
+use crate::backend::dat_loader::{decode_dat, write_dat, DatDecoder, DatOutput, L2StringTable};
+use crate::backend::holder::{GameDataHolder, HolderMapOps, HolderOps};
+use crate::backend::log_holder::Log;
+use crate::data::{GameStringId, StringTable};
+use crate::entity::game_string::GameString;
+use l2_rw::ue2_rw::{ReadError, ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+use l2_rw::ue2_rw::{ASCF, DWORD};
+use l2_rw::DatVariant;
+use r#macro::{ReadUnreal, WriteUnreal};
+use std::thread;
+use std::thread::JoinHandle;
+
+/**Id and string tables which have the same layout in every chronicle
+ */
+const STRING_DATS: &[(StringTable, &str)] = &[
+    (StringTable::NpcString, "npcstring"),
+    (StringTable::SysString, "sysstring"),
+];
+
+pub(super) const LOCALIZED_DATS: &[(&str, DatDecoder)] = &[
+    ("npcstring", decode_dat::<StringDat>),
+    ("sysstring", decode_dat::<StringDat>),
+];
+
+impl GameDataHolder {
+    /**Tables which dats are missing are skipped with a warning, they are not saved either
+     */
+    pub(super) fn load_string_dats(&mut self) -> Result<Vec<Log>, Log> {
+        let mut logs = vec![];
+
+        for (table, base_name) in STRING_DATS {
+            let file_name = self.main_dat(base_name);
+
+            if !self.dat_paths.contains_key(&file_name) {
+                logs.push(Log::from_loader_w(&format!(
+                    "{file_name} not found, {table} strings are not loaded"
+                )));
+
+                continue;
+            }
+
+            let records = self.read_dat::<StringDat>(&file_name)?;
+
+            logs.extend(self.read_translations(base_name, &records));
+
+            for v in records {
+                let v = GameString::from_table(*table, v.id, v.value.to_string());
+
+                self.game_string_holder.insert(v.id, v);
+            }
+        }
+
+        Ok(logs)
+    }
+
+    /**Records of `table` which are not deleted, sorted by id
+     */
+    pub(super) fn game_strings(&self, table: StringTable) -> Vec<&GameString> {
+        let mut res: Vec<_> = self
+            .game_string_holder
+            .values()
+            .filter(|v| v.id.table == table && !v._deleted)
+            .collect();
+
+        res.sort_by_key(|v| v.id);
+
+        res
+    }
+
+    /**Dats of [`STRING_DATS`] which were loaded and their translations, each on its own thread
+     */
+    pub(super) fn serialize_string_dats(&self, output: &DatOutput) -> Vec<JoinHandle<Log>> {
+        let mut res = vec![];
+
+        for (table, base_name) in STRING_DATS {
+            let file_name = self.main_dat(base_name);
+
+            if !self.dat_envelopes.contains_key(&file_name) {
+                continue;
+            }
+
+            let records: Vec<_> = self
+                .game_strings(*table)
+                .into_iter()
+                .map(|v| StringDat {
+                    id: v.id.id,
+                    value: (&v.value).into(),
+                })
+                .collect();
+
+            let translations = self.translated(base_name, &records);
+            res.extend(self.save_translations(base_name, translations, output));
+
+            let path = self.dat_paths.get(&file_name).unwrap().clone();
+            let target = self.dat_target(&file_name, output);
+
+            res.push(thread::spawn(move || {
+                if let Err(e) = write_dat(
+                    path.path(),
+                    DatVariant::<(), StringDat>::Array(records),
+                    &target,
+                ) {
+                    Log::from(e)
+                } else {
+                    Log::from_loader_i(&format!("{file_name} saved"))
+                }
+            }));
+        }
+
+        res
+    }
+
+    /**l2gamedataname strings are edited through `game_string_table`, holder only
+    mirrors them. Strings are added there while other dats are serialized too, so new ones are
+    picked up after load and save
+     */
+    pub(super) fn sync_game_data_names(&mut self) {
+        let changed = !self.game_string_holder.is_unchanged();
+
+        let mut indexes: Vec<_> = self.game_string_table.keys().copied().collect();
+        indexes.sort();
+
+        for index in indexes {
+            let id = GameStringId {
+                table: StringTable::GameDataName,
+                id: index,
+            };
+
+            if self.game_string_holder.get(&id).is_none() {
+                let value = self.game_string_table.get_o(&index);
+
+                self.game_string_holder
+                    .insert(id, GameString::from_table(id.table, index, value));
+            }
+        }
+
+        self.game_string_holder.set_changed(changed);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal)]
+pub(super) struct StringDat {
+    pub(super) id: DWORD,
+    pub(super) value: ASCF,
+}
+
+impl LocalizedRecord for StringDat {
+    type Key = u32;
+
+    fn key(&self) -> u32 {
+        self.id
+    }
+
+    fn copy_text(&mut self, translation: &Self) {
+        self.value = translation.value.clone();
+    }
+}
@@ -1,11 +1,11 @@
 use crate::backend::log_holder::{Log, LogLevel};
 
 use l2_rw::ue2_rw::{ASCF, DWORD, INT};
-use l2_rw::{deserialize_dat, save_dat, DatVariant};
+use l2_rw::DatVariant;
 
-use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+use l2_rw::ue2_rw::{ReadError, ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
-use crate::backend::dat_loader::L2StringTable;
+use crate::backend::dat_loader::{decode_dat, write_dat, DatDecoder, DatOutput, L2StringTable};
 use crate::backend::holder::{GameDataHolder, HolderMapOps};
 use crate::data::AnimationComboId;
 use crate::entity::animation_combo::AnimationCombo;
@@ -13,8 +13,12 @@ use r#macro::{ReadUnreal, WriteUnreal};
 use std::thread;
 use std::thread::JoinHandle;
 
+pub(super) const DATS: &[(&str, DatDecoder)] = &[
+    ("animationcombo.dat", decode_dat::<AnimationComboDat>),
+];
+
 impl GameDataHolder {
-    pub fn serialize_animation_combo_to_binary(&mut self) -> JoinHandle<Log> {
+    pub fn serialize_animation_combo_to_binary(&mut self, output: &DatOutput) -> JoinHandle<Log> {
         let raid_grp: Vec<AnimationComboDat> = self
             .animation_combo_holder
             .values()
@@ -33,28 +37,25 @@ impl GameDataHolder {
             .get(&"animationcombo.dat".to_string())
             .unwrap()
             .clone();
+        let dat_target = self.dat_target("animationcombo.dat", output);
 
         thread::spawn(move || {
-            if let Err(e) = save_dat(
+            if let Err(e) = write_dat(
                 dat_path.path(),
                 DatVariant::<(), AnimationComboDat>::Array(raid_grp.to_vec()),
+                &dat_target,
             ) {
-                Log::from_loader_e(e)
+                Log::from(e)
             } else {
                 Log::from_loader_i("AnimationCombo saved")
             }
         })
     }
 
-    pub fn load_animation_combo(&mut self) -> Result<Vec<Log>, ()> {
+    pub fn load_animation_combo(&mut self) -> Result<Vec<Log>, Log> {
         let mut warnings = vec![];
 
-        let raid_grp = deserialize_dat::<AnimationComboDat>(
-            self.dat_paths
-                .get(&"animationcombo.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
+        let raid_grp = self.read_dat::<AnimationComboDat>("animationcombo.dat")?;
 
         for (i, v) in raid_grp.iter().enumerate() {
             let Some(name) = self.game_string_table.get(&v.name) else {
@@ -62,6 +63,7 @@ impl GameDataHolder {
                     level: LogLevel::Warning,
                     producer: "Animation Combo Loader".to_string(),
                     log: format!("No record in l2gamedataname for name {}", v.name),
+                    entity: None,
                 });
 
                 continue;
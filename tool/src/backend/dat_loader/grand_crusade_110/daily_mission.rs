@@ -2,11 +2,12 @@ use crate::backend::log_holder::Log;
 use std::io::{Read, Write};
 
 use l2_rw::ue2_rw::{ASCF, DWORD};
-use l2_rw::{deserialize_dat, save_dat, DatVariant};
+use l2_rw::DatVariant;
 
-use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+use l2_rw::ue2_rw::{ReadError, ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
-use crate::backend::dat_loader::GetId;
+use crate::backend::dat_loader::translation::LocalizedRecord;
+use crate::backend::dat_loader::{decode_dat, write_dat, DatDecoder, DatOutput, GetId};
 use crate::backend::holder::{GameDataHolder, HolderMapOps};
 use crate::data::PlayerClass;
 use crate::entity::daily_mission::{
@@ -17,8 +18,14 @@ use r#macro::{ReadUnreal, WriteUnreal};
 use std::thread;
 use std::thread::JoinHandle;
 
+pub(super) const LOCALIZED_DATS: &[(&str, DatDecoder)] =
+    &[("onedayreward", decode_dat::<OneDayRewardDat>)];
+
 impl GameDataHolder {
-    pub fn serialize_daily_missions_to_binary(&mut self) -> JoinHandle<Log> {
+    pub fn serialize_daily_missions_to_binary(
+        &mut self,
+        output: &DatOutput,
+    ) -> JoinHandle<Vec<Log>> {
         let onedayrewards: Vec<OneDayRewardDat> = self
             .daily_mission_holder
             .values()
@@ -68,29 +75,37 @@ impl GameDataHolder {
 
         let dat_path = self
             .dat_paths
-            .get(&"onedayreward-ru.dat".to_string())
+            .get(&self.main_dat("onedayreward"))
             .unwrap()
             .clone();
+        let dat_target = self.dat_target(&self.main_dat("onedayreward"), output);
+
+        let translations = self.translated("onedayreward", &onedayrewards);
+        let translation_handles = self.save_translations("onedayreward", translations, output);
 
         thread::spawn(move || {
-            if let Err(e) = save_dat(
+            let mut logs = if let Err(e) = write_dat(
                 dat_path.path(),
                 DatVariant::<(), OneDayRewardDat>::Array(onedayrewards.to_vec()),
+                &dat_target,
             ) {
-                Log::from_loader_e(e)
+                vec![Log::from(e)]
             } else {
-                Log::from_loader_i("DailyMissions saved")
+                vec![Log::from_loader_i("DailyMissions saved")]
+            };
+
+            for v in translation_handles {
+                logs.push(v.join().unwrap());
             }
+
+            logs
         })
     }
 
-    pub fn load_daily_missions(&mut self) -> Result<Vec<Log>, ()> {
-        let one_day_rewards = deserialize_dat::<OneDayRewardDat>(
-            self.dat_paths
-                .get(&"onedayreward-ru.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
+    pub fn load_daily_missions(&mut self) -> Result<Vec<Log>, Log> {
+        let one_day_rewards = self.read_dat::<OneDayRewardDat>(&self.main_dat("onedayreward"))?;
+
+        let warnings = self.read_translations("onedayreward", &one_day_rewards);
 
         for v in one_day_rewards {
             self.daily_mission_holder.insert(
@@ -148,7 +163,7 @@ impl GameDataHolder {
             );
         }
 
-        Ok(vec![])
+        Ok(warnings)
     }
 }
 
@@ -173,24 +188,34 @@ struct OneDayRewardDat {
 }
 
 impl ReadUnreal for OneDayRewardDat {
-    fn read_unreal<T: Read>(reader: &mut T) -> Self {
-        let base: OneDayRewardBase = reader.read_unreal_value();
+    fn read_unreal<T: Read>(reader: &mut T) -> Result<Self, ReadError> {
+        let base: OneDayRewardBase = reader
+            .read_unreal_value()
+            .map_err(|e| e.in_field("OneDayRewardDat", "base"))?;
 
         let mut unk7 = vec![];
         for _ in 0..base.unk7_count {
-            unk7.push(reader.read_unreal_value())
+            unk7.push(
+                reader
+                    .read_unreal_value()
+                    .map_err(|e| e.in_field("OneDayRewardDat", "unk7"))?,
+            )
         }
 
         let mut rewards = vec![];
         for _ in 0..base.rewards_ct {
-            rewards.push(reader.read_unreal_value())
+            rewards.push(
+                reader
+                    .read_unreal_value()
+                    .map_err(|e| e.in_field("OneDayRewardDat", "rewards"))?,
+            )
         }
 
-        Self {
+        Ok(Self {
             base,
             unk7,
             rewards,
-        }
+        })
     }
 }
 
@@ -234,3 +259,17 @@ impl GetId for OneDayRewardDat {
         self.base.id
     }
 }
+
+impl LocalizedRecord for OneDayRewardDat {
+    type Key = u32;
+
+    fn key(&self) -> u32 {
+        self.base.id
+    }
+
+    fn copy_text(&mut self, translation: &Self) {
+        self.base.reward_name = translation.base.reward_name.clone();
+        self.base.reward_desc = translation.base.reward_desc.clone();
+        self.base.reward_period = translation.base.reward_period.clone();
+    }
+}
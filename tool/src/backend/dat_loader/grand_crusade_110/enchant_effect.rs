@@ -0,0 +1,354 @@
+use crate::backend::dat_loader::grand_crusade_110::{Color, CoordsXYZ};
+use crate::backend::dat_loader::{decode_dat, write_dat, DatDecoder, DatOutput};
+use crate::backend::holder::{GameDataHolder, HolderMapOps};
+use crate::backend::log_holder::Log;
+use crate::data::{SetEnchantEffectId, WeaponEnchantEffectId};
+use crate::entity::armor_enchant_effect::ArmorEnchantEffect;
+use crate::entity::item::CrystalType;
+use crate::entity::weapon_enchant_effect::{EnchantEffectLevel, WeaponEnchantEffect};
+use eframe::egui::Color32;
+use l2_rw::ue2_rw::{ReadError, ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+use l2_rw::ue2_rw::{ASCF, BYTE, DWORD, FLOAT};
+use l2_rw::DatVariant;
+use num_traits::{FromPrimitive, ToPrimitive};
+use r#macro::{ReadUnreal, WriteUnreal};
+use std::thread;
+use std::thread::JoinHandle;
+
+const WEAPON_DAT: &str = "weaponenchanteffectdata.dat";
+const ARMOR_DAT: &str = "fullarmorenchanteffectdata.dat";
+
+pub(super) const DATS: &[(&str, DatDecoder)] = &[
+    (WEAPON_DAT, decode_dat::<WeaponEnchantEffectDat>),
+    (ARMOR_DAT, decode_dat::<ArmorEnchantEffectDat>),
+];
+
+impl GameDataHolder {
+    /**Both dats are optional. If one can't be read or has values the editor can't keep as is,
+    its effects are left out and the file is never saved
+     */
+    pub(super) fn load_enchant_effects(&mut self) -> Result<Vec<Log>, Log> {
+        let mut warnings = vec![];
+
+        if let Some(records) = self.read_optional_dat::<WeaponEnchantEffectDat>(
+            WEAPON_DAT,
+            "enchant effects",
+            &mut warnings,
+        ) {
+            match weapon_enchant_effects(records) {
+                Ok(v) => {
+                    for v in v {
+                        self.weapon_enchant_effect_holder.insert(v.id, v);
+                    }
+                }
+                Err(e) => self.skip_enchant_effects(WEAPON_DAT, &e, &mut warnings),
+            }
+        }
+
+        if let Some(records) = self.read_optional_dat::<ArmorEnchantEffectDat>(
+            ARMOR_DAT,
+            "enchant effects",
+            &mut warnings,
+        ) {
+            match armor_enchant_effects(records) {
+                Ok(v) => {
+                    for v in v {
+                        self.armor_enchant_effect_holder.insert(v.id, v);
+                    }
+                }
+                Err(e) => self.skip_enchant_effects(ARMOR_DAT, &e, &mut warnings),
+            }
+        }
+
+        Ok(warnings)
+    }
+
+    fn skip_enchant_effects(&mut self, file_name: &str, error: &str, warnings: &mut Vec<Log>) {
+        self.dat_envelopes.remove(file_name);
+
+        warnings.push(Log::from_loader_w(&format!(
+            "{file_name}: {error}, enchant effects are not loaded"
+        )));
+    }
+
+    pub(super) fn serialize_weapon_enchant_effects(
+        &self,
+        output: &DatOutput,
+    ) -> Option<JoinHandle<Log>> {
+        if !self.dat_envelopes.contains_key(WEAPON_DAT) {
+            return None;
+        }
+
+        let mut effects: Vec<_> = self
+            .weapon_enchant_effect_holder
+            .values()
+            .filter(|v| !v._deleted)
+            .collect();
+        effects.sort_by_key(|v| v.id);
+
+        let records: Vec<_> = effects
+            .into_iter()
+            .flat_map(|v| {
+                v.levels.iter().map(|l| WeaponEnchantEffectDat {
+                    id: v.id.0,
+                    crystal_type: v.crystal_type.to_u8().unwrap(),
+                    level: l.into(),
+                })
+            })
+            .collect();
+
+        let path = self.dat_paths.get(WEAPON_DAT).unwrap().clone();
+        let target = self.dat_target(WEAPON_DAT, output);
+
+        Some(thread::spawn(move || {
+            if let Err(e) = write_dat(
+                path.path(),
+                DatVariant::<(), WeaponEnchantEffectDat>::Array(records),
+                &target,
+            ) {
+                Log::from(e)
+            } else {
+                Log::from_loader_i("Weapon Enchant Effects saved")
+            }
+        }))
+    }
+
+    pub(super) fn serialize_armor_enchant_effects(
+        &self,
+        output: &DatOutput,
+    ) -> Option<JoinHandle<Log>> {
+        if !self.dat_envelopes.contains_key(ARMOR_DAT) {
+            return None;
+        }
+
+        let mut effects: Vec<_> = self
+            .armor_enchant_effect_holder
+            .values()
+            .filter(|v| !v._deleted)
+            .collect();
+        effects.sort_by_key(|v| v.id);
+
+        let records: Vec<_> = effects
+            .into_iter()
+            .flat_map(|v| {
+                v.levels.iter().map(|l| ArmorEnchantEffectDat {
+                    effect_type: v.id.0 as DWORD,
+                    level: l.into(),
+                })
+            })
+            .collect();
+
+        let path = self.dat_paths.get(ARMOR_DAT).unwrap().clone();
+        let target = self.dat_target(ARMOR_DAT, output);
+
+        Some(thread::spawn(move || {
+            if let Err(e) = write_dat(
+                path.path(),
+                DatVariant::<(), ArmorEnchantEffectDat>::Array(records),
+                &target,
+            ) {
+                Log::from(e)
+            } else {
+                Log::from_loader_i("Armor Enchant Effects saved")
+            }
+        }))
+    }
+}
+
+/**Records go one per level, effect of the first record keeps its crystal type. Unknown crystal
+type fails the whole dat, it would be saved back as another grade otherwise
+ */
+fn weapon_enchant_effects(
+    records: Vec<WeaponEnchantEffectDat>,
+) -> Result<Vec<WeaponEnchantEffect>, String> {
+    let mut res: Vec<WeaponEnchantEffect> = vec![];
+
+    for v in records {
+        let Some(crystal_type) = CrystalType::from_u8(v.crystal_type) else {
+            return Err(format!(
+                "effect {} has unknown crystal type {}",
+                v.id, v.crystal_type
+            ));
+        };
+
+        let id = WeaponEnchantEffectId(v.id);
+
+        if let Some(effect) = res.iter_mut().find(|e| e.id == id) {
+            effect.levels.push((&v.level).into());
+        } else {
+            res.push(WeaponEnchantEffect {
+                id,
+                crystal_type,
+                levels: vec![(&v.level).into()],
+                ..Default::default()
+            });
+        }
+    }
+
+    Ok(res)
+}
+
+/**Set effect ids are bytes in armorgrp, bigger ones can't be referenced and fail the whole dat
+ */
+fn armor_enchant_effects(
+    records: Vec<ArmorEnchantEffectDat>,
+) -> Result<Vec<ArmorEnchantEffect>, String> {
+    let mut res: Vec<ArmorEnchantEffect> = vec![];
+
+    for v in records {
+        let Ok(id) = u8::try_from(v.effect_type).map(SetEnchantEffectId) else {
+            return Err(format!(
+                "effect type {} is out of byte range",
+                v.effect_type
+            ));
+        };
+
+        if let Some(effect) = res.iter_mut().find(|e| e.id == id) {
+            effect.levels.push((&v.level).into());
+        } else {
+            res.push(ArmorEnchantEffect {
+                id,
+                levels: vec![(&v.level).into()],
+                ..Default::default()
+            });
+        }
+    }
+
+    Ok(res)
+}
+
+impl From<&EnchantEffectLevelDat> for EnchantEffectLevel {
+    fn from(value: &EnchantEffectLevelDat) -> Self {
+        Self {
+            enchant_level: value.enchant_level,
+            effect: value.effect.to_string(),
+            bone: value.bone.to_string(),
+            offset: value.offset.into(),
+            scale: value.scale,
+            color: Color32::from_rgba_unmultiplied(
+                value.color.r,
+                value.color.g,
+                value.color.b,
+                value.color.a,
+            ),
+        }
+    }
+}
+
+impl From<&EnchantEffectLevel> for EnchantEffectLevelDat {
+    fn from(value: &EnchantEffectLevel) -> Self {
+        Self {
+            enchant_level: value.enchant_level,
+            effect: (&value.effect).into(),
+            bone: (&value.bone).into(),
+            offset: value.offset.into(),
+            scale: value.scale,
+            color: Color {
+                r: value.color.r(),
+                g: value.color.g(),
+                b: value.color.b(),
+                a: value.color.a(),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal)]
+struct EnchantEffectLevelDat {
+    enchant_level: DWORD,
+    effect: ASCF,
+    bone: ASCF,
+    offset: CoordsXYZ,
+    scale: FLOAT,
+    color: Color,
+}
+
+#[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal)]
+struct WeaponEnchantEffectDat {
+    id: DWORD,
+    crystal_type: BYTE,
+    level: EnchantEffectLevelDat,
+}
+
+#[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal)]
+struct ArmorEnchantEffectDat {
+    effect_type: DWORD,
+    level: EnchantEffectLevelDat,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::dat_loader::tests::check_optional_dat;
+
+    fn level(enchant_level: DWORD) -> EnchantEffectLevelDat {
+        EnchantEffectLevelDat {
+            enchant_level,
+            effect: "LineageEffect.c_u001".to_string().into(),
+            bone: "Bip01 Spine".to_string().into(),
+            offset: CoordsXYZ {
+                x: 0.,
+                y: 1.5,
+                z: -2.,
+            },
+            scale: 1.,
+            color: Color {
+                b: 10,
+                g: 20,
+                r: 30,
+                a: 255,
+            },
+        }
+    }
+
+    #[test]
+    fn reads_weapon_effects_whole() {
+        check_optional_dat(
+            WEAPON_DAT,
+            vec![
+                WeaponEnchantEffectDat {
+                    id: 1,
+                    crystal_type: 2,
+                    level: level(4),
+                },
+                WeaponEnchantEffectDat {
+                    id: 1,
+                    crystal_type: 2,
+                    level: level(7),
+                },
+            ],
+        );
+    }
+
+    #[test]
+    fn reads_armor_effects_whole() {
+        check_optional_dat(
+            ARMOR_DAT,
+            vec![ArmorEnchantEffectDat {
+                effect_type: 3,
+                level: level(6),
+            }],
+        );
+    }
+
+    #[test]
+    fn unknown_crystal_type_fails() {
+        let res = weapon_enchant_effects(vec![WeaponEnchantEffectDat {
+            id: 1,
+            crystal_type: 200,
+            level: level(4),
+        }]);
+
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn wide_effect_type_fails() {
+        let res = armor_enchant_effects(vec![ArmorEnchantEffectDat {
+            effect_type: 256,
+            level: level(4),
+        }]);
+
+        assert!(res.is_err());
+    }
+}
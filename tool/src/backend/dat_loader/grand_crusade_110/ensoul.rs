@@ -0,0 +1,261 @@
+use crate::backend::dat_loader::translation::LocalizedRecord;
+use crate::backend::dat_loader::{decode_dat, write_dat, DatDecoder, DatOutput};
+use crate::backend::holder::{GameDataHolder, HolderMapOps};
+use crate::backend::log_holder::Log;
+use crate::data::{EnsoulOptionId, ItemId, SkillId};
+use crate::entity::ensoul_option::{EnsoulOption, EnsoulStone};
+use l2_rw::ue2_rw::{ReadError, ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+use l2_rw::ue2_rw::{ASCF, DWORD};
+use l2_rw::DatVariant;
+use r#macro::{ReadUnreal, WriteUnreal};
+use std::collections::BTreeMap;
+use std::thread;
+use std::thread::JoinHandle;
+
+const STONE_DAT: &str = "ensoul_stone_client.dat";
+
+pub(super) const DATS: &[(&str, DatDecoder)] = &[(STONE_DAT, decode_dat::<EnsoulStoneDat>)];
+
+pub(super) const LOCALIZED_DATS: &[(&str, DatDecoder)] =
+    &[("ensoul_option_client", decode_dat::<EnsoulOptionDat>)];
+
+impl GameDataHolder {
+    /**Both dats are optional. Stones are read only together with options, otherwise saving them
+    would drop every option
+     */
+    pub(super) fn load_ensoul_options(&mut self) -> Result<Vec<Log>, Log> {
+        let mut warnings = vec![];
+
+        let option_dat = self.main_dat("ensoul_option_client");
+
+        let Some(options) =
+            self.read_optional_dat::<EnsoulOptionDat>(&option_dat, "ensoul options", &mut warnings)
+        else {
+            return Ok(warnings);
+        };
+
+        warnings.extend(self.read_translations("ensoul_option_client", &options));
+
+        for v in options {
+            let id = EnsoulOptionId(v.id);
+
+            self.ensoul_option_holder.insert(
+                id,
+                EnsoulOption {
+                    id,
+                    name: v.name.to_string(),
+                    desc: v.desc.to_string(),
+                    option_type: v.option_type,
+                    step: v.step,
+                    skill_id: SkillId(v.skill_id),
+                    skill_level: v.skill_level,
+                    extraction_item_id: ItemId(v.extraction_item_id),
+                    icon: v.icon.to_string(),
+                    icon_panel: v.icon_panel.to_string(),
+                    stones: vec![],
+                    ..Default::default()
+                },
+            );
+        }
+
+        if let Some(stones) =
+            self.read_optional_dat::<EnsoulStoneDat>(STONE_DAT, "ensoul stones", &mut warnings)
+        {
+            for v in stones {
+                for option_id in &v.options {
+                    if let Some(option) = self
+                        .ensoul_option_holder
+                        .get_mut(&EnsoulOptionId(*option_id))
+                    {
+                        option.stones.push(EnsoulStone {
+                            item_id: ItemId(v.item_id),
+                            slot_type: v.slot_type,
+                        });
+                    } else {
+                        warnings.push(Log::from_loader_w(&format!(
+                            "{STONE_DAT}: stone {} has unknown option {option_id}, it will be \
+                             dropped on save",
+                            v.item_id
+                        )));
+                    }
+                }
+            }
+        }
+
+        Ok(warnings)
+    }
+
+    /**Stones are rebuilt from options, so each stone gets its options sorted by id. Stones
+    which are left without options are not written
+     */
+    pub(super) fn serialize_ensoul_options(&self, output: &DatOutput) -> Vec<JoinHandle<Log>> {
+        let mut res = vec![];
+
+        let option_dat = self.main_dat("ensoul_option_client");
+
+        if !self.dat_envelopes.contains_key(&option_dat) {
+            return res;
+        }
+
+        let mut options: Vec<_> = self
+            .ensoul_option_holder
+            .values()
+            .filter(|v| !v._deleted)
+            .collect();
+        options.sort_by_key(|v| v.id);
+
+        let mut stones: BTreeMap<(u32, u32), Vec<DWORD>> = BTreeMap::new();
+
+        for option in &options {
+            for stone in &option.stones {
+                stones
+                    .entry((stone.item_id.0, stone.slot_type))
+                    .or_default()
+                    .push(option.id.0);
+            }
+        }
+
+        let option_records: Vec<_> = options.into_iter().map(EnsoulOptionDat::from).collect();
+
+        let translations = self.translated("ensoul_option_client", &option_records);
+        res.extend(self.save_translations("ensoul_option_client", translations, output));
+
+        let path = self.dat_paths.get(&option_dat).unwrap().clone();
+        let target = self.dat_target(&option_dat, output);
+
+        res.push(thread::spawn(move || {
+            if let Err(e) = write_dat(
+                path.path(),
+                DatVariant::<(), EnsoulOptionDat>::Array(option_records),
+                &target,
+            ) {
+                Log::from(e)
+            } else {
+                Log::from_loader_i("Ensoul Options saved")
+            }
+        }));
+
+        if self.dat_envelopes.contains_key(STONE_DAT) {
+            let stone_records: Vec<_> = stones
+                .into_iter()
+                .map(|((item_id, slot_type), options)| EnsoulStoneDat {
+                    item_id,
+                    slot_type,
+                    options,
+                })
+                .collect();
+
+            let path = self.dat_paths.get(STONE_DAT).unwrap().clone();
+            let target = self.dat_target(STONE_DAT, output);
+
+            res.push(thread::spawn(move || {
+                if let Err(e) = write_dat(
+                    path.path(),
+                    DatVariant::<(), EnsoulStoneDat>::Array(stone_records),
+                    &target,
+                ) {
+                    Log::from(e)
+                } else {
+                    Log::from_loader_i("Ensoul Stones saved")
+                }
+            }));
+        }
+
+        res
+    }
+}
+
+impl From<&EnsoulOption> for EnsoulOptionDat {
+    fn from(value: &EnsoulOption) -> Self {
+        Self {
+            option_type: value.option_type,
+            step: value.step,
+            id: value.id.0,
+            name: (&value.name).into(),
+            desc: (&value.desc).into(),
+            extraction_item_id: value.extraction_item_id.0,
+            icon: (&value.icon).into(),
+            icon_panel: (&value.icon_panel).into(),
+            skill_id: value.skill_id.0,
+            skill_level: value.skill_level,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal)]
+struct EnsoulOptionDat {
+    option_type: DWORD,
+    step: DWORD,
+    id: DWORD,
+    name: ASCF,
+    desc: ASCF,
+    extraction_item_id: DWORD,
+    icon: ASCF,
+    icon_panel: ASCF,
+    skill_id: DWORD,
+    skill_level: DWORD,
+}
+
+impl LocalizedRecord for EnsoulOptionDat {
+    type Key = u32;
+
+    fn key(&self) -> u32 {
+        self.id
+    }
+
+    fn copy_text(&mut self, translation: &Self) {
+        self.name = translation.name.clone();
+        self.desc = translation.desc.clone();
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal)]
+struct EnsoulStoneDat {
+    item_id: DWORD,
+    slot_type: DWORD,
+    options: Vec<DWORD>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::dat_loader::tests::check_optional_dat;
+
+    #[test]
+    fn reads_options_whole() {
+        check_optional_dat(
+            "ensoul_option_client-e.dat",
+            vec![EnsoulOptionDat {
+                option_type: 1,
+                step: 2,
+                id: 3,
+                name: "P. Atk.".to_string().into(),
+                desc: "P. Atk. +30".to_string().into(),
+                extraction_item_id: 29000,
+                icon: "icon.ensoul_1".to_string().into(),
+                icon_panel: "icon.ensoul_panel_1".to_string().into(),
+                skill_id: 18000,
+                skill_level: 1,
+            }],
+        );
+    }
+
+    #[test]
+    fn reads_stones_whole() {
+        check_optional_dat(
+            STONE_DAT,
+            vec![
+                EnsoulStoneDat {
+                    item_id: 29001,
+                    slot_type: 1,
+                    options: vec![3, 4, 5],
+                },
+                EnsoulStoneDat {
+                    item_id: 29002,
+                    slot_type: 2,
+                    options: vec![],
+                },
+            ],
+        );
+    }
+}
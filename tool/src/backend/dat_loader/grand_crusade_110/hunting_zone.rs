@@ -1,12 +1,13 @@
 use crate::backend::entity_editor::WindowParams;
 
 use l2_rw::ue2_rw::{ASCF, DWORD, INT, SHORT, USHORT};
-use l2_rw::{deserialize_dat, save_dat, DatVariant};
+use l2_rw::DatVariant;
 
-use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+use l2_rw::ue2_rw::{ReadError, ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
 use crate::backend::dat_loader::grand_crusade_110::{CoordsXYZ};
-use crate::backend::dat_loader::L2StringTable;
+use crate::backend::dat_loader::translation::LocalizedRecord;
+use crate::backend::dat_loader::{decode_dat, write_dat, DatDecoder, DatOutput, L2StringTable};
 use crate::backend::holder::{GameDataHolder, HolderMapOps, L2GeneralStringTable};
 use crate::backend::log_holder::{Log, LogLevel};
 use crate::data::QuestId;
@@ -37,8 +38,18 @@ impl From<(&HuntingZone, &mut L2GeneralStringTable)> for HuntingZoneDat {
     }
 }
 
+pub(super) const DATS: &[(&str, DatDecoder)] = &[
+    ("minimapregion.dat", decode_dat::<MiniMapRegionDat>),
+];
+
+pub(super) const LOCALIZED_DATS: &[(&str, DatDecoder)] =
+    &[("huntingzone", decode_dat::<HuntingZoneDat>)];
+
 impl GameDataHolder {
-    pub fn serialize_hunting_zones_to_binary(&mut self) -> JoinHandle<Vec<Log>> {
+    pub fn serialize_hunting_zones_to_binary(
+        &mut self,
+        output: &DatOutput,
+    ) -> JoinHandle<Vec<Log>> {
         let mut map_objects: Vec<MiniMapRegionDat> = vec![];
 
         for zone in self.hunting_zone_holder.values().filter(|v| !v._deleted) {
@@ -64,7 +75,7 @@ impl GameDataHolder {
             }
         }
 
-        let hunting_zones = self
+        let hunting_zones: Vec<HuntingZoneDat> = self
             .hunting_zone_holder
             .values()
             .map(|v| (v, &mut self.game_string_table).into())
@@ -72,57 +83,58 @@ impl GameDataHolder {
 
         let huntingzone_path = self
             .dat_paths
-            .get(&"huntingzone-ru.dat".to_string())
+            .get(&self.main_dat("huntingzone"))
             .unwrap()
             .clone();
+        let huntingzone_target = self.dat_target(&self.main_dat("huntingzone"), output);
+
+        let translations = self.translated("huntingzone", &hunting_zones);
+        let translation_handles = self.save_translations("huntingzone", translations, output);
 
         let minimapregion_path = self
             .dat_paths
             .get(&"minimapregion.dat".to_string())
             .unwrap()
             .clone();
+        let minimapregion_target = self.dat_target("minimapregion.dat", output);
 
         thread::spawn(move || {
             let mut logs = vec![];
 
-            if let Err(e) = save_dat(
+            if let Err(e) = write_dat(
                 minimapregion_path.path(),
                 DatVariant::<(), MiniMapRegionDat>::Array(map_objects.to_vec()),
+                &minimapregion_target,
             ) {
-                logs.push(Log::from_loader_e(e));
+                logs.push(Log::from(e));
             } else {
                 logs.push(Log::from_loader_i("Mini Map Region saved"));
             }
 
-            if let Err(e) = save_dat(
+            if let Err(e) = write_dat(
                 huntingzone_path.path(),
                 DatVariant::<(), HuntingZoneDat>::Array(hunting_zones),
+                &huntingzone_target,
             ) {
-                logs.push(Log::from_loader_e(e));
+                logs.push(Log::from(e));
             } else {
                 logs.push(Log::from_loader_i("Hunting Zone saved"));
             }
 
+            for v in translation_handles {
+                logs.push(v.join().unwrap());
+            }
+
             logs
         })
     }
 
-    pub fn load_hunting_zones(&mut self) -> Result<Vec<Log>, ()> {
-        let mut warnings = vec![];
+    pub fn load_hunting_zones(&mut self) -> Result<Vec<Log>, Log> {
+        let hunting_zones = self.read_dat::<HuntingZoneDat>(&self.main_dat("huntingzone"))?;
 
-        let hunting_zones = deserialize_dat::<HuntingZoneDat>(
-            self.dat_paths
-                .get(&"huntingzone-ru.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
+        let mut warnings = self.read_translations("huntingzone", &hunting_zones);
 
-        let map_objects = deserialize_dat::<MiniMapRegionDat>(
-            self.dat_paths
-                .get(&"minimapregion.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
+        let map_objects = self.read_dat::<MiniMapRegionDat>("minimapregion.dat")?;
 
         for v in hunting_zones {
             self.hunting_zone_holder.insert(
@@ -168,6 +180,7 @@ impl GameDataHolder {
                     level: LogLevel::Error,
                     producer: "Hunting Zone Loader".to_string(),
                     log: format!("Row {} in mimapregion points to\nunexisting huntingzone with secondary id {}\nRow Skipped", i, v.hunting_zone_second_id),
+                    entity: None,
                 })
             }
         }
@@ -192,6 +205,19 @@ struct HuntingZoneDat {
     instant_zone_id: DWORD,
 }
 
+impl LocalizedRecord for HuntingZoneDat {
+    type Key = u32;
+
+    fn key(&self) -> u32 {
+        self.id
+    }
+
+    fn copy_text(&mut self, translation: &Self) {
+        self.desc = translation.desc.clone();
+        self.name = translation.name.clone();
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal)]
 struct MiniMapRegionDat {
     hunting_zone_second_id: USHORT,
@@ -0,0 +1,166 @@
+use crate::backend::dat_loader::translation::LocalizedRecord;
+use crate::backend::dat_loader::{decode_dat, write_dat, DatDecoder, DatOutput};
+use crate::backend::holder::{GameDataHolder, HolderMapOps};
+use crate::backend::log_holder::Log;
+use crate::data::InstantZoneId;
+use crate::entity::instant_zone::InstantZone;
+use l2_rw::ue2_rw::{ReadError, ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+use l2_rw::ue2_rw::{ASCF, DWORD};
+use l2_rw::DatVariant;
+use r#macro::{ReadUnreal, WriteUnreal};
+use std::thread;
+use std::thread::JoinHandle;
+
+pub(super) const LOCALIZED_DATS: &[(&str, DatDecoder)] =
+    &[("instantzonedata", decode_dat::<InstantZoneDat>)];
+
+impl GameDataHolder {
+    /**instantzonedata is optional. Has to be called after hunting zones, their links to missing
+    instances are logged
+     */
+    pub(super) fn load_instant_zones(&mut self) -> Result<Vec<Log>, Log> {
+        let mut warnings = vec![];
+
+        let file_name = self.main_dat("instantzonedata");
+
+        let Some(records) =
+            self.read_optional_dat::<InstantZoneDat>(&file_name, "instant zones", &mut warnings)
+        else {
+            return Ok(warnings);
+        };
+
+        warnings.extend(self.read_translations("instantzonedata", &records));
+
+        for v in records {
+            let id = InstantZoneId(v.id);
+
+            self.instant_zone_holder.insert(
+                id,
+                InstantZone {
+                    id,
+                    name: v.name.to_string(),
+                    desc: v.desc.to_string(),
+                    icon: v.icon.to_string(),
+                    level_min: v.level_min,
+                    level_max: v.level_max,
+                    max_members: v.max_members,
+                    reuse_time: v.reuse_time,
+                    ..Default::default()
+                },
+            );
+        }
+
+        for (hunting_zone_id, instant_zone_id) in self.dangling_instant_zone_ids() {
+            warnings.push(Log::from_loader_w(&format!(
+                "Hunting Zone {} references missing Instant Zone {}",
+                hunting_zone_id.0, instant_zone_id.0
+            )));
+        }
+
+        Ok(warnings)
+    }
+
+    pub(super) fn serialize_instant_zones(
+        &self,
+        output: &DatOutput,
+    ) -> Option<JoinHandle<Vec<Log>>> {
+        let file_name = self.main_dat("instantzonedata");
+
+        if !self.dat_envelopes.contains_key(&file_name) {
+            return None;
+        }
+
+        let mut zones: Vec<_> = self
+            .instant_zone_holder
+            .values()
+            .filter(|v| !v._deleted)
+            .collect();
+        zones.sort_by_key(|v| v.id);
+
+        let records: Vec<_> = zones
+            .into_iter()
+            .map(|v| InstantZoneDat {
+                id: v.id.0,
+                name: (&v.name).into(),
+                icon: (&v.icon).into(),
+                desc: (&v.desc).into(),
+                level_min: v.level_min,
+                level_max: v.level_max,
+                max_members: v.max_members,
+                reuse_time: v.reuse_time,
+            })
+            .collect();
+
+        let translations = self.translated("instantzonedata", &records);
+        let translation_handles = self.save_translations("instantzonedata", translations, output);
+
+        let path = self.dat_paths.get(&file_name).unwrap().clone();
+        let target = self.dat_target(&file_name, output);
+
+        Some(thread::spawn(move || {
+            let mut logs = if let Err(e) = write_dat(
+                path.path(),
+                DatVariant::<(), InstantZoneDat>::Array(records),
+                &target,
+            ) {
+                vec![Log::from(e)]
+            } else {
+                vec![Log::from_loader_i("Instant Zones saved")]
+            };
+
+            for v in translation_handles {
+                logs.push(v.join().unwrap());
+            }
+
+            logs
+        }))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal)]
+struct InstantZoneDat {
+    id: DWORD,
+    name: ASCF,
+    icon: ASCF,
+    desc: ASCF,
+    level_min: DWORD,
+    level_max: DWORD,
+    max_members: DWORD,
+    reuse_time: DWORD,
+}
+
+impl LocalizedRecord for InstantZoneDat {
+    type Key = u32;
+
+    fn key(&self) -> u32 {
+        self.id
+    }
+
+    fn copy_text(&mut self, translation: &Self) {
+        self.name = translation.name.clone();
+        self.desc = translation.desc.clone();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::dat_loader::tests::check_optional_dat;
+
+    #[test]
+    fn reads_zones_whole() {
+        check_optional_dat(
+            "instantzonedata-e.dat",
+            vec![InstantZoneDat {
+                id: 120,
+                name: "Kamaloka".to_string().into(),
+                icon: "icon.kamaloka".to_string().into(),
+                desc: "Hall of the Abyss".to_string().into(),
+                level_min: 23,
+                level_max: 33,
+                max_members: 9,
+                reuse_time: 86400,
+            }],
+        );
+    }
+}
@@ -2,7 +2,7 @@ use crate::backend::dat_loader::grand_crusade_110::item::{
     AdditionalItemGrpDat, DropDatInfo, ItemBaseInfoDat, ItemNameDat, ItemStatDataDat,
 };
 use crate::backend::dat_loader::grand_crusade_110::{L2GeneralStringTable};
-use crate::backend::dat_loader::{GetId, L2StringTable};
+use crate::backend::dat_loader::{write_dat, DatOutput, GetId, L2StringTable};
 use crate::backend::entity_editor::WindowParams;
 use crate::backend::holder::{GameDataHolder, HolderMapOps};
 use crate::backend::log_holder::{Log, LogLevel};
@@ -15,9 +15,9 @@ use crate::entity::item::{
     ItemBaseInfo, ItemBattleStats, ItemDefaultAction, ItemDropInfo, ItemDropMeshInfo, ItemIcons,
     ItemMaterial, ItemNameColor, ItemQuality, KeepType,
 };
-use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+use l2_rw::ue2_rw::{ReadError, ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 use l2_rw::ue2_rw::{BYTE, DWORD, MTX, MTX3, SHORT, USHORT, UVEC};
-use l2_rw::{deserialize_dat, save_dat, DatVariant};
+use l2_rw::DatVariant;
 use num_traits::{FromPrimitive, ToPrimitive};
 use r#macro::{ReadUnreal, WriteUnreal};
 use std::collections::HashMap;
@@ -252,7 +252,7 @@ impl From<(&Armor, &mut L2GeneralStringTable)> for ArmorGrpDat {
 }
 
 impl GameDataHolder {
-    pub fn serialize_armor_to_binary(&mut self) -> JoinHandle<Log> {
+    pub fn serialize_armor_to_binary(&mut self, output: &DatOutput) -> JoinHandle<Log> {
         let mut items: Vec<ArmorGrpDat> = vec![];
 
         for v in self.armor_holder.values().filter(|v| !v._deleted) {
@@ -264,13 +264,15 @@ impl GameDataHolder {
             .get(&"armorgrp.dat".to_string())
             .unwrap()
             .clone();
+        let armor_grp_target = self.dat_target("armorgrp.dat", output);
 
         thread::spawn(move || {
-            if let Err(e) = save_dat(
+            if let Err(e) = write_dat(
                 armor_grp_path.path(),
                 DatVariant::<(), ArmorGrpDat>::Array(items),
+                &armor_grp_target,
             ) {
-                Log::from_loader_e(e)
+                Log::from(e)
             } else {
                 Log::from_loader_i("Armor Grp saved")
             }
@@ -298,13 +300,8 @@ impl GameDataHolder {
         item_stat: &HashMap<u32, ItemStatDataDat>,
         item_base_info: &HashMap<u32, ItemBaseInfoDat>,
         item_name: &HashMap<u32, ItemNameDat>,
-    ) -> Result<Vec<Log>, ()> {
-        let armor_grp = deserialize_dat::<ArmorGrpDat>(
-            self.dat_paths
-                .get(&"armorgrp.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
+    ) -> Result<Vec<Log>, Log> {
+        let armor_grp = self.read_dat::<ArmorGrpDat>("armorgrp.dat")?;
 
         let base_info_default = ItemBaseInfoDat::default();
         let base_stat_default = ItemStatDataDat::default();
@@ -317,6 +314,7 @@ impl GameDataHolder {
                     level: LogLevel::Error,
                     producer: "Armor Loader".to_string(),
                     log: format!("Item[{}]: No record in itemname found. Skipped", item.id),
+                    entity: None,
                 });
 
                 continue;
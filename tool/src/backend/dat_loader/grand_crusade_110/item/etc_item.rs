@@ -11,11 +11,11 @@ use crate::entity::item::{
 };
 
 use l2_rw::ue2_rw::{BYTE, DWORD, SHORT, USHORT, UVEC};
-use l2_rw::{deserialize_dat, save_dat, DatVariant};
+use l2_rw::DatVariant;
 
-use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+use l2_rw::ue2_rw::{ReadError, ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
-use crate::backend::dat_loader::{GetId, L2StringTable};
+use crate::backend::dat_loader::{write_dat, DatOutput, GetId, L2StringTable};
 use crate::backend::holder::{GameDataHolder, HolderMapOps};
 use crate::backend::log_holder::{Log, LogLevel};
 use num_traits::{FromPrimitive, ToPrimitive};
@@ -186,7 +186,7 @@ impl From<(&EtcItem, &mut L2GeneralStringTable)> for EtcItemGrpDat {
 }
 
 impl GameDataHolder {
-    pub fn serialize_etc_items_to_binary(&mut self) -> JoinHandle<Log> {
+    pub fn serialize_etc_items_to_binary(&mut self, output: &DatOutput) -> JoinHandle<Log> {
         let mut items: Vec<EtcItemGrpDat> = vec![];
 
         for v in self.etc_item_holder.values().filter(|v| !v._deleted) {
@@ -198,13 +198,15 @@ impl GameDataHolder {
             .get(&"etcitemgrp.dat".to_string())
             .unwrap()
             .clone();
+        let etc_item_grp_target = self.dat_target("etcitemgrp.dat", output);
 
         thread::spawn(move || {
-            if let Err(e) = save_dat(
+            if let Err(e) = write_dat(
                 etc_item_grp_path.path(),
                 DatVariant::<(), EtcItemGrpDat>::Array(items),
+                &etc_item_grp_target,
             ) {
-                Log::from_loader_e(e)
+                Log::from(e)
             } else {
                 Log::from_loader_i("Etc Item Grp saved")
             }
@@ -232,13 +234,8 @@ impl GameDataHolder {
         item_stat: &HashMap<u32, ItemStatDataDat>,
         item_base_info: &HashMap<u32, ItemBaseInfoDat>,
         item_name: &HashMap<u32, ItemNameDat>,
-    ) -> Result<Vec<Log>, ()> {
-        let etc_grp = deserialize_dat::<EtcItemGrpDat>(
-            self.dat_paths
-                .get(&"etcitemgrp.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
+    ) -> Result<Vec<Log>, Log> {
+        let etc_grp = self.read_dat::<EtcItemGrpDat>("etcitemgrp.dat")?;
 
         let base_info_default = ItemBaseInfoDat::default();
         let base_stat_default = ItemStatDataDat::default();
@@ -251,6 +248,7 @@ impl GameDataHolder {
                     level: LogLevel::Error,
                     producer: "Weapon Loader".to_string(),
                     log: format!("Item[{}]: No record in itemname found. Skipped", item.id),
+                    entity: None,
                 });
 
                 continue;
@@ -5,19 +5,40 @@ mod weapon;
 use crate::backend::log_holder::Log;
 
 use l2_rw::ue2_rw::{ASCF, BYTE, DWORD, FLOAT, LONG, SHORT, USHORT, UVEC};
-use l2_rw::{deserialize_dat, save_dat, DatVariant};
+use l2_rw::DatVariant;
 
-use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+use l2_rw::ue2_rw::{ReadError, ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
-use crate::backend::dat_loader::{wrap_into_id_map, GetId};
+use crate::backend::dat_loader::translation::LocalizedRecord;
+use crate::backend::dat_loader::{
+    decode_dat, wrap_into_id_map, write_dat, DatDecoder, DatOutput, GetId,
+};
 use crate::backend::holder::{GameDataHolder, HolderOps};
 use crate::entity::item::ItemDefaultAction;
 use r#macro::{ReadUnreal, WriteUnreal};
 use std::thread;
 use std::thread::JoinHandle;
 
+pub(super) const DATS: &[(&str, DatDecoder)] = &[
+    ("additionalitemgrp.dat", decode_dat::<AdditionalItemGrpDat>),
+    ("itemstatdata.dat", decode_dat::<ItemStatDataDat>),
+    ("item_baseinfo.dat", decode_dat::<ItemBaseInfoDat>),
+    ("weapongrp.dat", decode_dat::<weapon::WeaponGrpDat>),
+    ("armorgrp.dat", decode_dat::<armor::ArmorGrpDat>),
+    ("etcitemgrp.dat", decode_dat::<etc_item::EtcItemGrpDat>),
+];
+
+pub(super) const LOCALIZED_DATS: &[(&str, DatDecoder)] = &[("itemname", decode_dat::<ItemNameDat>)];
+
 impl GameDataHolder {
-    pub fn serialize_items_to_binary(&mut self) -> JoinHandle<Vec<Log>> {
+    /**Common item dats are always written, grp dats only for changed holders or all of them if
+    `all` is set
+     */
+    pub fn serialize_items_to_binary(
+        &mut self,
+        all: bool,
+        output: &DatOutput,
+    ) -> JoinHandle<Vec<Log>> {
         let mut logs = vec![];
 
         let mut additional_item_grp = vec![];
@@ -25,20 +46,20 @@ impl GameDataHolder {
         let mut item_base_info = vec![];
         let mut item_name = vec![];
 
-        let weapon_handle = if self.weapon_holder.was_changed() {
-            Some(self.serialize_weapons_to_binary())
+        let weapon_handle = if all || self.weapon_holder.was_changed() {
+            Some(self.serialize_weapons_to_binary(output))
         } else {
             None
         };
 
-        let etc_item_handle = if self.etc_item_holder.was_changed() {
-            Some(self.serialize_etc_items_to_binary())
+        let etc_item_handle = if all || self.etc_item_holder.was_changed() {
+            Some(self.serialize_etc_items_to_binary(output))
         } else {
             None
         };
 
-        let armor_handle = if self.armor_holder.was_changed() {
-            Some(self.serialize_armor_to_binary())
+        let armor_handle = if all || self.armor_holder.was_changed() {
+            Some(self.serialize_armor_to_binary(output))
         } else {
             None
         };
@@ -69,65 +90,76 @@ impl GameDataHolder {
             .get(&"additionalitemgrp.dat".to_string())
             .unwrap()
             .clone();
+        let additional_item_grp_target = self.dat_target("additionalitemgrp.dat", output);
 
         let item_stat_path = self
             .dat_paths
             .get(&"itemstatdata.dat".to_string())
             .unwrap()
             .clone();
+        let item_stat_target = self.dat_target("itemstatdata.dat", output);
 
         let item_base_info_path = self
             .dat_paths
             .get(&"item_baseinfo.dat".to_string())
             .unwrap()
             .clone();
+        let item_base_info_target = self.dat_target("item_baseinfo.dat", output);
 
         let item_name_path = self
             .dat_paths
-            .get(&"itemname-ru.dat".to_string())
+            .get(&self.main_dat("itemname"))
             .unwrap()
             .clone();
+        let item_name_target = self.dat_target(&self.main_dat("itemname"), output);
+
+        let translations = self.translated("itemname", &item_name);
+        let translation_handles = self.save_translations("itemname", translations, output);
 
         thread::spawn(move || {
             let additional_item_grp_handle = thread::spawn(move || {
-                if let Err(e) = save_dat(
+                if let Err(e) = write_dat(
                     additional_item_grp_path.path(),
                     DatVariant::<(), AdditionalItemGrpDat>::Array(additional_item_grp),
+                    &additional_item_grp_target,
                 ) {
-                    Log::from_loader_e(e)
+                    Log::from(e)
                 } else {
                     Log::from_loader_i("Additional Item Grp saved")
                 }
             });
 
             let item_stat_handle = thread::spawn(move || {
-                if let Err(e) = save_dat(
+                if let Err(e) = write_dat(
                     item_stat_path.path(),
                     DatVariant::<(), ItemStatDataDat>::Array(item_stat),
+                    &item_stat_target,
                 ) {
-                    Log::from_loader_e(e)
+                    Log::from(e)
                 } else {
                     Log::from_loader_i("Item Stat saved")
                 }
             });
 
             let item_base_info_handle = thread::spawn(move || {
-                if let Err(e) = save_dat(
+                if let Err(e) = write_dat(
                     item_base_info_path.path(),
                     DatVariant::<(), ItemBaseInfoDat>::Array(item_base_info),
+                    &item_base_info_target,
                 ) {
-                    Log::from_loader_e(e)
+                    Log::from(e)
                 } else {
                     Log::from_loader_i("Item Base Info saved")
                 }
             });
 
             let item_name_handle = thread::spawn(move || {
-                if let Err(e) = save_dat(
+                if let Err(e) = write_dat(
                     item_name_path.path(),
                     DatVariant::<(), ItemNameDat>::Array(item_name),
+                    &item_name_target,
                 ) {
-                    Log::from_loader_e(e)
+                    Log::from(e)
                 } else {
                     Log::from_loader_i("Item Name saved")
                 }
@@ -138,6 +170,10 @@ impl GameDataHolder {
             logs.push(item_base_info_handle.join().unwrap());
             logs.push(item_name_handle.join().unwrap());
 
+            for v in translation_handles {
+                logs.push(v.join().unwrap());
+            }
+
             if let Some(h) = weapon_handle {
                 logs.push(h.join().unwrap());
             }
@@ -154,41 +190,27 @@ impl GameDataHolder {
         })
     }
 
-    pub fn load_items(&mut self) -> Result<Vec<Log>, ()> {
-        let additional_item_grp = wrap_into_id_map(deserialize_dat::<AdditionalItemGrpDat>(
-            self.dat_paths
-                .get(&"additionalitemgrp.dat".to_string())
-                .unwrap()
-                .path(),
-        )?);
+    pub fn load_items(&mut self) -> Result<Vec<Log>, Log> {
+        let additional_item_grp =
+            wrap_into_id_map(self.read_dat::<AdditionalItemGrpDat>("additionalitemgrp.dat")?);
 
-        let item_stat = wrap_into_id_map(deserialize_dat::<ItemStatDataDat>(
-            self.dat_paths
-                .get(&"itemstatdata.dat".to_string())
-                .unwrap()
-                .path(),
-        )?);
+        let item_stat = wrap_into_id_map(self.read_dat::<ItemStatDataDat>("itemstatdata.dat")?);
 
-        let item_base_info = wrap_into_id_map(deserialize_dat::<ItemBaseInfoDat>(
-            self.dat_paths
-                .get(&"item_baseinfo.dat".to_string())
-                .unwrap()
-                .path(),
-        )?);
+        let item_base_info =
+            wrap_into_id_map(self.read_dat::<ItemBaseInfoDat>("item_baseinfo.dat")?);
 
-        let item_name = wrap_into_id_map(deserialize_dat::<ItemNameDat>(
-            self.dat_paths
-                .get(&"itemname-ru.dat".to_string())
-                .unwrap()
-                .path(),
-        )?);
+        let item_name = self.read_dat::<ItemNameDat>(&self.main_dat("itemname"))?;
+
+        let mut logs = self.read_translations("itemname", &item_name);
 
-        let mut logs = self.load_weapons(
+        let item_name = wrap_into_id_map(item_name);
+
+        logs.extend(self.load_weapons(
             &additional_item_grp,
             &item_stat,
             &item_base_info,
             &item_name,
-        )?;
+        )?);
 
         logs.extend(self.load_etc_items(
             &additional_item_grp,
@@ -272,6 +294,20 @@ impl GetId for ItemNameDat {
     }
 }
 
+impl LocalizedRecord for ItemNameDat {
+    type Key = u32;
+
+    fn key(&self) -> u32 {
+        self.id
+    }
+
+    fn copy_text(&mut self, translation: &Self) {
+        self.name_link = translation.name_link;
+        self.additional_name = translation.additional_name.clone();
+        self.description = translation.description.clone();
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal, Default)]
 pub(crate) struct AdditionalItemGrpDat {
     id: DWORD,
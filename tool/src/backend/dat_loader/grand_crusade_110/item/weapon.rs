@@ -14,11 +14,11 @@ use crate::entity::item::{
 };
 
 use l2_rw::ue2_rw::{BYTE, DVEC, DWORD, FLOAT, SHORT, USHORT, UVEC};
-use l2_rw::{deserialize_dat, save_dat, DatVariant};
+use l2_rw::DatVariant;
 
-use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+use l2_rw::ue2_rw::{ReadError, ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
-use crate::backend::dat_loader::{GetId, L2StringTable};
+use crate::backend::dat_loader::{write_dat, DatOutput, GetId, L2StringTable};
 use crate::backend::holder::{GameDataHolder, HolderMapOps};
 use crate::backend::log_holder::{Log, LogLevel};
 use num_traits::{FromPrimitive, ToPrimitive};
@@ -257,7 +257,7 @@ impl From<(&Weapon, &mut L2GeneralStringTable)> for WeaponGrpDat {
 }
 
 impl GameDataHolder {
-    pub fn serialize_weapons_to_binary(&mut self) -> JoinHandle<Log> {
+    pub fn serialize_weapons_to_binary(&mut self, output: &DatOutput) -> JoinHandle<Log> {
         let mut weapons: Vec<WeaponGrpDat> = vec![];
 
         for v in self.weapon_holder.values().filter(|v| !v._deleted) {
@@ -269,13 +269,15 @@ impl GameDataHolder {
             .get(&"weapongrp.dat".to_string())
             .unwrap()
             .clone();
+        let weapon_grp_target = self.dat_target("weapongrp.dat", output);
 
         thread::spawn(move || {
-            if let Err(e) = save_dat(
+            if let Err(e) = write_dat(
                 weapon_grp_path.path(),
                 DatVariant::<(), WeaponGrpDat>::Array(weapons),
+                &weapon_grp_target,
             ) {
-                Log::from_loader_e(e)
+                Log::from(e)
             } else {
                 Log::from_loader_i("Weapon Grp saved")
             }
@@ -303,13 +305,8 @@ impl GameDataHolder {
         item_stat: &HashMap<u32, ItemStatDataDat>,
         item_base_info: &HashMap<u32, ItemBaseInfoDat>,
         item_name: &HashMap<u32, ItemNameDat>,
-    ) -> Result<Vec<Log>, ()> {
-        let weapon_grp = deserialize_dat::<WeaponGrpDat>(
-            self.dat_paths
-                .get(&"weapongrp.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
+    ) -> Result<Vec<Log>, Log> {
+        let weapon_grp = self.read_dat::<WeaponGrpDat>("weapongrp.dat")?;
 
         let base_info_default = ItemBaseInfoDat::default();
         let base_stat_default = ItemStatDataDat::default();
@@ -323,6 +320,7 @@ impl GameDataHolder {
                     level: LogLevel::Error,
                     producer: "Weapon Loader".to_string(),
                     log: format!("Item[{}]: No record in itemname found. Skipped", weapon.id),
+                    entity: None,
                 });
 
                 continue;
@@ -365,6 +363,7 @@ impl GameDataHolder {
                         "Item[{}]: Corrupted mesh texture array! Filled with NO_TEX",
                         weapon.id
                     ),
+                    entity: None,
                 });
             }
 
@@ -4,11 +4,12 @@ use crate::data::ItemId;
 use crate::entity::item_set::{ItemSet, ItemSetEnchantInfo};
 
 use l2_rw::ue2_rw::{ASCF, DWORD, UVEC};
-use l2_rw::{deserialize_dat, save_dat, DatVariant};
+use l2_rw::DatVariant;
 
-use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+use l2_rw::ue2_rw::{ReadError, ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
-use crate::backend::dat_loader::GetId;
+use crate::backend::dat_loader::translation::LocalizedRecord;
+use crate::backend::dat_loader::{decode_dat, write_dat, DatDecoder, DatOutput, GetId};
 use crate::backend::holder::{GameDataHolder, HolderMapOps};
 use r#macro::{ReadUnreal, WriteUnreal};
 use std::thread;
@@ -59,8 +60,11 @@ impl From<(&ItemSet, &mut L2GeneralStringTable)> for ItemSetGrpDat {
     }
 }
 
+pub(super) const LOCALIZED_DATS: &[(&str, DatDecoder)] =
+    &[("setitemgrp", decode_dat::<ItemSetGrpDat>)];
+
 impl GameDataHolder {
-    pub fn serialize_item_sets_to_binary(&mut self) -> JoinHandle<Log> {
+    pub fn serialize_item_sets_to_binary(&mut self, output: &DatOutput) -> JoinHandle<Vec<Log>> {
         let mut set_grp: Vec<ItemSetGrpDat> = vec![];
 
         for set in self.item_set_holder.values().filter(|v| !v._deleted) {
@@ -69,29 +73,37 @@ impl GameDataHolder {
 
         let set_grp_path = self
             .dat_paths
-            .get(&"setitemgrp-ru.dat".to_string())
+            .get(&self.main_dat("setitemgrp"))
             .unwrap()
             .clone();
+        let set_grp_target = self.dat_target(&self.main_dat("setitemgrp"), output);
+
+        let translations = self.translated("setitemgrp", &set_grp);
+        let translation_handles = self.save_translations("setitemgrp", translations, output);
 
         thread::spawn(move || {
-            if let Err(e) = save_dat(
+            let mut logs = if let Err(e) = write_dat(
                 set_grp_path.path(),
                 DatVariant::<(), ItemSetGrpDat>::Array(set_grp.to_vec()),
+                &set_grp_target,
             ) {
-                Log::from_loader_e(e)
+                vec![Log::from(e)]
             } else {
-                Log::from_loader_i("Set Item Grp saved")
+                vec![Log::from_loader_i("Set Item Grp saved")]
+            };
+
+            for v in translation_handles {
+                logs.push(v.join().unwrap());
             }
+
+            logs
         })
     }
 
-    pub fn load_item_sets(&mut self) -> Result<Vec<Log>, ()> {
-        let set_grp = deserialize_dat::<ItemSetGrpDat>(
-            self.dat_paths
-                .get(&"setitemgrp-ru.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
+    pub fn load_item_sets(&mut self) -> Result<Vec<Log>, Log> {
+        let set_grp = self.read_dat::<ItemSetGrpDat>(&self.main_dat("setitemgrp"))?;
+
+        let warnings = self.read_translations("setitemgrp", &set_grp);
 
         for v in set_grp {
             self.item_set_holder.insert(
@@ -138,7 +150,7 @@ impl GameDataHolder {
             );
         }
 
-        Ok(vec![])
+        Ok(warnings)
     }
 }
 
@@ -166,3 +178,42 @@ impl GetId for ItemSetGrpDat {
         self.id
     }
 }
+
+/**Descriptions are copied by index, ones added after load keep the main text
+ */
+impl LocalizedRecord for ItemSetGrpDat {
+    type Key = u32;
+
+    fn key(&self) -> u32 {
+        self.id
+    }
+
+    fn copy_text(&mut self, translation: &Self) {
+        for (v, t) in self
+            .base_descriptions
+            .inner
+            .iter_mut()
+            .zip(&translation.base_descriptions.inner)
+        {
+            *v = t.clone();
+        }
+
+        for (v, t) in self
+            .additional_descriptions
+            .inner
+            .iter_mut()
+            .zip(&translation.additional_descriptions.inner)
+        {
+            *v = t.clone();
+        }
+
+        for (v, t) in self
+            .enchant_bonuses
+            .inner
+            .iter_mut()
+            .zip(&translation.enchant_bonuses.inner)
+        {
+            v.description = t.description.clone();
+        }
+    }
+}
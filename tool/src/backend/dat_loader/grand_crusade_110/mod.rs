@@ -4,7 +4,10 @@
 )]
 mod animation_combo;
 mod daily_mission;
+mod enchant_effect;
+mod ensoul;
 mod hunting_zone;
+mod instant_zone;
 mod item;
 mod item_set;
 mod npc;
@@ -14,28 +17,31 @@ mod recipe;
 mod region;
 mod skill;
 mod residence;
+mod system_msg;
+mod variation;
 
 use crate::backend::holder::{GameDataHolder, HolderMapOps, HolderOps, L2GeneralStringTable};
 use crate::data::{ Location, Position,
 };
 use crate::frontend::IS_SAVING;
 
-use crate::backend::dat_loader::{DatLoader, L2StringTable};
+use crate::backend::dat_loader::{
+    decode_dat, decode_in_parallel, game_string, write_dat, DatDecoder, DatOutput, L2StringTable,
+};
 use crate::backend::log_holder::Log;
 use crate::entity::CommonEntity;
-use l2_rw::ue2_rw::{ASCF, BYTE, DWORD, FLOAT, STR};
-use l2_rw::{deserialize_dat, save_dat, DatVariant};
+use l2_rw::ue2_rw::{BYTE, FLOAT, STR};
+use l2_rw::DatVariant;
 use r#macro::{ReadUnreal, WriteUnreal};
 use std::collections::hash_map::Keys;
 use std::collections::HashMap;
 use std::ops::Index;
-use std::path::Path;
 use std::sync::atomic::Ordering;
 use std::thread;
-use walkdir::DirEntry;
+use std::thread::JoinHandle;
 
 use crate::log_multiple;
-use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+use l2_rw::ue2_rw::{ReadError, ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
 #[derive(Default, Clone)]
 pub struct L2SkillStringTable {
@@ -100,19 +106,27 @@ impl Index<u32> for L2SkillStringTable {
     }
 }
 
-impl DatLoader for GameDataHolder {
-    fn load_from_binary(&mut self, dat_paths: HashMap<String, DirEntry>) -> Result<Vec<Log>, ()> {
-        let Some(path) = dat_paths.get(&"l2gamedataname.dat".to_string()) else {
-            return Err(());
-        };
+const DATS: &[(&str, DatDecoder)] = &[
+    ("l2gamedataname.dat", decode_dat::<String>),
+];
+
+impl GameDataHolder {
+    /**`dat_paths` and `locales` are already set by [`super::DatLoader::load_from_binary`]
+     */
+    pub(super) fn load_grand_crusade_110(&mut self) -> Result<Vec<Log>, Log> {
+        if !self.dat_paths.contains_key("l2gamedataname.dat") {
+            return Err(Log::from_loader_e("l2gamedataname.dat not found"));
+        }
+
+        self.decoded_dats = decode_in_parallel(&self.dat_paths, &self.parallel_dats());
 
-        self.game_string_table = Self::load_game_data_name(path.path())?;
-        self.dat_paths = dat_paths;
+        self.load_game_data_name()?;
 
         let mut logs = vec![];
 
         logs.extend(self.load_npcs()?);
-        logs.extend(self.load_npc_strings()?);
+        logs.extend(self.load_string_dats()?);
+        logs.extend(self.load_system_messages()?);
 
         logs.extend(self.load_items()?);
         self.refill_all_items();
@@ -128,10 +142,15 @@ impl DatLoader for GameDataHolder {
         logs.extend(self.load_daily_missions()?);
         logs.extend(self.load_animation_combo()?);
         logs.extend(self.load_residences()?);
+        logs.extend(self.load_enchant_effects()?);
+        logs.extend(self.load_ensoul_options()?);
+        logs.extend(self.load_variation_options()?);
+        logs.extend(self.load_instant_zones()?);
 
         let mut log = "Dats loaded".to_string();
+        log.push_str(&format!("\nChronicle: {}", self.chronicle));
+        log.push_str(&format!("\nLocales: {}", self.locales.join(", ")));
         log.push_str(&format!("\nNpcs: {}", self.npc_holder.len()));
-        log.push_str(&format!("\nNpc Strings: {}", self.npc_strings.len()));
         log.push_str(&format!("\nQuests: {}", self.quest_holder.len()));
         log.push_str(&format!("\nSkills: {}", self.skill_holder.len()));
         log.push_str(&format!("\nItems: {}", self.item_holder.len()));
@@ -155,6 +174,27 @@ impl DatLoader for GameDataHolder {
             self.animation_combo_holder.len()
         ));
         log.push_str(&format!("\nResidences: {}", self.residence_holder.len()));
+        log.push_str(&format!("\nStrings: {}", self.game_string_holder.len()));
+        log.push_str(&format!(
+            "\nWeapon Enchant Effects: {}",
+            self.weapon_enchant_effect_holder.len()
+        ));
+        log.push_str(&format!(
+            "\nArmor Enchant Effects: {}",
+            self.armor_enchant_effect_holder.len()
+        ));
+        log.push_str(&format!(
+            "\nEnsoul Options: {}",
+            self.ensoul_option_holder.len()
+        ));
+        log.push_str(&format!(
+            "\nVariation Options: {}",
+            self.variation_option_holder.len()
+        ));
+        log.push_str(&format!(
+            "\nInstant Zones: {}",
+            self.instant_zone_holder.len()
+        ));
         log.push_str("\n======================================");
 
         logs.push(Log::from_loader_i(&log));
@@ -162,86 +202,150 @@ impl DatLoader for GameDataHolder {
         Ok(logs)
     }
 
-    fn save_to_binary(&mut self) -> std::io::Result<()> {
-        let mut res = vec![];
-
+    pub(super) fn save_grand_crusade_110(&mut self) -> std::io::Result<()> {
         IS_SAVING.store(true, Ordering::Relaxed);
 
-        let skills_handle = if self.skill_holder.was_changed() {
-            Some(self.serialize_skills_to_binary())
+        let handle = self.serialize_grand_crusade_110(false, &DatOutput::Disk);
+
+        thread::spawn(move || {
+            let mut res = handle.join().unwrap();
+
+            res.push(Log::from_loader_i("Binaries Saved"));
+
+            log_multiple(res);
+
+            IS_SAVING.store(false, Ordering::Relaxed);
+        });
+
+        Ok(())
+    }
+
+    /**Serializes changed holders, or all of them if `all` is set
+     */
+    pub(super) fn serialize_grand_crusade_110(
+        &mut self,
+        all: bool,
+        output: &DatOutput,
+    ) -> JoinHandle<Vec<Log>> {
+        let mut res = vec![];
+
+        let skills_handle = if all || self.skill_holder.was_changed() {
+            Some(self.serialize_skills_to_binary(output))
         } else {
             None
         };
-        let quest_handle = if self.quest_holder.was_changed() {
-            Some(self.serialize_quests_to_binary())
+        let quest_handle = if all || self.quest_holder.was_changed() {
+            Some(self.serialize_quests_to_binary(output))
         } else {
             None
         };
 
-        let npcs_handle = if self.npc_holder.was_changed() {
-            Some(self.serialize_npcs_to_binary())
+        let npcs_handle = if all || self.npc_holder.was_changed() {
+            Some(self.serialize_npcs_to_binary(output))
         } else {
             None
         };
 
-        let items_handle = if self.weapon_holder.was_changed()
+        let items_handle = if all
+            || self.weapon_holder.was_changed()
             || self.etc_item_holder.was_changed()
             || self.armor_holder.was_changed()
         {
-            Some(self.serialize_items_to_binary())
+            Some(self.serialize_items_to_binary(all, output))
+        } else {
+            None
+        };
+
+        let item_sets_handle = if all || self.item_set_holder.was_changed() {
+            Some(self.serialize_item_sets_to_binary(output))
+        } else {
+            None
+        };
+
+        let recipes_handle = if all || self.recipe_holder.was_changed() {
+            Some(self.serialize_recipes_to_binary(output))
+        } else {
+            None
+        };
+
+        let hunting_zones_handle = if all || self.hunting_zone_holder.was_changed() {
+            Some(self.serialize_hunting_zones_to_binary(output))
+        } else {
+            None
+        };
+
+        let regions_handle = if all || self.region_holder.was_changed() {
+            Some(self.serialize_regions_to_binary(output))
         } else {
             None
         };
 
-        let item_sets_handle = if self.item_set_holder.was_changed() {
-            Some(self.serialize_item_sets_to_binary())
+        let raid_info_handle = if all || self.raid_info_holder.was_changed() {
+            Some(self.serialize_raid_data_to_binary(output))
         } else {
             None
         };
 
-        let recipes_handle = if self.recipe_holder.was_changed() {
-            Some(self.serialize_recipes_to_binary())
+        let daily_missions_handle = if all || self.daily_mission_holder.was_changed() {
+            Some(self.serialize_daily_missions_to_binary(output))
         } else {
             None
         };
 
-        let hunting_zones_handle = if self.hunting_zone_holder.was_changed() {
-            Some(self.serialize_hunting_zones_to_binary())
+        let animations_combo_handle = if all || self.animation_combo_holder.was_changed() {
+            Some(self.serialize_animation_combo_to_binary(output))
         } else {
             None
         };
 
-        let regions_handle = if self.region_holder.was_changed() {
-            Some(self.serialize_regions_to_binary())
+        let residences_handle = if all || self.residence_holder.was_changed() {
+            Some(self.serialize_residence_to_binary(output))
         } else {
             None
         };
 
-        let raid_info_handle = if self.raid_info_holder.was_changed() {
-            Some(self.serialize_raid_data_to_binary())
+        let (string_handles, system_msg_handle) = if all || self.game_string_holder.was_changed()
+        {
+            (
+                self.serialize_string_dats(output),
+                self.serialize_system_messages(output),
+            )
+        } else {
+            (vec![], None)
+        };
+
+        let weapon_enchant_effects_handle = if all || self.weapon_enchant_effect_holder.was_changed()
+        {
+            self.serialize_weapon_enchant_effects(output)
         } else {
             None
         };
 
-        let daily_missions_handle = if self.daily_mission_holder.was_changed() {
-            Some(self.serialize_daily_missions_to_binary())
+        let armor_enchant_effects_handle = if all || self.armor_enchant_effect_holder.was_changed() {
+            self.serialize_armor_enchant_effects(output)
         } else {
             None
         };
 
-        let animations_combo_handle = if self.animation_combo_holder.was_changed() {
-            Some(self.serialize_animation_combo_to_binary())
+        let ensoul_handles = if all || self.ensoul_option_holder.was_changed() {
+            self.serialize_ensoul_options(output)
+        } else {
+            vec![]
+        };
+
+        let variation_options_handle = if all || self.variation_option_holder.was_changed() {
+            self.serialize_variation_options(output)
         } else {
             None
         };
 
-        let residences_handle = if self.residence_holder.was_changed() {
-            Some(self.serialize_residence_to_binary())
+        let instant_zones_handle = if all || self.instant_zone_holder.was_changed() {
+            self.serialize_instant_zones(output)
         } else {
             None
         };
 
-        let gdn_changed = self.game_string_table.was_changed;
+        let gdn_changed = all || self.game_string_table.was_changed;
 
         let l2_game_data_name_values = self.game_string_table.to_vec();
         let l2_game_data_name = self
@@ -249,15 +353,17 @@ impl DatLoader for GameDataHolder {
             .get(&"l2gamedataname.dat".to_string())
             .unwrap()
             .clone();
+        let l2_game_data_name_target = self.dat_target("l2gamedataname.dat", output);
 
         thread::spawn(move || {
             let gdn_handel = if gdn_changed {
                 Some(thread::spawn(move || {
-                    if let Err(e) = save_dat(
+                    if let Err(e) = write_dat(
                         l2_game_data_name.path(),
                         DatVariant::<(), String>::Array(l2_game_data_name_values),
+                        &l2_game_data_name_target,
                     ) {
-                        Log::from_loader_e(&format!("{e:?}"))
+                        Log::from(e)
                     } else {
                         Log::from_loader_i("Game Data Name saved")
                     }
@@ -287,7 +393,7 @@ impl DatLoader for GameDataHolder {
             }
 
             if let Some(v) = item_sets_handle {
-                res.push(v.join().unwrap());
+                res.extend(v.join().unwrap());
             }
 
             if let Some(v) = recipes_handle {
@@ -299,15 +405,15 @@ impl DatLoader for GameDataHolder {
             }
 
             if let Some(v) = regions_handle {
-                res.push(v.join().unwrap());
+                res.extend(v.join().unwrap());
             }
 
             if let Some(v) = raid_info_handle {
-                res.push(v.join().unwrap());
+                res.extend(v.join().unwrap());
             }
 
             if let Some(v) = daily_missions_handle {
-                res.push(v.join().unwrap());
+                res.extend(v.join().unwrap());
             }
 
             if let Some(v) = animations_combo_handle {
@@ -315,21 +421,41 @@ impl DatLoader for GameDataHolder {
             }
 
             if let Some(v) = residences_handle {
+                res.extend(v.join().unwrap());
+            }
+
+            for v in string_handles {
                 res.push(v.join().unwrap());
             }
 
-            res.push(Log::from_loader_i("Binaries Saved"));
+            if let Some(v) = system_msg_handle {
+                res.extend(v.join().unwrap());
+            }
 
-            log_multiple(res);
+            if let Some(v) = weapon_enchant_effects_handle {
+                res.push(v.join().unwrap());
+            }
 
-            IS_SAVING.store(false, Ordering::Relaxed);
-        });
+            if let Some(v) = armor_enchant_effects_handle {
+                res.push(v.join().unwrap());
+            }
 
-        Ok(())
+            for v in ensoul_handles {
+                res.push(v.join().unwrap());
+            }
+
+            if let Some(v) = variation_options_handle {
+                res.extend(v.join().unwrap());
+            }
+
+            if let Some(v) = instant_zones_handle {
+                res.extend(v.join().unwrap());
+            }
+
+            res
+        })
     }
-}
 
-impl GameDataHolder {
     /**Returns cloned String from `game data name`
      */
     fn gdns_cloned(&self, index: &u32) -> String {
@@ -345,26 +471,55 @@ impl GameDataHolder {
             .collect()
     }
 
-    fn load_game_data_name(path: &Path) -> Result<L2GeneralStringTable, ()> {
-        match deserialize_dat(path) {
-            Ok(r) => Ok(L2GeneralStringTable::from_vec(r)),
-            Err(e) => Err(e),
-        }
+    /**Dats that are decoded in parallel before entities are loaded, each loader module lists
+    the ones it reads. Files which are not listed here are decoded when loader asks for them
+     */
+    fn parallel_dats(&self) -> Vec<(String, DatDecoder)> {
+        let mut res: Vec<_> = [
+            DATS,
+            npc::DATS,
+            item::DATS,
+            hunting_zone::DATS,
+            skill::DATS,
+            recipe::DATS,
+            animation_combo::DATS,
+            enchant_effect::DATS,
+            ensoul::DATS,
+        ]
+        .concat()
+        .into_iter()
+        .map(|(file_name, decode)| (file_name.to_string(), decode))
+        .collect();
+
+        let localized = [
+            game_string::LOCALIZED_DATS,
+            system_msg::LOCALIZED_DATS,
+            npc::LOCALIZED_DATS,
+            item::LOCALIZED_DATS,
+            hunting_zone::LOCALIZED_DATS,
+            quest::LOCALIZED_DATS,
+            skill::LOCALIZED_DATS,
+            item_set::LOCALIZED_DATS,
+            region::LOCALIZED_DATS,
+            raid_data::LOCALIZED_DATS,
+            daily_mission::LOCALIZED_DATS,
+            residence::LOCALIZED_DATS,
+            ensoul::LOCALIZED_DATS,
+            variation::LOCALIZED_DATS,
+            instant_zone::LOCALIZED_DATS,
+        ]
+        .concat();
+
+        res.extend(self.localized_parallel_dats(&localized));
+
+        res
     }
 
-    fn load_npc_strings(&mut self) -> Result<Vec<Log>, ()> {
-        let vals = deserialize_dat::<NpcStringDat>(
-            self.dat_paths
-                .get(&"npcstring-ru.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
+    fn load_game_data_name(&mut self) -> Result<(), Log> {
+        self.game_string_table =
+            L2GeneralStringTable::from_vec(self.read_dat("l2gamedataname.dat")?);
 
-        for v in vals {
-            self.npc_strings.insert(v.id, v.value.to_string());
-        }
-
-        Ok(vec![])
+        Ok(())
     }
 
     fn refill_all_items(&mut self) {
@@ -384,12 +539,6 @@ struct L2GameDataNameDat {
     value: STR,
 }
 
-#[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal)]
-struct NpcStringDat {
-    id: DWORD,
-    value: ASCF,
-}
-
 #[derive(Debug, Copy, Clone, PartialEq, ReadUnreal, WriteUnreal, Default)]
 pub struct CoordsXYZ {
     pub(crate) x: FLOAT,
@@ -5,16 +5,19 @@ use crate::backend::entity_editor::WindowParams;
 use crate::data::{ItemId, NpcId, QuestId, SkillId};
 use crate::entity::npc::{
     Npc, NpcAdditionalParts, NpcDecorationEffect, NpcEquipParams, NpcMeshParams, NpcProperty,
-    NpcQuestInfo, NpcSkillAnimation, NpcSoundParams, NpcSummonParams, SummonType,
+    NpcQuestInfo, NpcSkillAnimation, NpcSoundParams, NpcSummonParams, NpcTranslation,
+    SummonType,
 };
 
 use l2_rw::ue2_rw::{ASCF, BYTE, DOUBLE, DWORD, FLOAT, USHORT, UVEC};
-use l2_rw::{deserialize_dat, save_dat, DatVariant};
+use l2_rw::DatVariant;
 
-use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+use l2_rw::ue2_rw::{ReadError, ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
+use crate::backend::dat_loader::translation::LocalizedRecord;
 use crate::backend::dat_loader::{
-    wrap_into_id_map, wrap_into_id_vec_map, DebugUtils, GetId, L2StringTable,
+    decode_dat, wrap_into_id_map, wrap_into_id_vec_map, write_dat, DatDecoder, DatOutput,
+    DebugUtils, GetId, L2StringTable,
 };
 use crate::backend::holder::{GameDataHolder, HolderMapOps};
 use crate::backend::log_holder::{Log, LogLevel};
@@ -60,6 +63,22 @@ impl From<(&Npc, &mut L2GeneralStringTable)> for NpcNameDat {
     }
 }
 
+impl NpcNameDat {
+    /**Same record with strings of the translation, npcs which have no translation yet keep
+    strings of the main locale
+     */
+    fn translated(&self, translation: Option<&NpcTranslation>) -> Self {
+        let mut res = self.clone();
+
+        if let Some(v) = translation {
+            res.name = (&v.name).into();
+            res.title = (&v.title).into();
+        }
+
+        res
+    }
+}
+
 impl AdditionalNpcGrpPartsDat {
     fn from(v: (&Npc, &mut L2GeneralStringTable)) -> Option<Self> {
         let npc = v.0;
@@ -194,8 +213,16 @@ impl From<(&Npc, &mut L2GeneralStringTable)> for NpcGrpDat {
     }
 }
 
+pub(super) const DATS: &[(&str, DatDecoder)] = &[
+    ("npcgrp.dat", decode_dat::<NpcGrpDat>),
+    ("additionalnpcgrpparts.dat", decode_dat::<AdditionalNpcGrpPartsDat>),
+    ("mobskillanimgrp.dat", decode_dat::<MobSkillAnimGrpDat>),
+];
+
+pub(super) const LOCALIZED_DATS: &[(&str, DatDecoder)] = &[("npcname", decode_dat::<NpcNameDat>)];
+
 impl GameDataHolder {
-    pub fn serialize_npcs_to_binary(&mut self) -> JoinHandle<Vec<Log>> {
+    pub fn serialize_npcs_to_binary(&mut self, output: &DatOutput) -> JoinHandle<Vec<Log>> {
         let mut logs = vec![];
 
         let mut npc_grp: Vec<NpcGrpDat> = vec![];
@@ -203,6 +230,13 @@ impl GameDataHolder {
         let mut npc_name: Vec<NpcNameDat> = vec![];
         let mut mob_skill_anim: Vec<MobSkillAnimGrpDat> = vec![];
 
+        let translation_locales: Vec<_> = self
+            .translation_dats("npcname")
+            .into_iter()
+            .map(|v| v.0)
+            .collect();
+        let mut translated_names = vec![vec![]; translation_locales.len()];
+
         for npc in self.npc_holder.values().filter(|v| !v._deleted) {
             npc_grp.push((npc, &mut self.game_string_table).into());
 
@@ -210,7 +244,15 @@ impl GameDataHolder {
                 additional_npc_parts_grp.push(v);
             }
 
-            npc_name.push((npc, &mut self.game_string_table).into());
+            let name: NpcNameDat = (npc, &mut self.game_string_table).into();
+
+            for (i, locale) in translation_locales.iter().enumerate() {
+                translated_names[i].push(
+                    name.translated(npc.translations.iter().find(|v| &v.locale == locale)),
+                );
+            }
+
+            npc_name.push(name);
 
             mob_skill_anim.extend(MobSkillAnimGrpDat::from((npc, &mut self.game_string_table)));
         }
@@ -220,64 +262,74 @@ impl GameDataHolder {
             .get(&"npcgrp.dat".to_string())
             .unwrap()
             .clone();
+        let npc_grp_target = self.dat_target("npcgrp.dat", output);
 
         let additional_npc_parts_path = self
             .dat_paths
             .get(&"additionalnpcgrpparts.dat".to_string())
             .unwrap()
             .clone();
+        let additional_npc_parts_target = self.dat_target("additionalnpcgrpparts.dat", output);
 
         let npc_name_path = self
             .dat_paths
-            .get(&"npcname-ru.dat".to_string())
+            .get(&self.main_dat("npcname"))
             .unwrap()
             .clone();
+        let npc_name_target = self.dat_target(&self.main_dat("npcname"), output);
 
         let mob_skill_anim_path = self
             .dat_paths
             .get(&"mobskillanimgrp.dat".to_string())
             .unwrap()
             .clone();
+        let mob_skill_anim_target = self.dat_target("mobskillanimgrp.dat", output);
+
+        let translation_handles = self.save_translations("npcname", translated_names, output);
 
         thread::spawn(move || {
             let npc_grp_handle = thread::spawn(move || {
-                if let Err(e) = save_dat(
+                if let Err(e) = write_dat(
                     npc_grp_path.path(),
                     DatVariant::<(), NpcGrpDat>::Array(npc_grp.to_vec()),
+                    &npc_grp_target,
                 ) {
-                    Log::from_loader_e(e)
+                    Log::from(e)
                 } else {
                     Log::from_loader_i("NpcGrp saved")
                 }
             });
             let additional_npc_parts_handle = thread::spawn(move || {
-                if let Err(e) = save_dat(
+                if let Err(e) = write_dat(
                     additional_npc_parts_path.path(),
                     DatVariant::<(), AdditionalNpcGrpPartsDat>::Array(
                         additional_npc_parts_grp.to_vec(),
                     ),
+                    &additional_npc_parts_target,
                 ) {
-                    Log::from_loader_e(e)
+                    Log::from(e)
                 } else {
                     Log::from_loader_i("AdditionalNpcPartsGrp saved")
                 }
             });
             let npc_name_handle = thread::spawn(move || {
-                if let Err(e) = save_dat(
+                if let Err(e) = write_dat(
                     npc_name_path.path(),
                     DatVariant::<(), NpcNameDat>::Array(npc_name.to_vec()),
+                    &npc_name_target,
                 ) {
-                    Log::from_loader_e(e)
+                    Log::from(e)
                 } else {
                     Log::from_loader_i("NpcName saved")
                 }
             });
             let mob_skill_anim_handle = thread::spawn(move || {
-                if let Err(e) = save_dat(
+                if let Err(e) = write_dat(
                     mob_skill_anim_path.path(),
                     DatVariant::<(), MobSkillAnimGrpDat>::Array(mob_skill_anim.to_vec()),
+                    &mob_skill_anim_target,
                 ) {
-                    Log::from_loader_e(e)
+                    Log::from(e)
                 } else {
                     Log::from_loader_i("MobSkillAnimGrp saved")
                 }
@@ -288,39 +340,31 @@ impl GameDataHolder {
             logs.push(npc_name_handle.join().unwrap());
             logs.push(mob_skill_anim_handle.join().unwrap());
 
+            for v in translation_handles {
+                logs.push(v.join().unwrap());
+            }
+
             logs
         })
     }
 
-    pub fn load_npcs(&mut self) -> Result<Vec<Log>, ()> {
-        let npc_grp = deserialize_dat::<NpcGrpDat>(
-            self.dat_paths
-                .get(&"npcgrp.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
+    pub fn load_npcs(&mut self) -> Result<Vec<Log>, Log> {
+        let npc_grp = self.read_dat::<NpcGrpDat>("npcgrp.dat")?;
 
         let mut npc_additional_parts_grp =
-            wrap_into_id_map(deserialize_dat::<AdditionalNpcGrpPartsDat>(
-                self.dat_paths
-                    .get(&"additionalnpcgrpparts.dat".to_string())
-                    .unwrap()
-                    .path(),
+            wrap_into_id_map(self.read_dat::<AdditionalNpcGrpPartsDat>(
+                "additionalnpcgrpparts.dat",
             )?);
 
-        let npc_name = wrap_into_id_map(deserialize_dat::<NpcNameDat>(
-            self.dat_paths
-                .get(&"npcname-ru.dat".to_string())
-                .unwrap()
-                .path(),
-        )?);
+        let npc_name = self.read_dat::<NpcNameDat>(&self.main_dat("npcname"))?;
+
+        let mut warnings = self.read_translations("npcname", &npc_name);
 
-        let mut mob_skill_anim = wrap_into_id_vec_map(deserialize_dat::<MobSkillAnimGrpDat>(
-            self.dat_paths
-                .get(&"mobskillanimgrp.dat".to_string())
-                .unwrap()
-                .path(),
-        )?);
+        let npc_name = wrap_into_id_map(npc_name);
+        let translations = self.translation_texts::<NpcNameDat>("npcname");
+
+        let mut mob_skill_anim =
+            wrap_into_id_vec_map(self.read_dat::<MobSkillAnimGrpDat>("mobskillanimgrp.dat")?);
 
         let default_npc_name = NpcNameDat::default();
 
@@ -337,8 +381,6 @@ impl GameDataHolder {
             println!("\n");
         }
 
-        let mut warnings = vec![];
-
         for npc in npc_grp {
             let id = npc.id as u32;
             let npc_name_record = if let Some(v) = npc_name.get(&id) {
@@ -351,6 +393,7 @@ impl GameDataHolder {
                         "Npc[{}]: No record in npcname. Default will be used",
                         npc.id
                     ),
+                    entity: None,
                 });
 
                 &default_npc_name
@@ -487,6 +530,16 @@ impl GameDataHolder {
                 icon: self.gdns_cloned(&npc.npc_icon),
                 additional_parts,
                 quest_infos,
+                translations: translations
+                    .iter()
+                    .filter_map(|(locale, records)| {
+                        records.get(&id).map(|v| NpcTranslation {
+                            locale: locale.clone(),
+                            name: v.name.to_string(),
+                            title: v.title.to_string(),
+                        })
+                    })
+                    .collect(),
                 ..Default::default()
             };
 
@@ -528,6 +581,19 @@ impl GetId for NpcNameDat {
     }
 }
 
+impl LocalizedRecord for NpcNameDat {
+    type Key = u32;
+
+    fn key(&self) -> u32 {
+        self.id
+    }
+
+    fn copy_text(&mut self, translation: &Self) {
+        self.name = translation.name.clone();
+        self.title = translation.title.clone();
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal)]
 struct DecoEffect {
     effect: DWORD,
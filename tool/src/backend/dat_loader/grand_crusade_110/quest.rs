@@ -3,16 +3,18 @@ use crate::backend::entity_editor::WindowParams;
 use crate::backend::entity_impl::quest::StepAction;
 use crate::data::{HuntingZoneId, ItemId, NpcId, PlayerClass, QuestId};
 use crate::entity::quest::{
-    GoalType, MarkType, Quest, QuestCategory, QuestReward, QuestStep, QuestType, StepGoal, Unk1,
-    Unk2, UnkQLevel,
+    GoalType, MarkType, Quest, QuestCategory, QuestReward, QuestStep, QuestStepTranslation,
+    QuestTranslation, QuestType, StepGoal, Unk1, Unk2, UnkQLevel,
 };
 
 use l2_rw::ue2_rw::{ASCF, DWORD, LONG};
-use l2_rw::{deserialize_dat, save_dat, DatVariant};
+use l2_rw::DatVariant;
 
-use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+use l2_rw::ue2_rw::{ReadError, ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
 use crate::backend::holder::{GameDataHolder, HolderMapOps};
+use crate::backend::dat_loader::translation::LocalizedRecord;
+use crate::backend::dat_loader::{decode_dat, write_dat, DatDecoder, DatOutput};
 use crate::backend::log_holder::{Log, LogLevel};
 use num_traits::{FromPrimitive, ToPrimitive};
 use r#macro::{ReadUnreal, WriteUnreal};
@@ -20,43 +22,64 @@ use std::sync::RwLock;
 use std::thread;
 use std::thread::JoinHandle;
 
+pub(super) const LOCALIZED_DATS: &[(&str, DatDecoder)] =
+    &[("questname", decode_dat::<QuestNameDat>)];
+
 impl GameDataHolder {
-    pub fn serialize_quests_to_binary(&mut self) -> JoinHandle<Vec<Log>> {
+    pub fn serialize_quests_to_binary(&mut self, output: &DatOutput) -> JoinHandle<Vec<Log>> {
         let mut res = Vec::new();
 
+        let translation_locales: Vec<_> = self
+            .translation_dats("questname")
+            .into_iter()
+            .map(|v| v.0)
+            .collect();
+        let mut translated = vec![vec![]; translation_locales.len()];
+
         let mut vals: Vec<_> = self.quest_holder.values().filter(|v| !v._deleted).collect();
         vals.sort_by(|a, b| a.id.cmp(&b.id));
 
         for quest in vals {
-            for step in QuestNameDat::from_quest(quest) {
-                res.push(step);
+            let steps = QuestNameDat::from_quest(quest);
+
+            for (i, locale) in translation_locales.iter().enumerate() {
+                translated[i].extend(QuestNameDat::translated(&steps, quest, locale));
             }
+
+            res.extend(steps);
         }
 
+        let translation_handles = self.save_translations("questname", translated, output);
+
         let quest_path = self
             .dat_paths
-            .get(&"questname-ru.dat".to_string())
+            .get(&self.main_dat("questname"))
             .unwrap()
             .clone();
+        let quest_target = self.dat_target(&self.main_dat("questname"), output);
 
         thread::spawn(move || {
-            if let Err(e) = save_dat(
+            let mut logs = if let Err(e) = write_dat(
                 quest_path.path(),
                 DatVariant::<(), QuestNameDat>::Array(res),
+                &quest_target,
             ) {
-                vec![Log::from_loader_e(e)]
+                vec![Log::from(e)]
             } else {
                 vec![Log::from_loader_i("Quest Name saved")]
+            };
+
+            for v in translation_handles {
+                logs.push(v.join().unwrap());
             }
+
+            logs
         })
     }
-    pub fn load_quests(&mut self) -> Result<Vec<Log>, ()> {
-        let vals = deserialize_dat::<QuestNameDat>(
-            self.dat_paths
-                .get(&"questname-ru.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
+    pub fn load_quests(&mut self) -> Result<Vec<Log>, Log> {
+        let vals = self.read_dat::<QuestNameDat>(&self.main_dat("questname"))?;
+
+        let mut warnings = self.read_translations("questname", &vals);
 
         let mut current_id = if let Some(v) = vals.first() {
             v.id
@@ -65,8 +88,6 @@ impl GameDataHolder {
         };
         let mut current_steps = Vec::new();
 
-        let mut warnings = vec![];
-
         for v in vals {
             if v.id == current_id {
                 current_steps.push(v);
@@ -80,6 +101,45 @@ impl GameDataHolder {
 
         warnings.extend(self.construct_quest(&current_steps));
 
+        for (locale, rows) in self.translation_texts::<QuestNameDat>("questname") {
+            let mut rows: Vec<_> = rows.into_values().collect();
+            rows.sort_by_key(|v| v.key());
+
+            for v in rows {
+                let Some(quest) = self.quest_holder.get_mut(&QuestId(v.id)) else {
+                    continue;
+                };
+
+                if !quest.translations.iter().any(|t| t.locale == locale) {
+                    quest.translations.push(QuestTranslation {
+                        locale: locale.clone(),
+                        title: v.title.to_string(),
+                        intro: v.intro.to_string(),
+                        requirements: v.requirements.to_string(),
+                    });
+                }
+
+                let Some(step) = quest.steps.iter_mut().find(|s| s.inner.level == v.level) else {
+                    continue;
+                };
+
+                if step.inner.translations.iter().any(|t| t.locale == locale) {
+                    continue;
+                }
+
+                step.inner.translations.push(QuestStepTranslation {
+                    locale: locale.clone(),
+                    title: if QuestStep::is_finish_level(v.level) {
+                        "FINISH".to_string()
+                    } else {
+                        v.sub_name.to_string()
+                    },
+                    label: v.entity_name.to_string(),
+                    desc: v.desc.to_string(),
+                });
+            }
+        }
+
         Ok(warnings)
     }
 
@@ -109,13 +169,13 @@ impl GameDataHolder {
                     })
                     .collect();
 
-                if v.level > 1_000 {
+                if QuestStep::is_finish_level(v.level) {
                     last_finish_id = v.level.min(last_finish_id);
                 }
 
                 return WindowParams {
                     inner: QuestStep {
-                        title: if v.level > 1_000 {
+                        title: if QuestStep::is_finish_level(v.level) {
                             "FINISH".to_string()
                         } else {
                             v.sub_name.to_string()
@@ -139,6 +199,7 @@ impl GameDataHolder {
                         unk_2: Unk2::from_u32(v.unk_2).unwrap(),
                         prev_steps: v.pre_level.clone(),
                         level: v.level,
+                        translations: vec![],
                     },
 
                     initial_id: (),
@@ -167,6 +228,7 @@ impl GameDataHolder {
                             "Quest[{}]: no reward count for item[{}]. Set to 0",
                             first.id, v
                         ),
+                        entity: None,
                     });
                     0
                 },
@@ -259,7 +321,56 @@ pub struct QuestNameDat {
     faction_level_max: DWORD,
 }
 
+impl LocalizedRecord for QuestNameDat {
+    type Key = (u32, u32);
+
+    fn key(&self) -> (u32, u32) {
+        (self.id, self.level)
+    }
+
+    fn copy_text(&mut self, translation: &Self) {
+        self.title = translation.title.clone();
+        self.sub_name = translation.sub_name.clone();
+        self.desc = translation.desc.clone();
+        self.entity_name = translation.entity_name.clone();
+        self.requirements = translation.requirements.clone();
+        self.intro = translation.intro.clone();
+    }
+}
+
 impl QuestNameDat {
+    /**Rows of [`Self::from_quest`] with strings of the translation, strings which have no
+    translation yet are kept from the main locale
+     */
+    fn translated(steps: &[Self], quest: &Quest, locale: &str) -> Vec<Self> {
+        let translation = quest.translations.iter().find(|v| v.locale == locale);
+
+        steps
+            .iter()
+            .zip(&quest.steps)
+            .map(|(row, step)| {
+                let mut res = row.clone();
+
+                if let Some(v) = translation {
+                    res.title = (&v.title).into();
+                    res.intro = (&v.intro).into();
+                    res.requirements = (&v.requirements).into();
+                }
+
+                if let Some(v) = step.inner.translations.iter().find(|v| v.locale == locale) {
+                    if !QuestStep::is_finish_level(step.inner.level) {
+                        res.sub_name = (&v.title).into();
+                    }
+
+                    res.entity_name = (&v.label).into();
+                    res.desc = (&v.desc).into();
+                }
+
+                res
+            })
+            .collect()
+    }
+
     fn from_quest(quest: &Quest) -> Vec<Self> {
         let mut res = Vec::with_capacity(quest.steps.len() + 1);
 
@@ -279,7 +390,7 @@ impl QuestNameDat {
                 id: quest.id.0,
                 level: step.level,
                 title: (&quest.title).into(),
-                sub_name: if step.level < 1_000 {
+                sub_name: if !QuestStep::is_finish_level(step.level) {
                     (&step.title).into()
                 } else {
                     ASCF::empty()
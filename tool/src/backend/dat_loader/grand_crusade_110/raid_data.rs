@@ -1,11 +1,12 @@
 use crate::backend::log_holder::Log;
 
 use l2_rw::ue2_rw::{ASCF, BYTE, DWORD, FLOAT};
-use l2_rw::{deserialize_dat, save_dat, DatVariant};
+use l2_rw::DatVariant;
 
-use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+use l2_rw::ue2_rw::{ReadError, ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
-use crate::backend::dat_loader::GetId;
+use crate::backend::dat_loader::translation::LocalizedRecord;
+use crate::backend::dat_loader::{decode_dat, write_dat, DatDecoder, DatOutput, GetId};
 use crate::backend::holder::{GameDataHolder, HolderMapOps};
 use crate::data::Position;
 use crate::entity::raid_info::RaidInfo;
@@ -13,8 +14,10 @@ use r#macro::{ReadUnreal, WriteUnreal};
 use std::thread;
 use std::thread::JoinHandle;
 
+pub(super) const LOCALIZED_DATS: &[(&str, DatDecoder)] = &[("raiddata", decode_dat::<RaidDataDat>)];
+
 impl GameDataHolder {
-    pub fn serialize_raid_data_to_binary(&mut self) -> JoinHandle<Log> {
+    pub fn serialize_raid_data_to_binary(&mut self, output: &DatOutput) -> JoinHandle<Vec<Log>> {
         let raid_grp: Vec<RaidDataDat> = self
             .raid_info_holder
             .values()
@@ -35,29 +38,37 @@ impl GameDataHolder {
 
         let dat_path = self
             .dat_paths
-            .get(&"raiddata-ru.dat".to_string())
+            .get(&self.main_dat("raiddata"))
             .unwrap()
             .clone();
+        let dat_target = self.dat_target(&self.main_dat("raiddata"), output);
+
+        let translations = self.translated("raiddata", &raid_grp);
+        let translation_handles = self.save_translations("raiddata", translations, output);
 
         thread::spawn(move || {
-            if let Err(e) = save_dat(
+            let mut logs = if let Err(e) = write_dat(
                 dat_path.path(),
                 DatVariant::<(), RaidDataDat>::Array(raid_grp.to_vec()),
+                &dat_target,
             ) {
-                Log::from_loader_e(e)
+                vec![Log::from(e)]
             } else {
-                Log::from_loader_i("RaidData saved")
+                vec![Log::from_loader_i("RaidData saved")]
+            };
+
+            for v in translation_handles {
+                logs.push(v.join().unwrap());
             }
+
+            logs
         })
     }
 
-    pub fn load_raid_data(&mut self) -> Result<Vec<Log>, ()> {
-        let raid_grp = deserialize_dat::<RaidDataDat>(
-            self.dat_paths
-                .get(&"raiddata-ru.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
+    pub fn load_raid_data(&mut self) -> Result<Vec<Log>, Log> {
+        let raid_grp = self.read_dat::<RaidDataDat>(&self.main_dat("raiddata"))?;
+
+        let warnings = self.read_translations("raiddata", &raid_grp);
 
         for v in raid_grp {
             self.raid_info_holder.insert(
@@ -82,7 +93,7 @@ impl GameDataHolder {
             );
         }
 
-        Ok(vec![])
+        Ok(warnings)
     }
 }
 
@@ -106,3 +117,15 @@ impl GetId for RaidDataDat {
         self.id
     }
 }
+
+impl LocalizedRecord for RaidDataDat {
+    type Key = u32;
+
+    fn key(&self) -> u32 {
+        self.id
+    }
+
+    fn copy_text(&mut self, translation: &Self) {
+        self.desc = translation.desc.clone();
+    }
+}
@@ -3,11 +3,11 @@ use crate::backend::log_holder::Log;
 use crate::entity::recipe::{Recipe, RecipeMaterial};
 
 use l2_rw::ue2_rw::{ASCF, DWORD, UVEC};
-use l2_rw::{deserialize_dat, save_dat, DatVariant};
+use l2_rw::DatVariant;
 
-use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+use l2_rw::ue2_rw::{ReadError, ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
-use crate::backend::dat_loader::GetId;
+use crate::backend::dat_loader::{decode_dat, write_dat, DatDecoder, DatOutput, GetId};
 use crate::backend::holder::{GameDataHolder, HolderMapOps};
 use r#macro::{ReadUnreal, WriteUnreal};
 use std::thread;
@@ -42,8 +42,12 @@ impl From<(&Recipe, &mut L2GeneralStringTable)> for RecipeDat {
     }
 }
 
+pub(super) const DATS: &[(&str, DatDecoder)] = &[
+    ("recipe.dat", decode_dat::<RecipeDat>),
+];
+
 impl GameDataHolder {
-    pub fn serialize_recipes_to_binary(&mut self) -> JoinHandle<Log> {
+    pub fn serialize_recipes_to_binary(&mut self, output: &DatOutput) -> JoinHandle<Log> {
         let mut set_grp: Vec<RecipeDat> = vec![];
 
         for set in self.recipe_holder.values().filter(|v| !v._deleted) {
@@ -55,26 +59,23 @@ impl GameDataHolder {
             .get(&"recipe.dat".to_string())
             .unwrap()
             .clone();
+        let set_grp_target = self.dat_target("recipe.dat", output);
 
         thread::spawn(move || {
-            if let Err(e) = save_dat(
+            if let Err(e) = write_dat(
                 set_grp_path.path(),
                 DatVariant::<(), RecipeDat>::Array(set_grp.to_vec()),
+                &set_grp_target,
             ) {
-                Log::from_loader_e(e)
+                Log::from(e)
             } else {
                 Log::from_loader_i("Recipe saved")
             }
         })
     }
 
-    pub fn load_recipes(&mut self) -> Result<Vec<Log>, ()> {
-        let set_grp = deserialize_dat::<RecipeDat>(
-            self.dat_paths
-                .get(&"recipe.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
+    pub fn load_recipes(&mut self) -> Result<Vec<Log>, Log> {
+        let set_grp = self.read_dat::<RecipeDat>("recipe.dat")?;
 
         for v in set_grp {
             self.recipe_holder.insert(
@@ -1,11 +1,12 @@
 use crate::backend::log_holder::Log;
 
 use l2_rw::ue2_rw::{ASCF, DWORD, FLOAT, INT, SHORT, USHORT};
-use l2_rw::{deserialize_dat, save_dat, DatVariant};
+use l2_rw::DatVariant;
 
-use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+use l2_rw::ue2_rw::{ReadError, ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
-use crate::backend::dat_loader::L2StringTable;
+use crate::backend::dat_loader::translation::LocalizedRecord;
+use crate::backend::dat_loader::{decode_dat, write_dat, DatDecoder, DatOutput, L2StringTable};
 use crate::backend::holder::{GameDataHolder, HolderMapOps, L2GeneralStringTable};
 use crate::entity::region::{Continent, MapInfo, Region};
 use num_traits::{FromPrimitive, ToPrimitive};
@@ -54,8 +55,10 @@ impl From<(&Region, &mut L2GeneralStringTable, &MapInfo)> for ZoneNameDat {
     }
 }
 
+pub(super) const LOCALIZED_DATS: &[(&str, DatDecoder)] = &[("zonename", decode_dat::<ZoneNameDat>)];
+
 impl GameDataHolder {
-    pub fn serialize_regions_to_binary(&mut self) -> JoinHandle<Log> {
+    pub fn serialize_regions_to_binary(&mut self, output: &DatOutput) -> JoinHandle<Vec<Log>> {
         let mut zonenames: Vec<&Region> = self
             .region_holder
             .values()
@@ -73,38 +76,44 @@ impl GameDataHolder {
             texture: String::from("None"),
         };
 
-        let zonenames = zonenames
+        let zonenames: Vec<ZoneNameDat> = zonenames
             .iter()
             .map(|v| (*v, &mut self.game_string_table, &none_map_info).into())
             .collect();
 
         let zonename_path = self
             .dat_paths
-            .get(&"zonename-ru.dat".to_string())
+            .get(&self.main_dat("zonename"))
             .unwrap()
             .clone();
+        let zonename_target = self.dat_target(&self.main_dat("zonename"), output);
+
+        let translations = self.translated("zonename", &zonenames);
+        let translation_handles = self.save_translations("zonename", translations, output);
 
         thread::spawn(move || {
-            if let Err(e) = save_dat(
+            let mut logs = if let Err(e) = write_dat(
                 zonename_path.path(),
                 DatVariant::<(), ZoneNameDat>::Array(zonenames),
+                &zonename_target,
             ) {
-                Log::from_loader_e(e)
+                vec![Log::from(e)]
             } else {
-                Log::from_loader_i("Mini Map Region saved")
+                vec![Log::from_loader_i("Mini Map Region saved")]
+            };
+
+            for v in translation_handles {
+                logs.push(v.join().unwrap());
             }
+
+            logs
         })
     }
 
-    pub fn load_regions(&mut self) -> Result<Vec<Log>, ()> {
-        let warnings = vec![];
+    pub fn load_regions(&mut self) -> Result<Vec<Log>, Log> {
+        let zonename = self.read_dat::<ZoneNameDat>(&self.main_dat("zonename"))?;
 
-        let zonename = deserialize_dat::<ZoneNameDat>(
-            self.dat_paths
-                .get(&"zonename-ru.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
+        let warnings = self.read_translations("zonename", &zonename);
 
         for v in zonename {
             let map_texture = self.game_string_table.get_o(&v.town_map_texture);
@@ -171,3 +180,15 @@ struct ZoneNameDat {
     town_center_x: INT,
     town_center_y: INT,
 }
+
+impl LocalizedRecord for ZoneNameDat {
+    type Key = u16;
+
+    fn key(&self) -> u16 {
+        self.id
+    }
+
+    fn copy_text(&mut self, translation: &Self) {
+        self.name = translation.name.clone();
+    }
+}
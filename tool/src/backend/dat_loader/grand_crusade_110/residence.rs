@@ -1,9 +1,10 @@
 use l2_rw::ue2_rw::{ASCF, DWORD, USHORT};
-use l2_rw::{deserialize_dat, save_dat, DatVariant};
+use l2_rw::DatVariant;
 
-use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+use l2_rw::ue2_rw::{ReadError, ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
-use crate::backend::dat_loader::L2StringTable;
+use crate::backend::dat_loader::translation::LocalizedRecord;
+use crate::backend::dat_loader::{decode_dat, write_dat, DatDecoder, DatOutput, L2StringTable};
 use crate::backend::holder::{GameDataHolder, HolderMapOps, L2GeneralStringTable};
 use crate::backend::log_holder::{Log};
 use r#macro::{ReadUnreal, WriteUnreal};
@@ -31,9 +32,12 @@ impl From<(&Residence, &mut L2GeneralStringTable)> for CastleNameDat {
     }
 }
 
+pub(super) const LOCALIZED_DATS: &[(&str, DatDecoder)] =
+    &[("castlename", decode_dat::<CastleNameDat>)];
+
 impl GameDataHolder {
-    pub fn serialize_residence_to_binary(&mut self) -> JoinHandle<Log> {
-        let residences = self
+    pub fn serialize_residence_to_binary(&mut self, output: &DatOutput) -> JoinHandle<Vec<Log>> {
+        let residences: Vec<CastleNameDat> = self
             .residence_holder
             .values()
             .map(|v| (v, &mut self.game_string_table).into())
@@ -41,31 +45,37 @@ impl GameDataHolder {
 
         let residence_path = self
             .dat_paths
-            .get(&"castlename-ru.dat".to_string())
+            .get(&self.main_dat("castlename"))
             .unwrap()
             .clone();
+        let residence_target = self.dat_target(&self.main_dat("castlename"), output);
+
+        let translations = self.translated("castlename", &residences);
+        let translation_handles = self.save_translations("castlename", translations, output);
 
         thread::spawn(move || {
-            if let Err(e) = save_dat(
+            let mut logs = if let Err(e) = write_dat(
                 residence_path.path(),
                 DatVariant::<(), CastleNameDat>::Array(residences),
+                &residence_target,
             ) {
-                Log::from_loader_e(e)
+                vec![Log::from(e)]
             } else {
-                Log::from_loader_i("Residences saved")
+                vec![Log::from_loader_i("Residences saved")]
+            };
+
+            for v in translation_handles {
+                logs.push(v.join().unwrap());
             }
+
+            logs
         })
     }
 
-    pub fn load_residences(&mut self) -> Result<Vec<Log>, ()> {
-        let warnings = vec![];
+    pub fn load_residences(&mut self) -> Result<Vec<Log>, Log> {
+        let residences = self.read_dat::<CastleNameDat>(&self.main_dat("castlename"))?;
 
-        let residences = deserialize_dat::<CastleNameDat>(
-            self.dat_paths
-                .get(&"castlename-ru.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
+        let warnings = self.read_translations("castlename", &residences);
 
         for v in residences {
             self.residence_holder.insert(
@@ -102,4 +112,19 @@ struct CastleNameDat {
     flag_icon: DWORD,
     merc_name: ASCF,
     region_id: USHORT,
+}
+
+impl LocalizedRecord for CastleNameDat {
+    type Key = u32;
+
+    fn key(&self) -> u32 {
+        self.id
+    }
+
+    fn copy_text(&mut self, translation: &Self) {
+        self.name = translation.name.clone();
+        self.loc = translation.loc.clone();
+        self.desc = translation.desc.clone();
+        self.merc_name = translation.merc_name.clone();
+    }
 }
\ No newline at end of file
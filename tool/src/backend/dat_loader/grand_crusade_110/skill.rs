@@ -11,16 +11,20 @@ use crate::entity::skill::{
 };
 
 use l2_rw::ue2_rw::{ASCF, BYTE, DWORD, FLOAT, INT, SHORT, USHORT, UVEC};
-use l2_rw::{deserialize_dat, deserialize_dat_with_string_dict, save_dat, DatVariant};
+use l2_rw::DatVariant;
 
-use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+use l2_rw::ue2_rw::{ReadError, ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
-use crate::backend::dat_loader::L2StringTable;
+use crate::backend::dat_loader::translation::{LocalizedRecord, TranslationRows};
+use crate::backend::dat_loader::{
+    decode_dat, decode_dat_with_string_dict, unread_bytes, write_dat, DatDecoder, DatOutput,
+    L2StringTable,
+};
 use crate::backend::holder::{GameDataHolder, HolderMapOps};
 use crate::backend::log_holder::{Log, LogLevel};
 use num_traits::{FromPrimitive, ToPrimitive};
 use r#macro::{ReadUnreal, WriteUnreal};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::marker::PhantomData;
 use std::sync::RwLock;
 use std::thread;
@@ -93,8 +97,20 @@ impl MSConditionDataDat {
     }
 }
 
+pub(super) const DATS: &[(&str, DatDecoder)] = &[
+    ("skillgrp.dat", decode_dat::<SkillGrpDat>),
+    ("skillsoundgrp.dat", decode_dat::<SkillSoundDat>),
+    ("skillsoundsource.dat", decode_dat::<SkillSoundSourceDat>),
+    ("msconditiondata.dat", decode_dat::<MSConditionDataDat>),
+];
+
+pub(super) const LOCALIZED_DATS: &[(&str, DatDecoder)] = &[(
+    "skillname",
+    decode_dat_with_string_dict::<SkillNameTableRecord, SkillNameDat>,
+)];
+
 impl GameDataHolder {
-    pub fn serialize_skills_to_binary(&mut self) -> JoinHandle<Vec<Log>> {
+    pub fn serialize_skills_to_binary(&mut self, output: &DatOutput) -> JoinHandle<Vec<Log>> {
         let mut logs = vec![];
 
         let mut skill_grp = vec![];
@@ -191,77 +207,89 @@ impl GameDataHolder {
             .get(&"skillsoundsource.dat".to_string())
             .unwrap()
             .clone();
+        let skill_sound_src_target = self.dat_target("skillsoundsource.dat", output);
         let ms_condition_path = self
             .dat_paths
             .get(&"msconditiondata.dat".to_string())
             .unwrap()
             .clone();
+        let ms_condition_target = self.dat_target("msconditiondata.dat", output);
         let skill_name_path = self
             .dat_paths
-            .get(&"skillname-ru.dat".to_string())
+            .get(&self.main_dat("skillname"))
             .unwrap()
             .clone();
+        let skill_name_target = self.dat_target(&self.main_dat("skillname"), output);
         let skill_grp_path = self
             .dat_paths
             .get(&"skillgrp.dat".to_string())
             .unwrap()
             .clone();
+        let skill_grp_target = self.dat_target("skillgrp.dat", output);
+        let translation_handles =
+            self.save_skill_name_translations(&skill_name, &skill_string_table, output);
         let skill_sound_path = self
             .dat_paths
             .get(&"skillsoundgrp.dat".to_string())
             .unwrap()
             .clone();
+        let skill_sound_target = self.dat_target("skillsoundgrp.dat", output);
 
         thread::spawn(move || {
             let ms_condition_handle = thread::spawn(move || {
-                if let Err(e) = save_dat(
+                if let Err(e) = write_dat(
                     ms_condition_path.path(),
                     DatVariant::<(), MSConditionDataDat>::Array(ms_condition),
+                    &ms_condition_target,
                 ) {
-                    Log::from_loader_e(e)
+                    Log::from(e)
                 } else {
                     Log::from_loader_i("Ms Condition saved")
                 }
             });
             let skill_name_handel = thread::spawn(move || {
-                if let Err(e) = save_dat(
+                if let Err(e) = write_dat(
                     skill_name_path.path(),
                     DatVariant::DoubleArray(
                         SkillNameTableRecord::from_table(skill_string_table),
                         skill_name,
                     ),
+                    &skill_name_target,
                 ) {
-                    Log::from_loader_e(e)
+                    Log::from(e)
                 } else {
                     Log::from_loader_i("Skill Name saved")
                 }
             });
             let skill_grp_handel = thread::spawn(move || {
-                if let Err(e) = save_dat(
+                if let Err(e) = write_dat(
                     skill_grp_path.path(),
                     DatVariant::<(), SkillGrpDat>::Array(skill_grp),
+                    &skill_grp_target,
                 ) {
-                    Log::from_loader_e(e)
+                    Log::from(e)
                 } else {
                     Log::from_loader_i("Skill Grp saved")
                 }
             });
             let skill_sound_handel = thread::spawn(move || {
-                if let Err(e) = save_dat(
+                if let Err(e) = write_dat(
                     skill_sound_path.path(),
                     DatVariant::<(), SkillSoundDat>::Array(skill_sound),
+                    &skill_sound_target,
                 ) {
-                    Log::from_loader_e(e)
+                    Log::from(e)
                 } else {
                     Log::from_loader_i("Skill Sound saved")
                 }
             });
             let skill_sound_src_handel = thread::spawn(move || {
-                if let Err(e) = save_dat(
+                if let Err(e) = write_dat(
                     skill_sound_src_path.path(),
                     DatVariant::<(), SkillSoundSourceDat>::Array(skill_sound_src),
+                    &skill_sound_src_target,
                 ) {
-                    Log::from_loader_e(e)
+                    Log::from(e)
                 } else {
                     Log::from_loader_i("Skill Sound Src saved")
                 }
@@ -273,25 +301,19 @@ impl GameDataHolder {
             logs.push(skill_sound_src_handel.join().unwrap());
             logs.push(ms_condition_handle.join().unwrap());
 
+            for v in translation_handles {
+                logs.push(v.join().unwrap());
+            }
+
             logs
         })
     }
-    pub fn load_skills(&mut self) -> Result<Vec<Log>, ()> {
+    pub fn load_skills(&mut self) -> Result<Vec<Log>, Log> {
         let mut warnings = vec![];
 
-        let skill_grp = deserialize_dat::<SkillGrpDat>(
-            self.dat_paths
-                .get(&"skillgrp.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
+        let skill_grp = self.read_dat::<SkillGrpDat>("skillgrp.dat")?;
 
-        let skill_sound = deserialize_dat::<SkillSoundDat>(
-            self.dat_paths
-                .get(&"skillsoundgrp.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
+        let skill_sound = self.read_dat::<SkillSoundDat>("skillsoundgrp.dat")?;
 
         let mut sound_map = HashMap::new();
 
@@ -299,12 +321,7 @@ impl GameDataHolder {
             sound_map.insert(s.id, s);
         }
 
-        let skill_sound_source = deserialize_dat::<SkillSoundSourceDat>(
-            self.dat_paths
-                .get(&"skillsoundsource.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
+        let skill_sound_source = self.read_dat::<SkillSoundSourceDat>("skillsoundsource.dat")?;
 
         let mut sound_source_map = HashMap::new();
 
@@ -313,13 +330,12 @@ impl GameDataHolder {
         }
 
         let (skill_name_table, skill_name) =
-            deserialize_dat_with_string_dict::<SkillNameTableRecord, SkillNameDat>(
-                self.dat_paths
-                    .get(&"skillname-ru.dat".to_string())
-                    .unwrap()
-                    .path(),
+            self.read_dat_with_string_dict::<SkillNameTableRecord, SkillNameDat>(
+                &self.main_dat("skillname"),
             )?;
 
+        warnings.extend(self.read_skill_name_translations(&skill_name));
+
         let mut string_dict = HashMap::new();
 
         for SkillNameTableRecord { val, id } in skill_name_table {
@@ -352,12 +368,7 @@ impl GameDataHolder {
             }
         }
 
-        let skill_condition_dat = deserialize_dat::<MSConditionDataDat>(
-            self.dat_paths
-                .get(&"msconditiondata.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
+        let skill_condition_dat = self.read_dat::<MSConditionDataDat>("msconditiondata.dat")?;
 
         let mut treed_conditions: HashMap<u32, HashMap<u8, HashMap<i16, MSConditionDataDat>>> =
             HashMap::new();
@@ -479,6 +490,7 @@ impl GameDataHolder {
                         "Skill[{}]: No record in mscondition for level {}. Skipped",
                         first_grp.id, first_grp.level
                     ),
+                    entity: None,
                 });
             };
 
@@ -490,6 +502,7 @@ impl GameDataHolder {
                         "Skill[{}]: No record in mscondition for sub-level {} of level {}. Skipped",
                         first_grp.id, first_grp.sub_level, first_grp.level,
                     ),
+                    entity: None,
                 });
             };
 
@@ -1123,6 +1136,130 @@ impl SkillGrpDat {
     }
 }
 
+/**Skill name translation keeps its own string dict, its rows point there
+ */
+struct SkillNameTranslation {
+    dict: L2SkillStringTable,
+    rows: TranslationRows<SkillNameDat>,
+}
+
+impl GameDataHolder {
+    fn read_skill_name_translations(&mut self, main: &[SkillNameDat]) -> Vec<Log> {
+        let main_keys: HashSet<_> = main.iter().map(|v| v.key()).collect();
+        let mut logs = vec![];
+
+        for (_, file_name) in self.translation_dats("skillname") {
+            let (dict, rows) = match self
+                .read_dat_with_string_dict::<SkillNameTableRecord, SkillNameDat>(&file_name)
+            {
+                Ok(v) => v,
+                Err(e) => {
+                    logs.push(Log::from_loader_w(&format!(
+                        "{file_name} can't be read, strings of this locale are not loaded\n{}",
+                        e.log
+                    )));
+
+                    continue;
+                }
+            };
+
+            let left = unread_bytes(&self.dat_envelopes.get(&file_name).unwrap().tail);
+
+            if left > 0 {
+                self.dat_envelopes.remove(&file_name);
+
+                logs.push(Log::from_loader_w(&format!(
+                    "{file_name} has {left} unread bytes after the records, strings of this \
+                     locale are not loaded"
+                )));
+
+                continue;
+            }
+
+            let translation = SkillNameTranslation {
+                dict: L2SkillStringTable::from_dict(dict),
+                rows: TranslationRows::new(rows, &main_keys),
+            };
+
+            self.translation_rows.insert(file_name, Box::new(translation));
+        }
+
+        logs
+    }
+
+    /**Rows the translation has keep its strings. Rows it doesn't have get strings of the main
+    locale, they are added to the end of its dict
+     */
+    fn save_skill_name_translations(
+        &self,
+        records: &[SkillNameDat],
+        table: &L2SkillStringTable,
+        output: &DatOutput,
+    ) -> Vec<JoinHandle<Log>> {
+        self.translation_dats("skillname")
+            .into_iter()
+            .filter_map(|(_, file_name)| {
+                let translation = self
+                    .translation_rows
+                    .get(&file_name)?
+                    .downcast_ref::<SkillNameTranslation>()?;
+
+                let mut dict = translation.dict.clone();
+
+                let records = records
+                    .iter()
+                    .map(|v| {
+                        let mut res = *v;
+
+                        if let Some(t) = translation.rows.text.get(&v.key()) {
+                            res.copy_text(t);
+                        } else {
+                            res.remap_text(table, &mut dict);
+                        }
+
+                        res
+                    })
+                    .collect();
+
+                let records = translation.rows.weave(records);
+
+                let path = self.dat_paths.get(&file_name).unwrap().clone();
+                let target = self.dat_target(&file_name, output);
+
+                Some(thread::spawn(move || {
+                    if let Err(e) = write_dat(
+                        path.path(),
+                        DatVariant::DoubleArray(SkillNameTableRecord::from_table(dict), records),
+                        &target,
+                    ) {
+                        Log::from(e)
+                    } else {
+                        Log::from_loader_i(&format!("{file_name} saved"))
+                    }
+                }))
+            })
+            .collect()
+    }
+}
+
+impl L2SkillStringTable {
+    /**Keeps ids of the dict, new strings go after the biggest one
+     */
+    fn from_dict(dict: Vec<SkillNameTableRecord>) -> Self {
+        let mut res = Self::default();
+
+        for SkillNameTableRecord { val, id } in dict {
+            let val = val.to_string();
+
+            res.reverse_map.entry(val.clone()).or_insert(id);
+            res.inner.insert(id, val);
+            res.next_index = res.next_index.max(id.saturating_add(1));
+        }
+
+        res
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal, Default)]
 pub struct SkillNameTableRecord {
     val: ASCF,
@@ -1164,7 +1301,41 @@ pub struct SkillNameDat {
     enchant_desc_params: DWORD,
 }
 
+impl LocalizedRecord for SkillNameDat {
+    type Key = (u32, i16, i16);
+
+    fn key(&self) -> (u32, i16, i16) {
+        (self.id, self.level, self.sub_level)
+    }
+
+    fn copy_text(&mut self, translation: &Self) {
+        self.name = translation.name;
+        self.desc = translation.desc;
+        self.desc_params = translation.desc_params;
+        self.enchant_name = translation.enchant_name;
+        self.enchant_name_params = translation.enchant_name_params;
+        self.enchant_desc = translation.enchant_desc;
+        self.enchant_desc_params = translation.enchant_desc_params;
+    }
+}
+
 impl SkillNameDat {
+    /**Points strings from `from` dict to the same strings in `to`, adding missing ones
+     */
+    fn remap_text(&mut self, from: &L2SkillStringTable, to: &mut L2SkillStringTable) {
+        for v in [
+            &mut self.name,
+            &mut self.desc,
+            &mut self.desc_params,
+            &mut self.enchant_name,
+            &mut self.enchant_name_params,
+            &mut self.enchant_desc,
+            &mut self.enchant_desc_params,
+        ] {
+            *v = to.get_index(&from[*v]);
+        }
+    }
+
     #[inline]
     fn fill_from_enchant_level(
         &mut self,
@@ -0,0 +1,168 @@
+use crate::backend::dat_loader::grand_crusade_110::Color;
+use crate::backend::dat_loader::translation::LocalizedRecord;
+use crate::backend::dat_loader::{decode_dat, write_dat, DatDecoder, DatOutput};
+use crate::backend::holder::{GameDataHolder, HolderMapOps};
+use crate::backend::log_holder::Log;
+use crate::data::StringTable;
+use crate::entity::game_string::{GameString, SystemMessageParams};
+use eframe::egui::Color32;
+use l2_rw::ue2_rw::{ReadError, ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+use l2_rw::ue2_rw::{ASCF, DWORD};
+use l2_rw::DatVariant;
+use r#macro::{ReadUnreal, WriteUnreal};
+use std::thread;
+use std::thread::JoinHandle;
+
+pub(super) const LOCALIZED_DATS: &[(&str, DatDecoder)] =
+    &[("systemmsg", decode_dat::<SystemMsgDat>)];
+
+impl GameDataHolder {
+    /**Layout of systemmsg differs between client builds, so the file is optional. If it can't
+    be read, system messages are left out and the file is never saved
+     */
+    pub(super) fn load_system_messages(&mut self) -> Result<Vec<Log>, Log> {
+        let file_name = self.main_dat("systemmsg");
+
+        if !self.dat_paths.contains_key(&file_name) {
+            return Ok(vec![Log::from_loader_w(&format!(
+                "{file_name} not found, system messages are not loaded"
+            ))]);
+        }
+
+        let records = match self.read_dat::<SystemMsgDat>(&file_name) {
+            Ok(v) => v,
+            Err(e) => {
+                return Ok(vec![Log::from_loader_w(&format!(
+                    "{file_name} can't be read, system messages are not loaded\n{}",
+                    e.log
+                ))]);
+            }
+        };
+
+        let logs = self.read_translations("systemmsg", &records);
+
+        for v in records {
+            let mut string =
+                GameString::from_table(StringTable::SystemMsg, v.id, v.message.to_string());
+
+            string.system_message = SystemMessageParams {
+                group: v.group,
+                color: Color32::from_rgba_unmultiplied(v.color.r, v.color.g, v.color.b, v.color.a),
+                sound: v.sound.to_string(),
+                reference: v.reference.to_string(),
+                window: v.window,
+                font: v.font,
+                lifetime: v.lifetime,
+                background: v.background,
+                animation: v.animation,
+                sub_message: v.sub_message.to_string(),
+                message_type: v.message_type.to_string(),
+                unk1: v.unk1,
+            };
+
+            self.game_string_holder.insert(string.id, string);
+        }
+
+        Ok(logs)
+    }
+
+    pub(super) fn serialize_system_messages(
+        &self,
+        output: &DatOutput,
+    ) -> Option<JoinHandle<Vec<Log>>> {
+        let file_name = self.main_dat("systemmsg");
+
+        if !self.dat_envelopes.contains_key(&file_name) {
+            return None;
+        }
+
+        let records: Vec<_> = self
+            .game_strings(StringTable::SystemMsg)
+            .into_iter()
+            .map(SystemMsgDat::from)
+            .collect();
+
+        let translations = self.translated("systemmsg", &records);
+        let translation_handles = self.save_translations("systemmsg", translations, output);
+
+        let path = self.dat_paths.get(&file_name).unwrap().clone();
+        let target = self.dat_target(&file_name, output);
+
+        Some(thread::spawn(move || {
+            let mut logs = if let Err(e) = write_dat(
+                path.path(),
+                DatVariant::<(), SystemMsgDat>::Array(records),
+                &target,
+            ) {
+                vec![Log::from(e)]
+            } else {
+                vec![Log::from_loader_i("System Messages saved")]
+            };
+
+            for v in translation_handles {
+                logs.push(v.join().unwrap());
+            }
+
+            logs
+        }))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal)]
+pub(super) struct SystemMsgDat {
+    id: DWORD,
+    unk1: DWORD,
+    message: ASCF,
+    group: DWORD,
+    color: Color,
+    sound: ASCF,
+    reference: ASCF,
+    window: DWORD,
+    font: DWORD,
+    lifetime: DWORD,
+    background: DWORD,
+    animation: DWORD,
+    sub_message: ASCF,
+    message_type: ASCF,
+}
+
+impl LocalizedRecord for SystemMsgDat {
+    type Key = u32;
+
+    fn key(&self) -> u32 {
+        self.id
+    }
+
+    fn copy_text(&mut self, translation: &Self) {
+        self.message = translation.message.clone();
+        self.sub_message = translation.sub_message.clone();
+    }
+}
+
+impl From<&GameString> for SystemMsgDat {
+    fn from(value: &GameString) -> Self {
+        let params = &value.system_message;
+
+        Self {
+            id: value.id.id,
+            unk1: params.unk1,
+            message: (&value.value).into(),
+            group: params.group,
+            color: Color {
+                r: params.color.r(),
+                g: params.color.g(),
+                b: params.color.b(),
+                a: params.color.a(),
+            },
+            sound: (&params.sound).into(),
+            reference: (&params.reference).into(),
+            window: params.window,
+            font: params.font,
+            lifetime: params.lifetime,
+            background: params.background,
+            animation: params.animation,
+            sub_message: (&params.sub_message).into(),
+            message_type: (&params.message_type).into(),
+        }
+    }
+}
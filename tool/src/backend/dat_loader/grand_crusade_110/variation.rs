@@ -0,0 +1,152 @@
+use crate::backend::dat_loader::translation::LocalizedRecord;
+use crate::backend::dat_loader::{decode_dat, write_dat, DatDecoder, DatOutput};
+use crate::backend::holder::{GameDataHolder, HolderMapOps};
+use crate::backend::log_holder::Log;
+use crate::data::{SkillId, VariationOptionId};
+use crate::entity::variation_option::VariationOption;
+use l2_rw::ue2_rw::{ReadError, ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+use l2_rw::ue2_rw::{ASCF, DWORD};
+use l2_rw::DatVariant;
+use r#macro::{ReadUnreal, WriteUnreal};
+use std::thread;
+use std::thread::JoinHandle;
+
+pub(super) const LOCALIZED_DATS: &[(&str, DatDecoder)] =
+    &[("optiondata_client", decode_dat::<OptionDataDat>)];
+
+impl GameDataHolder {
+    /**Clients without augmentation have no optiondata, options are just left empty then
+     */
+    pub(super) fn load_variation_options(&mut self) -> Result<Vec<Log>, Log> {
+        let mut warnings = vec![];
+
+        let file_name = self.main_dat("optiondata_client");
+
+        if let Some(records) =
+            self.read_optional_dat::<OptionDataDat>(&file_name, "variation options", &mut warnings)
+        {
+            warnings.extend(self.read_translations("optiondata_client", &records));
+
+            for v in records {
+                let id = VariationOptionId(v.id);
+
+                self.variation_option_holder.insert(
+                    id,
+                    VariationOption {
+                        id,
+                        option_type: v.option_type,
+                        skill_id: SkillId(v.skill_id),
+                        skill_level: v.skill_level,
+                        desc: v.desc.to_string(),
+                        ..Default::default()
+                    },
+                );
+            }
+        }
+
+        Ok(warnings)
+    }
+
+    pub(super) fn serialize_variation_options(
+        &self,
+        output: &DatOutput,
+    ) -> Option<JoinHandle<Vec<Log>>> {
+        let file_name = self.main_dat("optiondata_client");
+
+        if !self.dat_envelopes.contains_key(&file_name) {
+            return None;
+        }
+
+        let mut options: Vec<_> = self
+            .variation_option_holder
+            .values()
+            .filter(|v| !v._deleted)
+            .collect();
+        options.sort_by_key(|v| v.id);
+
+        let records: Vec<_> = options
+            .into_iter()
+            .map(|v| OptionDataDat {
+                id: v.id.0,
+                option_type: v.option_type,
+                skill_id: v.skill_id.0,
+                skill_level: v.skill_level,
+                desc: (&v.desc).into(),
+            })
+            .collect();
+
+        let translations = self.translated("optiondata_client", &records);
+        let translation_handles = self.save_translations("optiondata_client", translations, output);
+
+        let path = self.dat_paths.get(&file_name).unwrap().clone();
+        let target = self.dat_target(&file_name, output);
+
+        Some(thread::spawn(move || {
+            let mut logs = if let Err(e) = write_dat(
+                path.path(),
+                DatVariant::<(), OptionDataDat>::Array(records),
+                &target,
+            ) {
+                vec![Log::from(e)]
+            } else {
+                vec![Log::from_loader_i("Variation Options saved")]
+            };
+
+            for v in translation_handles {
+                logs.push(v.join().unwrap());
+            }
+
+            logs
+        }))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal)]
+struct OptionDataDat {
+    id: DWORD,
+    option_type: DWORD,
+    skill_id: DWORD,
+    skill_level: DWORD,
+    desc: ASCF,
+}
+
+impl LocalizedRecord for OptionDataDat {
+    type Key = u32;
+
+    fn key(&self) -> u32 {
+        self.id
+    }
+
+    fn copy_text(&mut self, translation: &Self) {
+        self.desc = translation.desc.clone();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::dat_loader::tests::check_optional_dat;
+
+    #[test]
+    fn reads_options_whole() {
+        check_optional_dat(
+            "optiondata_client-e.dat",
+            vec![
+                OptionDataDat {
+                    id: 16000,
+                    option_type: 0,
+                    skill_id: 3200,
+                    skill_level: 1,
+                    desc: "STR +1".to_string().into(),
+                },
+                OptionDataDat {
+                    id: 16001,
+                    option_type: 7,
+                    skill_id: 3201,
+                    skill_level: 2,
+                    desc: "".to_string().into(),
+                },
+            ],
+        );
+    }
+}
@@ -0,0 +1,383 @@
+mod npc;
+mod quest;
+
+use crate::backend::dat_loader::{decode_in_parallel, game_string, DatOutput};
+use crate::backend::holder::{GameDataHolder, HolderMapOps, HolderOps};
+use crate::backend::log_holder::{Log, LogLevel};
+use crate::frontend::IS_SAVING;
+use crate::log_multiple;
+use std::sync::atomic::Ordering;
+use std::thread;
+use std::thread::JoinHandle;
+
+impl GameDataHolder {
+    /**`dat_paths` and `locales` are already set by [`super::DatLoader::load_from_binary`].
+
+    Only npc names, strings and quests are mapped for now, other holders stay empty
+     */
+    pub(super) fn load_high_five(&mut self) -> Result<Vec<Log>, Log> {
+        let dats = self.localized_parallel_dats(
+            &[
+                game_string::LOCALIZED_DATS,
+                npc::LOCALIZED_DATS,
+                quest::LOCALIZED_DATS,
+            ]
+            .concat(),
+        );
+        self.decoded_dats = decode_in_parallel(&self.dat_paths, &dats);
+
+        let mut logs = vec![Log {
+            level: LogLevel::Warning,
+            producer: "Dat Loader".to_string(),
+            log: "High Five: only npc names, strings and quests are loaded, catalogs of other entities are disabled"
+                .to_string(),
+            entity: None,
+        }];
+
+        logs.extend(self.load_high_five_npcs()?);
+        logs.extend(self.load_string_dats()?);
+        logs.extend(self.load_high_five_quests()?);
+
+        let mut log = "Dats loaded".to_string();
+        log.push_str(&format!("\nChronicle: {}", self.chronicle));
+        log.push_str(&format!("\nLocales: {}", self.locales.join(", ")));
+        log.push_str(&format!("\nNpcs: {}", self.npc_holder.len()));
+        log.push_str(&format!("\nStrings: {}", self.game_string_holder.len()));
+        log.push_str(&format!("\nQuests: {}", self.quest_holder.len()));
+        log.push_str("\n======================================");
+
+        logs.push(Log::from_loader_i(&log));
+
+        Ok(logs)
+    }
+
+    pub(super) fn save_high_five(&mut self) -> std::io::Result<()> {
+        IS_SAVING.store(true, Ordering::Relaxed);
+
+        let handle = self.serialize_high_five(false, &DatOutput::Disk);
+
+        thread::spawn(move || {
+            let mut res = handle.join().unwrap();
+
+            res.push(Log::from_loader_i("Binaries Saved"));
+
+            log_multiple(res);
+
+            IS_SAVING.store(false, Ordering::Relaxed);
+        });
+
+        Ok(())
+    }
+
+    /**Serializes changed holders, or all of them if `all` is set
+     */
+    pub(super) fn serialize_high_five(
+        &mut self,
+        all: bool,
+        output: &DatOutput,
+    ) -> JoinHandle<Vec<Log>> {
+        let npcs_handle = if all || self.npc_holder.was_changed() {
+            Some(self.serialize_high_five_npcs(output))
+        } else {
+            None
+        };
+
+        let quests_handle = if all || self.quest_holder.was_changed() {
+            Some(self.serialize_high_five_quests(output))
+        } else {
+            None
+        };
+
+        let string_handles = if all || self.game_string_holder.was_changed() {
+            self.serialize_string_dats(output)
+        } else {
+            vec![]
+        };
+
+        thread::spawn(move || {
+            let mut res = vec![];
+
+            if let Some(v) = npcs_handle {
+                res.extend(v.join().unwrap());
+            }
+
+            if let Some(v) = quests_handle {
+                res.extend(v.join().unwrap());
+            }
+
+            for v in string_handles {
+                res.push(v.join().unwrap());
+            }
+
+            res
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::npc::NpcNameDat;
+    use super::quest::QuestNameDat;
+    use crate::backend::dat_loader::game_string::StringDat;
+    use crate::backend::dat_loader::grand_crusade_110::{Color, CoordsXYZ};
+    use crate::backend::dat_loader::{load_game_data_holder, Chronicle};
+    use crate::backend::holder::{HolderMapOps, HolderOps};
+    use crate::data::{GameStringId, NpcId, QuestId};
+    use crate::entity::quest::GoalType;
+    use l2_rw::ue2_rw::ASCF;
+    use l2_rw::{deserialize_dat, save_dat, DatVariant, EncVersion};
+    use std::path::Path;
+
+    /**Written by [`generate_fixtures`], run it after changing records below:
+    `cargo test -p l2w_dat_tool generate_fixtures -- --ignored`
+     */
+    const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/high_five");
+
+    /**Empty strings are stored with terminating zero, like the client does
+     */
+    fn text(v: &str) -> ASCF {
+        if v.is_empty() {
+            ASCF::empty()
+        } else {
+            v.to_string().into()
+        }
+    }
+
+    fn npc_names(ru: bool) -> Vec<NpcNameDat> {
+        let white = Color {
+            b: 255,
+            g: 255,
+            r: 255,
+            a: 255,
+        };
+        let npc = |id, name: [&str; 2], title: [&str; 2], title_color| NpcNameDat {
+            id,
+            name: text(name[ru as usize]),
+            title: text(title[ru as usize]),
+            title_color,
+        };
+
+        vec![
+            npc(20001, ["Gremlin", "Гремлин"], ["", ""], white.clone()),
+            npc(
+                30001,
+                ["Lector", "Лектор"],
+                ["Grocer", "Торговец"],
+                Color {
+                    b: 100,
+                    g: 200,
+                    r: 50,
+                    a: 255,
+                },
+            ),
+            npc(
+                30002,
+                ["Jackson", "Джексон"],
+                ["Blacksmith", "Кузнец"],
+                white,
+            ),
+        ]
+    }
+
+    fn quest_names(ru: bool) -> Vec<QuestNameDat> {
+        let t = |v: [&str; 2]| text(v[ru as usize]);
+        let xyz = |x, y, z| CoordsXYZ { x, y, z };
+        let step = |id, level| QuestNameDat {
+            tag: 1,
+            id,
+            level,
+            title: if id == 1 {
+                t(["Letters of Love", "Любовные письма"])
+            } else {
+                t(["Trade", "Торговля"])
+            },
+            sub_name: text(""),
+            desc: text(""),
+            entity_name: text(""),
+            lvl_min: 2,
+            lvl_max: 5,
+            quest_type: 1,
+            start_npc_id: 30001,
+            start_npc_loc: xyz(-84108., 244604., -3729.),
+            requirements: t(["Level 2 and above", "Уровень 2 и выше"]),
+            intro: t(["Darin is in love", "Дарин влюблен"]),
+            mark_type: 1,
+            ..Default::default()
+        };
+
+        vec![
+            QuestNameDat {
+                sub_name: t(["Talk to Darin", "Поговорите с Дарином"]),
+                desc: t(["Go", "Идите"]),
+                goal_ids: vec![57].into(),
+                goal_nums: vec![1].into(),
+                target_loc: xyz(-84436., 242793., -3729.),
+                entity_name: t(["Darin", "Дарин"]),
+                get_item_in_quest: 1,
+                unk_1: 1,
+                quest_items: vec![687, 688].into(),
+                ..step(1, 1)
+            },
+            QuestNameDat {
+                sub_name: t(["Hunt gremlins", "Охота"]),
+                desc: t(["Kill\nthem", "Убейте\nих"]),
+                goal_ids: vec![1_020_001].into(),
+                goal_nums: vec![10].into(),
+                target_loc: xyz(-80000., 240000., -3500.),
+                entity_name: t(["Gremlin", "Гремлин"]),
+                quest_items: vec![687, 688].into(),
+                ..step(1, 2)
+            },
+            QuestNameDat {
+                desc: t(["Done", "Готово"]),
+                target_loc: xyz(-84436., 242793., -3729.),
+                quest_items: vec![687, 688].into(),
+                ..step(1, 1001)
+            },
+            QuestNameDat {
+                sub_name: t(["Talk to Jackson", "Поговорите с Джексоном"]),
+                desc: t(["Trade", "Торгуйте"]),
+                target_loc: xyz(1., 2., 3.),
+                entity_name: t(["Jackson", "Джексон"]),
+                cleared_quest: 1,
+                ..step(2, 1)
+            },
+        ]
+    }
+
+    fn npc_strings() -> Vec<StringDat> {
+        vec![
+            StringDat {
+                id: 1_000_001,
+                value: text("Talk to me"),
+            },
+            StringDat {
+                id: 1_000_002,
+                value: text("Go away"),
+            },
+        ]
+    }
+
+    #[test]
+    #[ignore = "writes fixtures"]
+    fn generate_fixtures() {
+        let dir = Path::new(FIXTURES);
+        let version = Some(EncVersion::V413);
+
+        std::fs::create_dir_all(dir).unwrap();
+
+        for (name, ru) in [("npcname-e.dat", false), ("npcname-ru.dat", true)] {
+            let records = DatVariant::<(), _>::Array(npc_names(ru));
+            save_dat(&dir.join(name), records, version).unwrap();
+        }
+        for (name, ru) in [("questname-e.dat", false), ("questname-ru.dat", true)] {
+            let records = DatVariant::<(), _>::Array(quest_names(ru));
+            save_dat(&dir.join(name), records, version).unwrap();
+        }
+
+        let records = DatVariant::<(), _>::Array(npc_strings());
+        save_dat(&dir.join("npcstring-e.dat"), records, version).unwrap();
+    }
+
+    #[test]
+    fn fixtures_are_generated() {
+        let dat = |name: &str| Path::new(FIXTURES).join(name);
+
+        assert_eq!(
+            deserialize_dat::<NpcNameDat>(&dat("npcname-e.dat")).unwrap(),
+            npc_names(false)
+        );
+        assert_eq!(
+            deserialize_dat::<NpcNameDat>(&dat("npcname-ru.dat")).unwrap(),
+            npc_names(true)
+        );
+        assert_eq!(
+            deserialize_dat::<QuestNameDat>(&dat("questname-e.dat")).unwrap(),
+            quest_names(false)
+        );
+        assert_eq!(
+            deserialize_dat::<QuestNameDat>(&dat("questname-ru.dat")).unwrap(),
+            quest_names(true)
+        );
+        assert_eq!(
+            deserialize_dat::<StringDat>(&dat("npcstring-e.dat")).unwrap(),
+            npc_strings()
+        );
+    }
+
+    #[test]
+    fn loads_fixtures() {
+        let (holder, _) = load_game_data_holder(FIXTURES, &[], None).unwrap();
+
+        assert_eq!(holder.chronicle, Chronicle::HighFive);
+        assert_eq!(holder.locales, ["e", "ru"]);
+
+        let npc = holder.npc_holder.get(&NpcId(30001)).unwrap();
+        assert_eq!(npc.name, "Lector");
+        assert_eq!(npc.title, "Grocer");
+        assert_eq!(npc.translations[0].locale, "ru");
+        assert_eq!(npc.translations[0].name, "Лектор");
+
+        let string = holder
+            .game_string_holder
+            .get(&GameStringId::from(1_000_001));
+        assert_eq!(string.unwrap().value, "Talk to me");
+
+        let quest = holder.quest_holder.get(&QuestId(1)).unwrap();
+        assert_eq!(quest.title, "Letters of Love");
+        assert_eq!(quest.steps.len(), 3);
+        assert_eq!(quest.steps[1].inner.goals[0].goal_type, GoalType::KillNpc);
+        assert_eq!(quest.steps[1].inner.goals[0].target_id, 20001);
+        assert_eq!(quest.steps[2].inner.title, "FINISH");
+        assert_eq!(quest.last_finish_step_id, 1001);
+    }
+
+    #[test]
+    fn round_trips_fixtures() {
+        let (holder, _) = load_game_data_holder(FIXTURES, &[], None).unwrap();
+        let dat = |name: &str| Path::new(FIXTURES).join(name);
+
+        let (npc_name, npc_translations) = holder.high_five_npc_names();
+        assert_eq!(
+            npc_name,
+            deserialize_dat::<NpcNameDat>(&dat("npcname-e.dat")).unwrap()
+        );
+        assert_eq!(
+            npc_translations,
+            [deserialize_dat::<NpcNameDat>(&dat("npcname-ru.dat")).unwrap()]
+        );
+
+        let (quest_name, quest_translations) = holder.high_five_quest_names();
+        assert_eq!(
+            quest_name,
+            deserialize_dat::<QuestNameDat>(&dat("questname-e.dat")).unwrap()
+        );
+        assert_eq!(
+            quest_translations,
+            [deserialize_dat::<QuestNameDat>(&dat("questname-ru.dat")).unwrap()]
+        );
+    }
+
+    #[test]
+    fn verifies_fixtures() {
+        let (mut holder, _) = load_game_data_holder(FIXTURES, &[], None).unwrap();
+
+        let checks = holder.verify_dats().join().unwrap();
+
+        assert_eq!(
+            checks
+                .iter()
+                .map(|v| v.file_name.as_str())
+                .collect::<Vec<_>>(),
+            [
+                "npcname-e.dat",
+                "npcname-ru.dat",
+                "npcstring-e.dat",
+                "questname-e.dat",
+                "questname-ru.dat"
+            ]
+        );
+        assert!(checks.iter().all(|v| v.is_same()), "{checks:#?}");
+        assert!(!holder.npc_holder.was_changed());
+    }
+}
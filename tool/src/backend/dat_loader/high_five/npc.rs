@@ -0,0 +1,182 @@
+use crate::backend::dat_loader::grand_crusade_110::Color;
+use crate::backend::dat_loader::translation::LocalizedRecord;
+use crate::backend::dat_loader::{decode_dat, write_dat, DatDecoder, DatOutput, GetId};
+use crate::backend::holder::{GameDataHolder, HolderMapOps};
+use crate::backend::log_holder::Log;
+use crate::data::NpcId;
+use crate::entity::npc::{Npc, NpcTranslation};
+use eframe::egui::Color32;
+
+use l2_rw::ue2_rw::{ASCF, DWORD};
+use l2_rw::DatVariant;
+
+use l2_rw::ue2_rw::{ReadError, ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+
+use r#macro::{ReadUnreal, WriteUnreal};
+use std::thread;
+use std::thread::JoinHandle;
+
+pub(super) const LOCALIZED_DATS: &[(&str, DatDecoder)] = &[("npcname", decode_dat::<NpcNameDat>)];
+
+impl GameDataHolder {
+    /**Main locale records and records of each translation, sorted by id
+     */
+    pub(super) fn high_five_npc_names(&self) -> (Vec<NpcNameDat>, Vec<Vec<NpcNameDat>>) {
+        let translation_locales: Vec<_> = self
+            .translation_dats("npcname")
+            .into_iter()
+            .map(|v| v.0)
+            .collect();
+
+        let mut translated = vec![vec![]; translation_locales.len()];
+        let mut res = vec![];
+
+        let mut vals: Vec<_> = self.npc_holder.values().filter(|v| !v._deleted).collect();
+        vals.sort_by_key(|v| v.id);
+
+        for npc in vals {
+            let name = NpcNameDat::from(npc);
+
+            for (i, locale) in translation_locales.iter().enumerate() {
+                translated[i]
+                    .push(name.translated(npc.translations.iter().find(|v| &v.locale == locale)));
+            }
+
+            res.push(name);
+        }
+
+        (res, translated)
+    }
+
+    pub(super) fn serialize_high_five_npcs(&self, output: &DatOutput) -> JoinHandle<Vec<Log>> {
+        let (npc_name, translated) = self.high_five_npc_names();
+
+        let npc_name_path = self
+            .dat_paths
+            .get(&self.main_dat("npcname"))
+            .unwrap()
+            .clone();
+        let npc_name_target = self.dat_target(&self.main_dat("npcname"), output);
+
+        let translation_handles = self.save_translations("npcname", translated, output);
+
+        thread::spawn(move || {
+            let mut logs = if let Err(e) = write_dat(
+                npc_name_path.path(),
+                DatVariant::<(), NpcNameDat>::Array(npc_name),
+                &npc_name_target,
+            ) {
+                vec![Log::from(e)]
+            } else {
+                vec![Log::from_loader_i("Npc Name saved")]
+            };
+
+            for v in translation_handles {
+                logs.push(v.join().unwrap());
+            }
+
+            logs
+        })
+    }
+
+    /**High Five npcgrp isn't mapped yet, so npcs have only names and title colors
+     */
+    pub(super) fn load_high_five_npcs(&mut self) -> Result<Vec<Log>, Log> {
+        let npc_name = self.read_dat::<NpcNameDat>(&self.main_dat("npcname"))?;
+
+        let warnings = self.read_translations("npcname", &npc_name);
+
+        let translations = self.translation_texts::<NpcNameDat>("npcname");
+
+        for v in npc_name {
+            let npc = Npc {
+                id: NpcId(v.id),
+                name: v.name.to_string(),
+                title: v.title.to_string(),
+                title_color: Color32::from_rgba_unmultiplied(
+                    v.title_color.r,
+                    v.title_color.g,
+                    v.title_color.b,
+                    v.title_color.a,
+                ),
+                translations: translations
+                    .iter()
+                    .filter_map(|(locale, records)| {
+                        records.get(&v.id).map(|t| NpcTranslation {
+                            locale: locale.clone(),
+                            name: t.name.to_string(),
+                            title: t.title.to_string(),
+                        })
+                    })
+                    .collect(),
+                ..Default::default()
+            };
+
+            self.npc_holder.insert(npc.id, npc);
+        }
+
+        Ok(warnings)
+    }
+}
+
+/**Same layout as in later chronicles
+ */
+#[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal)]
+pub(super) struct NpcNameDat {
+    pub(super) id: DWORD,
+    pub(super) name: ASCF,
+    pub(super) title: ASCF,
+    pub(super) title_color: Color,
+}
+
+impl From<&Npc> for NpcNameDat {
+    fn from(npc: &Npc) -> Self {
+        Self {
+            id: npc.id.0,
+            name: (&npc.name).into(),
+            title: (&npc.title).into(),
+            title_color: Color {
+                r: npc.title_color.r(),
+                g: npc.title_color.g(),
+                b: npc.title_color.b(),
+                a: npc.title_color.a(),
+            },
+        }
+    }
+}
+
+impl NpcNameDat {
+    /**Same record with strings of the translation, npcs which have no translation yet keep
+    strings of the main locale
+     */
+    fn translated(&self, translation: Option<&NpcTranslation>) -> Self {
+        let mut res = self.clone();
+
+        if let Some(v) = translation {
+            res.name = (&v.name).into();
+            res.title = (&v.title).into();
+        }
+
+        res
+    }
+}
+
+impl GetId for NpcNameDat {
+    #[inline(always)]
+    fn get_id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl LocalizedRecord for NpcNameDat {
+    type Key = u32;
+
+    fn key(&self) -> u32 {
+        self.id
+    }
+
+    fn copy_text(&mut self, translation: &Self) {
+        self.name = translation.name.clone();
+        self.title = translation.title.clone();
+    }
+}
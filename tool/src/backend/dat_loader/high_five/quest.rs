@@ -0,0 +1,375 @@
+use crate::backend::dat_loader::grand_crusade_110::CoordsXYZ;
+use crate::backend::dat_loader::translation::LocalizedRecord;
+use crate::backend::dat_loader::{decode_dat, write_dat, DatDecoder, DatOutput};
+use crate::backend::entity_editor::WindowParams;
+use crate::backend::entity_impl::quest::StepAction;
+use crate::backend::holder::{GameDataHolder, HolderMapOps};
+use crate::backend::log_holder::Log;
+use crate::data::{HuntingZoneId, ItemId, NpcId, PlayerClass, QuestId};
+use crate::entity::quest::{
+    GoalType, MarkType, Quest, QuestStep, QuestStepTranslation, QuestTranslation, QuestType,
+    StepGoal, Unk1, Unk2,
+};
+
+use l2_rw::ue2_rw::{ASCF, DWORD, UVEC};
+use l2_rw::DatVariant;
+
+use l2_rw::ue2_rw::{ReadError, ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+
+use num_traits::{FromPrimitive, ToPrimitive};
+use r#macro::{ReadUnreal, WriteUnreal};
+use std::sync::RwLock;
+use std::thread;
+use std::thread::JoinHandle;
+
+pub(super) const LOCALIZED_DATS: &[(&str, DatDecoder)] =
+    &[("questname", decode_dat::<QuestNameDat>)];
+
+impl GameDataHolder {
+    /**Main locale records and records of each translation, quests are sorted by id
+     */
+    pub(super) fn high_five_quest_names(&self) -> (Vec<QuestNameDat>, Vec<Vec<QuestNameDat>>) {
+        let translation_locales: Vec<_> = self
+            .translation_dats("questname")
+            .into_iter()
+            .map(|v| v.0)
+            .collect();
+
+        let mut translated = vec![vec![]; translation_locales.len()];
+        let mut res = vec![];
+
+        let mut vals: Vec<_> = self.quest_holder.values().filter(|v| !v._deleted).collect();
+        vals.sort_by_key(|v| v.id);
+
+        for quest in vals {
+            let steps = QuestNameDat::from_quest(quest);
+
+            for (i, locale) in translation_locales.iter().enumerate() {
+                translated[i].extend(QuestNameDat::translated(&steps, quest, locale));
+            }
+
+            res.extend(steps);
+        }
+
+        (res, translated)
+    }
+
+    pub(super) fn serialize_high_five_quests(&self, output: &DatOutput) -> JoinHandle<Vec<Log>> {
+        let (quest_name, translated) = self.high_five_quest_names();
+
+        let quest_path = self
+            .dat_paths
+            .get(&self.main_dat("questname"))
+            .unwrap()
+            .clone();
+        let quest_target = self.dat_target(&self.main_dat("questname"), output);
+
+        let translation_handles = self.save_translations("questname", translated, output);
+
+        thread::spawn(move || {
+            let mut logs = if let Err(e) = write_dat(
+                quest_path.path(),
+                DatVariant::<(), QuestNameDat>::Array(quest_name),
+                &quest_target,
+            ) {
+                vec![Log::from(e)]
+            } else {
+                vec![Log::from_loader_i("Quest Name saved")]
+            };
+
+            for v in translation_handles {
+                logs.push(v.join().unwrap());
+            }
+
+            logs
+        })
+    }
+
+    pub(super) fn load_high_five_quests(&mut self) -> Result<Vec<Log>, Log> {
+        let vals = self.read_dat::<QuestNameDat>(&self.main_dat("questname"))?;
+
+        let warnings = self.read_translations("questname", &vals);
+
+        for steps in vals.chunk_by(|a, b| a.id == b.id) {
+            self.construct_high_five_quest(steps);
+        }
+
+        for (locale, rows) in self.translation_texts::<QuestNameDat>("questname") {
+            let mut rows: Vec<_> = rows.into_values().collect();
+            rows.sort_by_key(|v| v.key());
+
+            for v in rows {
+                let Some(quest) = self.quest_holder.get_mut(&QuestId(v.id)) else {
+                    continue;
+                };
+
+                if !quest.translations.iter().any(|t| t.locale == locale) {
+                    quest.translations.push(QuestTranslation {
+                        locale: locale.clone(),
+                        title: v.title.to_string(),
+                        intro: v.intro.to_string(),
+                        requirements: v.requirements.to_string(),
+                    });
+                }
+
+                let Some(step) = quest.steps.iter_mut().find(|s| s.inner.level == v.level) else {
+                    continue;
+                };
+
+                if step.inner.translations.iter().any(|t| t.locale == locale) {
+                    continue;
+                }
+
+                step.inner.translations.push(QuestStepTranslation {
+                    locale: locale.clone(),
+                    title: if QuestStep::is_finish_level(v.level) {
+                        "FINISH".to_string()
+                    } else {
+                        v.sub_name.to_string()
+                    },
+                    label: v.entity_name.to_string(),
+                    desc: v.desc.to_string(),
+                });
+            }
+        }
+
+        Ok(warnings)
+    }
+
+    fn construct_high_five_quest(&mut self, rows: &[QuestNameDat]) {
+        let mut last_finish_id = u32::MAX;
+
+        let steps = rows
+            .iter()
+            .map(|v| {
+                if QuestStep::is_finish_level(v.level) {
+                    last_finish_id = v.level.min(last_finish_id);
+                }
+
+                WindowParams {
+                    inner: QuestStep {
+                        title: if QuestStep::is_finish_level(v.level) {
+                            "FINISH".to_string()
+                        } else {
+                            v.sub_name.to_string()
+                        },
+                        label: v.entity_name.to_string(),
+                        desc: v.desc.to_string(),
+                        goals: v
+                            .goal_ids
+                            .inner
+                            .iter()
+                            .zip(&v.goal_nums.inner)
+                            .map(|(id, count)| {
+                                let (target_id, goal_type) = GoalType::from_pair(*id, 0);
+
+                                StepGoal {
+                                    target_id,
+                                    goal_type,
+                                    count: *count,
+                                }
+                            })
+                            .collect(),
+                        location: v.target_loc.into(),
+                        _get_item_in_step: v.get_item_in_quest == 1,
+                        unk_1: Unk1::from_u32(v.unk_1).unwrap_or_default(),
+                        unk_2: Unk2::from_u32(v.unk_2).unwrap_or_default(),
+                        level: v.level,
+                        ..Default::default()
+                    },
+
+                    initial_id: (),
+                    opened: false,
+                    action: RwLock::new(StepAction::None),
+                    params: (),
+                }
+            })
+            .collect();
+
+        let first = &rows[0];
+
+        let quest = Quest {
+            id: QuestId(first.id),
+            title: first.title.to_string(),
+            intro: first.intro.to_string(),
+            requirements: first.requirements.to_string(),
+            steps,
+            last_finish_step_id: last_finish_id,
+            quest_type: QuestType::from_u32(first.quest_type).unwrap_or_default(),
+            mark_type: MarkType::from_u32(first.mark_type).unwrap_or_default(),
+            min_lvl: first.lvl_min,
+            max_lvl: first.lvl_max,
+            allowed_classes: if first.class_limit.inner.is_empty() {
+                None
+            } else {
+                Some(
+                    first
+                        .class_limit
+                        .inner
+                        .iter()
+                        .filter_map(|v| PlayerClass::from_u32(*v))
+                        .collect(),
+                )
+            },
+            required_completed_quest_id: QuestId(first.cleared_quest),
+            search_zone_id: HuntingZoneId(first.search_zone_id),
+            _is_clan_pet_quest: first.clan_pet_quest == 1,
+            start_npc_loc: first.start_npc_loc.into(),
+            start_npc_ids: if first.start_npc_id == 0 {
+                vec![]
+            } else {
+                vec![NpcId(first.start_npc_id)]
+            },
+            quest_items: first.quest_items.inner.iter().map(|v| ItemId(*v)).collect(),
+            ..Default::default()
+        };
+
+        self.quest_holder.insert(quest.id, quest);
+    }
+}
+
+/**Older layout of questname. Counters are DWORDs, goals have no types, quests have a single
+start npc and no rewards, categories, additional locations or factions
+ */
+#[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal, Default)]
+pub(super) struct QuestNameDat {
+    pub(super) tag: DWORD,
+    pub(super) id: DWORD,
+    pub(super) level: DWORD,
+    pub(super) title: ASCF,
+    pub(super) sub_name: ASCF,
+    pub(super) desc: ASCF,
+    pub(super) goal_ids: UVEC<DWORD, DWORD>,
+    pub(super) goal_nums: UVEC<DWORD, DWORD>,
+    pub(super) target_loc: CoordsXYZ,
+    pub(super) lvl_min: DWORD,
+    pub(super) lvl_max: DWORD,
+    pub(super) quest_type: DWORD,
+    pub(super) entity_name: ASCF,
+    pub(super) get_item_in_quest: DWORD,
+    pub(super) unk_1: DWORD,
+    pub(super) unk_2: DWORD,
+    pub(super) start_npc_id: DWORD,
+    pub(super) start_npc_loc: CoordsXYZ,
+    pub(super) requirements: ASCF,
+    pub(super) intro: ASCF,
+    pub(super) class_limit: UVEC<DWORD, DWORD>,
+    pub(super) quest_items: UVEC<DWORD, DWORD>,
+    pub(super) clan_pet_quest: DWORD,
+    pub(super) cleared_quest: DWORD,
+    pub(super) mark_type: DWORD,
+    pub(super) search_zone_id: DWORD,
+}
+
+impl LocalizedRecord for QuestNameDat {
+    type Key = (u32, u32);
+
+    fn key(&self) -> (u32, u32) {
+        (self.id, self.level)
+    }
+
+    fn copy_text(&mut self, translation: &Self) {
+        self.title = translation.title.clone();
+        self.sub_name = translation.sub_name.clone();
+        self.desc = translation.desc.clone();
+        self.entity_name = translation.entity_name.clone();
+        self.requirements = translation.requirements.clone();
+        self.intro = translation.intro.clone();
+    }
+}
+
+impl QuestNameDat {
+    /**Rows of [`Self::from_quest`] with strings of the translation, strings which have no
+    translation yet are kept from the main locale
+     */
+    fn translated(steps: &[Self], quest: &Quest, locale: &str) -> Vec<Self> {
+        let translation = quest.translations.iter().find(|v| v.locale == locale);
+
+        steps
+            .iter()
+            .zip(&quest.steps)
+            .map(|(row, step)| {
+                let mut res = row.clone();
+
+                if let Some(v) = translation {
+                    res.title = (&v.title).into();
+                    res.intro = (&v.intro).into();
+                    res.requirements = (&v.requirements).into();
+                }
+
+                if let Some(v) = step.inner.translations.iter().find(|v| v.locale == locale) {
+                    if !QuestStep::is_finish_level(step.inner.level) {
+                        res.sub_name = (&v.title).into();
+                    }
+
+                    res.entity_name = (&v.label).into();
+                    res.desc = (&v.desc).into();
+                }
+
+                res
+            })
+            .collect()
+    }
+
+    /**Goals of [`GoalType::Other`] are written as items, High Five has no goal types
+     */
+    fn from_quest(quest: &Quest) -> Vec<Self> {
+        quest
+            .steps
+            .iter()
+            .map(|v| &v.inner)
+            .map(|step| Self {
+                tag: 1,
+                id: quest.id.0,
+                level: step.level,
+                title: (&quest.title).into(),
+                sub_name: if !QuestStep::is_finish_level(step.level) {
+                    (&step.title).into()
+                } else {
+                    ASCF::empty()
+                },
+                desc: (&step.desc).into(),
+                goal_ids: step
+                    .goals
+                    .iter()
+                    .map(|v| v.goal_type.as_pair(v.target_id).0)
+                    .collect::<Vec<_>>()
+                    .into(),
+                goal_nums: step
+                    .goals
+                    .iter()
+                    .map(|v| v.count)
+                    .collect::<Vec<_>>()
+                    .into(),
+                target_loc: step.location.into(),
+                lvl_min: quest.min_lvl,
+                lvl_max: quest.max_lvl,
+                quest_type: quest.quest_type.to_u32().unwrap(),
+                entity_name: (&step.label).into(),
+                get_item_in_quest: step._get_item_in_step.into(),
+                unk_1: step.unk_1.to_u32().unwrap(),
+                unk_2: step.unk_2.to_u32().unwrap(),
+                start_npc_id: quest.start_npc_ids.first().map_or(0, |v| v.0),
+                start_npc_loc: quest.start_npc_loc.into(),
+                requirements: (&quest.requirements).into(),
+                intro: (&quest.intro).into(),
+                class_limit: if let Some(c) = &quest.allowed_classes {
+                    c.iter().map(|v| v.to_u32().unwrap()).collect::<Vec<_>>()
+                } else {
+                    vec![]
+                }
+                .into(),
+                quest_items: quest
+                    .quest_items
+                    .iter()
+                    .map(|v| v.0)
+                    .collect::<Vec<_>>()
+                    .into(),
+                clan_pet_quest: quest._is_clan_pet_quest.into(),
+                cleared_quest: quest.required_completed_quest_id.0,
+                mark_type: quest.mark_type.to_u32().unwrap(),
+                search_zone_id: quest.search_zone_id.0,
+            })
+            .collect()
+    }
+}
@@ -1,12 +1,32 @@
 use crate::backend::holder::GameDataHolder;
 use crate::backend::log_holder::{Log, LogLevel};
+use crate::entity::Entity;
+use crate::log_multiple;
+use l2_rw::ue2_rw::{ReadUnreal, WriteUnreal};
+use l2_rw::{
+    compare_payload, compare_payload_with_string_dict, deserialize_dat_with_envelope,
+    deserialize_dat_with_string_dict_and_envelope, read_encoded_file, save_dat_with_envelope,
+    serialize_payload, DatEnvelope, DatVariant, EncVersion, PayloadMismatch, SAFE_PACKAGE_TAIL,
+};
+use serde::{Deserialize, Serialize};
+use std::any::Any;
 use std::collections::hash_map::Keys;
 use std::collections::HashMap;
-use std::fmt::Debug;
+use std::fmt::{Debug, Formatter};
 use std::hash::Hash;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::thread::JoinHandle;
+use strum_macros::{Display, EnumIter};
 use walkdir::{DirEntry, WalkDir};
 
+mod game_string;
 mod grand_crusade_110;
+mod high_five;
+mod translation;
+
+pub use translation::AnyTranslationRows;
 
 impl Log {
     fn from_loader_i(val: &str) -> Self {
@@ -14,6 +34,16 @@ impl Log {
             level: LogLevel::Info,
             producer: "Dat Loader".to_string(),
             log: val.to_string(),
+            entity: None,
+        }
+    }
+
+    fn from_loader_w(val: &str) -> Self {
+        Log {
+            level: LogLevel::Warning,
+            producer: "Dat Loader".to_string(),
+            log: val.to_string(),
+            entity: None,
         }
     }
 
@@ -22,16 +52,166 @@ impl Log {
             level: LogLevel::Error,
             producer: "Dat Loader".to_string(),
             log: format!("{val:#?}"),
+            entity: None,
+        }
+    }
+}
+
+impl From<l2_rw::Error> for Log {
+    fn from(value: l2_rw::Error) -> Self {
+        Log {
+            level: LogLevel::Error,
+            producer: "Dat Loader".to_string(),
+            log: value.to_string(),
+            entity: None,
+        }
+    }
+}
+
+impl From<&DatCheck> for Log {
+    fn from(value: &DatCheck) -> Self {
+        Log {
+            level: if value.is_same() {
+                LogLevel::Info
+            } else {
+                LogLevel::Error
+            },
+            producer: "Dat Loader".to_string(),
+            log: value.to_string(),
+            entity: None,
         }
     }
 }
 
 pub trait DatLoader {
-    fn load_from_binary(&mut self, dat_paths: HashMap<String, DirEntry>) -> Result<Vec<Log>, ()>;
+    fn load_from_binary(
+        &mut self,
+        dat_paths: HashMap<String, DirEntry>,
+        locales: Vec<String>,
+    ) -> Result<Vec<Log>, Log>;
     fn save_to_binary(&mut self) -> std::io::Result<()>;
 }
 
-pub fn load_game_data_holder(path: &str) -> Result<(GameDataHolder, Vec<Log>), ()> {
+/**Client version. Each one has its own module with dat structs, which are mapped into the same
+entities
+ */
+#[derive(Serialize, Deserialize, Debug, Default, EnumIter, Eq, PartialEq, Copy, Clone, Display)]
+pub enum Chronicle {
+    #[default]
+    #[strum(to_string = "Grand Crusade 110")]
+    GrandCrusade110,
+    /**Only npc names, npc/sys strings and quests are loaded and saved, other holders stay
+    empty and their dats are not touched
+     */
+    #[strum(to_string = "High Five")]
+    HighFive,
+}
+
+impl Chronicle {
+    /**Guesses chronicle by files in system folder, older clients have no `item_baseinfo.dat`
+     */
+    pub fn detect(dat_paths: &HashMap<String, DirEntry>) -> Option<Self> {
+        if dat_paths.contains_key("item_baseinfo.dat") {
+            Some(Self::GrandCrusade110)
+        } else if dat_paths
+            .keys()
+            .any(|v| v.starts_with("npcname-") || v.starts_with("questname-"))
+        {
+            Some(Self::HighFive)
+        } else {
+            None
+        }
+    }
+
+    /**Entities which dats this chronicle loads and saves. Holders of the others stay empty, so
+    their catalogs, editors and table import are blocked
+     */
+    pub fn supports(&self, entity: Entity) -> bool {
+        match self {
+            Chronicle::GrandCrusade110 => true,
+            Chronicle::HighFive => {
+                matches!(entity, Entity::Npc | Entity::Quest | Entity::GameString)
+            }
+        }
+    }
+}
+
+impl DatLoader for GameDataHolder {
+    fn load_from_binary(
+        &mut self,
+        dat_paths: HashMap<String, DirEntry>,
+        locales: Vec<String>,
+    ) -> Result<Vec<Log>, Log> {
+        self.dat_paths = dat_paths;
+        self.locales = locales;
+        self.dat_envelopes.clear();
+        self.translation_rows.clear();
+
+        let res = match self.chronicle {
+            Chronicle::GrandCrusade110 => self.load_grand_crusade_110(),
+            Chronicle::HighFive => self.load_high_five(),
+        };
+
+        self.decoded_dats.clear();
+        self.sync_game_data_names();
+        self.set_all_holders_unchanged();
+
+        res
+    }
+
+    fn save_to_binary(&mut self) -> std::io::Result<()> {
+        let res = match self.chronicle {
+            Chronicle::GrandCrusade110 => self.save_grand_crusade_110(),
+            Chronicle::HighFive => self.save_high_five(),
+        };
+
+        self.sync_game_data_names();
+
+        res
+    }
+}
+
+impl GameDataHolder {
+    /**Serializes every loaded dat through the same functions as saving, but into memory, and
+    compares result with decoded payload of the file. Without edits they should be the same,
+    anything else means loader and saver don't agree on the layout.
+
+    Nothing is written, game string table is restored, so new strings added while serializing
+    don't show up as changes. Files are compared in background, checks come from the handle
+     */
+    pub fn verify_dats(&mut self) -> JoinHandle<Vec<DatCheck>> {
+        let checks = Arc::new(Mutex::new(vec![]));
+        let game_string_table = self.game_string_table.clone();
+        let output = DatOutput::Verify(checks.clone());
+
+        let handle = match self.chronicle {
+            Chronicle::GrandCrusade110 => self.serialize_grand_crusade_110(true, &output),
+            Chronicle::HighFive => self.serialize_high_five(true, &output),
+        };
+
+        self.game_string_table = game_string_table;
+
+        thread::spawn(move || {
+            let _ = handle.join();
+
+            let mut res = std::mem::take(&mut *checks.lock().unwrap());
+            res.sort_by(|a, b| a.file_name.cmp(&b.file_name));
+
+            res
+        })
+    }
+}
+
+/**`locales` are suffixes of localized dats like `npcname-e.dat`, empty means all found in the
+folder. First one is loaded into entity fields, the rest are loaded as translations.
+
+`chronicle` is detected from files when `None`
+ */
+pub fn load_game_data_holder(
+    path: &str,
+    locales: &[String],
+    chronicle: Option<Chronicle>,
+) -> Result<(GameDataHolder, Vec<Log>), Log> {
     let mut dat_paths = HashMap::new();
 
     for path in WalkDir::new(path).into_iter().flatten() {
@@ -42,13 +222,430 @@ pub fn load_game_data_holder(path: &str) -> Result<(GameDataHolder, Vec<Log>), (
         }
     }
 
-    let mut holder = GameDataHolder::default();
+    let Some(chronicle) = chronicle.or_else(|| Chronicle::detect(&dat_paths)) else {
+        return Err(Log::from_loader_e(
+            "Can't detect chronicle, no known dats found in system folder",
+        ));
+    };
+
+    let locales = select_locales(&dat_paths, locales)?;
+
+    let mut holder = GameDataHolder {
+        chronicle,
+        ..Default::default()
+    };
 
-    let warnings = holder.load_from_binary(dat_paths).unwrap();
+    let warnings = holder.load_from_binary(dat_paths, locales)?;
 
     Ok((holder, warnings))
 }
 
+/**Localized file name, `npcname` and `e` give `npcname-e.dat`
+ */
+pub fn localized_dat(base_name: &str, locale: &str) -> String {
+    format!("{base_name}-{locale}.dat")
+}
+
+/**Locales which have `npcname-<locale>.dat` in the folder, sorted
+ */
+pub fn detect_locales(dat_paths: &HashMap<String, DirEntry>) -> Vec<String> {
+    let mut res: Vec<_> = dat_paths
+        .keys()
+        .filter_map(|v| v.strip_prefix("npcname-")?.strip_suffix(".dat"))
+        .map(String::from)
+        .collect();
+
+    res.sort();
+
+    res
+}
+
+/**Keeps order of `preferred`, locales missing in the folder are skipped
+ */
+fn select_locales(
+    dat_paths: &HashMap<String, DirEntry>,
+    preferred: &[String],
+) -> Result<Vec<String>, Log> {
+    let found = detect_locales(dat_paths);
+
+    let res: Vec<_> = if preferred.is_empty() {
+        found.clone()
+    } else {
+        preferred
+            .iter()
+            .map(|v| v.to_lowercase())
+            .filter(|v| found.contains(v))
+            .collect()
+    };
+
+    if res.is_empty() {
+        return Err(Log::from_loader_e(format!(
+            "No localized dats found for locales {preferred:?}, found: {found:?}"
+        )));
+    }
+
+    Ok(res)
+}
+
+/**Records of a dat decoded ahead of time by [`decode_in_parallel`]
+ */
+pub struct DecodedDat {
+    records: Box<dyn Any + Send + Sync>,
+    records_type: &'static str,
+    envelope: DatEnvelope,
+}
+
+impl DecodedDat {
+    fn new<V: Send + Sync + 'static>(records: V, envelope: DatEnvelope) -> Self {
+        Self {
+            records: Box::new(records),
+            records_type: std::any::type_name::<V>(),
+            envelope,
+        }
+    }
+
+    /**Returns records if they were decoded as `V`, otherwise the type they were decoded as
+     */
+    pub fn take<V: 'static>(self) -> Result<(V, DatEnvelope), &'static str> {
+        let envelope = self.envelope;
+        let records_type = self.records_type;
+
+        self.records
+            .downcast()
+            .map(|v| (*v, envelope))
+            .map_err(|_| records_type)
+    }
+}
+
+/**Where serialize functions put dats, passed down from the caller. With `Verify` nothing is
+written, each dat is compared with the loaded file and the result goes into the sink
+ */
+#[derive(Clone)]
+pub enum DatOutput {
+    Disk,
+    Verify(Arc<Mutex<Vec<DatCheck>>>),
+}
+
+/**Result of re-serializing a dat without edits, `Ok(None)` if it's the same as the file
+ */
+#[derive(Debug)]
+pub struct DatCheck {
+    pub file_name: String,
+    pub result: Result<Option<PayloadMismatch>, l2_rw::Error>,
+}
+
+impl DatCheck {
+    pub fn is_same(&self) -> bool {
+        matches!(self.result, Ok(None))
+    }
+}
+
+impl std::fmt::Display for DatCheck {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.result {
+            Ok(None) => write!(f, "{}: same as file", self.file_name),
+            Ok(Some(v)) => write!(f, "{}: {v}", self.file_name),
+            Err(e) => write!(f, "{}: {e}", self.file_name),
+        }
+    }
+}
+
+/**Envelope of a dat and holder output, see [`write_dat`]
+ */
+#[derive(Clone)]
+pub struct DatTarget {
+    envelope: DatEnvelope,
+    output: DatOutput,
+}
+
+/**Same as [`save_dat_with_envelope`] while holder writes to disk. While it's verified, data is
+compared with decoded payload of the file at `path` and the result goes to [`DatCheck`]s
+ */
+pub fn write_dat<S, T>(
+    path: &Path,
+    data: DatVariant<S, T>,
+    target: &DatTarget,
+) -> Result<usize, l2_rw::Error>
+where
+    S: ReadUnreal + WriteUnreal + Debug,
+    T: ReadUnreal + WriteUnreal + Debug,
+{
+    let DatOutput::Verify(checks) = &target.output else {
+        return save_dat_with_envelope(path, data, &target.envelope);
+    };
+
+    let with_dict = matches!(data, DatVariant::DoubleArray(..));
+
+    let result = serialize_payload(data, &target.envelope)
+        .map_err(|source| l2_rw::Error::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+        .and_then(|serialized| {
+            let original = read_encoded_file(path)?;
+
+            Ok(if with_dict {
+                compare_payload_with_string_dict::<S, T>(path, &original, &serialized)
+            } else {
+                compare_payload::<T>(path, &original, &serialized)
+            })
+        });
+
+    checks.lock().unwrap().push(DatCheck {
+        file_name: path
+            .file_name()
+            .map(|v| v.to_string_lossy().to_lowercase())
+            .unwrap_or_default(),
+        result,
+    });
+
+    Ok(0)
+}
+
+pub type DatDecoder = fn(&Path) -> Result<DecodedDat, l2_rw::Error>;
+
+pub fn decode_dat<T: ReadUnreal + Debug + Send + Sync + 'static>(
+    path: &Path,
+) -> Result<DecodedDat, l2_rw::Error> {
+    let (records, envelope) = deserialize_dat_with_envelope::<T>(path)?;
+
+    Ok(DecodedDat::new(records, envelope))
+}
+
+pub fn decode_dat_with_string_dict<
+    S: ReadUnreal + Debug + Send + Sync + 'static,
+    T: ReadUnreal + Debug + Send + Sync + 'static,
+>(
+    path: &Path,
+) -> Result<DecodedDat, l2_rw::Error> {
+    let (dict, records, envelope) = deserialize_dat_with_string_dict_and_envelope::<S, T>(path)?;
+
+    Ok(DecodedDat::new((dict, records), envelope))
+}
+
+/**Decodes `dats` on a pool of at most [`thread::available_parallelism`] threads, which take
+files from a shared queue, files missing in `dat_paths` are skipped.
+
+Entities are still built from decoded records one holder after another, so the result doesn't
+depend on which file was decoded first
+ */
+pub fn decode_in_parallel(
+    dat_paths: &HashMap<String, DirEntry>,
+    dats: &[(String, DatDecoder)],
+) -> HashMap<String, Result<DecodedDat, l2_rw::Error>> {
+    let queue =
+        Mutex::new(dats.iter().filter_map(|(file_name, decode)| {
+            Some((file_name, decode, dat_paths.get(file_name)?))
+        }));
+
+    let workers = thread::available_parallelism()
+        .map(|v| v.get())
+        .unwrap_or(1)
+        .min(dats.len());
+
+    thread::scope(|s| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                s.spawn(|| {
+                    let mut res = vec![];
+
+                    //lock is released before decoding, unlike with `while let`
+                    loop {
+                        let Some((file_name, decode, entry)) = queue.lock().unwrap().next() else {
+                            break;
+                        };
+
+                        res.push((file_name.to_string(), decode(entry.path())));
+                    }
+
+                    res
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    })
+}
+
+impl GameDataHolder {
+    /**Main locale and translation files of each localized dat, for [`decode_in_parallel`]
+     */
+    fn localized_parallel_dats(&self, dats: &[(&str, DatDecoder)]) -> Vec<(String, DatDecoder)> {
+        let mut res = vec![];
+
+        for (base_name, decode) in dats {
+            res.push((self.main_dat(base_name), *decode));
+
+            for (_, file_name) in self.translation_dats(base_name) {
+                res.push((file_name, *decode));
+            }
+        }
+
+        res
+    }
+
+    /**Localized dat of the main locale, its strings are kept in entity fields
+     */
+    fn main_dat(&self, base_name: &str) -> String {
+        localized_dat(base_name, &self.locales[0])
+    }
+
+    /**Locales and file names of translations which exist in the folder
+     */
+    fn translation_dats(&self, base_name: &str) -> Vec<(String, String)> {
+        self.locales[1..]
+            .iter()
+            .map(|v| (v.clone(), localized_dat(base_name, v)))
+            .filter(|(_, file_name)| self.dat_paths.contains_key(file_name))
+            .collect()
+    }
+
+    /**Reads dat from `dat_paths` and remembers its envelope, so it's saved back the same way
+     */
+    fn read_dat<T: ReadUnreal + Debug + 'static>(
+        &mut self,
+        file_name: &str,
+    ) -> Result<Vec<T>, Log> {
+        let (res, envelope) = match self.take_decoded(file_name)? {
+            Some(v) => v,
+            None => deserialize_dat_with_envelope(self.dat_paths.get(file_name).unwrap().path())?,
+        };
+
+        self.dat_envelopes.insert(file_name.to_string(), envelope);
+
+        Ok(res)
+    }
+
+    /**For dats not every client has. Missing or unreadable file only adds a warning, so does a
+    file with bytes left between the records and "SafePackage": `T` doesn't describe its layout,
+    and saving would keep those bytes in the tail. Envelope of such file is not kept, so it's
+    never saved
+     */
+    fn read_optional_dat<T: ReadUnreal + Debug + 'static>(
+        &mut self,
+        file_name: &str,
+        what: &str,
+        warnings: &mut Vec<Log>,
+    ) -> Option<Vec<T>> {
+        if !self.dat_paths.contains_key(file_name) {
+            warnings.push(Log::from_loader_w(&format!(
+                "{file_name} not found, {what} are not loaded"
+            )));
+
+            return None;
+        }
+
+        match self.read_dat::<T>(file_name) {
+            Ok(v) => {
+                let left = unread_bytes(&self.dat_envelopes.get(file_name).unwrap().tail);
+
+                if left == 0 {
+                    return Some(v);
+                }
+
+                self.dat_envelopes.remove(file_name);
+
+                warnings.push(Log::from_loader_w(&format!(
+                    "{file_name} has {left} unread bytes after the records, {what} are not loaded"
+                )));
+
+                None
+            }
+            Err(e) => {
+                warnings.push(Log::from_loader_w(&format!(
+                    "{file_name} can't be read, {what} are not loaded\n{}",
+                    e.log
+                )));
+
+                None
+            }
+        }
+    }
+
+    fn read_dat_with_string_dict<
+        S: ReadUnreal + Debug + 'static,
+        T: ReadUnreal + Debug + 'static,
+    >(
+        &mut self,
+        file_name: &str,
+    ) -> Result<(Vec<S>, Vec<T>), Log> {
+        let ((dict, res), envelope) = match self.take_decoded(file_name)? {
+            Some(v) => v,
+            None => {
+                let (dict, res, envelope) = deserialize_dat_with_string_dict_and_envelope(
+                    self.dat_paths.get(file_name).unwrap().path(),
+                )?;
+
+                ((dict, res), envelope)
+            }
+        };
+
+        self.dat_envelopes.insert(file_name.to_string(), envelope);
+
+        Ok((dict, res))
+    }
+
+    /**Envelope the dat was loaded with and where it goes. Files which weren't loaded, or were
+    encrypted with a version which private key is unknown (411, 412, 414), are saved as 413
+     */
+    fn dat_target(&self, file_name: &str, output: &DatOutput) -> DatTarget {
+        let envelope = match self.dat_envelopes.get(file_name) {
+            Some(v) => match v.version {
+                Some(version) if !version.can_encode() => {
+                    if matches!(output, DatOutput::Disk) {
+                        log_multiple(vec![Log::from_loader_w(&format!(
+                            "{file_name}: private key of {version} is unknown, saved as 413"
+                        ))]);
+                    }
+
+                    let mut envelope = DatEnvelope::new(Some(EncVersion::V413));
+                    envelope.tail = v.tail.clone();
+
+                    envelope
+                }
+                _ => v.clone(),
+            },
+            None => DatEnvelope::new(Some(EncVersion::V413)),
+        };
+
+        DatTarget {
+            envelope,
+            output: output.clone(),
+        }
+    }
+
+    /**Records decoded by [`decode_in_parallel`], `None` if file wasn't decoded ahead of time.
+    Decoder registered with another record type is an error, reading the file again would hide it
+     */
+    fn take_decoded<V: 'static>(
+        &mut self,
+        file_name: &str,
+    ) -> Result<Option<(V, DatEnvelope)>, Log> {
+        match self.decoded_dats.remove(file_name) {
+            Some(Ok(v)) => v.take().map(Some).map_err(|decoded| {
+                Log::from_loader_e(format!(
+                    "{file_name} was decoded as {decoded}, but is read as {}",
+                    std::any::type_name::<V>()
+                ))
+            }),
+            Some(Err(e)) => Err(e.into()),
+            None => Ok(None),
+        }
+    }
+}
+
+/**Bytes of envelope tail which are not the "SafePackage" marker, records left unread by a wrong
+layout end up there
+ */
+fn unread_bytes(tail: &[u8]) -> usize {
+    tail.strip_suffix(SAFE_PACKAGE_TAIL.as_slice())
+        .unwrap_or(tail)
+        .len()
+}
+
 pub trait GetId {
     fn get_id(&self) -> u32;
 }
@@ -166,3 +763,227 @@ impl StrUtils for str {
         deunicode::deunicode(self).replace('\'', "")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use l2_rw::ue2_rw::{ReadError, UnrealReader, UnrealWriter, ASCF, DWORD};
+    use r#macro::{ReadUnreal, WriteUnreal};
+    use strum::IntoEnumIterator;
+
+    /**Writes `records` as `file_name` into a temp folder, with `extra` bytes before
+    "SafePackage"
+     */
+    fn holder_with_dat<T: WriteUnreal + Debug>(
+        file_name: &str,
+        records: Vec<T>,
+        extra: &[u8],
+    ) -> GameDataHolder {
+        let dir = std::env::temp_dir().join(format!(
+            "l2w_optional_dat_{}_{}_{}",
+            std::process::id(),
+            extra.len(),
+            file_name
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let mut envelope = DatEnvelope::new(None);
+        envelope.tail = [extra, SAFE_PACKAGE_TAIL.as_slice()].concat();
+
+        save_dat_with_envelope(
+            &dir.join(file_name),
+            DatVariant::<(), T>::Array(records),
+            &envelope,
+        )
+        .unwrap();
+
+        let mut holder = GameDataHolder::default();
+
+        for v in WalkDir::new(&dir).into_iter().flatten() {
+            if v.file_type().is_file() {
+                holder
+                    .dat_paths
+                    .insert(v.file_name().to_str().unwrap().to_string(), v);
+            }
+        }
+
+        holder
+    }
+
+    /**Records of optional dat are read back whole, and the file is skipped if they don't take
+    all bytes before "SafePackage"
+     */
+    pub(super) fn check_optional_dat<
+        T: ReadUnreal + WriteUnreal + Debug + Clone + PartialEq + 'static,
+    >(
+        file_name: &str,
+        records: Vec<T>,
+    ) {
+        let mut warnings = vec![];
+
+        let mut holder = holder_with_dat(file_name, records.clone(), &[]);
+        let res = holder.read_optional_dat::<T>(file_name, "records", &mut warnings);
+
+        assert_eq!(res, Some(records.clone()));
+        assert!(warnings.is_empty());
+        assert_eq!(holder.dat_envelopes[file_name].tail, SAFE_PACKAGE_TAIL);
+
+        remove_dat_dir(&holder, file_name);
+
+        let mut holder = holder_with_dat(file_name, records, &[1, 0, 0, 0]);
+        let res = holder.read_optional_dat::<T>(file_name, "records", &mut warnings);
+
+        assert_eq!(res, None);
+        assert_eq!(warnings.len(), 1);
+        assert!(!holder.dat_envelopes.contains_key(file_name));
+
+        remove_dat_dir(&holder, file_name);
+    }
+
+    fn remove_dat_dir(holder: &GameDataHolder, file_name: &str) {
+        std::fs::remove_dir_all(holder.dat_paths[file_name].path().parent().unwrap()).unwrap();
+    }
+
+    #[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal)]
+    struct TestDat {
+        id: DWORD,
+        name: ASCF,
+    }
+
+    fn test_records() -> Vec<TestDat> {
+        (0..100)
+            .map(|id| TestDat {
+                id,
+                name: ASCF::from(format!("record {id}")),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parallel_decoding_reads_same_as_sequential() {
+        let file_name = "parallel.dat";
+        let mut holder = holder_with_dat(file_name, test_records(), &[]);
+
+        let sequential = holder.read_dat::<TestDat>(file_name).unwrap();
+        let sequential_envelope = holder.dat_envelopes.remove(file_name).unwrap();
+
+        holder.decoded_dats = decode_in_parallel(
+            &holder.dat_paths,
+            &[
+                (file_name.to_string(), decode_dat::<TestDat>),
+                ("missing.dat".to_string(), decode_dat::<TestDat>),
+            ],
+        );
+
+        assert_eq!(holder.decoded_dats.len(), 1);
+
+        let parallel = holder.read_dat::<TestDat>(file_name).unwrap();
+        let parallel_envelope = &holder.dat_envelopes[file_name];
+
+        assert_eq!(parallel, sequential);
+        assert_eq!(parallel, test_records());
+        assert_eq!(parallel_envelope.version, sequential_envelope.version);
+        assert_eq!(parallel_envelope.tail, sequential_envelope.tail);
+        assert_eq!(parallel_envelope.footer, sequential_envelope.footer);
+        assert!(holder.decoded_dats.is_empty());
+
+        remove_dat_dir(&holder, file_name);
+    }
+
+    #[test]
+    fn decodes_more_dats_than_workers() {
+        let file_name = "queued.dat";
+        let mut holder = holder_with_dat(file_name, test_records(), &[]);
+
+        let entry = holder.dat_paths[file_name].clone();
+        let workers = thread::available_parallelism().unwrap().get();
+        let mut dats = vec![];
+
+        for i in 0..workers * 3 {
+            let name = format!("queued_{i}.dat");
+
+            holder.dat_paths.insert(name.clone(), entry.clone());
+            dats.push((name, decode_dat::<TestDat> as DatDecoder));
+        }
+
+        holder.decoded_dats = decode_in_parallel(&holder.dat_paths, &dats);
+
+        assert_eq!(holder.decoded_dats.len(), dats.len());
+
+        for (name, _) in &dats {
+            assert_eq!(holder.read_dat::<TestDat>(name).unwrap(), test_records());
+        }
+
+        remove_dat_dir(&holder, file_name);
+    }
+
+    #[test]
+    fn decoded_as_other_type_is_an_error() {
+        let file_name = "other_type.dat";
+        let mut holder = holder_with_dat(file_name, test_records(), &[]);
+
+        holder.decoded_dats = decode_in_parallel(
+            &holder.dat_paths,
+            &[(file_name.to_string(), decode_dat::<TestDat>)],
+        );
+
+        let e = holder.read_dat::<u32>(file_name).unwrap_err();
+
+        assert_eq!(e.level, LogLevel::Error);
+        assert!(e.log.contains("TestDat"), "{}", e.log);
+        assert!(!holder.dat_envelopes.contains_key(file_name));
+
+        remove_dat_dir(&holder, file_name);
+    }
+
+    #[test]
+    fn unread_bytes_skip_safe_package() {
+        assert_eq!(unread_bytes(&[]), 0);
+        assert_eq!(unread_bytes(SAFE_PACKAGE_TAIL), 0);
+        assert_eq!(
+            unread_bytes(&[[1, 2].as_slice(), SAFE_PACKAGE_TAIL].concat()),
+            2
+        );
+        assert_eq!(unread_bytes(&[1, 2, 3]), 3);
+    }
+
+    #[test]
+    fn versions_without_private_key_are_saved_as_413() {
+        let mut holder = GameDataHolder::default();
+        let output = DatOutput::Verify(Default::default());
+
+        for (file_name, version) in [
+            ("npcgrp.dat", EncVersion::V414),
+            ("armorgrp.dat", EncVersion::V411),
+            ("zonename.dat", EncVersion::V121),
+        ] {
+            let mut envelope = DatEnvelope::new(Some(version));
+            envelope.tail = b"tail".to_vec();
+
+            holder.dat_envelopes.insert(file_name.to_string(), envelope);
+        }
+
+        let npcgrp = holder.dat_target("npcgrp.dat", &output).envelope;
+
+        assert_eq!(npcgrp.version, Some(EncVersion::V413));
+        assert_eq!(npcgrp.tail, b"tail");
+        assert_eq!(
+            holder.dat_target("armorgrp.dat", &output).envelope.version,
+            Some(EncVersion::V413)
+        );
+        assert_eq!(
+            holder.dat_target("zonename.dat", &output).envelope.version,
+            Some(EncVersion::V121)
+        );
+    }
+
+    #[test]
+    fn high_five_supports_only_loaded_entities() {
+        let supported: Vec<_> = Entity::iter()
+            .filter(|v| Chronicle::HighFive.supports(*v))
+            .collect();
+
+        assert_eq!(supported, [Entity::Npc, Entity::Quest, Entity::GameString]);
+        assert!(Entity::iter().all(|v| Chronicle::GrandCrusade110.supports(v)));
+    }
+}
@@ -0,0 +1,385 @@
+use crate::backend::dat_loader::{write_dat, DatOutput};
+use crate::backend::holder::GameDataHolder;
+use crate::backend::log_holder::Log;
+use l2_rw::ue2_rw::{ReadUnreal, WriteUnreal};
+use l2_rw::DatVariant;
+use std::any::Any;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::thread;
+use std::thread::JoinHandle;
+
+/**Record of a localized dat. Same record in each locale has the same key, only its text differs
+ */
+pub(super) trait LocalizedRecord: Clone + Send + Sync + 'static {
+    type Key: Copy + Eq + Hash + Send + Sync + 'static;
+
+    fn key(&self) -> Self::Key;
+
+    /**Puts text fields of `translation` into the record, everything else stays from the main
+    locale
+     */
+    fn copy_text(&mut self, translation: &Self);
+}
+
+/**Rows of a translation file as they were loaded
+ */
+pub(super) struct TranslationRows<T: LocalizedRecord> {
+    /**First row of each key the main locale has too
+     */
+    pub(super) text: HashMap<T::Key, T>,
+    /**Key of each row in the file, `None` for rows in `extra`
+     */
+    order: Vec<Option<T::Key>>,
+    /**Rows the main locale doesn't have and rows repeating a key, in file order
+     */
+    extra: Vec<T>,
+}
+
+impl<T: LocalizedRecord> TranslationRows<T> {
+    pub(super) fn new(rows: Vec<T>, main_keys: &HashSet<T::Key>) -> Self {
+        let mut text = HashMap::new();
+        let mut order = Vec::with_capacity(rows.len());
+        let mut extra = vec![];
+
+        for v in rows {
+            let key = v.key();
+
+            if main_keys.contains(&key) && !text.contains_key(&key) {
+                text.insert(key, v);
+                order.push(Some(key));
+            } else {
+                extra.push(v);
+                order.push(None);
+            }
+        }
+
+        Self { text, order, extra }
+    }
+
+    /**Main locale `records` with text of these rows, records this locale has no row for keep
+    the main text
+     */
+    pub(super) fn translate(&self, records: &[T]) -> Vec<T> {
+        records
+            .iter()
+            .map(|v| {
+                let mut res = v.clone();
+
+                if let Some(t) = self.text.get(&v.key()) {
+                    res.copy_text(t);
+                }
+
+                res
+            })
+            .collect()
+    }
+
+    /**Puts rows only this locale has back after the same row as in the file. If that row is
+    gone, after the closest one before it which is still there, or first if there is none
+     */
+    pub(super) fn weave(&self, records: Vec<T>) -> Vec<T> {
+        if self.extra.is_empty() {
+            return records;
+        }
+
+        let kept: HashSet<_> = records.iter().map(|v| v.key()).collect();
+
+        let mut after: HashMap<T::Key, Vec<T>> = HashMap::new();
+        let mut first = vec![];
+        let mut anchor = None;
+        let mut extra = self.extra.iter();
+
+        for key in &self.order {
+            match key {
+                Some(key) if kept.contains(key) => anchor = Some(*key),
+                Some(_) => {}
+                None => {
+                    let v = extra.next().unwrap().clone();
+
+                    match anchor {
+                        Some(key) => after.entry(key).or_default().push(v),
+                        None => first.push(v),
+                    }
+                }
+            }
+        }
+
+        let mut res = first;
+
+        for v in records {
+            let rows = after.remove(&v.key());
+
+            res.push(v);
+
+            if let Some(rows) = rows {
+                res.extend(rows);
+            }
+        }
+
+        res
+    }
+}
+
+impl GameDataHolder {
+    /**Reads every translation of `base_name` and keeps its rows for saving, keys of `main` are
+    records the main locale has. Translations which can't be read whole are skipped with a
+    warning and never saved
+     */
+    pub(super) fn read_translations<T: LocalizedRecord + ReadUnreal + Debug>(
+        &mut self,
+        base_name: &str,
+        main: &[T],
+    ) -> Vec<Log> {
+        let main_keys: HashSet<_> = main.iter().map(|v| v.key()).collect();
+        let mut logs = vec![];
+
+        for (_, file_name) in self.translation_dats(base_name) {
+            if let Some(rows) =
+                self.read_optional_dat::<T>(&file_name, "strings of this locale", &mut logs)
+            {
+                let rows = TranslationRows::new(rows, &main_keys);
+
+                self.translation_rows.insert(file_name, Box::new(rows));
+            }
+        }
+
+        logs
+    }
+
+    pub(super) fn translation_rows<T: LocalizedRecord>(
+        &self,
+        file_name: &str,
+    ) -> Option<&TranslationRows<T>> {
+        self.translation_rows
+            .get(file_name)
+            .and_then(|v| v.downcast_ref())
+    }
+
+    /**Locales and rows of each translation by key, for entities which keep their own
+    translations
+     */
+    pub(super) fn translation_texts<T: LocalizedRecord>(
+        &self,
+        base_name: &str,
+    ) -> Vec<(String, HashMap<T::Key, T>)> {
+        self.translation_dats(base_name)
+            .into_iter()
+            .filter_map(|(locale, file_name)| {
+                self.translation_rows::<T>(&file_name)
+                    .map(|rows| (locale, rows.text.clone()))
+            })
+            .collect()
+    }
+
+    /**Main locale `records` with text of each translation, in the order of
+    [`Self::translation_dats`]
+     */
+    pub(super) fn translated<T: LocalizedRecord>(
+        &self,
+        base_name: &str,
+        records: &[T],
+    ) -> Vec<Vec<T>> {
+        self.translation_dats(base_name)
+            .into_iter()
+            .map(
+                |(_, file_name)| match self.translation_rows::<T>(&file_name) {
+                    Some(rows) => rows.translate(records),
+                    None => records.to_vec(),
+                },
+            )
+            .collect()
+    }
+
+    /**Saves each translation which was read on its own thread, `records` go in the same order
+    as [`Self::translation_dats`] returns files. Rows only the translation has are put back
+     */
+    pub(super) fn save_translations<T: LocalizedRecord + ReadUnreal + WriteUnreal + Debug>(
+        &self,
+        base_name: &str,
+        records: Vec<Vec<T>>,
+        output: &DatOutput,
+    ) -> Vec<JoinHandle<Log>> {
+        self.translation_dats(base_name)
+            .into_iter()
+            .zip(records)
+            .filter_map(|((_, file_name), records)| {
+                let records = self.translation_rows::<T>(&file_name)?.weave(records);
+
+                let path = self.dat_paths.get(&file_name).unwrap().clone();
+                let target = self.dat_target(&file_name, output);
+
+                Some(thread::spawn(move || {
+                    if let Err(e) =
+                        write_dat(path.path(), DatVariant::<(), T>::Array(records), &target)
+                    {
+                        Log::from(e)
+                    } else {
+                        Log::from_loader_i(&format!("{file_name} saved"))
+                    }
+                }))
+            })
+            .collect()
+    }
+}
+
+/**Translation rows of one dat, kept as [`TranslationRows`] of its record type
+ */
+pub type AnyTranslationRows = Box<dyn Any + Send + Sync>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Row {
+        id: u32,
+        text: &'static str,
+        value: u32,
+    }
+
+    impl LocalizedRecord for Row {
+        type Key = u32;
+
+        fn key(&self) -> u32 {
+            self.id
+        }
+
+        fn copy_text(&mut self, translation: &Self) {
+            self.text = translation.text;
+        }
+    }
+
+    fn row(id: u32, text: &'static str, value: u32) -> Row {
+        Row { id, text, value }
+    }
+
+    fn rows(main: &[Row], translation: Vec<Row>) -> TranslationRows<Row> {
+        TranslationRows::new(translation, &main.iter().map(|v| v.id).collect())
+    }
+
+    #[test]
+    fn translates_text_only() {
+        let main = vec![row(1, "one", 10), row(2, "two", 20), row(3, "three", 30)];
+        let rows = rows(&main, vec![row(1, "uno", 11), row(3, "tres", 33)]);
+
+        assert_eq!(
+            rows.translate(&main),
+            vec![row(1, "uno", 10), row(2, "two", 20), row(3, "tres", 30)]
+        );
+    }
+
+    #[test]
+    fn keeps_translation_only_rows_in_place() {
+        let main = vec![row(1, "one", 10), row(3, "three", 30)];
+        let rows = rows(
+            &main,
+            vec![
+                row(0, "cero", 0),
+                row(1, "uno", 10),
+                row(2, "dos", 20),
+                row(4, "cuatro", 40),
+                row(3, "tres", 30),
+            ],
+        );
+
+        let res = rows.weave(rows.translate(&main));
+
+        assert_eq!(
+            res.iter().map(|v| v.text).collect::<Vec<_>>(),
+            ["cero", "uno", "dos", "cuatro", "tres"]
+        );
+    }
+
+    #[test]
+    fn keeps_repeated_rows() {
+        let main = vec![row(1, "one", 10), row(2, "two", 20)];
+        let rows = rows(
+            &main,
+            vec![row(1, "uno", 10), row(1, "otro", 10), row(2, "dos", 20)],
+        );
+
+        assert_eq!(rows.text[&1].text, "uno");
+        assert_eq!(
+            rows.weave(rows.translate(&main))
+                .iter()
+                .map(|v| v.text)
+                .collect::<Vec<_>>(),
+            ["uno", "otro", "dos"]
+        );
+    }
+
+    #[test]
+    fn extra_rows_after_removed_row_follow_previous_one() {
+        let main = vec![row(1, "one", 10), row(2, "two", 20)];
+        let rows = rows(
+            &main,
+            vec![row(1, "uno", 10), row(2, "dos", 20), row(5, "cinco", 50)],
+        );
+
+        let res = rows.weave(rows.translate(&main[..1]));
+
+        assert_eq!(
+            res.iter().map(|v| v.text).collect::<Vec<_>>(),
+            ["uno", "cinco"]
+        );
+    }
+
+    #[test]
+    fn extra_rows_go_first_when_nothing_before_them_is_kept() {
+        let main = vec![row(1, "one", 10), row(2, "two", 20)];
+        let rows = rows(
+            &main,
+            vec![
+                row(1, "uno", 10),
+                row(5, "cinco", 50),
+                row(1, "otro", 10),
+                row(2, "dos", 20),
+            ],
+        );
+
+        let res = rows.weave(rows.translate(&main[1..]));
+
+        assert_eq!(
+            res.iter().map(|v| v.text).collect::<Vec<_>>(),
+            ["cinco", "otro", "dos"]
+        );
+    }
+
+    /**Entities exported to ron before translations were added
+     */
+    #[test]
+    fn entities_without_translations_are_imported() {
+        use crate::backend::entity_editor::WindowParams;
+        use crate::entity::npc::Npc;
+        use crate::entity::quest::{Quest, QuestStep};
+
+        let quest = Quest {
+            steps: vec![WindowParams {
+                inner: QuestStep::default(),
+                opened: false,
+                initial_id: (),
+                action: Default::default(),
+                params: (),
+            }],
+            ..Default::default()
+        };
+
+        let without_translations = |ron: String| {
+            assert!(ron.contains("translations:[]"));
+
+            ron.replace("translations:[],", "")
+                .replace(",translations:[]", "")
+        };
+
+        let old = without_translations(ron::ser::to_string(&quest).unwrap());
+
+        assert!(!old.contains("translations"));
+        assert_eq!(ron::from_str::<Quest>(&old).unwrap(), quest);
+
+        let old = without_translations(ron::ser::to_string(&Npc::default()).unwrap());
+
+        assert_eq!(ron::from_str::<Npc>(&old).unwrap(), Npc::default());
+    }
+}
@@ -1,8 +1,9 @@
 use crate::backend::holder::HolderMapOps;
 use crate::backend::util::is_in_range;
-use crate::data::{AnimationComboId, DailyMissionId, HuntingZoneId, ItemId, ItemSetId, NpcId, QuestId, RaidInfoId, RecipeId, RegionId, ResidenceId, SkillId};
+use crate::data::{AnimationComboId, DailyMissionId, EnsoulOptionId, GameStringId, HuntingZoneId, InstantZoneId, ItemId, ItemSetId, NpcId, QuestId, RaidInfoId, RecipeId, RegionId, ResidenceId, SetEnchantEffectId, SkillId, VariationOptionId, WeaponEnchantEffectId};
 use crate::entity::animation_combo::AnimationCombo;
 use crate::entity::daily_mission::DailyMission;
+use crate::entity::game_string::GameString;
 use crate::entity::hunting_zone::HuntingZone;
 use crate::entity::item::armor::Armor;
 use crate::entity::item::etc_item::EtcItem;
@@ -21,6 +22,11 @@ use std::marker::PhantomData;
 use std::str::FromStr;
 use strum_macros::{Display, EnumIter};
 use crate::entity::residence::Residence;
+use crate::entity::weapon_enchant_effect::WeaponEnchantEffect;
+use crate::entity::armor_enchant_effect::ArmorEnchantEffect;
+use crate::entity::ensoul_option::EnsoulOption;
+use crate::entity::variation_option::VariationOption;
+use crate::entity::instant_zone::InstantZone;
 
 #[derive(Copy, Clone, EnumIter, PartialEq, Eq, Display)]
 pub enum FilterMode {
@@ -149,6 +155,12 @@ pub struct EntityCatalogsHolder {
     pub daily_mission: EntityCatalog<DailyMission, DailyMissionId>,
     pub animation_combo: EntityCatalog<AnimationCombo, AnimationComboId>,
     pub residence: EntityCatalog<Residence, ResidenceId>,
+    pub game_string: EntityCatalog<GameString, GameStringId>,
+    pub weapon_enchant_effect: EntityCatalog<WeaponEnchantEffect, WeaponEnchantEffectId>,
+    pub armor_enchant_effect: EntityCatalog<ArmorEnchantEffect, SetEnchantEffectId>,
+    pub ensoul_option: EntityCatalog<EnsoulOption, EnsoulOptionId>,
+    pub variation_option: EntityCatalog<VariationOption, VariationOptionId>,
+    pub instant_zone: EntityCatalog<InstantZone, InstantZoneId>,
 }
 
 impl EntityCatalogsHolder {
@@ -402,6 +414,124 @@ impl EntityCatalogsHolder {
                     }
                 }),
             },
+            game_string: EntityCatalog {
+                filter: "".to_string(),
+                history: vec![],
+                catalog: vec![],
+                filter_fn: Box::new(|v, s| {
+                    if s.is_empty() {
+                        true
+                    } else if let Some(val) = s.strip_prefix("table:") {
+                        v.id.table.to_string().to_lowercase().contains(val)
+                    } else if let Some(range) = s.strip_prefix("r:") {
+                        is_in_range(range, v.id.id)
+                    } else if let Ok(id) = u32::from_str(s) {
+                        v.id.id == id
+                    } else {
+                        v.value.to_lowercase().contains(s)
+                    }
+                }),
+            },
+            weapon_enchant_effect: EntityCatalog {
+                filter: "".to_string(),
+                history: vec![],
+                catalog: vec![],
+                filter_fn: Box::new(|v, s| {
+                    if s.is_empty() {
+                        true
+                    } else if let Some(range) = s.strip_prefix("r:") {
+                        is_in_range(range, v.id.0)
+                    } else if let Some(val) = s.strip_prefix("effect:") {
+                        v.levels
+                            .iter()
+                            .any(|l| l.effect.to_lowercase().contains(val))
+                    } else if let Ok(id) = u32::from_str(s) {
+                        v.id.0 == id
+                    } else {
+                        v.crystal_type.to_string().to_lowercase() == s
+                    }
+                }),
+            },
+            armor_enchant_effect: EntityCatalog {
+                filter: "".to_string(),
+                history: vec![],
+                catalog: vec![],
+                filter_fn: Box::new(|v, s| {
+                    if s.is_empty() {
+                        true
+                    } else if let Some(range) = s.strip_prefix("r:") {
+                        is_in_range(range, v.id.0 as u32)
+                    } else if let Some(val) = s.strip_prefix("effect:") {
+                        v.levels
+                            .iter()
+                            .any(|l| l.effect.to_lowercase().contains(val))
+                    } else if let Ok(id) = u32::from_str(s) {
+                        v.id.0 as u32 == id
+                    } else {
+                        false
+                    }
+                }),
+            },
+            ensoul_option: EntityCatalog {
+                filter: "".to_string(),
+                history: vec![],
+                catalog: vec![],
+                filter_fn: Box::new(|v, s| {
+                    if s.is_empty() {
+                        true
+                    } else if let Some(range) = s.strip_prefix("r:") {
+                        is_in_range(range, v.id.0)
+                    } else if let Some(id) = s.strip_prefix("skill:") {
+                        if let Ok(id) = u32::from_str(id) {
+                            v.skill_id == SkillId(id)
+                        } else {
+                            false
+                        }
+                    } else if let Ok(id) = u32::from_str(s) {
+                        v.id == EnsoulOptionId(id)
+                    } else {
+                        v.name.to_lowercase().contains(s)
+                    }
+                }),
+            },
+            variation_option: EntityCatalog {
+                filter: "".to_string(),
+                history: vec![],
+                catalog: vec![],
+                filter_fn: Box::new(|v, s| {
+                    if s.is_empty() {
+                        true
+                    } else if let Some(range) = s.strip_prefix("r:") {
+                        is_in_range(range, v.id.0)
+                    } else if let Some(id) = s.strip_prefix("skill:") {
+                        if let Ok(id) = u32::from_str(id) {
+                            v.skill_id == SkillId(id)
+                        } else {
+                            false
+                        }
+                    } else if let Ok(id) = u32::from_str(s) {
+                        v.id == VariationOptionId(id)
+                    } else {
+                        v.desc.to_lowercase().contains(s)
+                    }
+                }),
+            },
+            instant_zone: EntityCatalog {
+                filter: "".to_string(),
+                history: vec![],
+                catalog: vec![],
+                filter_fn: Box::new(|v, s| {
+                    if s.is_empty() {
+                        true
+                    } else if let Some(range) = s.strip_prefix("r:") {
+                        is_in_range(range, v.id.0)
+                    } else if let Ok(id) = u32::from_str(s) {
+                        v.id == InstantZoneId(id)
+                    } else {
+                        v.name.to_lowercase().contains(s)
+                    }
+                }),
+            },
         }
     }
 }
@@ -1,5 +1,6 @@
 use crate::backend::entity_impl::animation_combo::AnimationComboEditor;
 use crate::backend::entity_impl::daily_missions::DailyMissionEditor;
+use crate::backend::entity_impl::game_string::GameStringEditor;
 use crate::backend::entity_impl::hunting_zone::HuntingZoneEditor;
 use crate::backend::entity_impl::item::armor::ArmorEditor;
 use crate::backend::entity_impl::item::etc_item::EtcItemEditor;
@@ -21,6 +22,11 @@ use serde::{Deserialize, Serialize};
 use std::hash::Hash;
 use std::sync::RwLock;
 use crate::backend::entity_impl::residence::ResidenceEditor;
+use crate::backend::entity_impl::weapon_enchant_effect::WeaponEnchantEffectEditor;
+use crate::backend::entity_impl::armor_enchant_effect::ArmorEnchantEffectEditor;
+use crate::backend::entity_impl::ensoul_option::EnsoulOptionEditor;
+use crate::backend::entity_impl::variation_option::VariationOptionEditor;
+use crate::backend::entity_impl::instant_zone::InstantZoneEditor;
 
 pub trait EditParamsCommonOps {
     fn is_changed(&self) -> bool;
@@ -97,6 +103,12 @@ pub enum CurrentEntity {
     DailyMission(usize),
     AnimationCombo(usize),
     Residence(usize),
+    GameString(usize),
+    WeaponEnchantEffect(usize),
+    ArmorEnchantEffect(usize),
+    EnsoulOption(usize),
+    VariationOption(usize),
+    InstantZone(usize),
 }
 
 impl CurrentEntity {
@@ -229,6 +241,12 @@ pub struct EditParams {
     pub daily_mission: DailyMissionEditor,
     pub animation_combo: AnimationComboEditor,
     pub residences: ResidenceEditor,
+    pub game_strings: GameStringEditor,
+    pub weapon_enchant_effects: WeaponEnchantEffectEditor,
+    pub armor_enchant_effects: ArmorEnchantEffectEditor,
+    pub ensoul_options: EnsoulOptionEditor,
+    pub variation_options: VariationOptionEditor,
+    pub instant_zones: InstantZoneEditor,
 
     pub current_entity: CurrentEntity,
 }
@@ -390,6 +408,72 @@ impl EditParams {
                     self.animation_combo.opened.remove(i);
                 }
             }
+            EntityT::GameString(id) => {
+                if let Some((i, _)) = self
+                    .game_strings
+                    .opened
+                    .iter()
+                    .enumerate()
+                    .find(|(_, v)| v.inner.initial_id == id)
+                {
+                    self.game_strings.opened.remove(i);
+                }
+            }
+            EntityT::WeaponEnchantEffect(id) => {
+                if let Some((i, _)) = self
+                    .weapon_enchant_effects
+                    .opened
+                    .iter()
+                    .enumerate()
+                    .find(|(_, v)| v.inner.initial_id == id)
+                {
+                    self.weapon_enchant_effects.opened.remove(i);
+                }
+            }
+            EntityT::ArmorEnchantEffect(id) => {
+                if let Some((i, _)) = self
+                    .armor_enchant_effects
+                    .opened
+                    .iter()
+                    .enumerate()
+                    .find(|(_, v)| v.inner.initial_id == id)
+                {
+                    self.armor_enchant_effects.opened.remove(i);
+                }
+            }
+            EntityT::EnsoulOption(id) => {
+                if let Some((i, _)) = self
+                    .ensoul_options
+                    .opened
+                    .iter()
+                    .enumerate()
+                    .find(|(_, v)| v.inner.initial_id == id)
+                {
+                    self.ensoul_options.opened.remove(i);
+                }
+            }
+            EntityT::VariationOption(id) => {
+                if let Some((i, _)) = self
+                    .variation_options
+                    .opened
+                    .iter()
+                    .enumerate()
+                    .find(|(_, v)| v.inner.initial_id == id)
+                {
+                    self.variation_options.opened.remove(i);
+                }
+            }
+            EntityT::InstantZone(id) => {
+                if let Some((i, _)) = self
+                    .instant_zones
+                    .opened
+                    .iter()
+                    .enumerate()
+                    .find(|(_, v)| v.inner.initial_id == id)
+                {
+                    self.instant_zones.opened.remove(i);
+                }
+            }
         }
 
         self.find_opened_entity();
@@ -416,8 +500,122 @@ impl EditParams {
                 .animation_combo
                 .reset_initial(&holders.animation_combo_holder),
             Entity::Residence => self.residences.reset_initial(&holders.residence_holder),
+            Entity::GameString => self.game_strings.reset_initial(&holders.game_string_holder),
+            Entity::WeaponEnchantEffect => self
+                .weapon_enchant_effects
+                .reset_initial(&holders.weapon_enchant_effect_holder),
+            Entity::ArmorEnchantEffect => self
+                .armor_enchant_effects
+                .reset_initial(&holders.armor_enchant_effect_holder),
+            Entity::EnsoulOption => self
+                .ensoul_options
+                .reset_initial(&holders.ensoul_option_holder),
+            Entity::VariationOption => self
+                .variation_options
+                .reset_initial(&holders.variation_option_holder),
+            Entity::InstantZone => self
+                .instant_zones
+                .reset_initial(&holders.instant_zone_holder),
         }
     }
+
+    /**Opens `entity` from its holder, or switches to it if already opened
+     */
+    pub fn open_entity(&mut self, entity: EntityT, holders: &mut GameDataHolder) {
+        match entity {
+            EntityT::Quest(id) => self.open_quest(id, &mut holders.quest_holder),
+            EntityT::Skill(id) => self.open_skill(id, &mut holders.skill_holder),
+            EntityT::Npc(id) => self.open_npc(id, &mut holders.npc_holder),
+            EntityT::Weapon(id) => self.open_weapon(id, &mut holders.weapon_holder),
+            EntityT::Armor(id) => self.open_armor(id, &mut holders.armor_holder),
+            EntityT::EtcItem(id) => self.open_etc_item(id, &mut holders.etc_item_holder),
+            EntityT::ItemSet(id) => self.open_item_set(id, &mut holders.item_set_holder),
+            EntityT::Recipe(id) => self.open_recipe(id, &mut holders.recipe_holder),
+            EntityT::HuntingZone(id) => {
+                self.open_hunting_zone(id, &mut holders.hunting_zone_holder)
+            }
+            EntityT::Region(id) => self.open_region(id, &mut holders.region_holder),
+            EntityT::RaidInfo(id) => self.open_raid_info(id, &mut holders.raid_info_holder),
+            EntityT::DailyMission(id) => {
+                self.open_daily_mission(id, &mut holders.daily_mission_holder)
+            }
+            EntityT::AnimationCombo(id) => {
+                self.open_animation_combo(id, &mut holders.animation_combo_holder)
+            }
+            EntityT::Residence(id) => self.open_residence(id, &mut holders.residence_holder),
+            EntityT::GameString(id) => self.open_game_string(id, &mut holders.game_string_holder),
+            EntityT::WeaponEnchantEffect(id) => {
+                self.open_weapon_enchant_effect(id, &mut holders.weapon_enchant_effect_holder)
+            }
+            EntityT::ArmorEnchantEffect(id) => {
+                self.open_armor_enchant_effect(id, &mut holders.armor_enchant_effect_holder)
+            }
+            EntityT::EnsoulOption(id) => {
+                self.open_ensoul_option(id, &mut holders.ensoul_option_holder)
+            }
+            EntityT::VariationOption(id) => {
+                self.open_variation_option(id, &mut holders.variation_option_holder)
+            }
+            EntityT::InstantZone(id) => {
+                self.open_instant_zone(id, &mut holders.instant_zone_holder)
+            }
+        }
+    }
+    /**Id of entity in current tab as it was opened, `None` if no tab is selected
+     */
+    pub fn current_entity_t(&self) -> Option<EntityT> {
+        Some(match self.current_entity {
+            CurrentEntity::Quest(i) => EntityT::Quest(self.quests.opened[i].inner.initial_id),
+            CurrentEntity::Skill(i) => EntityT::Skill(self.skills.opened[i].inner.initial_id),
+            CurrentEntity::Npc(i) => EntityT::Npc(self.npcs.opened[i].inner.initial_id),
+            CurrentEntity::Weapon(i) => EntityT::Weapon(self.weapons.opened[i].inner.initial_id),
+            CurrentEntity::EtcItem(i) => {
+                EntityT::EtcItem(self.etc_items.opened[i].inner.initial_id)
+            }
+            CurrentEntity::Armor(i) => EntityT::Armor(self.armor.opened[i].inner.initial_id),
+            CurrentEntity::ItemSet(i) => {
+                EntityT::ItemSet(self.item_sets.opened[i].inner.initial_id)
+            }
+            CurrentEntity::Recipe(i) => EntityT::Recipe(self.recipes.opened[i].inner.initial_id),
+            CurrentEntity::HuntingZone(i) => {
+                EntityT::HuntingZone(self.hunting_zones.opened[i].inner.initial_id)
+            }
+            CurrentEntity::Region(i) => EntityT::Region(self.regions.opened[i].inner.initial_id),
+            CurrentEntity::RaidInfo(i) => {
+                EntityT::RaidInfo(self.raid_info.opened[i].inner.initial_id)
+            }
+            CurrentEntity::DailyMission(i) => {
+                EntityT::DailyMission(self.daily_mission.opened[i].inner.initial_id)
+            }
+            CurrentEntity::AnimationCombo(i) => {
+                EntityT::AnimationCombo(self.animation_combo.opened[i].inner.initial_id)
+            }
+            CurrentEntity::Residence(i) => {
+                EntityT::Residence(self.residences.opened[i].inner.initial_id)
+            }
+            CurrentEntity::GameString(i) => {
+                EntityT::GameString(self.game_strings.opened[i].inner.initial_id)
+            }
+            CurrentEntity::WeaponEnchantEffect(i) => {
+                EntityT::WeaponEnchantEffect(self.weapon_enchant_effects.opened[i].inner.initial_id)
+            }
+            CurrentEntity::ArmorEnchantEffect(i) => {
+                EntityT::ArmorEnchantEffect(self.armor_enchant_effects.opened[i].inner.initial_id)
+            }
+            CurrentEntity::EnsoulOption(i) => {
+                EntityT::EnsoulOption(self.ensoul_options.opened[i].inner.initial_id)
+            }
+            CurrentEntity::VariationOption(i) => {
+                EntityT::VariationOption(self.variation_options.opened[i].inner.initial_id)
+            }
+            CurrentEntity::InstantZone(i) => {
+                EntityT::InstantZone(self.instant_zones.opened[i].inner.initial_id)
+            }
+
+            CurrentEntity::None => return None,
+        })
+    }
+
     pub(crate) fn find_opened_entity(&mut self) {
         match self.current_entity {
             CurrentEntity::Quest(i) => {
@@ -528,6 +726,57 @@ impl EditParams {
                     return;
                 }
             }
+            CurrentEntity::GameString(i) => {
+                if !self.game_strings.opened.is_empty() {
+                    self.current_entity =
+                        CurrentEntity::GameString(i.min(self.game_strings.opened.len() - 1));
+
+                    return;
+                }
+            }
+            CurrentEntity::WeaponEnchantEffect(i) => {
+                if !self.weapon_enchant_effects.opened.is_empty() {
+                    self.current_entity = CurrentEntity::WeaponEnchantEffect(
+                        i.min(self.weapon_enchant_effects.opened.len() - 1),
+                    );
+
+                    return;
+                }
+            }
+            CurrentEntity::ArmorEnchantEffect(i) => {
+                if !self.armor_enchant_effects.opened.is_empty() {
+                    self.current_entity = CurrentEntity::ArmorEnchantEffect(
+                        i.min(self.armor_enchant_effects.opened.len() - 1),
+                    );
+
+                    return;
+                }
+            }
+            CurrentEntity::EnsoulOption(i) => {
+                if !self.ensoul_options.opened.is_empty() {
+                    self.current_entity =
+                        CurrentEntity::EnsoulOption(i.min(self.ensoul_options.opened.len() - 1));
+
+                    return;
+                }
+            }
+            CurrentEntity::VariationOption(i) => {
+                if !self.variation_options.opened.is_empty() {
+                    self.current_entity = CurrentEntity::VariationOption(
+                        i.min(self.variation_options.opened.len() - 1),
+                    );
+
+                    return;
+                }
+            }
+            CurrentEntity::InstantZone(i) => {
+                if !self.instant_zones.opened.is_empty() {
+                    self.current_entity =
+                        CurrentEntity::InstantZone(i.min(self.instant_zones.opened.len() - 1));
+
+                    return;
+                }
+            }
 
             CurrentEntity::None => {}
         }
@@ -560,6 +809,20 @@ impl EditParams {
             self.current_entity = CurrentEntity::AnimationCombo(self.animation_combo.len() - 1);
         } else if !self.residences.is_empty() {
             self.current_entity = CurrentEntity::Residence(self.residences.len() - 1);
+        } else if !self.game_strings.is_empty() {
+            self.current_entity = CurrentEntity::GameString(self.game_strings.len() - 1);
+        } else if !self.weapon_enchant_effects.is_empty() {
+            self.current_entity =
+                CurrentEntity::WeaponEnchantEffect(self.weapon_enchant_effects.len() - 1);
+        } else if !self.armor_enchant_effects.is_empty() {
+            self.current_entity =
+                CurrentEntity::ArmorEnchantEffect(self.armor_enchant_effects.len() - 1);
+        } else if !self.ensoul_options.is_empty() {
+            self.current_entity = CurrentEntity::EnsoulOption(self.ensoul_options.len() - 1);
+        } else if !self.variation_options.is_empty() {
+            self.current_entity = CurrentEntity::VariationOption(self.variation_options.len() - 1);
+        } else if !self.instant_zones.is_empty() {
+            self.current_entity = CurrentEntity::InstantZone(self.instant_zones.len() - 1);
         } else {
             self.current_entity = CurrentEntity::None;
         }
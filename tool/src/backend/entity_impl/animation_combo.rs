@@ -6,7 +6,7 @@ use crate::backend::holder::{FDHashMap, HolderMapOps};
 use crate::backend::{Backend, HandleAction};
 use crate::data::AnimationComboId;
 use crate::entity::animation_combo::AnimationCombo;
-use crate::entity::CommonEntity;
+use crate::entity::{CommonEntity, EntityT};
 
 pub type AnimationComboEditor = EntityEditParams<AnimationCombo, AnimationComboId, (), ()>;
 
@@ -93,13 +93,15 @@ impl Backend {
         }
         v._changed = true;
 
+        let entity = EntityT::AnimationCombo(v.id);
+
         self.holders
             .game_data_holder
             .animation_combo_holder
             .insert(v.id, v);
 
         self.filter_animation_combo();
-        self.check_for_unwrote_changed();
+        self.check_for_unwrote_changed(entity);
     }
 }
 
@@ -0,0 +1,128 @@
+use crate::backend::entity_catalog::EntityInfo;
+use crate::backend::entity_editor::{
+    CommonEditorOps, CurrentEntity, EditParams, EditParamsCommonOps, EntityEditParams, WindowParams,
+};
+use crate::backend::entity_impl::weapon_enchant_effect::EnchantEffectAction;
+use crate::backend::holder::{FHashMap, HolderMapOps};
+use crate::backend::{Backend, HandleAction};
+use crate::data::SetEnchantEffectId;
+use crate::entity::armor_enchant_effect::ArmorEnchantEffect;
+use crate::entity::{CommonEntity, EntityT};
+
+pub type ArmorEnchantEffectEditor =
+    EntityEditParams<ArmorEnchantEffect, SetEnchantEffectId, EnchantEffectAction, ()>;
+
+impl HandleAction
+    for WindowParams<ArmorEnchantEffect, SetEnchantEffectId, EnchantEffectAction, ()>
+{
+    fn handle_action(&mut self) {
+        let item = self;
+
+        let mut action = item.action.write().unwrap();
+
+        if let EnchantEffectAction::RemoveLevel(i) = *action {
+            item.inner.levels.remove(i);
+        }
+
+        *action = EnchantEffectAction::None;
+    }
+}
+
+impl EditParams {
+    pub fn get_opened_armor_enchant_effects_info(&self) -> Vec<(String, SetEnchantEffectId, bool)> {
+        self.armor_enchant_effects.get_opened_info()
+    }
+
+    pub fn open_armor_enchant_effect(
+        &mut self,
+        id: SetEnchantEffectId,
+        holder: &mut FHashMap<SetEnchantEffectId, ArmorEnchantEffect>,
+    ) {
+        for (i, q) in self.armor_enchant_effects.opened.iter().enumerate() {
+            if q.inner.initial_id == id {
+                self.current_entity = CurrentEntity::ArmorEnchantEffect(i);
+
+                return;
+            }
+        }
+
+        if let Some(q) = holder.get(&id) {
+            self.current_entity = CurrentEntity::ArmorEnchantEffect(
+                self.armor_enchant_effects.add(q.clone(), q.id(), false),
+            );
+        }
+    }
+
+    pub fn set_current_armor_enchant_effect(&mut self, index: usize) {
+        if index < self.armor_enchant_effects.opened.len() {
+            self.current_entity = CurrentEntity::ArmorEnchantEffect(index);
+        }
+    }
+
+    pub fn create_new_armor_enchant_effect(&mut self) {
+        self.current_entity =
+            CurrentEntity::ArmorEnchantEffect(self.armor_enchant_effects.add_new());
+    }
+}
+
+impl Backend {
+    pub fn filter_armor_enchant_effects(&mut self) {
+        self.entity_catalogs.armor_enchant_effect.filter(
+            &self.holders.game_data_holder.armor_enchant_effect_holder,
+            self.entity_catalogs.filter_mode,
+        );
+    }
+
+    pub fn save_armor_enchant_effect_from_dlg(&mut self, id: SetEnchantEffectId) {
+        if let CurrentEntity::ArmorEnchantEffect(index) = self.edit_params.current_entity {
+            let new_entity = self
+                .edit_params
+                .armor_enchant_effects
+                .opened
+                .get_mut(index)
+                .unwrap();
+
+            if new_entity.inner.inner.id() != id {
+                return;
+            }
+
+            new_entity.inner.initial_id = new_entity.inner.inner.id;
+
+            let entity = new_entity.inner.inner.clone();
+
+            new_entity.on_save();
+
+            self.save_armor_enchant_effect_force(entity);
+        }
+    }
+
+    pub(crate) fn save_armor_enchant_effect_force(&mut self, mut v: ArmorEnchantEffect) {
+        if let Some(vv) = self
+            .holders
+            .game_data_holder
+            .armor_enchant_effect_holder
+            .get(&v.id)
+        {
+            if *vv == v {
+                return;
+            }
+        }
+        v._changed = true;
+
+        let entity = EntityT::ArmorEnchantEffect(v.id);
+
+        self.holders
+            .game_data_holder
+            .armor_enchant_effect_holder
+            .insert(v.id, v);
+
+        self.filter_armor_enchant_effects();
+        self.check_for_unwrote_changed(entity);
+    }
+}
+
+impl From<&ArmorEnchantEffect> for EntityInfo<ArmorEnchantEffect, SetEnchantEffectId> {
+    fn from(value: &ArmorEnchantEffect) -> Self {
+        EntityInfo::new(&format!("ID: {}\n{}", value.id.0, value.desc()), value)
+    }
+}
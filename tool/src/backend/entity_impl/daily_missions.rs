@@ -6,7 +6,7 @@ use crate::backend::holder::{FHashMap, HolderMapOps};
 use crate::backend::{Backend, HandleAction};
 use crate::data::DailyMissionId;
 use crate::entity::daily_mission::DailyMission;
-use crate::entity::CommonEntity;
+use crate::entity::{CommonEntity, EntityT};
 use serde::{Deserialize, Serialize};
 
 pub type DailyMissionEditor =
@@ -118,13 +118,15 @@ impl Backend {
         }
         v._changed = true;
 
+        let entity = EntityT::DailyMission(v.id);
+
         self.holders
             .game_data_holder
             .daily_mission_holder
             .insert(v.id, v);
 
         self.filter_daily_mission();
-        self.check_for_unwrote_changed();
+        self.check_for_unwrote_changed(entity);
     }
 }
 
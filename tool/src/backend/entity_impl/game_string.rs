@@ -0,0 +1,193 @@
+use crate::backend::dat_loader::L2StringTable;
+use crate::backend::entity_catalog::EntityInfo;
+use crate::backend::entity_editor::{
+    CommonEditorOps, CurrentEntity, EditParams, EditParamsCommonOps, EntityEditParams, WindowParams,
+};
+use crate::backend::holder::{FHashMap, HolderMapOps, HolderOps};
+use crate::backend::{Backend, Dialog, HandleAction};
+use crate::data::{GameStringId, StringTable};
+use crate::entity::game_string::GameString;
+use crate::entity::{CommonEntity, EntityT};
+use serde::{Deserialize, Serialize};
+
+pub type GameStringEditor = EntityEditParams<GameString, GameStringId, GameStringAction, ()>;
+
+impl HandleAction for WindowParams<GameString, GameStringId, GameStringAction, ()> {
+    fn handle_action(&mut self) {
+        let item = self;
+
+        let mut action = item.action.write().unwrap();
+
+        *action = GameStringAction::None;
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Default)]
+pub enum GameStringAction {
+    #[default]
+    None,
+}
+
+impl EditParams {
+    pub fn get_opened_game_strings_info(&self) -> Vec<(String, GameStringId, bool)> {
+        self.game_strings.get_opened_info()
+    }
+
+    pub fn open_game_string(
+        &mut self,
+        id: GameStringId,
+        holder: &mut FHashMap<GameStringId, GameString>,
+    ) {
+        for (i, q) in self.game_strings.opened.iter().enumerate() {
+            if q.inner.initial_id == id {
+                self.current_entity = CurrentEntity::GameString(i);
+
+                return;
+            }
+        }
+
+        if let Some(q) = holder.get(&id) {
+            self.current_entity =
+                CurrentEntity::GameString(self.game_strings.add(q.clone(), q.id(), false));
+        }
+    }
+
+    pub fn set_current_game_string(&mut self, index: usize) {
+        if index < self.game_strings.opened.len() {
+            self.current_entity = CurrentEntity::GameString(index);
+        }
+    }
+
+    pub fn create_new_game_string(&mut self) {
+        self.current_entity = CurrentEntity::GameString(self.game_strings.add_new());
+    }
+}
+
+impl Backend {
+    pub fn filter_game_strings(&mut self) {
+        self.entity_catalogs.game_string.filter(
+            &self.holders.game_data_holder.game_string_holder,
+            self.entity_catalogs.filter_mode,
+        );
+    }
+
+    /**l2gamedataname strings are referenced by index from other dats, so they can't be deleted
+     */
+    pub fn toggle_game_string_deleted(&mut self, id: GameStringId) {
+        if id.table == StringTable::GameDataName {
+            self.show_dialog(Dialog::ShowWarning(
+                "Game Data Name strings are referenced by index and can't be deleted".to_string(),
+            ));
+
+            return;
+        }
+
+        let holder = &mut self.holders.game_data_holder.game_string_holder;
+
+        if let Some(v) = holder.get_mut(&id) {
+            v._deleted = !v._deleted;
+
+            if v._deleted {
+                self.edit_params.close_if_opened(EntityT::GameString(id));
+                holder.inc_deleted();
+            } else {
+                holder.dec_deleted();
+            }
+
+            self.filter_game_strings();
+            self.check_for_unwrote_changed(EntityT::GameString(id));
+            self.warn_if_deleted_entity_used(EntityT::GameString(id));
+        }
+    }
+
+    /**Strings are appended to l2gamedataname without gaps, shows warning if `id` is out of it.
+
+    Existing strings are read only: entities keep the text, not the index, and look it up again
+    on save, so an edited record would only make them append the old text once more
+     */
+    pub(crate) fn game_data_name_rejected(&mut self, v: &GameString) -> bool {
+        if v.id.table != StringTable::GameDataName {
+            return false;
+        }
+
+        let table = &self.holders.game_data_holder.game_string_table;
+        let next_index = table.next_index();
+
+        if v.id.id > next_index {
+            self.show_dialog(Dialog::ShowWarning(format!(
+                "Game Data Name strings can only be appended, next index is {next_index}"
+            )));
+
+            return true;
+        }
+
+        if v.id.id < next_index && table.get(&v.id.id) != Some(&v.value) {
+            self.show_dialog(Dialog::ShowWarning(
+                "Existing Game Data Name strings are referenced by entities and can't be edited, \
+                 change the entities instead"
+                    .to_string(),
+            ));
+
+            return true;
+        }
+
+        false
+    }
+
+    pub fn save_game_string_from_dlg(&mut self, id: GameStringId) {
+        if let CurrentEntity::GameString(index) = self.edit_params.current_entity {
+            let new_entity = self.edit_params.game_strings.opened.get_mut(index).unwrap();
+
+            if new_entity.inner.inner.id() != id {
+                return;
+            }
+
+            new_entity.inner.initial_id = new_entity.inner.inner.id;
+
+            let entity = new_entity.inner.inner.clone();
+
+            new_entity.on_save();
+
+            self.save_game_string_force(entity);
+        }
+    }
+
+    pub(crate) fn save_game_string_force(&mut self, mut v: GameString) {
+        if self.game_data_name_rejected(&v) {
+            return;
+        }
+
+        if let Some(vv) = self.holders.game_data_holder.game_string_holder.get(&v.id) {
+            if *vv == v {
+                return;
+            }
+        }
+        v._changed = true;
+
+        if v.id.table == StringTable::GameDataName {
+            self.holders
+                .game_data_holder
+                .game_string_table
+                .set(v.id.id, v.value.clone());
+        }
+
+        let entity = EntityT::GameString(v.id);
+
+        self.holders
+            .game_data_holder
+            .game_string_holder
+            .insert(v.id, v);
+
+        self.filter_game_strings();
+        self.check_for_unwrote_changed(entity);
+    }
+}
+
+impl From<&GameString> for EntityInfo<GameString, GameStringId> {
+    fn from(value: &GameString) -> Self {
+        EntityInfo::new(
+            &format!("{}: {}\n{}", value.id.table, value.id.id, value.value),
+            value,
+        )
+    }
+}
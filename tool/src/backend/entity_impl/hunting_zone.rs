@@ -6,7 +6,7 @@ use crate::backend::holder::{FHashMap, HolderMapOps};
 use crate::backend::{Backend, HandleAction};
 use crate::data::HuntingZoneId;
 use crate::entity::hunting_zone::HuntingZone;
-use crate::entity::CommonEntity;
+use crate::entity::{CommonEntity, EntityT};
 use serde::{Deserialize, Serialize};
 
 pub type HuntingZoneEditor = EntityEditParams<HuntingZone, HuntingZoneId, HuntingZoneAction, ()>;
@@ -135,13 +135,15 @@ impl Backend {
         }
         v._changed = true;
 
+        let entity = EntityT::HuntingZone(v.id);
+
         self.holders
             .game_data_holder
             .hunting_zone_holder
             .insert(v.id, v);
 
         self.filter_hunting_zones();
-        self.check_for_unwrote_changed();
+        self.check_for_unwrote_changed(entity);
     }
 }
 
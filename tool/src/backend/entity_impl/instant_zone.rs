@@ -0,0 +1,144 @@
+use crate::backend::entity_catalog::EntityInfo;
+use crate::backend::entity_editor::{
+    CommonEditorOps, CurrentEntity, EditParams, EditParamsCommonOps, EntityEditParams, WindowParams,
+};
+use crate::backend::holder::{FHashMap, GameDataHolder, HolderMapOps};
+use crate::backend::{Backend, HandleAction};
+use crate::data::{HuntingZoneId, InstantZoneId};
+use crate::entity::instant_zone::InstantZone;
+use crate::entity::{CommonEntity, EntityT};
+use serde::{Deserialize, Serialize};
+
+pub type InstantZoneEditor = EntityEditParams<InstantZone, InstantZoneId, InstantZoneAction, ()>;
+
+impl HandleAction for WindowParams<InstantZone, InstantZoneId, InstantZoneAction, ()> {
+    fn handle_action(&mut self) {
+        let item = self;
+
+        let mut action = item.action.write().unwrap();
+
+        *action = InstantZoneAction::None;
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Default)]
+pub enum InstantZoneAction {
+    #[default]
+    None,
+}
+
+impl EditParams {
+    pub fn get_opened_instant_zones_info(&self) -> Vec<(String, InstantZoneId, bool)> {
+        self.instant_zones.get_opened_info()
+    }
+
+    pub fn open_instant_zone(
+        &mut self,
+        id: InstantZoneId,
+        holder: &mut FHashMap<InstantZoneId, InstantZone>,
+    ) {
+        for (i, q) in self.instant_zones.opened.iter().enumerate() {
+            if q.inner.initial_id == id {
+                self.current_entity = CurrentEntity::InstantZone(i);
+
+                return;
+            }
+        }
+
+        if let Some(q) = holder.get(&id) {
+            self.current_entity =
+                CurrentEntity::InstantZone(self.instant_zones.add(q.clone(), q.id(), false));
+        }
+    }
+
+    pub fn set_current_instant_zone(&mut self, index: usize) {
+        if index < self.instant_zones.opened.len() {
+            self.current_entity = CurrentEntity::InstantZone(index);
+        }
+    }
+
+    pub fn create_new_instant_zone(&mut self) {
+        self.current_entity = CurrentEntity::InstantZone(self.instant_zones.add_new());
+    }
+}
+
+impl Backend {
+    pub fn filter_instant_zones(&mut self) {
+        self.entity_catalogs.instant_zone.filter(
+            &self.holders.game_data_holder.instant_zone_holder,
+            self.entity_catalogs.filter_mode,
+        );
+    }
+
+    pub fn save_instant_zone_from_dlg(&mut self, id: InstantZoneId) {
+        if let CurrentEntity::InstantZone(index) = self.edit_params.current_entity {
+            let new_entity = self
+                .edit_params
+                .instant_zones
+                .opened
+                .get_mut(index)
+                .unwrap();
+
+            if new_entity.inner.inner.id() != id {
+                return;
+            }
+
+            new_entity.inner.initial_id = new_entity.inner.inner.id;
+
+            let entity = new_entity.inner.inner.clone();
+
+            new_entity.on_save();
+
+            self.save_instant_zone_force(entity);
+        }
+    }
+
+    pub(crate) fn save_instant_zone_force(&mut self, mut v: InstantZone) {
+        if let Some(vv) = self.holders.game_data_holder.instant_zone_holder.get(&v.id) {
+            if *vv == v {
+                return;
+            }
+        }
+        v._changed = true;
+
+        let entity = EntityT::InstantZone(v.id);
+
+        self.holders
+            .game_data_holder
+            .instant_zone_holder
+            .insert(v.id, v);
+
+        self.filter_instant_zones();
+        self.check_for_unwrote_changed(entity);
+    }
+}
+
+impl GameDataHolder {
+    /**Hunting zones which point to an instant zone that doesn't exist, `0` means no instance
+     */
+    pub fn dangling_instant_zone_ids(&self) -> Vec<(HuntingZoneId, InstantZoneId)> {
+        let mut res: Vec<_> = self
+            .hunting_zone_holder
+            .values()
+            .filter(|v| {
+                !v._deleted
+                    && v.instant_zone_id.0 != 0
+                    && self
+                        .instant_zone_holder
+                        .get(&v.instant_zone_id)
+                        .is_none_or(|z| z._deleted)
+            })
+            .map(|v| (v.id, v.instant_zone_id))
+            .collect();
+
+        res.sort();
+
+        res
+    }
+}
+
+impl From<&InstantZone> for EntityInfo<InstantZone, InstantZoneId> {
+    fn from(value: &InstantZone) -> Self {
+        EntityInfo::new(&format!("ID: {}\n{}", value.id.0, value.name), value)
+    }
+}
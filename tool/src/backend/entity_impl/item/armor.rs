@@ -160,6 +160,8 @@ impl Backend {
             self.filter_etc_items();
         }
 
+        let entity = EntityT::Armor(v.base_info.id);
+
         self.holders
             .game_data_holder
             .item_holder
@@ -169,7 +171,7 @@ impl Backend {
             .armor_holder
             .insert(v.base_info.id, v);
 
-        self.check_for_unwrote_changed();
+        self.check_for_unwrote_changed(entity);
         self.filter_armor();
     }
 }
@@ -161,6 +161,8 @@ impl Backend {
             self.filter_weapons();
         }
 
+        let entity = EntityT::EtcItem(v.base_info.id);
+
         self.holders
             .game_data_holder
             .item_holder
@@ -171,7 +173,7 @@ impl Backend {
             .insert(v.base_info.id, v);
 
         self.filter_etc_items();
-        self.check_for_unwrote_changed();
+        self.check_for_unwrote_changed(entity);
     }
 }
 
@@ -228,6 +228,8 @@ impl Backend {
             self.filter_etc_items();
         }
 
+        let entity = EntityT::Weapon(v.base_info.id);
+
         self.holders
             .game_data_holder
             .item_holder
@@ -238,7 +240,7 @@ impl Backend {
             .insert(v.base_info.id, v);
 
         self.filter_weapons();
-        self.check_for_unwrote_changed();
+        self.check_for_unwrote_changed(entity);
     }
 }
 
@@ -6,7 +6,7 @@ use crate::backend::holder::{FHashMap, HolderMapOps};
 use crate::backend::{Backend, HandleAction};
 use crate::data::{ItemId, ItemSetId};
 use crate::entity::item_set::ItemSet;
-use crate::entity::CommonEntity;
+use crate::entity::{CommonEntity, EntityT};
 use serde::{Deserialize, Serialize};
 
 pub type ItemSetEditor = EntityEditParams<ItemSet, ItemSetId, ItemSetAction, ()>;
@@ -166,13 +166,15 @@ impl Backend {
         }
         v._changed = true;
 
+        let entity = EntityT::ItemSet(v.id);
+
         self.holders
             .game_data_holder
             .item_set_holder
             .insert(v.id, v);
 
         self.filter_item_sets();
-        self.check_for_unwrote_changed();
+        self.check_for_unwrote_changed(entity);
     }
 }
 
@@ -1,5 +1,6 @@
 pub mod animation_combo;
 pub mod daily_missions;
+pub mod game_string;
 pub mod hunting_zone;
 pub mod item;
 pub mod item_set;
@@ -10,3 +11,8 @@ pub mod recipe;
 pub mod region;
 pub mod skill;
 pub mod residence;
+pub mod weapon_enchant_effect;
+pub mod armor_enchant_effect;
+pub mod ensoul_option;
+pub mod variation_option;
+pub mod instant_zone;
@@ -6,6 +6,7 @@ use crate::backend::holder::{FHashMap, HolderMapOps};
 use crate::backend::{Backend, HandleAction};
 use crate::data::NpcId;
 use crate::entity::npc::Npc;
+use crate::entity::EntityT;
 use serde::{Deserialize, Serialize};
 
 pub type NpcEditor = EntityEditParams<Npc, NpcId, NpcAction, ()>;
@@ -188,10 +189,12 @@ impl Backend {
 
         v._changed = true;
 
+        let entity = EntityT::Npc(v.id);
+
         self.holders.game_data_holder.npc_holder.insert(v.id, v);
 
         self.filter_npcs();
-        self.check_for_unwrote_changed();
+        self.check_for_unwrote_changed(entity);
     }
 }
 
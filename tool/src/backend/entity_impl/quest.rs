@@ -6,6 +6,7 @@ use crate::backend::holder::{FHashMap, HolderMapOps};
 use crate::backend::{Backend, HandleAction};
 use crate::data::QuestId;
 use crate::entity::quest::Quest;
+use crate::entity::EntityT;
 use serde::{Deserialize, Serialize};
 
 pub type QuestEditor = EntityEditParams<Quest, QuestId, QuestAction, ()>;
@@ -150,10 +151,12 @@ impl Backend {
             }
         }
 
+        let entity = EntityT::Quest(v.id);
+
         self.holders.game_data_holder.quest_holder.insert(v.id, v);
 
         self.filter_quests();
-        self.check_for_unwrote_changed();
+        self.check_for_unwrote_changed(entity);
     }
 }
 
@@ -168,3 +171,265 @@ impl From<&Quest> for EntityInfo<Quest, QuestId> {
         EntityInfo::new(&format!("ID: {}\n{}", value.id.0, value.title), value)
     }
 }
+
+/**Problems of step graph built from [`crate::entity::quest::QuestStep::prev_steps`], steps are
+referenced by their position in [`Quest::steps`]
+ */
+#[derive(Default, Debug)]
+pub struct StepGraphIssues {
+    pub in_cycle: Vec<usize>,
+    /**Can't be reached from any start step
+     */
+    pub unreachable: Vec<usize>,
+    /**Step and its previous step level which no step has
+     */
+    pub unknown_prev_steps: Vec<(usize, u32)>,
+    pub finish_not_linked: bool,
+}
+
+impl StepGraphIssues {
+    pub fn is_empty(&self) -> bool {
+        self.in_cycle.is_empty()
+            && self.unreachable.is_empty()
+            && self.unknown_prev_steps.is_empty()
+            && !self.finish_not_linked
+    }
+}
+
+impl Quest {
+    /**Finish step levels are counted down from `u32::MAX`, so they are above normal ones
+     */
+    pub fn is_finish_step(&self, index: usize) -> bool {
+        self.steps[index].inner.level >= self.last_finish_step_id
+    }
+
+    /**Steps point to previous ones by level, not by position
+     */
+    pub fn step_index_by_level(&self, level: u32) -> Option<usize> {
+        self.steps.iter().position(|v| v.inner.level == level)
+    }
+
+    /**`(from, to)` pairs of step positions
+     */
+    pub fn step_edges(&self) -> Vec<(usize, usize)> {
+        let mut res = vec![];
+
+        for (to, step) in self.steps.iter().enumerate() {
+            for level in step.inner.linked_prev_steps() {
+                if let Some(from) = self.step_index_by_level(level) {
+                    if !res.contains(&(from, to)) {
+                        res.push((from, to));
+                    }
+                }
+            }
+        }
+
+        res
+    }
+
+    /**Positions of steps quest can begin with, see [`crate::entity::quest::QuestStep::is_start`]
+     */
+    pub fn start_steps(&self) -> Vec<usize> {
+        (0..self.steps.len())
+            .filter(|v| self.steps[*v].inner.is_start())
+            .collect()
+    }
+
+    /**Links `to` after `from`, does nothing if they are already linked or `from` has level `0`,
+    which means quest start in [`crate::entity::quest::QuestStep::prev_steps`]
+     */
+    pub fn link_steps(&mut self, from: usize, to: usize) {
+        let level = self.steps[from].inner.level;
+        let prev_steps = &mut self.steps[to].inner.prev_steps;
+
+        if from != to && level != 0 && !prev_steps.contains(&level) {
+            prev_steps.push(level);
+        }
+    }
+
+    pub fn unlink_steps(&mut self, from: usize, to: usize) {
+        let level = self.steps[from].inner.level;
+
+        self.steps[to].inner.prev_steps.retain(|v| *v != level);
+    }
+
+    pub fn step_graph_issues(&self) -> StepGraphIssues {
+        let mut res = StepGraphIssues::default();
+
+        if self.steps.is_empty() {
+            return res;
+        }
+
+        let edges = self.step_edges();
+        let next = |i: usize| edges.iter().filter(move |v| v.0 == i).map(|v| v.1);
+
+        let reachable_from = |starts: &[usize]| {
+            let mut visited = vec![false; self.steps.len()];
+            let mut stack: Vec<_> = starts.iter().flat_map(|v| next(*v)).collect();
+
+            while let Some(i) = stack.pop() {
+                if !visited[i] {
+                    visited[i] = true;
+                    stack.extend(next(i));
+                }
+            }
+
+            visited
+        };
+
+        for i in 0..self.steps.len() {
+            if reachable_from(&[i])[i] {
+                res.in_cycle.push(i);
+            }
+        }
+
+        let starts = self.start_steps();
+        let mut reachable = reachable_from(&starts);
+
+        for i in starts {
+            reachable[i] = true;
+        }
+
+        res.unreachable = (0..self.steps.len()).filter(|v| !reachable[*v]).collect();
+
+        for (i, step) in self.steps.iter().enumerate() {
+            for level in step.inner.linked_prev_steps() {
+                if self.step_index_by_level(level).is_none() {
+                    res.unknown_prev_steps.push((i, level));
+                }
+            }
+        }
+
+        res.finish_not_linked = match self.step_index_by_level(self.last_finish_step_id) {
+            Some(finish) => !edges.iter().any(|v| v.1 == finish),
+            None => true,
+        };
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::entity_editor::WindowParams;
+    use crate::entity::quest::QuestStep;
+
+    const FINISH: u32 = u32::MAX - 1;
+
+    /**Steps as `(level, prev_steps)`, finish step level is [`FINISH`]
+     */
+    fn quest(steps: &[(u32, &[u32])]) -> Quest {
+        Quest {
+            steps: steps
+                .iter()
+                .map(|(level, prev_steps)| WindowParams {
+                    inner: QuestStep {
+                        level: *level,
+                        prev_steps: prev_steps.to_vec(),
+                        ..Default::default()
+                    },
+                    opened: false,
+                    initial_id: (),
+                    action: Default::default(),
+                    params: (),
+                })
+                .collect(),
+            last_finish_step_id: FINISH,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn linked_chain_has_no_issues() {
+        let quest = quest(&[(1, &[0]), (2, &[1]), (3, &[2, 1]), (FINISH, &[3])]);
+
+        assert!(quest.step_graph_issues().is_empty());
+        assert_eq!(quest.step_edges(), [(0, 1), (1, 2), (0, 2), (2, 3)]);
+    }
+
+    #[test]
+    fn finds_cycle() {
+        let issues = quest(&[
+            (1, &[0]),
+            (2, &[1, 4]),
+            (3, &[2]),
+            (4, &[3]),
+            (FINISH, &[4]),
+        ])
+        .step_graph_issues();
+
+        assert_eq!(issues.in_cycle, [1, 2, 3]);
+        assert!(issues.unreachable.is_empty());
+        assert!(!issues.finish_not_linked);
+    }
+
+    #[test]
+    fn finds_steps_unreachable_from_start() {
+        let issues = quest(&[
+            (1, &[0]),
+            (2, &[0]),
+            (3, &[2]),
+            (4, &[5]),
+            (5, &[4]),
+            (FINISH, &[1, 3]),
+        ])
+        .step_graph_issues();
+
+        assert_eq!(issues.unreachable, [3, 4]);
+        assert_eq!(issues.in_cycle, [3, 4]);
+        assert!(issues.unknown_prev_steps.is_empty());
+        assert!(!issues.finish_not_linked);
+    }
+
+    #[test]
+    fn every_start_branch_is_reachable() {
+        let issues =
+            quest(&[(1, &[0]), (2, &[]), (3, &[2]), (FINISH, &[1, 3])]).step_graph_issues();
+
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn finds_unknown_prev_steps() {
+        let issues = quest(&[(1, &[0]), (2, &[1, 7]), (FINISH, &[2])]).step_graph_issues();
+
+        assert_eq!(issues.unknown_prev_steps, [(1, 7)]);
+        assert!(issues.unreachable.is_empty());
+    }
+
+    #[test]
+    fn finds_not_linked_finish() {
+        assert!(
+            quest(&[(1, &[0]), (FINISH, &[])])
+                .step_graph_issues()
+                .finish_not_linked
+        );
+        assert!(
+            quest(&[(1, &[0]), (2, &[1])])
+                .step_graph_issues()
+                .finish_not_linked
+        );
+    }
+
+    #[test]
+    fn level_zero_is_quest_start_everywhere() {
+        let mut quest = quest(&[(0, &[]), (1, &[0]), (FINISH, &[1])]);
+
+        assert_eq!(quest.step_edges(), [(1, 2)]);
+        assert!(quest.step_graph_issues().unknown_prev_steps.is_empty());
+        assert!(quest.step_graph_issues().unreachable.is_empty());
+
+        quest.link_steps(0, 1);
+
+        assert_eq!(quest.steps[1].inner.prev_steps, [0]);
+
+        quest.link_steps(1, 2);
+        quest.unlink_steps(1, 2);
+        quest.link_steps(2, 1);
+
+        assert!(quest.steps[2].inner.prev_steps.is_empty());
+        assert_eq!(quest.steps[1].inner.prev_steps, [0, FINISH]);
+        assert!(quest.step_graph_issues().finish_not_linked);
+    }
+}
@@ -6,7 +6,7 @@ use crate::backend::holder::{FHashMap, HolderMapOps};
 use crate::backend::{Backend, HandleAction};
 use crate::data::RaidInfoId;
 use crate::entity::raid_info::RaidInfo;
-use crate::entity::CommonEntity;
+use crate::entity::{CommonEntity, EntityT};
 
 pub type RaidInfoEditor = EntityEditParams<RaidInfo, RaidInfoId, (), ()>;
 
@@ -79,13 +79,15 @@ impl Backend {
         }
         v._changed = true;
 
+        let entity = EntityT::RaidInfo(v.id);
+
         self.holders
             .game_data_holder
             .raid_info_holder
             .insert(v.id, v);
 
         self.filter_raid_info();
-        self.check_for_unwrote_changed();
+        self.check_for_unwrote_changed(entity);
     }
 }
 
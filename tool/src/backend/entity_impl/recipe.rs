@@ -6,7 +6,7 @@ use crate::backend::holder::{FHashMap, HolderMapOps};
 use crate::backend::{Backend, HandleAction};
 use crate::data::RecipeId;
 use crate::entity::recipe::Recipe;
-use crate::entity::CommonEntity;
+use crate::entity::{CommonEntity, EntityT};
 use serde::{Deserialize, Serialize};
 
 pub type RecipeEditor = EntityEditParams<Recipe, RecipeId, RecipeAction, ()>;
@@ -100,10 +100,12 @@ impl Backend {
         }
         v._changed = true;
 
+        let entity = EntityT::Recipe(v.id);
+
         self.holders.game_data_holder.recipe_holder.insert(v.id, v);
 
         self.filter_recipes();
-        self.check_for_unwrote_changed();
+        self.check_for_unwrote_changed(entity);
     }
 }
 
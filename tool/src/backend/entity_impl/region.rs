@@ -6,7 +6,7 @@ use crate::backend::holder::{FHashMap, HolderMapOps};
 use crate::backend::{Backend, HandleAction};
 use crate::data::RegionId;
 use crate::entity::region::Region;
-use crate::entity::CommonEntity;
+use crate::entity::{CommonEntity, EntityT};
 
 pub type RegionEditor = EntityEditParams<Region, RegionId, (), ()>;
 
@@ -78,10 +78,12 @@ impl Backend {
         }
         v._changed = true;
 
+        let entity = EntityT::Region(v.id);
+
         self.holders.game_data_holder.region_holder.insert(v.id, v);
 
         self.filter_regions();
-        self.check_for_unwrote_changed();
+        self.check_for_unwrote_changed(entity);
     }
 }
 
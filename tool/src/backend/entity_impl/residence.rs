@@ -5,7 +5,7 @@ use crate::backend::entity_editor::{
 use crate::backend::holder::{FHashMap, HolderMapOps};
 use crate::backend::{Backend, HandleAction};
 use crate::data::ResidenceId;
-use crate::entity::CommonEntity;
+use crate::entity::{CommonEntity, EntityT};
 use serde::{Deserialize, Serialize};
 use crate::entity::residence::Residence;
 
@@ -91,10 +91,12 @@ impl Backend {
         }
         v._changed = true;
 
+        let entity = EntityT::Residence(v.id);
+
         self.holders.game_data_holder.residence_holder.insert(v.id, v);
 
         self.filter_residences();
-        self.check_for_unwrote_changed();
+        self.check_for_unwrote_changed(entity);
     }
 }
 
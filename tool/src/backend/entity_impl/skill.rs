@@ -6,6 +6,7 @@ use crate::backend::holder::{FHashMap, HolderMapOps};
 use crate::backend::{Backend, HandleAction};
 use crate::data::SkillId;
 use crate::entity::skill::{EnchantInfo, EnchantLevelInfo, Skill, SkillLevelInfo};
+use crate::entity::EntityT;
 use serde::{Deserialize, Serialize};
 use std::sync::RwLock;
 
@@ -277,10 +278,12 @@ impl Backend {
         }
         v._changed = true;
 
+        let entity = EntityT::Skill(v.id);
+
         self.holders.game_data_holder.skill_holder.insert(v.id, v);
 
         self.filter_skills();
-        self.check_for_unwrote_changed();
+        self.check_for_unwrote_changed(entity);
     }
 }
 
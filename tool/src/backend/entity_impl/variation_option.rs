@@ -0,0 +1,149 @@
+use crate::backend::entity_catalog::EntityInfo;
+use crate::backend::entity_editor::{
+    CommonEditorOps, CurrentEntity, EditParams, EditParamsCommonOps, EntityEditParams, WindowParams,
+};
+use crate::backend::holder::{FHashMap, GameDataHolder, HolderMapOps};
+use crate::backend::{Backend, HandleAction};
+use crate::data::VariationOptionId;
+use crate::entity::variation_option::VariationOption;
+use crate::entity::{CommonEntity, EntityT};
+use serde::{Deserialize, Serialize};
+
+pub type VariationOptionEditor =
+    EntityEditParams<VariationOption, VariationOptionId, VariationOptionAction, ()>;
+
+impl HandleAction for WindowParams<VariationOption, VariationOptionId, VariationOptionAction, ()> {
+    fn handle_action(&mut self) {
+        let item = self;
+
+        let mut action = item.action.write().unwrap();
+
+        *action = VariationOptionAction::None;
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Default)]
+pub enum VariationOptionAction {
+    #[default]
+    None,
+}
+
+impl EditParams {
+    pub fn get_opened_variation_options_info(&self) -> Vec<(String, VariationOptionId, bool)> {
+        self.variation_options.get_opened_info()
+    }
+
+    pub fn open_variation_option(
+        &mut self,
+        id: VariationOptionId,
+        holder: &mut FHashMap<VariationOptionId, VariationOption>,
+    ) {
+        for (i, q) in self.variation_options.opened.iter().enumerate() {
+            if q.inner.initial_id == id {
+                self.current_entity = CurrentEntity::VariationOption(i);
+
+                return;
+            }
+        }
+
+        if let Some(q) = holder.get(&id) {
+            self.current_entity = CurrentEntity::VariationOption(self.variation_options.add(
+                q.clone(),
+                q.id(),
+                false,
+            ));
+        }
+    }
+
+    pub fn set_current_variation_option(&mut self, index: usize) {
+        if index < self.variation_options.opened.len() {
+            self.current_entity = CurrentEntity::VariationOption(index);
+        }
+    }
+
+    pub fn create_new_variation_option(&mut self) {
+        self.current_entity = CurrentEntity::VariationOption(self.variation_options.add_new());
+    }
+}
+
+impl Backend {
+    pub fn filter_variation_options(&mut self) {
+        self.entity_catalogs.variation_option.filter(
+            &self.holders.game_data_holder.variation_option_holder,
+            self.entity_catalogs.filter_mode,
+        );
+    }
+
+    pub fn save_variation_option_from_dlg(&mut self, id: VariationOptionId) {
+        if let CurrentEntity::VariationOption(index) = self.edit_params.current_entity {
+            let new_entity = self
+                .edit_params
+                .variation_options
+                .opened
+                .get_mut(index)
+                .unwrap();
+
+            if new_entity.inner.inner.id() != id {
+                return;
+            }
+
+            new_entity.inner.initial_id = new_entity.inner.inner.id;
+
+            let entity = new_entity.inner.inner.clone();
+
+            new_entity.on_save();
+
+            self.save_variation_option_force(entity);
+        }
+    }
+
+    pub(crate) fn save_variation_option_force(&mut self, mut v: VariationOption) {
+        if let Some(vv) = self
+            .holders
+            .game_data_holder
+            .variation_option_holder
+            .get(&v.id)
+        {
+            if *vv == v {
+                return;
+            }
+        }
+        v._changed = true;
+
+        let entity = EntityT::VariationOption(v.id);
+
+        self.holders
+            .game_data_holder
+            .variation_option_holder
+            .insert(v.id, v);
+
+        self.filter_variation_options();
+        self.check_for_unwrote_changed(entity);
+    }
+}
+
+impl GameDataHolder {
+    /**Name and description of the skill option gives, level ones are preferred over the base
+    ones. `None` if skill is not in [`GameDataHolder::skill_holder`]
+     */
+    pub fn variation_option_skill_text(&self, option: &VariationOption) -> Option<String> {
+        let skill = self.skill_holder.get(&option.skill_id)?;
+        let level = skill
+            .skill_levels
+            .iter()
+            .find(|v| v.level == option.skill_level);
+
+        let name = level.and_then(|v| v.name.as_ref()).unwrap_or(&skill.name);
+        let desc = level
+            .and_then(|v| v.description.as_ref())
+            .unwrap_or(&skill.description);
+
+        Some(format!("{name} Lv. {}\n{desc}", option.skill_level))
+    }
+}
+
+impl From<&VariationOption> for EntityInfo<VariationOption, VariationOptionId> {
+    fn from(value: &VariationOption) -> Self {
+        EntityInfo::new(&format!("ID: {}\n{}", value.id.0, value.name()), value)
+    }
+}
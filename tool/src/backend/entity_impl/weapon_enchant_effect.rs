@@ -0,0 +1,139 @@
+use crate::backend::entity_catalog::EntityInfo;
+use crate::backend::entity_editor::{
+    CommonEditorOps, CurrentEntity, EditParams, EditParamsCommonOps, EntityEditParams, WindowParams,
+};
+use crate::backend::holder::{FHashMap, HolderMapOps};
+use crate::backend::{Backend, HandleAction};
+use crate::data::WeaponEnchantEffectId;
+use crate::entity::weapon_enchant_effect::WeaponEnchantEffect;
+use crate::entity::{CommonEntity, EntityT};
+use serde::{Deserialize, Serialize};
+
+pub type WeaponEnchantEffectEditor =
+    EntityEditParams<WeaponEnchantEffect, WeaponEnchantEffectId, EnchantEffectAction, ()>;
+
+impl HandleAction
+    for WindowParams<WeaponEnchantEffect, WeaponEnchantEffectId, EnchantEffectAction, ()>
+{
+    fn handle_action(&mut self) {
+        let item = self;
+
+        let mut action = item.action.write().unwrap();
+
+        if let EnchantEffectAction::RemoveLevel(i) = *action {
+            item.inner.levels.remove(i);
+        }
+
+        *action = EnchantEffectAction::None;
+    }
+}
+
+/**Shared by weapon and armor enchant effect editors
+ */
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Default)]
+pub enum EnchantEffectAction {
+    #[default]
+    None,
+    RemoveLevel(usize),
+}
+
+impl EditParams {
+    pub fn get_opened_weapon_enchant_effects_info(
+        &self,
+    ) -> Vec<(String, WeaponEnchantEffectId, bool)> {
+        self.weapon_enchant_effects.get_opened_info()
+    }
+
+    pub fn open_weapon_enchant_effect(
+        &mut self,
+        id: WeaponEnchantEffectId,
+        holder: &mut FHashMap<WeaponEnchantEffectId, WeaponEnchantEffect>,
+    ) {
+        for (i, q) in self.weapon_enchant_effects.opened.iter().enumerate() {
+            if q.inner.initial_id == id {
+                self.current_entity = CurrentEntity::WeaponEnchantEffect(i);
+
+                return;
+            }
+        }
+
+        if let Some(q) = holder.get(&id) {
+            self.current_entity = CurrentEntity::WeaponEnchantEffect(
+                self.weapon_enchant_effects.add(q.clone(), q.id(), false),
+            );
+        }
+    }
+
+    pub fn set_current_weapon_enchant_effect(&mut self, index: usize) {
+        if index < self.weapon_enchant_effects.opened.len() {
+            self.current_entity = CurrentEntity::WeaponEnchantEffect(index);
+        }
+    }
+
+    pub fn create_new_weapon_enchant_effect(&mut self) {
+        self.current_entity =
+            CurrentEntity::WeaponEnchantEffect(self.weapon_enchant_effects.add_new());
+    }
+}
+
+impl Backend {
+    pub fn filter_weapon_enchant_effects(&mut self) {
+        self.entity_catalogs.weapon_enchant_effect.filter(
+            &self.holders.game_data_holder.weapon_enchant_effect_holder,
+            self.entity_catalogs.filter_mode,
+        );
+    }
+
+    pub fn save_weapon_enchant_effect_from_dlg(&mut self, id: WeaponEnchantEffectId) {
+        if let CurrentEntity::WeaponEnchantEffect(index) = self.edit_params.current_entity {
+            let new_entity = self
+                .edit_params
+                .weapon_enchant_effects
+                .opened
+                .get_mut(index)
+                .unwrap();
+
+            if new_entity.inner.inner.id() != id {
+                return;
+            }
+
+            new_entity.inner.initial_id = new_entity.inner.inner.id;
+
+            let entity = new_entity.inner.inner.clone();
+
+            new_entity.on_save();
+
+            self.save_weapon_enchant_effect_force(entity);
+        }
+    }
+
+    pub(crate) fn save_weapon_enchant_effect_force(&mut self, mut v: WeaponEnchantEffect) {
+        if let Some(vv) = self
+            .holders
+            .game_data_holder
+            .weapon_enchant_effect_holder
+            .get(&v.id)
+        {
+            if *vv == v {
+                return;
+            }
+        }
+        v._changed = true;
+
+        let entity = EntityT::WeaponEnchantEffect(v.id);
+
+        self.holders
+            .game_data_holder
+            .weapon_enchant_effect_holder
+            .insert(v.id, v);
+
+        self.filter_weapon_enchant_effects();
+        self.check_for_unwrote_changed(entity);
+    }
+}
+
+impl From<&WeaponEnchantEffect> for EntityInfo<WeaponEnchantEffect, WeaponEnchantEffectId> {
+    fn from(value: &WeaponEnchantEffect) -> Self {
+        EntityInfo::new(&format!("ID: {}\n{}", value.id.0, value.name()), value)
+    }
+}
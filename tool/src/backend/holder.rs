@@ -1,10 +1,13 @@
-use crate::backend::dat_loader::L2StringTable;
+use crate::backend::dat_loader::{AnyTranslationRows, Chronicle, DecodedDat, L2StringTable};
 use crate::backend::entity_editor::WindowParams;
+use crate::backend::references::ReferenceIndex;
+use crate::backend::server_side::stats::ServerStatsHolder;
 use crate::backend::server_side::ServerDataHolder;
 use crate::backend::Config;
-use crate::data::{AnimationComboId, DailyMissionId, HuntingZoneId, ItemId, ItemSetId, NpcId, QuestId, RaidInfoId, RecipeId, RegionId, ResidenceId, SkillId};
+use crate::data::{AnimationComboId, DailyMissionId, EnsoulOptionId, GameStringId, HuntingZoneId, InstantZoneId, ItemId, ItemSetId, NpcId, QuestId, RaidInfoId, RecipeId, RegionId, ResidenceId, SetEnchantEffectId, SkillId, VariationOptionId, WeaponEnchantEffectId};
 use crate::entity::animation_combo::AnimationCombo;
 use crate::entity::daily_mission::DailyMission;
+use crate::entity::game_string::GameString;
 use crate::entity::hunting_zone::HuntingZone;
 use crate::entity::item::armor::Armor;
 use crate::entity::item::etc_item::EtcItem;
@@ -17,7 +20,7 @@ use crate::entity::raid_info::RaidInfo;
 use crate::entity::recipe::Recipe;
 use crate::entity::region::Region;
 use crate::entity::skill::Skill;
-use crate::entity::{CommonEntity, Entity};
+use crate::entity::{CommonEntity, Entity, EntityT};
 use std::collections::hash_map::{Keys, Values, ValuesMut};
 use std::collections::HashMap;
 use std::fs::File;
@@ -29,10 +32,25 @@ use std::sync::RwLock;
 use strum::IntoEnumIterator;
 use walkdir::DirEntry;
 use crate::entity::residence::Residence;
+use crate::entity::weapon_enchant_effect::WeaponEnchantEffect;
+use crate::entity::armor_enchant_effect::ArmorEnchantEffect;
+use crate::entity::ensoul_option::EnsoulOption;
+use crate::entity::variation_option::VariationOption;
+use crate::entity::instant_zone::InstantZone;
+use l2_rw::DatEnvelope;
 
 #[derive(Default)]
 pub struct GameDataHolder {
     pub dat_paths: HashMap<String, DirEntry>,
+    pub dat_envelopes: HashMap<String, DatEnvelope>,
+    pub decoded_dats: HashMap<String, Result<DecodedDat, l2_rw::Error>>,
+    /**Rows of translation dats by file name, rows entities don't keep are saved back from here
+     */
+    pub translation_rows: HashMap<String, AnyTranslationRows>,
+    pub chronicle: Chronicle,
+    /**Suffixes of localized dats, first one is the main locale
+     */
+    pub locales: Vec<String>,
 
     pub npc_holder: FHashMap<NpcId, Npc>,
     pub quest_holder: FHashMap<QuestId, Quest>,
@@ -48,9 +66,14 @@ pub struct GameDataHolder {
     pub daily_mission_holder: FHashMap<DailyMissionId, DailyMission>,
     pub animation_combo_holder: FDHashMap<AnimationComboId, AnimationCombo>,
     pub residence_holder: FHashMap<ResidenceId, Residence>,
+    pub game_string_holder: FHashMap<GameStringId, GameString>,
+    pub weapon_enchant_effect_holder: FHashMap<WeaponEnchantEffectId, WeaponEnchantEffect>,
+    pub armor_enchant_effect_holder: FHashMap<SetEnchantEffectId, ArmorEnchantEffect>,
+    pub ensoul_option_holder: FHashMap<EnsoulOptionId, EnsoulOption>,
+    pub variation_option_holder: FHashMap<VariationOptionId, VariationOption>,
+    pub instant_zone_holder: FHashMap<InstantZoneId, InstantZone>,
 
     pub item_holder: HashMap<ItemId, Item>,
-    pub npc_strings: FHashMap<u32, String>,
     pub game_string_table: L2GeneralStringTable,
 }
 
@@ -73,6 +96,12 @@ impl Index<Entity> for GameDataHolder {
             Entity::DailyMission => &self.daily_mission_holder,
             Entity::AnimationCombo => &self.animation_combo_holder,
             Entity::Residence => &self.residence_holder,
+            Entity::GameString => &self.game_string_holder,
+            Entity::WeaponEnchantEffect => &self.weapon_enchant_effect_holder,
+            Entity::ArmorEnchantEffect => &self.armor_enchant_effect_holder,
+            Entity::EnsoulOption => &self.ensoul_option_holder,
+            Entity::VariationOption => &self.variation_option_holder,
+            Entity::InstantZone => &self.instant_zone_holder,
         }
     }
 }
@@ -94,6 +123,12 @@ impl IndexMut<Entity> for GameDataHolder{
             Entity::DailyMission => &mut self.daily_mission_holder,
             Entity::AnimationCombo => &mut self.animation_combo_holder,
             Entity::Residence => &mut self.residence_holder,
+            Entity::GameString => &mut self.game_string_holder,
+            Entity::WeaponEnchantEffect => &mut self.weapon_enchant_effect_holder,
+            Entity::ArmorEnchantEffect => &mut self.armor_enchant_effect_holder,
+            Entity::EnsoulOption => &mut self.ensoul_option_holder,
+            Entity::VariationOption => &mut self.variation_option_holder,
+            Entity::InstantZone => &mut self.instant_zone_holder,
         }
     }
 }
@@ -104,7 +139,6 @@ impl GameDataHolder {
             self[e].set_changed(false)
         }
 
-        self.npc_strings.set_changed(false);
         self.game_string_table.set_changed(false);
     }
 
@@ -391,6 +425,36 @@ impl L2GeneralStringTable {
         self.was_changed = val;
     }
 
+    pub fn next_index(&self) -> u32 {
+        self.next_index
+    }
+
+    /**Replaces string at `index`, or appends it if `index` is the next one. Strings can't be
+    added with gaps, index is their position in the dat. Returns `false` if nothing was set
+     */
+    pub fn set(&mut self, index: u32, value: String) -> bool {
+        if index == self.next_index {
+            self.add(value);
+        } else if let Some(old) = self.inner.get(&index) {
+            let old = old.to_lowercase();
+
+            if self.reverse_map.get(&old) == Some(&index) {
+                self.reverse_map.remove(&old);
+            }
+
+            self.reverse_map
+                .entry(value.to_lowercase())
+                .or_insert(index);
+            self.inner.insert(index, value);
+        } else {
+            return false;
+        }
+
+        self.was_changed = true;
+
+        true
+    }
+
     pub(crate) fn to_vec(&self) -> Vec<String> {
         let mut k: Vec<_> = self.keys().collect();
         k.sort();
@@ -483,6 +547,12 @@ impl Index<&u32> for L2GeneralStringTable {
 pub struct DataHolder {
     pub game_data_holder: GameDataHolder,
     pub server_data_holder: ServerDataHolder,
+    pub server_stats_holder: ServerStatsHolder,
+    /**Entity clicked in editor of another one, opened by [`crate::backend::Backend`] on next
+    update
+     */
+    pub entity_link: Option<EntityT>,
+    pub references: ReferenceIndex,
 }
 
 impl DataHolder {
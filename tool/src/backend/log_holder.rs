@@ -1,3 +1,4 @@
+use crate::entity::EntityT;
 use crate::logs_mut;
 use std::collections::HashSet;
 use strum_macros::{Display, EnumIter};
@@ -75,6 +76,16 @@ pub struct Log {
     pub level: LogLevel,
     pub producer: String,
     pub log: String,
+    /**Shown as link button next to the message
+     */
+    pub entity: Option<EntityT>,
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub enum LogHolderAction {
+    #[default]
+    None,
+    OpenEntity(EntityT),
 }
 
 pub struct LogHolderParams {
@@ -4,23 +4,30 @@ pub mod entity_editor;
 pub mod entity_impl;
 pub mod holder;
 pub mod log_holder;
+pub mod references;
 pub mod server_side;
+pub mod table;
 mod util;
 
 use crate::backend::holder::{DataHolder, GameDataHolder, HolderMapOps};
-use crate::backend::server_side::ServerDataHolder;
-use crate::data::{AnimationComboId, DailyMissionId, HuntingZoneId, ItemId, ItemSetId, NpcId, QuestId, RaidInfoId, RecipeId, RegionId, ResidenceId, SkillId};
-use crate::entity::{CommonEntity, Entity};
-use crate::logs_mut;
-use dat_loader::load_game_data_holder;
+use crate::backend::server_side::stats::{skeleton_xml, ServerStatsHolder};
+use crate::backend::server_side::{ServerDataHolder, ServerEmulator};
+use crate::data::{AnimationComboId, DailyMissionId, EnsoulOptionId, GameStringId, HuntingZoneId, InstantZoneId, ItemId, ItemSetId, NpcId, QuestId, RaidInfoId, RecipeId, RegionId, ResidenceId, SetEnchantEffectId, SkillId, StringTable, VariationOptionId, WeaponEnchantEffectId};
+use crate::entity::{CommonEntity, Entity, EntityT};
+use crate::frontend::IS_VERIFYING;
+use crate::{log_multiple, logs_mut};
+use dat_loader::{load_game_data_holder, Chronicle};
 use dat_loader::DatLoader;
 use entity_catalog::EntityCatalogsHolder;
 use entity_editor::{CurrentEntity, EditParams, EditParamsCommonOps, WindowParams};
-use log_holder::LogHolderParams;
+use log_holder::{Log, LogHolderAction, LogHolderParams, LogLevel};
+use references::{dangling_summary, ReferenceIndex};
 use serde::{Deserialize, Serialize};
 use std::fs::File;
 use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::Ordering;
+use std::thread;
 use std::time::{Duration, SystemTime};
 use strum::IntoEnumIterator;
 
@@ -40,7 +47,7 @@ pub struct Backend {
 
     has_unwrote_changes: bool,
 
-    pub logs: WindowParams<LogHolderParams, (), (), ()>,
+    pub logs: WindowParams<LogHolderParams, (), LogHolderAction, ()>,
 
     tasks: Tasks,
 }
@@ -49,16 +56,31 @@ impl Backend {
     pub fn init() -> Self {
         let config = Self::load_config();
 
-        let (game_data_holder, warnings) = if let Some(path) = &config.system_folder_path {
-            load_game_data_holder(path).unwrap()
+        let (game_data_holder, mut warnings) = if let Some(path) = &config.system_folder_path {
+            match load_game_data_holder(path, &config.locales, config.chronicle) {
+                Ok(v) => v,
+                Err(e) => (GameDataHolder::default(), vec![e]),
+            }
         } else {
             (GameDataHolder::default(), vec![])
         };
 
         let server_data_holder = if let Some(path) = &config.server_quests_java_classes_path {
-            ServerDataHolder::load(path)
+            ServerDataHolder::load(path, config.server_emulator)
+        } else {
+            ServerDataHolder {
+                emulator: config.server_emulator,
+                ..Default::default()
+            }
+        };
+
+        let server_stats_holder = if let Some(path) = &config.server_stats_folder_path {
+            let (holder, stats_warnings) = ServerStatsHolder::load(path);
+            warnings.extend(stats_warnings);
+
+            holder
         } else {
-            ServerDataHolder::default()
+            ServerStatsHolder::default()
         };
 
         let edit_params = if let Ok(f) = File::open(format!("./v{VERSION}.asave")) {
@@ -83,6 +105,9 @@ impl Backend {
             holders: DataHolder {
                 game_data_holder,
                 server_data_holder,
+                server_stats_holder,
+                entity_link: None,
+                references: ReferenceIndex::default(),
             },
             entity_catalogs: EntityCatalogsHolder::new(),
             dialog: Dialog::None,
@@ -96,16 +121,115 @@ impl Backend {
         };
 
         r.update_last_ids();
+        r.update_references();
 
         r
     }
 
+    /**Asks for confirmation if there are dangling references. They are only summed up in the
+    dialog, [`Backend::validate_references`] logs each of them
+     */
     pub fn save_to_dat(&mut self) {
+        let dangling = self.holders.game_data_holder.dangling_references();
+
+        if !dangling.is_empty() {
+            self.show_dialog(Dialog::ConfirmDatSave {
+                message: format!(
+                    "{}.\nValidate references to see them.\nSave anyway?",
+                    dangling_summary(&dangling)
+                ),
+            });
+
+            return;
+        }
+
+        self.write_dats();
+    }
+
+    fn write_dats(&mut self) {
         self.holders.game_data_holder.save_to_binary().unwrap();
 
         self.set_unchanged();
     }
 
+    /**Logs every reference to missing or deleted entity with link to the referencing one.
+    Returns their count
+     */
+    pub fn validate_references(&self) -> usize {
+        let holder = &self.holders.game_data_holder;
+        let dangling = holder.dangling_references();
+
+        let mut logs = logs_mut();
+
+        for v in &dangling {
+            logs.add(Log {
+                level: LogLevel::Warning,
+                producer: "Validator".to_string(),
+                log: format!(
+                    "{} - {} references missing {}",
+                    holder.entity_title(v.from),
+                    v.field,
+                    v.to
+                ),
+                entity: Some(v.from),
+            });
+        }
+
+        logs.add(Log {
+            level: if dangling.is_empty() {
+                LogLevel::Info
+            } else {
+                LogLevel::Warning
+            },
+            producer: "Validator".to_string(),
+            log: format!("{} dangling references found", dangling.len()),
+            entity: None,
+        });
+
+        dangling.len()
+    }
+
+    /**Serializes loaded dats into memory and logs which of them differ from files when done,
+    see [`GameDataHolder::verify_dats`]
+     */
+    pub fn verify_dats(&mut self) {
+        if self.is_changed() {
+            self.show_dialog(Dialog::ShowWarning(
+                "Save or discard changes before verifying dats".to_string(),
+            ));
+
+            return;
+        }
+
+        if IS_VERIFYING.swap(true, Ordering::Relaxed) {
+            return;
+        }
+
+        let handle = self.holders.game_data_holder.verify_dats();
+
+        thread::spawn(move || {
+            let checks = handle.join().unwrap();
+            let differ = checks.iter().filter(|v| !v.is_same()).count();
+
+            let mut logs: Vec<Log> = checks.iter().map(|v| v.into()).collect();
+
+            logs.push(Log {
+                level: if differ == 0 {
+                    LogLevel::Info
+                } else {
+                    LogLevel::Warning
+                },
+                producer: "Dat Loader".to_string(),
+                log: format!("Verified {} dats, {differ} differ from files", checks.len()),
+                entity: None,
+            });
+
+            log_multiple(logs);
+
+            IS_VERIFYING.store(false, Ordering::Relaxed);
+        });
+    }
+
     fn load_config() -> Config {
         let config_path = Path::new(CONFIG_FILE_NAME);
         if let Ok(mut f) = File::open(config_path) {
@@ -144,6 +268,18 @@ impl Backend {
             CurrentEntity::DailyMission(i) => Some(&self.edit_params.daily_mission.opened[i]),
             CurrentEntity::AnimationCombo(i) => Some(&self.edit_params.animation_combo.opened[i]),
             CurrentEntity::Residence(i) => Some(&self.edit_params.residences.opened[i]),
+            CurrentEntity::GameString(i) => Some(&self.edit_params.game_strings.opened[i]),
+            CurrentEntity::WeaponEnchantEffect(i) => {
+                Some(&self.edit_params.weapon_enchant_effects.opened[i])
+            }
+            CurrentEntity::ArmorEnchantEffect(i) => {
+                Some(&self.edit_params.armor_enchant_effects.opened[i])
+            }
+            CurrentEntity::EnsoulOption(i) => Some(&self.edit_params.ensoul_options.opened[i]),
+            CurrentEntity::VariationOption(i) => {
+                Some(&self.edit_params.variation_options.opened[i])
+            }
+            CurrentEntity::InstantZone(i) => Some(&self.edit_params.instant_zones.opened[i]),
 
             CurrentEntity::None => None,
         }
@@ -164,6 +300,18 @@ impl Backend {
             CurrentEntity::DailyMission(i) => Some(&mut self.edit_params.daily_mission.opened[i]),
             CurrentEntity::AnimationCombo(i) => Some(&mut self.edit_params.animation_combo.opened[i]),
             CurrentEntity::Residence(i) => Some(&mut self.edit_params.residences.opened[i]),
+            CurrentEntity::GameString(i) => Some(&mut self.edit_params.game_strings.opened[i]),
+            CurrentEntity::WeaponEnchantEffect(i) => {
+                Some(&mut self.edit_params.weapon_enchant_effects.opened[i])
+            }
+            CurrentEntity::ArmorEnchantEffect(i) => {
+                Some(&mut self.edit_params.armor_enchant_effects.opened[i])
+            }
+            CurrentEntity::EnsoulOption(i) => Some(&mut self.edit_params.ensoul_options.opened[i]),
+            CurrentEntity::VariationOption(i) => {
+                Some(&mut self.edit_params.variation_options.opened[i])
+            }
+            CurrentEntity::InstantZone(i) => Some(&mut self.edit_params.instant_zones.opened[i]),
 
             CurrentEntity::None => None,
         }
@@ -212,6 +360,24 @@ impl Backend {
         self.entity_catalogs.residence.filter = "".to_string();
         self.filter_residences();
 
+        self.entity_catalogs.game_string.filter = "".to_string();
+        self.filter_game_strings();
+
+        self.entity_catalogs.weapon_enchant_effect.filter = "".to_string();
+        self.filter_weapon_enchant_effects();
+
+        self.entity_catalogs.armor_enchant_effect.filter = "".to_string();
+        self.filter_armor_enchant_effects();
+
+        self.entity_catalogs.ensoul_option.filter = "".to_string();
+        self.filter_ensoul_options();
+
+        self.entity_catalogs.variation_option.filter = "".to_string();
+        self.filter_variation_options();
+
+        self.entity_catalogs.instant_zone.filter = "".to_string();
+        self.filter_instant_zones();
+
         self.edit_params.quests.next_id =
             if let Some(last) = self.entity_catalogs.quest.catalog.last() {
                 last.id.0 + 1
@@ -298,6 +464,51 @@ impl Backend {
             } else {
                 0
             };
+
+        self.edit_params.game_strings.next_id = self
+            .entity_catalogs
+            .game_string
+            .catalog
+            .iter()
+            .filter(|v| v.id.table == StringTable::NpcString)
+            .map(|v| v.id.id + 1)
+            .max()
+            .unwrap_or(0);
+
+        self.edit_params.weapon_enchant_effects.next_id =
+            if let Some(last) = self.entity_catalogs.weapon_enchant_effect.catalog.last() {
+                last.id.0 + 1
+            } else {
+                0
+            };
+
+        self.edit_params.armor_enchant_effects.next_id =
+            if let Some(last) = self.entity_catalogs.armor_enchant_effect.catalog.last() {
+                last.id.0 as u32 + 1
+            } else {
+                0
+            };
+
+        self.edit_params.ensoul_options.next_id =
+            if let Some(last) = self.entity_catalogs.ensoul_option.catalog.last() {
+                last.id.0 + 1
+            } else {
+                0
+            };
+
+        self.edit_params.variation_options.next_id =
+            if let Some(last) = self.entity_catalogs.variation_option.catalog.last() {
+                last.id.0 + 1
+            } else {
+                0
+            };
+
+        self.edit_params.instant_zones.next_id =
+            if let Some(last) = self.entity_catalogs.instant_zone.catalog.last() {
+                last.id.0 + 1
+            } else {
+                0
+            };
     }
 
     pub fn auto_save(&mut self, force: bool) {
@@ -340,8 +551,25 @@ impl Backend {
         }
     }
 
+    fn open_entity_link(&mut self) {
+        {
+            let mut action = self.logs.action.write().unwrap();
+
+            if let LogHolderAction::OpenEntity(v) = *action {
+                self.holders.entity_link = Some(v);
+            }
+
+            *action = LogHolderAction::None;
+        }
+
+        if let Some(v) = self.holders.entity_link.take() {
+            self.edit_params.open_entity(v, &mut self.holders.game_data_holder);
+        }
+    }
+
     pub fn on_update(&mut self) {
         self.proceed_actions();
+        self.open_entity_link();
         self.logs.inner.sync();
         self.auto_save(false);
         self.check_change();
@@ -351,21 +579,69 @@ impl Backend {
         if path.is_dir() {
             let path = path.to_str().unwrap().to_string();
 
-            if let Ok((h, w)) = load_game_data_holder(&path) {
-                self.holders.game_data_holder = h;
+            match load_game_data_holder(&path, &self.config.locales, self.config.chronicle) {
+                Ok((h, w)) => {
+                    self.holders.game_data_holder = h;
+
+                    self.edit_params.current_entity = CurrentEntity::None;
 
-                self.edit_params.current_entity = CurrentEntity::None;
+                    logs_mut().reset(w);
 
-                logs_mut().reset(w);
+                    self.update_last_ids();
+                    self.update_references();
 
-                self.update_last_ids();
+                    self.config.system_folder_path = Some(path);
+                    self.config.dump();
+                }
 
-                self.config.system_folder_path = Some(path);
-                self.config.dump();
+                Err(e) => logs_mut().add(e),
             }
         }
     }
 
+    /**Reloads dats with `locale` edited in entity fields, other loaded locales stay as
+    translations
+     */
+    pub fn update_main_locale(&mut self, locale: String) {
+        if self.is_changed() {
+            self.show_dialog(Dialog::ShowWarning(
+                "Save or discard changes before switching locale".to_string(),
+            ));
+
+            return;
+        }
+
+        let mut locales = self.holders.game_data_holder.locales.clone();
+        locales.retain(|v| *v != locale);
+        locales.insert(0, locale);
+
+        self.config.locales = locales;
+
+        if let Some(path) = self.config.system_folder_path.clone() {
+            self.update_system_path(PathBuf::from(path));
+        }
+    }
+
+    /**Reloads dats as `chronicle`, `None` detects it from files
+     */
+    pub fn update_chronicle(&mut self, chronicle: Option<Chronicle>) {
+        if self.is_changed() {
+            self.show_dialog(Dialog::ShowWarning(
+                "Save or discard changes before switching chronicle".to_string(),
+            ));
+
+            return;
+        }
+
+        self.config.chronicle = chronicle;
+
+        if let Some(path) = self.config.system_folder_path.clone() {
+            self.update_system_path(PathBuf::from(path));
+        } else {
+            self.config.dump();
+        }
+    }
+
     pub fn update_textures_path(&mut self, path: PathBuf) {
         if path.is_dir() {
             let path = path.to_str().unwrap().to_string();
@@ -378,13 +654,93 @@ impl Backend {
     pub fn update_quests_java_path(&mut self, path: PathBuf) {
         if path.is_dir() {
             let path = path.to_str().unwrap().to_string();
-            self.holders.server_data_holder = ServerDataHolder::load(&path);
+            self.holders.server_data_holder =
+                ServerDataHolder::load(&path, self.config.server_emulator);
 
             self.config.server_quests_java_classes_path = Some(path);
             self.config.dump();
         }
     }
 
+    /**Only changes template of generated scripts, already existing ones are kept as is
+     */
+    pub fn update_server_emulator(&mut self, emulator: ServerEmulator) {
+        self.holders.server_data_holder.emulator = emulator;
+
+        self.config.server_emulator = emulator;
+        self.config.dump();
+    }
+
+    pub fn update_server_stats_path(&mut self, path: PathBuf) {
+        if path.is_dir() {
+            let path = path.to_str().unwrap().to_string();
+            let (holder, warnings) = ServerStatsHolder::load(&path);
+
+            self.holders.server_stats_holder = holder;
+
+            let mut logs = logs_mut();
+
+            for v in warnings {
+                logs.add(v);
+            }
+
+            self.config.server_stats_folder_path = Some(path);
+            self.config.dump();
+        }
+    }
+
+    /**Logs every npc and item which differs from its server xml, see
+    [`ServerStatsHolder::all_differences`]
+     */
+    pub fn compare_server_stats(&self) {
+        let holder = &self.holders.game_data_holder;
+        let differences = self.holders.server_stats_holder.all_differences(holder);
+
+        let mut logs = logs_mut();
+
+        for (entity, v) in &differences {
+            logs.add(Log {
+                level: LogLevel::Warning,
+                producer: "Server Stats".to_string(),
+                log: format!(
+                    "{} - {}: server {}, client {}",
+                    holder.entity_title(*entity),
+                    v.field,
+                    v.server,
+                    v.client
+                ),
+                entity: Some(*entity),
+            });
+        }
+
+        logs.add(Log {
+            level: if differences.is_empty() {
+                LogLevel::Info
+            } else {
+                LogLevel::Warning
+            },
+            producer: "Server Stats".to_string(),
+            log: format!("{} differences with server stats found", differences.len()),
+            entity: None,
+        });
+    }
+
+    /**Writes skeleton server xml of npc or item, see [`skeleton_xml`]
+     */
+    pub fn create_server_xml(&mut self, entity: EntityT, path: PathBuf) {
+        let Some((_, xml)) = skeleton_xml(
+            entity,
+            &self.holders.game_data_holder,
+            &self.holders.server_stats_holder,
+        ) else {
+            return;
+        };
+
+        if let Err(e) = File::create(&path).and_then(|mut f| f.write_all(xml.as_bytes())) {
+            self.show_dialog(Dialog::ShowWarning(format!("{e:?}")));
+        }
+    }
+
     pub fn update_npc_spawn_path(&mut self, path: PathBuf) {
         if path.is_dir() {
             let path = path.to_str().unwrap().to_string();
@@ -838,6 +1194,189 @@ impl Backend {
                 }
             }
 
+            CurrentEntity::GameString(index) => {
+                let entity = self.edit_params.game_strings.opened[index].inner.inner.clone();
+
+                if self.game_data_name_rejected(&entity) {
+                    return;
+                }
+
+                let new_entity = self.edit_params.game_strings.opened.get(index).unwrap();
+
+                if let Some(old_entity) = self
+                    .holders
+                    .game_data_holder
+                    .game_string_holder
+                    .get(&new_entity.inner.inner.id)
+                {
+                    if new_entity.inner.initial_id == old_entity.id() || old_entity.deleted() {
+                        self.save_game_string_force(new_entity.inner.inner.clone());
+                    } else {
+                        self.show_dialog(Dialog::ConfirmGameStringSave {
+                            message: format!(
+                                "{} string with Id {} already exists.\nOverwrite?",
+                                old_entity.id.table, old_entity.id.id
+                            ),
+                            game_string_id: new_entity.inner.inner.id,
+                        });
+
+                        return;
+                    }
+                } else {
+                    self.save_game_string_force(new_entity.inner.inner.clone());
+                }
+            }
+
+            CurrentEntity::WeaponEnchantEffect(index) => {
+                let new_entity = self
+                    .edit_params
+                    .weapon_enchant_effects
+                    .opened
+                    .get(index)
+                    .unwrap();
+
+                if let Some(old_entity) = self
+                    .holders
+                    .game_data_holder
+                    .weapon_enchant_effect_holder
+                    .get(&new_entity.inner.inner.id)
+                {
+                    if new_entity.inner.initial_id == old_entity.id() || old_entity.deleted() {
+                        self.save_weapon_enchant_effect_force(new_entity.inner.inner.clone());
+                    } else {
+                        self.show_dialog(Dialog::ConfirmWeaponEnchantEffectSave {
+                            message: format!(
+                                "Weapon Enchant Effect with Id {} already exists.\nOverwrite?",
+                                old_entity.id.0
+                            ),
+                            effect_id: new_entity.inner.inner.id,
+                        });
+
+                        return;
+                    }
+                } else {
+                    self.save_weapon_enchant_effect_force(new_entity.inner.inner.clone());
+                }
+            }
+
+            CurrentEntity::ArmorEnchantEffect(index) => {
+                let new_entity = self
+                    .edit_params
+                    .armor_enchant_effects
+                    .opened
+                    .get(index)
+                    .unwrap();
+
+                if let Some(old_entity) = self
+                    .holders
+                    .game_data_holder
+                    .armor_enchant_effect_holder
+                    .get(&new_entity.inner.inner.id)
+                {
+                    if new_entity.inner.initial_id == old_entity.id() || old_entity.deleted() {
+                        self.save_armor_enchant_effect_force(new_entity.inner.inner.clone());
+                    } else {
+                        self.show_dialog(Dialog::ConfirmArmorEnchantEffectSave {
+                            message: format!(
+                                "Armor Enchant Effect with Id {} already exists.\nOverwrite?",
+                                old_entity.id.0
+                            ),
+                            effect_id: new_entity.inner.inner.id,
+                        });
+
+                        return;
+                    }
+                } else {
+                    self.save_armor_enchant_effect_force(new_entity.inner.inner.clone());
+                }
+            }
+
+            CurrentEntity::EnsoulOption(index) => {
+                let new_entity = self.edit_params.ensoul_options.opened.get(index).unwrap();
+
+                if let Some(old_entity) = self
+                    .holders
+                    .game_data_holder
+                    .ensoul_option_holder
+                    .get(&new_entity.inner.inner.id)
+                {
+                    if new_entity.inner.initial_id == old_entity.id() || old_entity.deleted() {
+                        self.save_ensoul_option_force(new_entity.inner.inner.clone());
+                    } else {
+                        self.show_dialog(Dialog::ConfirmEnsoulOptionSave {
+                            message: format!(
+                                "Ensoul Option with Id {} already exists.\nOverwrite?",
+                                old_entity.id.0
+                            ),
+                            option_id: new_entity.inner.inner.id,
+                        });
+
+                        return;
+                    }
+                } else {
+                    self.save_ensoul_option_force(new_entity.inner.inner.clone());
+                }
+            }
+
+            CurrentEntity::VariationOption(index) => {
+                let new_entity = self
+                    .edit_params
+                    .variation_options
+                    .opened
+                    .get(index)
+                    .unwrap();
+
+                if let Some(old_entity) = self
+                    .holders
+                    .game_data_holder
+                    .variation_option_holder
+                    .get(&new_entity.inner.inner.id)
+                {
+                    if new_entity.inner.initial_id == old_entity.id() || old_entity.deleted() {
+                        self.save_variation_option_force(new_entity.inner.inner.clone());
+                    } else {
+                        self.show_dialog(Dialog::ConfirmVariationOptionSave {
+                            message: format!(
+                                "Variation Option with Id {} already exists.\nOverwrite?",
+                                old_entity.id.0
+                            ),
+                            option_id: new_entity.inner.inner.id,
+                        });
+
+                        return;
+                    }
+                } else {
+                    self.save_variation_option_force(new_entity.inner.inner.clone());
+                }
+            }
+
+            CurrentEntity::InstantZone(index) => {
+                let new_entity = self.edit_params.instant_zones.opened.get(index).unwrap();
+
+                if let Some(old_entity) = self
+                    .holders
+                    .game_data_holder
+                    .instant_zone_holder
+                    .get(&new_entity.inner.inner.id)
+                {
+                    if new_entity.inner.initial_id == old_entity.id() || old_entity.deleted() {
+                        self.save_instant_zone_force(new_entity.inner.inner.clone());
+                    } else {
+                        self.show_dialog(Dialog::ConfirmInstantZoneSave {
+                            message: format!(
+                                "Instant Zone with Id {} already exists.\nOverwrite?",
+                                old_entity.id.0
+                            ),
+                            instant_zone_id: new_entity.inner.inner.id,
+                        });
+
+                        return;
+                    }
+                } else {
+                    self.save_instant_zone_force(new_entity.inner.inner.clone());
+                }
+            }
+
             CurrentEntity::None => {
                 return;
             }
@@ -848,8 +1387,47 @@ impl Backend {
         }
     }
 
-    pub fn check_for_unwrote_changed(&mut self) {
+    pub fn check_for_unwrote_changed(&mut self, changed: EntityT) {
         self.has_unwrote_changes = !self.holders.game_data_holder.changed_entities().is_empty();
+        self.holders
+            .references
+            .update(&self.holders.game_data_holder, changed);
+    }
+
+    fn update_references(&mut self) {
+        self.holders.references = ReferenceIndex::build(&self.holders.game_data_holder);
+    }
+
+    /**Called after `entity` was toggled in catalog. Deletion isn't blocked, ids in referencing
+    entities would just dangle, so user is only warned to fix them
+     */
+    pub fn warn_if_deleted_entity_used(&mut self, entity: EntityT) {
+        let holder = &self.holders.game_data_holder;
+
+        if !holder
+            .entity_info(entity)
+            .is_some_and(|(_, deleted)| deleted)
+        {
+            return;
+        }
+
+        let used_by = self.holders.references.used_by(entity);
+
+        if used_by.is_empty() {
+            return;
+        }
+
+        let mut message = format!("{} is still used by:\n", holder.entity_title(entity));
+
+        for v in used_by.iter().take(10) {
+            message.push_str(&format!("\n{}", holder.entity_title(*v)));
+        }
+
+        if used_by.len() > 10 {
+            message.push_str(&format!("\n...and {} more", used_by.len() - 10));
+        }
+
+        self.show_dialog(Dialog::ShowWarning(message));
     }
 
     fn set_unchanged(&mut self) {
@@ -955,6 +1533,50 @@ impl Backend {
                 }
             }
 
+            Dialog::ConfirmGameStringSave { game_string_id, .. } => {
+                if answer == DialogAnswer::Confirm {
+                    self.save_game_string_from_dlg(game_string_id);
+                }
+            }
+
+            Dialog::ConfirmWeaponEnchantEffectSave { effect_id, .. } => {
+                if answer == DialogAnswer::Confirm {
+                    self.save_weapon_enchant_effect_from_dlg(effect_id);
+                }
+            }
+
+            Dialog::ConfirmArmorEnchantEffectSave { effect_id, .. } => {
+                if answer == DialogAnswer::Confirm {
+                    self.save_armor_enchant_effect_from_dlg(effect_id);
+                }
+            }
+
+            Dialog::ConfirmEnsoulOptionSave { option_id, .. } => {
+                if answer == DialogAnswer::Confirm {
+                    self.save_ensoul_option_from_dlg(option_id);
+                }
+            }
+
+            Dialog::ConfirmVariationOptionSave { option_id, .. } => {
+                if answer == DialogAnswer::Confirm {
+                    self.save_variation_option_from_dlg(option_id);
+                }
+            }
+
+            Dialog::ConfirmInstantZoneSave {
+                instant_zone_id, ..
+            } => {
+                if answer == DialogAnswer::Confirm {
+                    self.save_instant_zone_from_dlg(instant_zone_id);
+                }
+            }
+
+            Dialog::ConfirmDatSave { .. } => {
+                if answer == DialogAnswer::Confirm {
+                    self.write_dats();
+                }
+            }
+
             Dialog::ShowWarning(_) => {}
 
             Dialog::ConfirmClose(index) => {
@@ -1151,6 +1773,82 @@ impl Backend {
 
                 self.edit_params.find_opened_entity();
             }
+            CurrentEntity::GameString(index) => {
+                if !force && self.edit_params.game_strings.opened[index].is_changed() {
+                    self.edit_params.current_entity = ind;
+                    self.show_dialog(Dialog::ConfirmClose(CurrentEntity::GameString(index)));
+
+                    return;
+                }
+
+                self.edit_params.game_strings.opened.remove(index);
+
+                self.edit_params.find_opened_entity();
+            }
+            CurrentEntity::WeaponEnchantEffect(index) => {
+                if !force && self.edit_params.weapon_enchant_effects.opened[index].is_changed() {
+                    self.edit_params.current_entity = ind;
+                    self.show_dialog(Dialog::ConfirmClose(CurrentEntity::WeaponEnchantEffect(
+                        index,
+                    )));
+
+                    return;
+                }
+
+                self.edit_params.weapon_enchant_effects.opened.remove(index);
+
+                self.edit_params.find_opened_entity();
+            }
+            CurrentEntity::ArmorEnchantEffect(index) => {
+                if !force && self.edit_params.armor_enchant_effects.opened[index].is_changed() {
+                    self.edit_params.current_entity = ind;
+                    self.show_dialog(Dialog::ConfirmClose(CurrentEntity::ArmorEnchantEffect(
+                        index,
+                    )));
+
+                    return;
+                }
+
+                self.edit_params.armor_enchant_effects.opened.remove(index);
+
+                self.edit_params.find_opened_entity();
+            }
+            CurrentEntity::EnsoulOption(index) => {
+                if !force && self.edit_params.ensoul_options.opened[index].is_changed() {
+                    self.edit_params.current_entity = ind;
+                    self.show_dialog(Dialog::ConfirmClose(CurrentEntity::EnsoulOption(index)));
+
+                    return;
+                }
+
+                self.edit_params.ensoul_options.opened.remove(index);
+
+                self.edit_params.find_opened_entity();
+            }
+            CurrentEntity::VariationOption(index) => {
+                if !force && self.edit_params.variation_options.opened[index].is_changed() {
+                    self.edit_params.current_entity = ind;
+                    self.show_dialog(Dialog::ConfirmClose(CurrentEntity::VariationOption(index)));
+
+                    return;
+                }
+
+                self.edit_params.variation_options.opened.remove(index);
+
+                self.edit_params.find_opened_entity();
+            }
+            CurrentEntity::InstantZone(index) => {
+                if !force && self.edit_params.instant_zones.opened[index].is_changed() {
+                    self.edit_params.current_entity = ind;
+                    self.show_dialog(Dialog::ConfirmClose(CurrentEntity::InstantZone(index)));
+
+                    return;
+                }
+
+                self.edit_params.instant_zones.opened.remove(index);
+
+                self.edit_params.find_opened_entity();
+            }
 
             CurrentEntity::None => {}
         }
@@ -1168,6 +1866,23 @@ pub struct Config {
     pub textures_folder_path: Option<String>,
     pub server_quests_java_classes_path: Option<String>,
     pub server_spawn_root_folder_path: Option<String>,
+    /**Server `stats` folder with `npcs` and `items` xml subfolders
+     */
+    #[serde(default)]
+    pub server_stats_folder_path: Option<String>,
+    /**Picks quest script template and class naming
+     */
+    #[serde(default)]
+    pub server_emulator: ServerEmulator,
+    /**Suffixes of localized dats to load, like `["e", "ru"]`. First one is edited in entity
+    fields, the rest are edited as translations. Empty means every locale found in system folder
+     */
+    #[serde(default)]
+    pub locales: Vec<String>,
+    /**Detected from system folder files when `None`
+     */
+    #[serde(default)]
+    pub chronicle: Option<Chronicle>,
 }
 
 impl Config {
@@ -1270,6 +1985,33 @@ pub enum Dialog {
         message: String,
         residence_id: ResidenceId,
     },
+    ConfirmGameStringSave {
+        message: String,
+        game_string_id: GameStringId,
+    },
+    ConfirmWeaponEnchantEffectSave {
+        message: String,
+        effect_id: WeaponEnchantEffectId,
+    },
+    ConfirmArmorEnchantEffectSave {
+        message: String,
+        effect_id: SetEnchantEffectId,
+    },
+    ConfirmEnsoulOptionSave {
+        message: String,
+        option_id: EnsoulOptionId,
+    },
+    ConfirmVariationOptionSave {
+        message: String,
+        option_id: VariationOptionId,
+    },
+    ConfirmInstantZoneSave {
+        message: String,
+        instant_zone_id: InstantZoneId,
+    },
+    ConfirmDatSave {
+        message: String,
+    },
 
     ShowWarning(String),
     ConfirmClose(CurrentEntity),
@@ -0,0 +1,788 @@
+use crate::backend::holder::{GameDataHolder, HolderMapOps};
+use crate::data::{ItemId, WeaponEnchantEffectId};
+use crate::entity::item::weapon::Weapon;
+use crate::entity::item::ItemBaseInfo;
+use crate::entity::quest::GoalType;
+use crate::entity::{CommonEntity, EntityT};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+
+/**Id stored in `field` of `from` entity
+ */
+#[derive(Copy, Clone, Debug)]
+pub struct Reference {
+    pub from: EntityT,
+    pub field: &'static str,
+    pub to: ReferenceTarget,
+}
+
+/**Items are split between three holders and referenced by plain [`ItemId`], holder containing
+them is looked up on use
+ */
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ReferenceTarget {
+    Entity(EntityT),
+    Item(ItemId),
+}
+
+impl ReferenceTarget {
+    /**Entity targets pointing to any item holder become [`ReferenceTarget::Item`]
+     */
+    fn key(self) -> Self {
+        match self {
+            ReferenceTarget::Entity(
+                EntityT::Weapon(id) | EntityT::Armor(id) | EntityT::EtcItem(id),
+            ) => ReferenceTarget::Item(id),
+            v => v,
+        }
+    }
+}
+
+impl Display for ReferenceTarget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReferenceTarget::Entity(v) => write!(f, "{v} {}", v.raw_id()),
+            ReferenceTarget::Item(v) => write!(f, "Item {}", v.0),
+        }
+    }
+}
+
+/**`3 dangling references in 2 entities (Quest 2, Npc 1)`, counted by kind of referencing
+entity in order of `dangling`
+ */
+pub fn dangling_summary(dangling: &[Reference]) -> String {
+    let mut kinds: Vec<(String, usize)> = vec![];
+    let mut entities = HashSet::new();
+
+    for v in dangling {
+        let kind = v.from.to_string();
+
+        entities.insert(v.from);
+
+        if let Some(c) = kinds.iter_mut().find(|c| c.0 == kind) {
+            c.1 += 1;
+        } else {
+            kinds.push((kind, 1));
+        }
+    }
+
+    let kinds: Vec<_> = kinds.iter().map(|(k, c)| format!("{k} {c}")).collect();
+
+    format!(
+        "{} dangling references in {} entities ({})",
+        dangling.len(),
+        entities.len(),
+        kinds.join(", ")
+    )
+}
+
+/**Reverse links between entities: for every referenced entity keeps the ones pointing to it.
+Deleted entities don't reference anything. Item targets are stored as [`ReferenceTarget::Item`],
+so moving an item to another holder or creating a missing one doesn't need a rebuild. Updated
+by [`crate::backend::Backend`] for every changed entity
+ */
+#[derive(Default)]
+pub struct ReferenceIndex {
+    used_by: HashMap<ReferenceTarget, HashSet<EntityT>>,
+    uses: HashMap<EntityT, HashSet<ReferenceTarget>>,
+}
+
+impl ReferenceIndex {
+    pub fn build(holder: &GameDataHolder) -> Self {
+        let mut res = Self::default();
+
+        holder.visit_references(|r| res.add(r));
+
+        res
+    }
+
+    /**Drops old references of `entity` and collects new ones. Item ids are shared between
+    holders, so every item with the same id is refreshed, changed weapon enchant effect refreshes
+    all weapons
+     */
+    pub fn update(&mut self, holder: &GameDataHolder, entity: EntityT) {
+        let entities = match entity {
+            EntityT::Weapon(id) | EntityT::Armor(id) | EntityT::EtcItem(id) => {
+                vec![
+                    EntityT::Weapon(id),
+                    EntityT::Armor(id),
+                    EntityT::EtcItem(id),
+                ]
+            }
+            //Weapons point to effects by effect names, which can change with the effect
+            EntityT::WeaponEnchantEffect(_) => holder
+                .weapon_holder
+                .keys()
+                .map(|v| EntityT::Weapon(*v))
+                .collect(),
+            _ => vec![entity],
+        };
+
+        for from in entities {
+            self.remove(from);
+
+            holder.visit_entity_references(from, |r| self.add(r));
+        }
+    }
+
+    pub fn used_by(&self, entity: EntityT) -> Vec<EntityT> {
+        let mut res: Vec<_> = self
+            .used_by
+            .get(&ReferenceTarget::Entity(entity).key())
+            .into_iter()
+            .flatten()
+            .copied()
+            .collect();
+
+        res.sort();
+
+        res
+    }
+
+    fn add(&mut self, r: Reference) {
+        let to = r.to.key();
+
+        if to == ReferenceTarget::Entity(r.from).key() {
+            return;
+        }
+
+        self.used_by.entry(to).or_default().insert(r.from);
+        self.uses.entry(r.from).or_default().insert(to);
+    }
+
+    fn remove(&mut self, from: EntityT) {
+        let Some(targets) = self.uses.remove(&from) else {
+            return;
+        };
+
+        for to in targets {
+            if let Some(v) = self.used_by.get_mut(&to) {
+                v.remove(&from);
+
+                if v.is_empty() {
+                    self.used_by.remove(&to);
+                }
+            }
+        }
+    }
+}
+
+impl GameDataHolder {
+    /**Calls `f` for every non zero id stored in not deleted entities
+     */
+    pub fn visit_references(&self, mut f: impl FnMut(Reference)) {
+        let entities = self
+            .quest_holder
+            .keys()
+            .map(|v| EntityT::Quest(*v))
+            .chain(self.npc_holder.keys().map(|v| EntityT::Npc(*v)))
+            .chain(self.skill_holder.keys().map(|v| EntityT::Skill(*v)))
+            .chain(self.weapon_holder.keys().map(|v| EntityT::Weapon(*v)))
+            .chain(self.armor_holder.keys().map(|v| EntityT::Armor(*v)))
+            .chain(self.etc_item_holder.keys().map(|v| EntityT::EtcItem(*v)))
+            .chain(self.item_set_holder.keys().map(|v| EntityT::ItemSet(*v)))
+            .chain(self.recipe_holder.keys().map(|v| EntityT::Recipe(*v)))
+            .chain(
+                self.hunting_zone_holder
+                    .keys()
+                    .map(|v| EntityT::HuntingZone(*v)),
+            )
+            .chain(self.raid_info_holder.keys().map(|v| EntityT::RaidInfo(*v)))
+            .chain(
+                self.daily_mission_holder
+                    .keys()
+                    .map(|v| EntityT::DailyMission(*v)),
+            )
+            .chain(
+                self.ensoul_option_holder
+                    .keys()
+                    .map(|v| EntityT::EnsoulOption(*v)),
+            )
+            .chain(
+                self.variation_option_holder
+                    .keys()
+                    .map(|v| EntityT::VariationOption(*v)),
+            );
+
+        for from in entities {
+            self.visit_entity_references(from, &mut f);
+        }
+    }
+
+    /**Same as [`Self::visit_references`] for a single entity, nothing if it doesn't exist or
+    is deleted
+     */
+    pub fn visit_entity_references(&self, from: EntityT, mut f: impl FnMut(Reference)) {
+        let f = &mut f;
+
+        match from {
+            EntityT::Quest(id) => {
+                let Some(v) = self.quest_holder.get(&id).filter(|v| !v._deleted) else {
+                    return;
+                };
+
+                for id in &v.start_npc_ids {
+                    entity(f, from, "start_npc_ids", EntityT::Npc(*id));
+                }
+                for r in &v.rewards {
+                    item(f, from, "rewards", r.reward_id);
+                }
+                for id in &v.quest_items {
+                    item(f, from, "quest_items", *id);
+                }
+                for step in &v.steps {
+                    for goal in &step.inner.goals {
+                        match goal.goal_type {
+                            GoalType::KillNpc => {
+                                entity(f, from, "goals", EntityT::Npc(goal.target_id.into()))
+                            }
+                            GoalType::CollectItem => item(f, from, "goals", goal.target_id.into()),
+                            GoalType::Other => {}
+                        }
+                    }
+                }
+                entity(
+                    f,
+                    from,
+                    "required_completed_quest_id",
+                    EntityT::Quest(v.required_completed_quest_id),
+                );
+                entity(
+                    f,
+                    from,
+                    "search_zone_id",
+                    EntityT::HuntingZone(v.search_zone_id),
+                );
+            }
+
+            EntityT::Npc(id) => {
+                let Some(v) = self.npc_holder.get(&id).filter(|v| !v._deleted) else {
+                    return;
+                };
+
+                for p in &v.properties {
+                    entity(f, from, "properties", EntityT::Skill(p.id));
+                }
+                for q in &v.quest_infos {
+                    entity(f, from, "quest_infos", EntityT::Quest(q.id));
+                }
+
+                let equipment = &v.equipment_params.inner;
+
+                item(f, from, "equipment", equipment.left_hand);
+                item(f, from, "equipment", equipment.right_hand);
+                item(f, from, "equipment", equipment.chest);
+            }
+
+            EntityT::Skill(id) => {
+                let Some(v) = self.skill_holder.get(&id).filter(|v| !v._deleted) else {
+                    return;
+                };
+
+                if let Some(condition) = &v.use_condition {
+                    item(
+                        f,
+                        from,
+                        "consumable_item_id",
+                        condition.inner.consumable_item_id,
+                    );
+                }
+            }
+
+            EntityT::Weapon(id) => {
+                let Some(v) = self.weapon_holder.get(&id).filter(|v| !v._deleted) else {
+                    return;
+                };
+
+                item_base_info(f, from, &v.base_info);
+
+                for id in self.weapon_enchant_effects(v) {
+                    f(Reference {
+                        from,
+                        field: "effect",
+                        to: ReferenceTarget::Entity(EntityT::WeaponEnchantEffect(id)),
+                    });
+                }
+            }
+            EntityT::Armor(id) => {
+                let Some(v) = self.armor_holder.get(&id).filter(|v| !v._deleted) else {
+                    return;
+                };
+
+                item_base_info(f, from, &v.base_info);
+                entity(
+                    f,
+                    from,
+                    "set_enchant_effect_id",
+                    EntityT::ArmorEnchantEffect(v.set_enchant_effect_id),
+                );
+            }
+            EntityT::EtcItem(id) => {
+                if let Some(v) = self.etc_item_holder.get(&id).filter(|v| !v._deleted) {
+                    item_base_info(f, from, &v.base_info);
+                }
+            }
+
+            EntityT::ItemSet(id) => {
+                let Some(v) = self.item_set_holder.get(&id).filter(|v| !v._deleted) else {
+                    return;
+                };
+
+                for id in v.base_items.iter().flatten() {
+                    item(f, from, "base_items", *id);
+                }
+                for id in v.additional_items.iter().flatten() {
+                    item(f, from, "additional_items", *id);
+                }
+            }
+
+            EntityT::Recipe(id) => {
+                let Some(v) = self.recipe_holder.get(&id).filter(|v| !v._deleted) else {
+                    return;
+                };
+
+                item(f, from, "recipe_item", v.recipe_item);
+                item(f, from, "product", v.product);
+
+                for m in &v.materials {
+                    item(f, from, "materials", m.id);
+                }
+            }
+
+            EntityT::HuntingZone(id) => {
+                let Some(v) = self.hunting_zone_holder.get(&id).filter(|v| !v._deleted) else {
+                    return;
+                };
+
+                entity(f, from, "npc_id", EntityT::Npc(v.npc_id));
+
+                for id in &v.quests {
+                    entity(f, from, "quests", EntityT::Quest(*id));
+                }
+
+                entity(f, from, "search_zone_id", EntityT::Region(v.search_zone_id));
+                entity(
+                    f,
+                    from,
+                    "instant_zone_id",
+                    EntityT::InstantZone(v.instant_zone_id),
+                );
+            }
+
+            EntityT::RaidInfo(id) => {
+                let Some(v) = self.raid_info_holder.get(&id).filter(|v| !v._deleted) else {
+                    return;
+                };
+
+                entity(f, from, "raid_id", EntityT::Npc(v.raid_id));
+                entity(
+                    f,
+                    from,
+                    "search_zone_id",
+                    EntityT::HuntingZone(v.search_zone_id),
+                );
+            }
+
+            EntityT::DailyMission(id) => {
+                let Some(v) = self.daily_mission_holder.get(&id).filter(|v| !v._deleted) else {
+                    return;
+                };
+
+                for r in &v.rewards {
+                    item(f, from, "rewards", r.item_id);
+                }
+            }
+
+            EntityT::EnsoulOption(id) => {
+                let Some(v) = self.ensoul_option_holder.get(&id).filter(|v| !v._deleted) else {
+                    return;
+                };
+
+                entity(f, from, "skill_id", EntityT::Skill(v.skill_id));
+                item(f, from, "extraction_item_id", v.extraction_item_id);
+
+                for s in &v.stones {
+                    item(f, from, "stones", s.item_id);
+                }
+            }
+
+            EntityT::VariationOption(id) => {
+                if let Some(v) = self
+                    .variation_option_holder
+                    .get(&id)
+                    .filter(|v| !v._deleted)
+                {
+                    entity(f, from, "skill_id", EntityT::Skill(v.skill_id));
+                }
+            }
+
+            EntityT::Region(_)
+            | EntityT::AnimationCombo(_)
+            | EntityT::Residence(_)
+            | EntityT::GameString(_)
+            | EntityT::WeaponEnchantEffect(_)
+            | EntityT::ArmorEnchantEffect(_)
+            | EntityT::InstantZone(_) => {}
+        }
+    }
+
+    /**Enchant effects with a level using one of effect names of `weapon`, deleted ones too
+     */
+    pub fn weapon_enchant_effects(&self, weapon: &Weapon) -> Vec<WeaponEnchantEffectId> {
+        let used: Vec<_> = weapon
+            .enchant_info
+            .inner
+            .params
+            .iter()
+            .map(|v| &v.effect)
+            .chain([&weapon.effect])
+            .filter(|v| !v.is_empty())
+            .collect();
+
+        let mut res: Vec<_> = self
+            .weapon_enchant_effect_holder
+            .values()
+            .filter(|v| {
+                v.levels
+                    .iter()
+                    .any(|l| used.iter().any(|u| u.eq_ignore_ascii_case(&l.effect)))
+            })
+            .map(|v| v.id)
+            .collect();
+
+        res.sort();
+
+        res
+    }
+
+    /**References to entities which don't exist or are deleted. Doesn't touch UI, so can be
+    checked before writing dats
+     */
+    pub fn dangling_references(&self) -> Vec<Reference> {
+        let mut res = vec![];
+
+        self.visit_references(|r| {
+            let exists = self
+                .resolve_reference(r.to)
+                .and_then(|v| self.entity_info(v))
+                .is_some_and(|(_, deleted)| !deleted);
+
+            if !exists {
+                res.push(r);
+            }
+        });
+
+        res.sort_by_key(|v| v.from);
+
+        res
+    }
+
+    pub fn resolve_reference(&self, target: ReferenceTarget) -> Option<EntityT> {
+        match target {
+            ReferenceTarget::Entity(v) => Some(v),
+            ReferenceTarget::Item(id) => self.item_entity(id),
+        }
+    }
+
+    pub fn item_entity(&self, id: ItemId) -> Option<EntityT> {
+        if self.weapon_holder.get(&id).is_some() {
+            Some(EntityT::Weapon(id))
+        } else if self.armor_holder.get(&id).is_some() {
+            Some(EntityT::Armor(id))
+        } else if self.etc_item_holder.get(&id).is_some() {
+            Some(EntityT::EtcItem(id))
+        } else {
+            None
+        }
+    }
+
+    /**Name and deleted flag of any entity, `None` if it doesn't exist
+     */
+    pub fn entity_info(&self, entity: EntityT) -> Option<(String, bool)> {
+        fn info<K: Hash + Eq + Copy + Clone, V: Clone + CommonEntity<K>>(
+            holder: &dyn HolderMapOps<K, V>,
+            id: K,
+        ) -> Option<(String, bool)> {
+            holder.get(&id).map(|v| (v.name(), v.deleted()))
+        }
+
+        match entity {
+            EntityT::Quest(id) => info(&self.quest_holder, id),
+            EntityT::Skill(id) => info(&self.skill_holder, id),
+            EntityT::Npc(id) => info(&self.npc_holder, id),
+            EntityT::Weapon(id) => info(&self.weapon_holder, id),
+            EntityT::Armor(id) => info(&self.armor_holder, id),
+            EntityT::EtcItem(id) => info(&self.etc_item_holder, id),
+            EntityT::ItemSet(id) => info(&self.item_set_holder, id),
+            EntityT::Recipe(id) => info(&self.recipe_holder, id),
+            EntityT::HuntingZone(id) => info(&self.hunting_zone_holder, id),
+            EntityT::Region(id) => info(&self.region_holder, id),
+            EntityT::RaidInfo(id) => info(&self.raid_info_holder, id),
+            EntityT::DailyMission(id) => info(&self.daily_mission_holder, id),
+            EntityT::AnimationCombo(id) => info(&self.animation_combo_holder, id),
+            EntityT::Residence(id) => info(&self.residence_holder, id),
+            EntityT::GameString(id) => info(&self.game_string_holder, id),
+            EntityT::WeaponEnchantEffect(id) => info(&self.weapon_enchant_effect_holder, id),
+            EntityT::ArmorEnchantEffect(id) => info(&self.armor_enchant_effect_holder, id),
+            EntityT::EnsoulOption(id) => info(&self.ensoul_option_holder, id),
+            EntityT::VariationOption(id) => info(&self.variation_option_holder, id),
+            EntityT::InstantZone(id) => info(&self.instant_zone_holder, id),
+        }
+    }
+
+    /**`Npc [20001]: Gremlin`, for logs and link buttons
+     */
+    pub fn entity_title(&self, entity: EntityT) -> String {
+        if let Some((name, _)) = self.entity_info(entity) {
+            format!("{entity} [{}]: {name}", entity.raw_id())
+        } else {
+            format!("{entity} [{}]: Not Exists", entity.raw_id())
+        }
+    }
+}
+
+/**Zero ids mean "not set" in every dat
+ */
+fn entity(f: &mut impl FnMut(Reference), from: EntityT, field: &'static str, to: EntityT) {
+    if to.raw_id() != 0 {
+        f(Reference {
+            from,
+            field,
+            to: ReferenceTarget::Entity(to),
+        });
+    }
+}
+
+fn item(f: &mut impl FnMut(Reference), from: EntityT, field: &'static str, id: ItemId) {
+    if id != ItemId::NONE {
+        f(Reference {
+            from,
+            field,
+            to: ReferenceTarget::Item(id),
+        });
+    }
+}
+
+fn item_base_info(f: &mut impl FnMut(Reference), from: EntityT, info: &ItemBaseInfo) {
+    entity(f, from, "set_id", EntityT::ItemSet(info.set_id));
+
+    for id in &info.related_quests {
+        entity(f, from, "related_quests", EntityT::Quest(*id));
+    }
+    for id in &info.additional_info.inner.include_items {
+        item(f, from, "include_items", *id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{NpcId, QuestId, SkillId};
+    use crate::entity::item::etc_item::EtcItem;
+    use crate::entity::npc::{Npc, NpcProperty, NpcQuestInfo};
+    use crate::entity::quest::{Quest, QuestReward};
+    use crate::entity::weapon_enchant_effect::{EnchantEffectLevel, WeaponEnchantEffect};
+
+    fn etc_item(id: u32, deleted: bool) -> EtcItem {
+        let mut res = EtcItem {
+            _deleted: deleted,
+            ..Default::default()
+        };
+        res.base_info.id = ItemId(id);
+
+        res
+    }
+
+    /**Quest 1 starts at npc 10 and missing npc 11, gives item 57 and deleted item 58. Npc 10 has
+    missing skill 5, deleted npc 12 has missing skill 6
+     */
+    fn holder() -> GameDataHolder {
+        let mut holder = GameDataHolder::default();
+
+        holder.quest_holder.insert(
+            QuestId(1),
+            Quest {
+                id: QuestId(1),
+                start_npc_ids: vec![NpcId(10), NpcId(11)],
+                rewards: vec![QuestReward {
+                    reward_id: ItemId(57),
+                    count: 1,
+                }],
+                quest_items: vec![ItemId(58)],
+                ..Default::default()
+            },
+        );
+
+        holder.npc_holder.insert(
+            NpcId(10),
+            Npc {
+                id: NpcId(10),
+                properties: vec![NpcProperty {
+                    id: SkillId(5),
+                    level: 1,
+                }],
+                quest_infos: vec![NpcQuestInfo {
+                    id: QuestId(1),
+                    step: 0,
+                }],
+                ..Default::default()
+            },
+        );
+        holder.npc_holder.insert(
+            NpcId(12),
+            Npc {
+                id: NpcId(12),
+                properties: vec![NpcProperty {
+                    id: SkillId(6),
+                    level: 1,
+                }],
+                _deleted: true,
+                ..Default::default()
+            },
+        );
+
+        holder
+            .etc_item_holder
+            .insert(ItemId(57), etc_item(57, false));
+        holder
+            .etc_item_holder
+            .insert(ItemId(58), etc_item(58, true));
+
+        holder
+    }
+
+    #[test]
+    fn finds_missing_and_deleted_targets() {
+        let dangling: Vec<_> = holder()
+            .dangling_references()
+            .iter()
+            .map(|v| (v.from, v.field, v.to.to_string()))
+            .collect();
+
+        assert_eq!(dangling.len(), 3);
+        assert!(dangling.contains(&(EntityT::Quest(QuestId(1)), "start_npc_ids", "Npc 11".into())));
+        assert!(dangling.contains(&(EntityT::Quest(QuestId(1)), "quest_items", "Item 58".into())));
+        assert_eq!(
+            dangling[2],
+            (EntityT::Npc(NpcId(10)), "properties", "Skill 5".into())
+        );
+    }
+
+    #[test]
+    fn summary_counts_references_by_entity_kind() {
+        assert_eq!(
+            dangling_summary(&holder().dangling_references()),
+            "3 dangling references in 2 entities (Quest 2, Npc 1)"
+        );
+    }
+
+    #[test]
+    fn index_skips_deleted_entities() {
+        let holder = holder();
+        let index = ReferenceIndex::build(&holder);
+
+        assert_eq!(
+            index.used_by(EntityT::Npc(NpcId(10))),
+            [EntityT::Quest(QuestId(1))]
+        );
+        assert_eq!(
+            index.used_by(EntityT::Quest(QuestId(1))),
+            [EntityT::Npc(NpcId(10))]
+        );
+        assert_eq!(
+            index.used_by(EntityT::EtcItem(ItemId(57))),
+            [EntityT::Quest(QuestId(1))]
+        );
+        assert!(index.used_by(EntityT::Skill(SkillId(6))).is_empty());
+    }
+
+    #[test]
+    fn update_replaces_references_of_changed_entity() {
+        let mut holder = holder();
+        let mut index = ReferenceIndex::build(&holder);
+
+        holder
+            .quest_holder
+            .get_mut(&QuestId(1))
+            .unwrap()
+            .start_npc_ids = vec![NpcId(11)];
+        index.update(&holder, EntityT::Quest(QuestId(1)));
+
+        assert!(index.used_by(EntityT::Npc(NpcId(10))).is_empty());
+        assert_eq!(
+            index.used_by(EntityT::Npc(NpcId(11))),
+            [EntityT::Quest(QuestId(1))]
+        );
+        assert_eq!(
+            index.used_by(EntityT::EtcItem(ItemId(57))),
+            [EntityT::Quest(QuestId(1))]
+        );
+
+        holder.npc_holder.get_mut(&NpcId(10)).unwrap()._deleted = true;
+        index.update(&holder, EntityT::Npc(NpcId(10)));
+
+        assert!(index.used_by(EntityT::Quest(QuestId(1))).is_empty());
+    }
+
+    #[test]
+    fn item_targets_follow_item_holder() {
+        let mut holder = holder();
+        let index = ReferenceIndex::build(&holder);
+
+        holder.etc_item_holder.remove(&ItemId(57));
+
+        let mut weapon = Weapon::default();
+        weapon.base_info.id = ItemId(57);
+        holder.weapon_holder.insert(ItemId(57), weapon);
+
+        assert_eq!(
+            index.used_by(EntityT::Weapon(ItemId(57))),
+            [EntityT::Quest(QuestId(1))]
+        );
+    }
+
+    #[test]
+    fn weapons_use_enchant_effects_by_name() {
+        let mut holder = holder();
+
+        holder.weapon_enchant_effect_holder.insert(
+            WeaponEnchantEffectId(3),
+            WeaponEnchantEffect {
+                id: WeaponEnchantEffectId(3),
+                levels: vec![EnchantEffectLevel {
+                    effect: "LineageEffect.p_u002_a".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+        );
+
+        let mut weapon = Weapon::default();
+        weapon.base_info.id = ItemId(100);
+        weapon.effect = "lineageeffect.P_U002_A".to_string();
+        holder.weapon_holder.insert(ItemId(100), weapon);
+
+        let mut index = ReferenceIndex::build(&holder);
+
+        assert_eq!(
+            index.used_by(EntityT::WeaponEnchantEffect(WeaponEnchantEffectId(3))),
+            [EntityT::Weapon(ItemId(100))]
+        );
+
+        holder
+            .weapon_enchant_effect_holder
+            .get_mut(&WeaponEnchantEffectId(3))
+            .unwrap()
+            .levels[0]
+            .effect = "LineageEffect.other".to_string();
+        index.update(
+            &holder,
+            EntityT::WeaponEnchantEffect(WeaponEnchantEffectId(3)),
+        );
+
+        assert!(index
+            .used_by(EntityT::WeaponEnchantEffect(WeaponEnchantEffectId(3)))
+            .is_empty());
+    }
+}
@@ -0,0 +1,647 @@
+use crate::backend::dat_loader::StrUtils;
+use crate::backend::holder::GameDataHolder;
+use crate::data::{ItemId, NpcId, PlayerClass};
+use crate::entity::quest::{GoalType, Quest, QuestReward};
+use std::collections::HashMap;
+use strum::IntoEnumIterator;
+use strum_macros::Display;
+
+const MAX_CONSTANT_DEPTH: usize = 8;
+
+/**Values found in quest script by common idioms: `static final int` constants, registration
+calls (`addStartNpc`, `addKillId`, `addQuestItem`, `addLevelCheck`, `addClassIdCheck` and their
+Mobius/aCis analogues) and `giveItems`. Anything more dynamic is ignored
+ */
+#[derive(Clone, Debug, Default)]
+pub struct ParsedQuestScript {
+    pub start_npcs: Vec<NpcId>,
+    pub kill_npcs: Vec<NpcId>,
+    pub quest_items: Vec<ItemId>,
+    /**Items given by `giveItems`/`rewardItems` which are not quest items
+     */
+    pub rewards: Vec<(ItemId, i64)>,
+    pub min_level: Option<u32>,
+    pub max_level: Option<u32>,
+    pub classes: Vec<PlayerClass>,
+}
+
+#[derive(Display, Debug, Copy, Clone, Eq, PartialEq)]
+pub enum QuestScriptField {
+    #[strum(to_string = "Start NPCs")]
+    StartNpcs,
+    #[strum(to_string = "Kill Targets")]
+    KillNpcs,
+    #[strum(to_string = "Quest Items")]
+    QuestItems,
+    Rewards,
+    #[strum(to_string = "Min Level")]
+    MinLevel,
+    #[strum(to_string = "Max Level")]
+    MaxLevel,
+    #[strum(to_string = "Allowed Classes")]
+    Classes,
+}
+
+impl QuestScriptField {
+    /**Kill targets belong to step goals, so there is no single field to put them in
+     */
+    pub fn can_pull(&self) -> bool {
+        *self != QuestScriptField::KillNpcs
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ScriptValue {
+    Npcs(Vec<NpcId>),
+    Items(Vec<ItemId>),
+    Rewards(Vec<(ItemId, i64)>),
+    Level(u32),
+    Classes(Vec<PlayerClass>),
+}
+
+impl ScriptValue {
+    pub fn describe(&self, holder: &GameDataHolder) -> String {
+        let res: Vec<String> = match self {
+            ScriptValue::Npcs(v) => v
+                .iter()
+                .map(|id| format!("{} [{}]", holder.get_npc_name(id), id.0))
+                .collect(),
+            ScriptValue::Items(v) => v
+                .iter()
+                .map(|id| format!("{} [{}]", holder.get_item_name(id), id.0))
+                .collect(),
+            ScriptValue::Rewards(v) => v
+                .iter()
+                .map(|(id, count)| format!("{} [{}] x{count}", holder.get_item_name(id), id.0))
+                .collect(),
+            ScriptValue::Level(v) => vec![v.to_string()],
+            ScriptValue::Classes(v) => v.iter().map(|c| c.to_string()).collect(),
+        };
+
+        if res.is_empty() {
+            "-".to_string()
+        } else {
+            res.join(", ")
+        }
+    }
+}
+
+pub struct QuestScriptDifference {
+    pub field: QuestScriptField,
+    pub server: ScriptValue,
+    pub client: ScriptValue,
+}
+
+impl ParsedQuestScript {
+    pub fn parse(source: &str) -> Self {
+        let source = strip_comments(source);
+        let constants = parse_constants(&source);
+        let resolve = |v: &str| resolve_values(v, &constants, 0);
+
+        let mut res = Self::default();
+
+        for args in calls(&source, &["addStartNpc"]) {
+            res.start_npcs.extend(
+                args.iter()
+                    .flat_map(|v| resolve(v))
+                    .map(|v| NpcId(v as u32)),
+            );
+        }
+        for args in calls(&source, &["addKillId"]) {
+            res.kill_npcs.extend(
+                args.iter()
+                    .flat_map(|v| resolve(v))
+                    .map(|v| NpcId(v as u32)),
+            );
+        }
+        for args in calls(
+            &source,
+            &["addQuestItem", "registerQuestItems", "setItemsIds"],
+        ) {
+            res.quest_items.extend(
+                args.iter()
+                    .flat_map(|v| resolve(v))
+                    .map(|v| ItemId(v as u32)),
+            );
+        }
+
+        for args in calls(&source, &["addLevelCheck", "addCondLevel"]) {
+            res.min_level = args
+                .first()
+                .and_then(|v| resolve(v).first().copied())
+                .map(|v| v as u32);
+
+            if args.len() > 1 {
+                res.max_level = resolve(&args[1]).first().map(|v| *v as u32);
+            }
+        }
+        for args in calls(&source, &["addCondMinLevel"]) {
+            res.min_level = args
+                .first()
+                .and_then(|v| resolve(v).first().copied())
+                .map(|v| v as u32);
+        }
+        for args in calls(&source, &["addCondMaxLevel"]) {
+            res.max_level = args
+                .first()
+                .and_then(|v| resolve(v).first().copied())
+                .map(|v| v as u32);
+        }
+
+        for args in calls(
+            &source,
+            &["addClassIdCheck", "addCondClassId", "addCondClassIds"],
+        ) {
+            for arg in args.iter().flat_map(|v| flatten(v)) {
+                let name = arg.rsplit('.').next().unwrap_or_default().to_lowercase();
+
+                if let Some(class) =
+                    PlayerClass::iter().find(|c| c.to_string().to_ascii_snake_case() == name)
+                {
+                    res.classes.push(class);
+                }
+            }
+        }
+
+        for (on_object, args) in method_calls(&source, &["giveItems", "rewardItems"]) {
+            //st.giveItems(id, count[, rate]) on quest state, giveItems(player, id, count) or
+            //giveItems(id, count) in quest class
+            let (id, count) = match (on_object, args.as_slice()) {
+                (true, [id, count, ..]) | (false, [id, count]) => (id, count),
+                (false, [_, id, count, ..]) => (id, count),
+                _ => continue,
+            };
+
+            let ids = resolve(id);
+            let counts = resolve(count);
+
+            let (Some(id), Some(count)) = (ids.first(), counts.first()) else {
+                continue;
+            };
+
+            let id = ItemId(*id as u32);
+
+            if !res.quest_items.contains(&id) && !res.rewards.iter().any(|v| v.0 == id) {
+                res.rewards.push((id, *count));
+            }
+        }
+
+        res
+    }
+
+    /**Fields which are set differently in script and in client quest. Level and class checks
+    are compared only if script has them
+     */
+    pub fn differences(&self, quest: &Quest) -> Vec<QuestScriptDifference> {
+        let mut res = vec![];
+
+        let mut add = |field, server: ScriptValue, client: ScriptValue| {
+            if server != client {
+                res.push(QuestScriptDifference {
+                    field,
+                    server,
+                    client,
+                });
+            }
+        };
+
+        let client_kill_npcs = quest
+            .steps
+            .iter()
+            .flat_map(|v| v.inner.goals.iter())
+            .filter(|v| v.goal_type == GoalType::KillNpc)
+            .map(|v| NpcId(v.target_id))
+            .collect();
+
+        add(
+            QuestScriptField::StartNpcs,
+            ScriptValue::Npcs(sorted(self.start_npcs.clone())),
+            ScriptValue::Npcs(sorted(quest.start_npc_ids.clone())),
+        );
+        add(
+            QuestScriptField::KillNpcs,
+            ScriptValue::Npcs(sorted(self.kill_npcs.clone())),
+            ScriptValue::Npcs(sorted(client_kill_npcs)),
+        );
+        add(
+            QuestScriptField::QuestItems,
+            ScriptValue::Items(sorted(self.quest_items.clone())),
+            ScriptValue::Items(sorted(quest.quest_items.clone())),
+        );
+        add(
+            QuestScriptField::Rewards,
+            ScriptValue::Rewards(sorted(self.rewards.clone())),
+            ScriptValue::Rewards(sorted(
+                quest
+                    .rewards
+                    .iter()
+                    .map(|v| (v.reward_id, v.count))
+                    .collect(),
+            )),
+        );
+
+        if let Some(v) = self.min_level {
+            add(
+                QuestScriptField::MinLevel,
+                ScriptValue::Level(v),
+                ScriptValue::Level(quest.min_lvl),
+            );
+        }
+        if let Some(v) = self.max_level {
+            add(
+                QuestScriptField::MaxLevel,
+                ScriptValue::Level(v),
+                ScriptValue::Level(quest.max_lvl),
+            );
+        }
+        if !self.classes.is_empty() {
+            add(
+                QuestScriptField::Classes,
+                ScriptValue::Classes(sorted_classes(self.classes.clone())),
+                ScriptValue::Classes(sorted_classes(
+                    quest.allowed_classes.clone().unwrap_or_default(),
+                )),
+            );
+        }
+
+        res
+    }
+
+    /**Replaces client quest field with script value
+     */
+    pub fn pull(&self, field: QuestScriptField, quest: &mut Quest) {
+        match field {
+            QuestScriptField::StartNpcs => quest.start_npc_ids = self.start_npcs.clone(),
+            QuestScriptField::KillNpcs => {}
+            QuestScriptField::QuestItems => quest.quest_items = self.quest_items.clone(),
+            QuestScriptField::Rewards => {
+                quest.rewards = self
+                    .rewards
+                    .iter()
+                    .map(|(reward_id, count)| QuestReward {
+                        reward_id: *reward_id,
+                        count: *count,
+                    })
+                    .collect()
+            }
+            QuestScriptField::MinLevel => {
+                if let Some(v) = self.min_level {
+                    quest.min_lvl = v;
+                }
+            }
+            QuestScriptField::MaxLevel => {
+                if let Some(v) = self.max_level {
+                    quest.max_lvl = v;
+                }
+            }
+            QuestScriptField::Classes => quest.allowed_classes = Some(self.classes.clone()),
+        }
+    }
+}
+
+fn sorted<T: Ord>(mut v: Vec<T>) -> Vec<T> {
+    v.sort();
+    v.dedup();
+
+    v
+}
+
+fn sorted_classes(mut v: Vec<PlayerClass>) -> Vec<PlayerClass> {
+    v.sort_by_key(|c| *c as u32);
+    v.dedup();
+
+    v
+}
+
+/**Comments are replaced with line breaks, string and char literals are kept as is
+ */
+fn strip_comments(source: &str) -> String {
+    let mut res = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' | '\'' => {
+                res.push(c);
+
+                while let Some(v) = chars.next() {
+                    res.push(v);
+
+                    if v == '\\' {
+                        if let Some(escaped) = chars.next() {
+                            res.push(escaped);
+                        }
+                    } else if v == c || v == '\n' {
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                for v in chars.by_ref() {
+                    if v == '\n' {
+                        break;
+                    }
+                }
+
+                res.push('\n');
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+
+                let mut prev = ' ';
+
+                for v in chars.by_ref() {
+                    if prev == '*' && v == '/' {
+                        break;
+                    }
+
+                    prev = v;
+                }
+
+                res.push('\n');
+            }
+            _ => res.push(c),
+        }
+    }
+
+    res
+}
+
+/**`static final int NAME = 1;`, `static final int[] NAME = {1, 2};` and `long` ones. Values are
+kept raw and resolved on use, so constants can reference each other
+ */
+fn parse_constants(source: &str) -> HashMap<String, String> {
+    let mut res = HashMap::from([
+        ("ADENA_ID".to_string(), "57".to_string()),
+        ("ADENA".to_string(), "57".to_string()),
+    ]);
+
+    for prefix in ["static final int", "static final long"] {
+        let mut rest = source;
+
+        while let Some(start) = rest.find(prefix) {
+            rest = &rest[start + prefix.len()..];
+
+            let Some(end) = rest.find(';') else {
+                break;
+            };
+
+            let declaration = rest[..end].trim_start_matches("[]").trim();
+            rest = &rest[end..];
+
+            if let Some((name, value)) = declaration.split_once('=') {
+                res.insert(name.trim().to_string(), value.trim().to_string());
+            }
+        }
+    }
+
+    res
+}
+
+/**Argument lists of every call of given methods, nested calls and array literals are kept
+as single argument
+ */
+fn calls(source: &str, names: &[&str]) -> Vec<Vec<String>> {
+    method_calls(source, names)
+        .into_iter()
+        .map(|(_, args)| args)
+        .collect()
+}
+
+/**Same as [`calls`], with `true` for calls on an object, like `st.giveItems(..)`
+ */
+fn method_calls(source: &str, names: &[&str]) -> Vec<(bool, Vec<String>)> {
+    let mut res = vec![];
+
+    for name in names {
+        let pattern = format!("{name}(");
+        let mut offset = 0;
+
+        while let Some(start) = source[offset..].find(&pattern) {
+            let start = offset + start;
+            offset = start + pattern.len();
+
+            let is_name_start = source[..start]
+                .chars()
+                .next_back()
+                .is_none_or(|c| !c.is_alphanumeric() && c != '_');
+
+            if !is_name_start {
+                continue;
+            }
+
+            let Some(args) = enclosed(&source[offset..]) else {
+                continue;
+            };
+
+            let on_object = source[..start].trim_end().ends_with('.');
+
+            res.push((on_object, split_args(args)));
+        }
+    }
+
+    res
+}
+
+/**Text up to the bracket closing already opened one
+ */
+fn enclosed(source: &str) -> Option<&str> {
+    let mut depth = 0;
+
+    for (i, c) in source.char_indices() {
+        match c {
+            '(' | '{' | '[' => depth += 1,
+            ')' | '}' | ']' if depth == 0 => return Some(&source[..i]),
+            ')' | '}' | ']' => depth -= 1,
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn split_args(args: &str) -> Vec<String> {
+    let mut res = vec![];
+    let mut depth = 0;
+    let mut current = String::new();
+
+    for c in args.chars() {
+        match c {
+            '(' | '{' | '[' => depth += 1,
+            ')' | '}' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                res.push(current.trim().to_string());
+                current.clear();
+
+                continue;
+            }
+            _ => {}
+        }
+
+        current.push(c);
+    }
+
+    if !current.trim().is_empty() {
+        res.push(current.trim().to_string());
+    }
+
+    res
+}
+
+/**`new int[]{A, B}`, `{A, B}` and `List.of(A, B)` into separate values
+ */
+fn flatten(arg: &str) -> Vec<String> {
+    let arg = arg.trim();
+
+    for prefix in ["new int[]", "List.of(", "Arrays.asList(", "{"] {
+        if let Some(inner) = arg.strip_prefix(prefix) {
+            let inner = inner.trim_start_matches('{');
+
+            if let Some(inner) = enclosed(inner) {
+                return split_args(inner).iter().flat_map(|v| flatten(v)).collect();
+            }
+        }
+    }
+
+    vec![arg.to_string()]
+}
+
+fn resolve_values(arg: &str, constants: &HashMap<String, String>, depth: usize) -> Vec<i64> {
+    if depth > MAX_CONSTANT_DEPTH {
+        return vec![];
+    }
+
+    flatten(arg)
+        .iter()
+        .flat_map(|v| {
+            let v = v.trim();
+
+            if v.starts_with(|c: char| c.is_ascii_digit() || c == '-') {
+                return v
+                    .trim_end_matches(['L', 'l'])
+                    .replace('_', "")
+                    .parse::<i64>()
+                    .map(|v| vec![v])
+                    .unwrap_or_default();
+            }
+
+            let name = v.rsplit('.').next().unwrap_or_default();
+
+            constants
+                .get(name)
+                .map(|v| resolve_values(v, constants, depth + 1))
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{calls, parse_constants, resolve_values, strip_comments, ParsedQuestScript};
+    use crate::data::{ItemId, NpcId};
+
+    #[test]
+    fn strips_comments() {
+        assert_eq!(strip_comments("a // b\nc"), "a \nc");
+        assert_eq!(strip_comments("a /* b\n*/ c"), "a \n c");
+        assert_eq!(strip_comments("a /*/ b */c"), "a \nc");
+        assert_eq!(strip_comments("a // b"), "a \n");
+    }
+
+    #[test]
+    fn keeps_literals_when_stripping_comments() {
+        let source = r#"html = "http://site/*.htm"; // comment
+c = '/'; s = "say \"//\""; // comment"#;
+
+        assert_eq!(
+            strip_comments(source),
+            "html = \"http://site/*.htm\"; \nc = '/'; s = \"say \\\"//\\\"\"; \n"
+        );
+    }
+
+    #[test]
+    fn parses_constants() {
+        let constants = parse_constants(
+            "private static final int NPC = 30_001;
+            public static final long COUNT = 10L;
+            private static final int[] MOBS = {20001, NPC};
+            private static final String NAME = \"x\";",
+        );
+
+        assert_eq!(constants["NPC"], "30_001");
+        assert_eq!(constants["COUNT"], "10L");
+        assert_eq!(constants["MOBS"], "{20001, NPC}");
+        assert_eq!(constants["ADENA"], "57");
+        assert!(!constants.contains_key("NAME"));
+    }
+
+    #[test]
+    fn extracts_calls() {
+        let source = "addKillId(A, B); myaddKillId(C); addKillId(new int[]{D, E}, f(G, H));";
+
+        assert_eq!(
+            calls(source, &["addKillId"]),
+            [vec!["A", "B"], vec!["new int[]{D, E}", "f(G, H)"]]
+        );
+        assert!(calls("addKillId(A", &["addKillId"]).is_empty());
+    }
+
+    #[test]
+    fn resolves_values() {
+        let constants = parse_constants(
+            "static final int A = 1_000;
+            static final int B = A;
+            static final int[] C = {B, 2};
+            static final int LOOP = LOOP;",
+        );
+        let resolve = |v: &str| resolve_values(v, &constants, 0);
+
+        assert_eq!(resolve("-5"), [-5]);
+        assert_eq!(resolve("10L"), [10]);
+        assert_eq!(resolve("B"), [1000]);
+        assert_eq!(resolve("Npcs.B"), [1000]);
+        assert_eq!(resolve("C"), [1000, 2]);
+        assert_eq!(resolve("List.of(C, 3)"), [1000, 2, 3]);
+        assert_eq!(resolve("UNKNOWN"), Vec::<i64>::new());
+        assert_eq!(resolve("LOOP"), Vec::<i64>::new());
+    }
+
+    #[test]
+    fn parses_script() {
+        let script = ParsedQuestScript::parse(
+            "private static final int LECTOR = 30001;
+            private static final int LETTER = 687;
+            // addStartNpc(1);
+            addStartNpc(LECTOR);
+            addKillId(20001, 20002);
+            registerQuestItems(LETTER);
+            addCondLevel(10, 20);
+            giveItems(player, LETTER, 1);
+            giveItems(player, ADENA_ID, 1_000);",
+        );
+
+        assert_eq!(script.start_npcs, [NpcId(30001)]);
+        assert_eq!(script.kill_npcs, [NpcId(20001), NpcId(20002)]);
+        assert_eq!(script.quest_items, [ItemId(687)]);
+        assert_eq!(script.rewards, [(ItemId(57), 1000)]);
+        assert_eq!(script.min_level, Some(10));
+        assert_eq!(script.max_level, Some(20));
+    }
+
+    #[test]
+    fn parses_rewards_given_on_quest_state() {
+        let script = ParsedQuestScript::parse(
+            "private static final int LETTER = 687;
+            registerQuestItems(LETTER);
+            st.giveItems(LETTER, 1);
+            st.giveItems(ADENA_ID, 1_000, 1);
+            qs . giveItems(1060, 5, 0);
+            quest.rewardItems(1061, 2);",
+        );
+
+        assert_eq!(
+            script.rewards,
+            [(ItemId(57), 1000), (ItemId(1060), 5), (ItemId(1061), 2)]
+        );
+    }
+}
@@ -0,0 +1,782 @@
+use crate::backend::dat_loader::StrUtils;
+use crate::backend::holder::{GameDataHolder, HolderMapOps};
+use crate::backend::server_side::ServerEmulator;
+use crate::data::{ItemId, NpcId, QuestId};
+use crate::entity::quest::{GoalType, Quest};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const QUEST_TEMPLATES_FOLDER: &str = "./templates";
+
+const MMOCORE_QUEST_TEMPLATE: &str = include_str!("../../../../files/templates/quest_mmocore.java");
+const L2J_MOBIUS_QUEST_TEMPLATE: &str =
+    include_str!("../../../../files/templates/quest_l2j_mobius.java");
+const ACIS_QUEST_TEMPLATE: &str = include_str!("../../../../files/templates/quest_acis.java");
+
+/**Value of template placeholder.
+
+`{{name}}` is replaced by [`TemplateValue::Text`], `{{#name}}...{{/name}}` repeats its body for
+every element of [`TemplateValue::List`], element fields shadow outer ones, `{{^name}}...{{/name}}`
+is rendered only for empty list. Flags are lists with zero or one empty element. Section tags
+placed on their own line don't leave empty lines
+ */
+#[derive(Clone)]
+pub enum TemplateValue {
+    Text(String),
+    List(Vec<TemplateScope>),
+}
+
+pub type TemplateScope = HashMap<&'static str, TemplateValue>;
+
+impl ServerEmulator {
+    fn builtin_quest_template(&self) -> &'static str {
+        match self {
+            ServerEmulator::Mmocore => MMOCORE_QUEST_TEMPLATE,
+            ServerEmulator::L2jMobius => L2J_MOBIUS_QUEST_TEMPLATE,
+            ServerEmulator::Acis => ACIS_QUEST_TEMPLATE,
+        }
+    }
+
+    pub fn quest_template_path(&self) -> PathBuf {
+        let name = match self {
+            ServerEmulator::Mmocore => "mmocore",
+            ServerEmulator::L2jMobius => "l2j_mobius",
+            ServerEmulator::Acis => "acis",
+        };
+
+        Path::new(QUEST_TEMPLATES_FOLDER).join(format!("quest_{name}.java"))
+    }
+
+    /**Reads template from [`QUEST_TEMPLATES_FOLDER`], built-in one is written there if file is
+    missing, so deleting the file restores it
+     */
+    pub fn load_quest_template(&self) -> String {
+        let path = self.quest_template_path();
+
+        if let Ok(v) = fs::read_to_string(&path) {
+            return v;
+        }
+
+        let template = self.builtin_quest_template();
+
+        if fs::create_dir_all(QUEST_TEMPLATES_FOLDER).is_ok() {
+            let _ = fs::write(path, template);
+        }
+
+        template.to_string()
+    }
+
+    pub fn quest_class_name(&self, id: QuestId, title: &str) -> String {
+        match self {
+            ServerEmulator::Mmocore => format!("_{}_{}", id.0, title.to_ascii_camel_case()),
+            ServerEmulator::L2jMobius => format!("Q{:05}_{}", id.0, title.to_ascii_camel_case()),
+            ServerEmulator::Acis => format!("Q{:03}_{}", id.0, title.to_ascii_camel_case()),
+        }
+    }
+
+    /**Mobius keeps every quest in its own package
+     */
+    pub fn quest_class_path(&self, quests_dir: &str, id: QuestId, title: &str) -> PathBuf {
+        let name = self.quest_class_name(id, title);
+
+        match self {
+            ServerEmulator::L2jMobius => Path::new(quests_dir)
+                .join(&name)
+                .join(format!("{name}.java")),
+            ServerEmulator::Mmocore | ServerEmulator::Acis => {
+                Path::new(quests_dir).join(format!("{name}.java"))
+            }
+        }
+    }
+
+    /**Id from class file name, works for every [`ServerEmulator::quest_class_name`] format
+     */
+    pub fn quest_id_from_file_name(file_name: &str) -> Option<QuestId> {
+        let name = file_name.strip_suffix(".java")?;
+        let id = name
+            .strip_prefix('_')
+            .or_else(|| name.strip_prefix('Q'))?
+            .split('_')
+            .next()?;
+
+        id.parse().ok().map(QuestId)
+    }
+}
+
+/**Placeholders available in quest templates:
+- `class_name`, `quest_id`, `quest_title`, `is_party`, `min_level`, `max_level`,
+  `start_npc_check`
+- `start_npcs`, `kill_npcs`, `quest_items`, `rewards` lists with `const`, `id`, `name` and
+  `declare` flag, which is set only for the first appearance of the constant. Rewards also have
+  `count`
+- `classes` list with `name` (`dark_avenger`) and `const` (`DARK_AVENGER`)
+- `first_cond` and `steps` list, finish steps are skipped. Step has `cond` (its level, which is
+  what client shows for server cond), `title`, `next_cond`, `has_next`, `is_last`, `is_hunt`,
+  `is_talk`, `has_goals`, `has_drop_npcs` flags, `goals` and `drop_npcs` (npcs dropping collected
+  items). Goal has `kind`, `const`, `id`, `count`, `var` (state variable for kill counter) and
+  `is_kill`, `is_collect`, `is_other` flags
+- `has_kill_npcs`, `has_quest_items`, `has_max_level`, `has_classes` flags
+
+Every list element also has `comma`, `or` and `and`, which are separators for all but the last
+one
+ */
+pub fn quest_template_scope(
+    quest: &Quest,
+    emulator: ServerEmulator,
+    game_data_holder: &GameDataHolder,
+) -> TemplateScope {
+    let mut constants = Constants::default();
+    let mut scope = TemplateScope::new();
+
+    let start_npcs: Vec<_> = quest
+        .start_npc_ids
+        .iter()
+        .map(|v| constants.npc(*v, game_data_holder))
+        .collect();
+
+    let start_npc_check = if start_npcs.is_empty() {
+        "false".to_string()
+    } else {
+        start_npcs
+            .iter()
+            .map(|v| format!("npc_id == {}", text(v, "const")))
+            .collect::<Vec<_>>()
+            .join(" || ")
+    };
+
+    let mut kill_npcs = vec![];
+    let mut quest_items = vec![];
+    let mut proceeded_npc = vec![];
+    let mut proceeded_items = vec![];
+    let mut step_goals = vec![];
+
+    for step in &quest.steps {
+        let mut goals = vec![];
+        let mut step_kill_npcs = vec![];
+
+        for goal in &step.inner.goals {
+            let (kind, mut goal_scope) = match goal.goal_type {
+                GoalType::KillNpc => {
+                    let npc = constants.npc(NpcId(goal.target_id), game_data_holder);
+
+                    if !proceeded_npc.contains(&goal.target_id) {
+                        proceeded_npc.push(goal.target_id);
+                        kill_npcs.push(npc.clone());
+                    }
+
+                    step_kill_npcs.push(npc.clone());
+
+                    ("Kill", npc)
+                }
+                GoalType::CollectItem => {
+                    let item = constants.item(ItemId(goal.target_id), game_data_holder);
+
+                    if !proceeded_items.contains(&goal.target_id) {
+                        proceeded_items.push(goal.target_id);
+                        quest_items.push(item.clone());
+                    }
+
+                    ("Collect", item)
+                }
+                GoalType::Other => {
+                    let mut v = TemplateScope::new();
+                    v.insert("const", TemplateValue::Text(goal.target_id.to_string()));
+                    v.insert("id", TemplateValue::Text(goal.target_id.to_string()));
+
+                    ("Other", v)
+                }
+            };
+
+            let var = format!(
+                "{}_{}_{}",
+                kind.to_lowercase(),
+                goal.target_id,
+                step.inner.level
+            );
+
+            goal_scope.insert("kind", TemplateValue::Text(kind.to_string()));
+            goal_scope.insert("var", TemplateValue::Text(var));
+            goal_scope.insert("count", TemplateValue::Text(goal.count.to_string()));
+            goal_scope.insert("is_kill", flag(goal.goal_type == GoalType::KillNpc));
+            goal_scope.insert("is_collect", flag(goal.goal_type == GoalType::CollectItem));
+            goal_scope.insert("is_other", flag(goal.goal_type == GoalType::Other));
+
+            goals.push(goal_scope);
+        }
+
+        step_goals.push((goals, step_kill_npcs));
+    }
+
+    let edges = quest.step_edges();
+    let mut steps = vec![];
+
+    for (i, (goals, step_kill_npcs)) in step_goals.into_iter().enumerate() {
+        if quest.is_finish_step(i) {
+            continue;
+        }
+
+        let step = &quest.steps[i].inner;
+
+        let next = edges
+            .iter()
+            .filter(|v| v.0 == i && !quest.is_finish_step(v.1))
+            .map(|v| quest.steps[v.1].inner.level)
+            .next();
+
+        let has_collect = step
+            .goals
+            .iter()
+            .any(|v| v.goal_type == GoalType::CollectItem);
+        let is_hunt = has_collect || !step_kill_npcs.is_empty();
+
+        //collected items drop from the step's own targets, or from any quest target
+        let drop_npcs = if !has_collect {
+            vec![]
+        } else if step_kill_npcs.is_empty() {
+            kill_npcs.clone()
+        } else {
+            step_kill_npcs
+        };
+
+        let mut v = TemplateScope::new();
+        v.insert("cond", TemplateValue::Text(step.level.to_string()));
+        v.insert("title", TemplateValue::Text(step.title.clone()));
+        v.insert("level", TemplateValue::Text(step.level.to_string()));
+        v.insert(
+            "next_cond",
+            TemplateValue::Text(next.map(|v| v.to_string()).unwrap_or_default()),
+        );
+        v.insert("has_next", flag(next.is_some()));
+        v.insert("is_last", flag(next.is_none()));
+        v.insert("is_hunt", flag(is_hunt));
+        v.insert("is_talk", flag(!is_hunt));
+        v.insert("has_goals", flag(!goals.is_empty()));
+        v.insert("goals", list(goals));
+        v.insert("has_drop_npcs", flag(!drop_npcs.is_empty()));
+        v.insert("drop_npcs", list(drop_npcs));
+
+        steps.push(v);
+    }
+
+    for id in &quest.quest_items {
+        if !proceeded_items.contains(&id.0) {
+            proceeded_items.push(id.0);
+            quest_items.push(constants.item(*id, game_data_holder));
+        }
+    }
+
+    let rewards: Vec<_> = quest
+        .rewards
+        .iter()
+        .map(|v| {
+            let mut r = constants.item(v.reward_id, game_data_holder);
+            r.insert("count", TemplateValue::Text(v.count.to_string()));
+
+            r
+        })
+        .collect();
+
+    let classes: Vec<_> = quest
+        .allowed_classes
+        .iter()
+        .flatten()
+        .map(|v| {
+            let name = v.to_string().to_ascii_snake_case();
+
+            let mut c = TemplateScope::new();
+            c.insert("const", TemplateValue::Text(name.to_uppercase()));
+            c.insert("name", TemplateValue::Text(name));
+
+            c
+        })
+        .collect();
+
+    scope.insert(
+        "class_name",
+        TemplateValue::Text(emulator.quest_class_name(quest.id, &quest.title)),
+    );
+    scope.insert("quest_id", TemplateValue::Text(quest.id.0.to_string()));
+    scope.insert(
+        "quest_title",
+        TemplateValue::Text(quest.title.replace('\\', "\\\\").replace('"', "\\\"")),
+    );
+    scope.insert("is_party", TemplateValue::Text("false".to_string()));
+    scope.insert("min_level", TemplateValue::Text(quest.min_lvl.to_string()));
+    scope.insert("max_level", TemplateValue::Text(quest.max_lvl.to_string()));
+    scope.insert("start_npc_check", TemplateValue::Text(start_npc_check));
+    scope.insert(
+        "first_cond",
+        TemplateValue::Text(
+            steps
+                .first()
+                .map(|v| text(v, "cond").to_string())
+                .unwrap_or_else(|| "1".to_string()),
+        ),
+    );
+
+    scope.insert("has_kill_npcs", flag(!kill_npcs.is_empty()));
+    scope.insert("has_quest_items", flag(!quest_items.is_empty()));
+    scope.insert("has_max_level", flag(quest.max_lvl > 0));
+    scope.insert("has_classes", flag(!classes.is_empty()));
+
+    scope.insert("start_npcs", list(start_npcs));
+    scope.insert("kill_npcs", list(kill_npcs));
+    scope.insert("quest_items", list(quest_items));
+    scope.insert("rewards", list(rewards));
+    scope.insert("classes", list(classes));
+    scope.insert("steps", list(steps));
+
+    scope
+}
+
+pub fn render_template(template: &str, scope: &TemplateScope) -> String {
+    let template = strip_standalone_sections(template);
+    let mut res = String::with_capacity(template.len());
+
+    render(&template, &mut vec![scope], &mut res);
+
+    res
+}
+
+fn render(template: &str, scopes: &mut Vec<&TemplateScope>, out: &mut String) {
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+
+        let after = &rest[start + 2..];
+
+        let Some(end) = after.find("}}") else {
+            out.push_str(&rest[start..]);
+
+            return;
+        };
+
+        let tag = after[..end].trim();
+        let raw_tag = &rest[start..start + end + 4];
+
+        rest = &after[end + 2..];
+
+        if let Some((inverted, name)) = section_name(tag) {
+            let close = format!("{{{{/{name}}}}}");
+
+            let Some(close_start) = rest.find(&close) else {
+                out.push_str(raw_tag);
+
+                continue;
+            };
+
+            let body = &rest[..close_start];
+            rest = &rest[close_start + close.len()..];
+
+            let items = match lookup(scopes, name) {
+                Some(TemplateValue::List(items)) => items.as_slice(),
+                _ => &[],
+            };
+
+            if inverted {
+                if items.is_empty() {
+                    render(body, scopes, out);
+                }
+            } else {
+                for item in items {
+                    scopes.push(item);
+                    render(body, scopes, out);
+                    scopes.pop();
+                }
+            }
+        } else if let Some(TemplateValue::Text(v)) = lookup(scopes, tag) {
+            out.push_str(v);
+        } else {
+            out.push_str(raw_tag);
+        }
+    }
+
+    out.push_str(rest);
+}
+
+/**`{{#name}}` or inverted `{{^name}}`, which is rendered once if list is empty
+ */
+fn section_name(tag: &str) -> Option<(bool, &str)> {
+    if let Some(name) = tag.strip_prefix('#') {
+        Some((false, name))
+    } else {
+        tag.strip_prefix('^').map(|name| (true, name))
+    }
+}
+
+fn lookup<'a>(scopes: &[&'a TemplateScope], name: &str) -> Option<&'a TemplateValue> {
+    scopes.iter().rev().find_map(|v| {
+        let v: &'a TemplateScope = v;
+
+        v.get(name)
+    })
+}
+
+fn strip_standalone_sections(template: &str) -> String {
+    let mut res = String::with_capacity(template.len());
+
+    for line in template.split_inclusive('\n') {
+        let trimmed = line.trim();
+
+        let standalone = (trimmed.starts_with("{{#")
+            || trimmed.starts_with("{{^")
+            || trimmed.starts_with("{{/"))
+            && trimmed.ends_with("}}")
+            && trimmed.matches("{{").count() == 1;
+
+        if standalone {
+            res.push_str(trimmed);
+        } else {
+            res.push_str(line);
+        }
+    }
+
+    res
+}
+
+fn text<'a>(scope: &'a TemplateScope, name: &str) -> &'a str {
+    if let Some(TemplateValue::Text(v)) = scope.get(name) {
+        v
+    } else {
+        ""
+    }
+}
+
+fn list(mut items: Vec<TemplateScope>) -> TemplateValue {
+    let len = items.len();
+
+    for (i, v) in items.iter_mut().enumerate() {
+        let last = i + 1 == len;
+
+        v.insert("comma", separator(last, ", "));
+        v.insert("or", separator(last, " || "));
+        v.insert("and", separator(last, " && "));
+    }
+
+    TemplateValue::List(items)
+}
+
+fn separator(last: bool, val: &str) -> TemplateValue {
+    TemplateValue::Text(if last { "" } else { val }.to_string())
+}
+
+fn flag(val: bool) -> TemplateValue {
+    list(if val {
+        vec![TemplateScope::new()]
+    } else {
+        vec![]
+    })
+}
+
+/**Java constant names, same name for different ids gets id suffix
+ */
+#[derive(Default)]
+struct Constants {
+    names: HashMap<String, (&'static str, u32)>,
+}
+
+impl Constants {
+    fn npc(&mut self, id: NpcId, game_data_holder: &GameDataHolder) -> TemplateScope {
+        let name = game_data_holder.npc_holder.get(&id).map(|v| v.name.clone());
+
+        self.get("NPC", id.0, name.unwrap_or_default())
+    }
+
+    fn item(&mut self, id: ItemId, game_data_holder: &GameDataHolder) -> TemplateScope {
+        let name = game_data_holder
+            .item_holder
+            .get(&id)
+            .map(|v| v.name.clone());
+
+        self.get("ITEM", id.0, name.unwrap_or_default())
+    }
+
+    fn get(&mut self, kind: &'static str, id: u32, name: String) -> TemplateScope {
+        let mut constant = name.to_ascii_snake_case().to_uppercase();
+
+        if constant.is_empty() || constant.starts_with(|c: char| c.is_ascii_digit()) {
+            constant = format!("{kind}_{id}");
+        }
+
+        if self.names.get(&constant).is_some_and(|v| *v != (kind, id)) {
+            constant = format!("{constant}_{id}");
+        }
+
+        let declare = self.names.insert(constant.clone(), (kind, id)).is_none();
+
+        let mut res = TemplateScope::new();
+        res.insert("const", TemplateValue::Text(constant));
+        res.insert("id", TemplateValue::Text(id.to_string()));
+        res.insert("name", TemplateValue::Text(name));
+        res.insert("declare", flag(declare));
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::entity_editor::WindowParams;
+    use crate::backend::entity_impl::quest::StepAction;
+    use crate::entity::quest::{QuestStep, StepGoal};
+
+    fn scope(fields: Vec<(&'static str, TemplateValue)>) -> TemplateScope {
+        fields.into_iter().collect()
+    }
+
+    fn txt(val: &str) -> TemplateValue {
+        TemplateValue::Text(val.to_string())
+    }
+
+    fn ids(ids: &[u32]) -> TemplateValue {
+        list(
+            ids.iter()
+                .map(|v| scope(vec![("id", txt(&v.to_string()))]))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn nested_sections_shadow_outer_fields() {
+        let root = scope(vec![
+            ("name", txt("top")),
+            (
+                "outer",
+                list(vec![
+                    scope(vec![
+                        ("name", txt("a")),
+                        (
+                            "inner",
+                            list(vec![
+                                scope(vec![("name", txt("x"))]),
+                                scope(vec![("name", txt("y"))]),
+                            ]),
+                        ),
+                    ]),
+                    scope(vec![("inner", list(vec![scope(vec![("name", txt("z"))])]))]),
+                ]),
+            ),
+        ]);
+
+        assert_eq!(
+            render_template(
+                "{{#outer}}{{name}}:{{#inner}}{{name}}{{comma}}{{/inner}};{{/outer}}{{ name }}",
+                &root
+            ),
+            "a:x, y;top:z;top"
+        );
+    }
+
+    #[test]
+    fn inverted_sections_render_for_empty_lists() {
+        let template = "{{^items}}none{{/items}}{{#items}}{{id}}{{or}}{{/items}}";
+
+        assert_eq!(render_template(template, &scope(vec![])), "none");
+        assert_eq!(
+            render_template(template, &scope(vec![("items", ids(&[]))])),
+            "none"
+        );
+        assert_eq!(
+            render_template(template, &scope(vec![("items", ids(&[1, 2]))])),
+            "1 || 2"
+        );
+
+        let template = "{{#flag}}yes{{/flag}}{{^flag}}no{{/flag}}";
+
+        assert_eq!(
+            render_template(template, &scope(vec![("flag", flag(true))])),
+            "yes"
+        );
+        assert_eq!(
+            render_template(template, &scope(vec![("flag", flag(false))])),
+            "no"
+        );
+    }
+
+    #[test]
+    fn standalone_section_lines_leave_no_empty_lines() {
+        let template = "class A {\n    {{#items}}\n    int {{id}};\n    {{/items}}\n}\n";
+
+        assert_eq!(
+            render_template(template, &scope(vec![("items", ids(&[1, 2]))])),
+            "class A {\n    int 1;\n    int 2;\n}\n"
+        );
+        assert_eq!(
+            render_template(template, &scope(vec![("items", ids(&[]))])),
+            "class A {\n}\n"
+        );
+    }
+
+    #[test]
+    fn strips_only_lines_with_single_section_tag() {
+        assert_eq!(
+            strip_standalone_sections("  {{#a}}  \n x {{y}}\n\t{{/a}}\r\n{{^b}}{{/b}}\n"),
+            "{{#a}} x {{y}}\n{{/a}}{{^b}}{{/b}}\n"
+        );
+        assert_eq!(
+            strip_standalone_sections("a {{#items}}\n{{id}}\n"),
+            "a {{#items}}\n{{id}}\n"
+        );
+    }
+
+    #[test]
+    fn unknown_and_unclosed_tags_are_kept() {
+        assert_eq!(
+            render_template("{{missing}} {{#open}}x {{broken", &scope(vec![])),
+            "{{missing}} {{#open}}x {{broken"
+        );
+    }
+
+    #[test]
+    fn constants_get_id_suffix_on_name_clash() {
+        let mut constants = Constants::default();
+
+        let mut get = |kind, id, name: &str| {
+            let v = constants.get(kind, id, name.to_string());
+            let declare = matches!(&v["declare"], TemplateValue::List(v) if v.len() == 1);
+
+            (text(&v, "const").to_string(), declare)
+        };
+
+        assert_eq!(get("NPC", 1, "Gremlin"), ("GREMLIN".to_string(), true));
+        assert_eq!(get("NPC", 1, "Gremlin"), ("GREMLIN".to_string(), false));
+        assert_eq!(get("NPC", 2, "Gremlin"), ("GREMLIN_2".to_string(), true));
+        assert_eq!(get("NPC", 2, "Gremlin"), ("GREMLIN_2".to_string(), false));
+        assert_eq!(get("ITEM", 1, "Gremlin"), ("GREMLIN_1".to_string(), true));
+        assert_eq!(get("ITEM", 3, ""), ("ITEM_3".to_string(), true));
+        assert_eq!(get("NPC", 4, "1st Guard"), ("NPC_4".to_string(), true));
+    }
+
+    #[test]
+    fn quest_id_from_every_class_name_format() {
+        for emulator in [
+            ServerEmulator::Mmocore,
+            ServerEmulator::L2jMobius,
+            ServerEmulator::Acis,
+        ] {
+            let name = emulator.quest_class_name(QuestId(42), "Letters of Love");
+
+            assert_eq!(
+                ServerEmulator::quest_id_from_file_name(&format!("{name}.java")),
+                Some(QuestId(42)),
+                "{name}"
+            );
+        }
+
+        assert_eq!(
+            ServerEmulator::quest_id_from_file_name("Q42.java"),
+            Some(QuestId(42))
+        );
+
+        for file_name in [
+            "Q00042_Foo.txt",
+            "__42_Foo.java",
+            "QQ42.java",
+            "_Q42.java",
+            "42.java",
+        ] {
+            assert_eq!(
+                ServerEmulator::quest_id_from_file_name(file_name),
+                None,
+                "{file_name}"
+            );
+        }
+    }
+
+    fn step(
+        level: u32,
+        prev_steps: Vec<u32>,
+        goals: Vec<StepGoal>,
+    ) -> WindowParams<QuestStep, (), StepAction, ()> {
+        WindowParams {
+            inner: QuestStep {
+                title: format!("Step {level}"),
+                level,
+                prev_steps,
+                goals,
+                ..Default::default()
+            },
+            opened: false,
+            initial_id: (),
+            action: Default::default(),
+            params: (),
+        }
+    }
+
+    fn hunt_quest() -> Quest {
+        Quest {
+            id: QuestId(7),
+            title: "Gremlin Hunt".to_string(),
+            steps: vec![
+                step(
+                    1,
+                    vec![],
+                    vec![
+                        StepGoal {
+                            target_id: 20241,
+                            goal_type: GoalType::KillNpc,
+                            count: 5,
+                        },
+                        StepGoal {
+                            target_id: 39508,
+                            goal_type: GoalType::CollectItem,
+                            count: 3,
+                        },
+                    ],
+                ),
+                step(2, vec![1], vec![]),
+                step(u32::MAX - 1, vec![2], vec![]),
+            ],
+            last_finish_step_id: u32::MAX - 1,
+            start_npc_ids: vec![NpcId(30001)],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn steps_chain_conds_and_skip_finish() {
+        let scope = quest_template_scope(
+            &hunt_quest(),
+            ServerEmulator::L2jMobius,
+            &GameDataHolder::default(),
+        );
+
+        assert_eq!(
+            render_template(
+                "{{first_cond}}|{{#steps}}{{cond}}>{{next_cond}}{{#is_hunt}} hunt{{/is_hunt}}\
+                 {{#is_talk}} talk{{/is_talk}}{{#is_last}} last{{/is_last}}\
+                 {{#drop_npcs}} {{const}}{{/drop_npcs}}{{#goals}} {{var}}{{/goals}};{{/steps}}|\
+                 {{start_npc_check}}",
+                &scope
+            ),
+            "1|1>2 hunt NPC_20241 kill_20241_1 collect_39508_1;2> talk last;|npc_id == NPC_30001"
+        );
+    }
+
+    #[test]
+    fn builtin_templates_have_no_unknown_placeholders() {
+        let quest = hunt_quest();
+
+        for emulator in [
+            ServerEmulator::Mmocore,
+            ServerEmulator::L2jMobius,
+            ServerEmulator::Acis,
+        ] {
+            let scope = quest_template_scope(&quest, emulator, &GameDataHolder::default());
+            let res = render_template(emulator.builtin_quest_template(), &scope);
+
+            assert!(!res.contains("{{"), "{emulator:?}: {res}");
+            assert!(
+                res.contains(&emulator.quest_class_name(quest.id, &quest.title)),
+                "{emulator:?}"
+            );
+            assert!(
+                res.contains("20241") && res.contains("39508"),
+                "{emulator:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,721 @@
+use crate::backend::holder::{GameDataHolder, HolderMapOps};
+use crate::backend::log_holder::{Log, LogLevel};
+use crate::data::{ItemId, NpcId};
+use crate::entity::item::ItemBaseInfo;
+use crate::entity::npc::Npc;
+use crate::entity::EntityT;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+use xml::reader::{EventReader, XmlEvent};
+
+const PRODUCER: &str = "Server Stats";
+
+/**Npc from server `stats/npcs` xml, fields missing in xml are `None`
+ */
+#[derive(Default, Clone, Debug)]
+pub struct ServerNpc {
+    pub id: NpcId,
+    pub name: String,
+    pub title: String,
+    pub level: Option<u32>,
+    pub npc_type: String,
+    pub hp: Option<f64>,
+    pub mp: Option<f64>,
+    pub collision_radius: Option<f64>,
+    pub collision_height: Option<f64>,
+    pub walk_speed: Option<f64>,
+    pub run_speed: Option<f64>,
+}
+
+impl ServerNpc {
+    /**Old L2J style `<set name="..." val="..."/>` stats
+     */
+    fn set(&mut self, name: &str, val: &str) {
+        match name {
+            "level" => self.level = val.parse().ok(),
+            "baseHpMax" | "hp" => self.hp = val.parse().ok(),
+            "baseMpMax" | "mp" => self.mp = val.parse().ok(),
+            "collision_radius" | "radius" => self.collision_radius = val.parse().ok(),
+            "collision_height" | "height" => self.collision_height = val.parse().ok(),
+            "baseWalkSpd" | "walkSpd" => self.walk_speed = val.parse().ok(),
+            "baseRunSpd" | "runSpd" => self.run_speed = val.parse().ok(),
+            "title" => self.title = val.to_string(),
+            _ => {}
+        }
+    }
+}
+
+/**Item from server `stats/items` xml
+ */
+#[derive(Default, Clone, Debug)]
+pub struct ServerItem {
+    pub id: ItemId,
+    pub name: String,
+    pub item_type: String,
+    pub weight: Option<u32>,
+    pub price: Option<i64>,
+}
+
+impl ServerItem {
+    fn set(&mut self, name: &str, val: &str) {
+        match name {
+            "weight" => self.weight = val.parse().ok(),
+            "price" => self.price = val.parse().ok(),
+            _ => {}
+        }
+    }
+}
+
+pub struct StatDifference {
+    pub field: &'static str,
+    pub server: String,
+    pub client: String,
+}
+
+/**Npcs and items parsed from server stats folder (the one with `npcs` and `items` subfolders)
+ */
+#[derive(Default)]
+pub struct ServerStatsHolder {
+    pub npcs: HashMap<NpcId, ServerNpc>,
+    pub items: HashMap<ItemId, ServerItem>,
+}
+
+impl ServerStatsHolder {
+    /**Files which can't be parsed are skipped and returned as warnings
+     */
+    pub fn load(path: &str) -> (Self, Vec<Log>) {
+        let mut res = Self::default();
+        let mut warnings = vec![];
+
+        for dir in ["npcs", "items"] {
+            for entry in WalkDir::new(Path::new(path).join(dir))
+                .into_iter()
+                .flatten()
+            {
+                if !entry.file_type().is_file()
+                    || entry.path().extension().and_then(|v| v.to_str()) != Some("xml")
+                {
+                    continue;
+                }
+
+                if let Err(e) = res.parse_file(entry.path()) {
+                    warnings.push(Log {
+                        level: LogLevel::Warning,
+                        producer: PRODUCER.to_string(),
+                        log: format!("{}: {e}", entry.path().display()),
+                        entity: None,
+                    });
+                }
+            }
+        }
+
+        warnings.push(Log {
+            level: LogLevel::Info,
+            producer: PRODUCER.to_string(),
+            log: format!(
+                "Loaded {} npcs and {} items from {path}",
+                res.npcs.len(),
+                res.items.len()
+            ),
+            entity: None,
+        });
+
+        (res, warnings)
+    }
+
+    /**Handles both Mobius style (`<vitals hp=".."/>`, `<collision><radius normal=".."/>`) and
+    old L2J `<set name val>` npcs. `<item>` inside npc is its drop, not an item
+     */
+    fn parse_file(&mut self, path: &Path) -> Result<(), String> {
+        let file = File::open(path).map_err(|e| e.to_string())?;
+
+        let mut npc: Option<ServerNpc> = None;
+        let mut item: Option<ServerItem> = None;
+
+        for e in EventReader::new(BufReader::new(file)) {
+            match e.map_err(|e| e.to_string())? {
+                XmlEvent::StartElement {
+                    name, attributes, ..
+                } => {
+                    let attr = |n: &str| {
+                        attributes
+                            .iter()
+                            .find(|v| v.name.local_name == n)
+                            .map(|v| v.value.as_str())
+                    };
+                    let num = |n: &str| attr(n).and_then(|v| v.parse::<f64>().ok());
+
+                    match name.local_name.as_str() {
+                        "npc" => {
+                            npc = attr("id").and_then(|v| v.parse().ok()).map(|id| ServerNpc {
+                                id: NpcId(id),
+                                name: attr("name").unwrap_or_default().to_string(),
+                                title: attr("title").unwrap_or_default().to_string(),
+                                level: attr("level").and_then(|v| v.parse().ok()),
+                                npc_type: attr("type").unwrap_or_default().to_string(),
+                                ..Default::default()
+                            })
+                        }
+                        "item" if npc.is_none() => {
+                            item = attr("id")
+                                .and_then(|v| v.parse().ok())
+                                .map(|id| ServerItem {
+                                    id: ItemId(id),
+                                    name: attr("name").unwrap_or_default().to_string(),
+                                    item_type: attr("type").unwrap_or_default().to_string(),
+                                    ..Default::default()
+                                })
+                        }
+                        "set" => {
+                            let (Some(n), Some(val)) = (attr("name"), attr("val")) else {
+                                continue;
+                            };
+
+                            if let Some(v) = &mut npc {
+                                v.set(n, val);
+                            } else if let Some(v) = &mut item {
+                                v.set(n, val);
+                            }
+                        }
+                        _ => {
+                            let Some(v) = &mut npc else { continue };
+
+                            match name.local_name.as_str() {
+                                "vitals" => {
+                                    v.hp = num("hp").or(v.hp);
+                                    v.mp = num("mp").or(v.mp);
+                                }
+                                "walk" => v.walk_speed = num("ground").or(v.walk_speed),
+                                "run" => v.run_speed = num("ground").or(v.run_speed),
+                                "radius" => {
+                                    v.collision_radius = num("normal").or(v.collision_radius)
+                                }
+                                "height" => {
+                                    v.collision_height = num("normal").or(v.collision_height)
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+                }
+
+                XmlEvent::EndElement { name } => match name.local_name.as_str() {
+                    "npc" => {
+                        if let Some(v) = npc.take() {
+                            self.npcs.insert(v.id, v);
+                        }
+                    }
+                    "item" => {
+                        if let Some(v) = item.take() {
+                            self.items.insert(v.id, v);
+                        }
+                    }
+                    _ => {}
+                },
+
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /**Differences of npc or item with its server version. Client has no npc level, so it's
+    not compared. Other entities have no differences
+     */
+    pub fn differences(&self, entity: EntityT, holder: &GameDataHolder) -> Vec<StatDifference> {
+        match entity {
+            EntityT::Npc(id) => {
+                let client = holder.npc_holder.get(&id).filter(|v| !v._deleted);
+
+                match (self.npcs.get(&id), client) {
+                    (Some(server), Some(client)) => npc_differences(server, client),
+                    (server, client) => existence(server.is_some(), client.is_some()),
+                }
+            }
+
+            EntityT::Weapon(id) | EntityT::Armor(id) | EntityT::EtcItem(id) => {
+                match (self.items.get(&id), client_item(holder, id)) {
+                    (Some(server), Some((item_type, client))) => {
+                        item_differences(server, item_type, client)
+                    }
+                    (server, client) => existence(server.is_some(), client.is_some()),
+                }
+            }
+
+            _ => vec![],
+        }
+    }
+
+    /**Every client npc/item with differences plus server ones missing in client
+     */
+    pub fn all_differences(&self, holder: &GameDataHolder) -> Vec<(EntityT, StatDifference)> {
+        let mut entities: Vec<EntityT> = holder
+            .npc_holder
+            .values()
+            .filter(|v| !v._deleted)
+            .map(|v| EntityT::Npc(v.id))
+            .collect();
+
+        entities.extend(
+            self.npcs
+                .keys()
+                .filter(|v| holder.npc_holder.get(v).is_none())
+                .map(|v| EntityT::Npc(*v)),
+        );
+
+        for id in holder.item_holder.keys() {
+            if let Some(v) = holder.item_entity(*id) {
+                entities.push(v);
+            }
+        }
+
+        entities.extend(
+            self.items
+                .values()
+                .filter(|v| holder.item_entity(v.id).is_none())
+                .map(|v| match v.item_type.as_str() {
+                    "Weapon" => EntityT::Weapon(v.id),
+                    "Armor" => EntityT::Armor(v.id),
+                    _ => EntityT::EtcItem(v.id),
+                }),
+        );
+
+        entities.sort();
+
+        entities
+            .into_iter()
+            .flat_map(|entity| {
+                self.differences(entity, holder)
+                    .into_iter()
+                    .map(move |v| (entity, v))
+            })
+            .collect()
+    }
+}
+
+fn client_item(holder: &GameDataHolder, id: ItemId) -> Option<(&'static str, &ItemBaseInfo)> {
+    if let Some(v) = holder.weapon_holder.get(&id).filter(|v| !v._deleted) {
+        Some(("Weapon", &v.base_info))
+    } else if let Some(v) = holder.armor_holder.get(&id).filter(|v| !v._deleted) {
+        Some(("Armor", &v.base_info))
+    } else {
+        holder
+            .etc_item_holder
+            .get(&id)
+            .filter(|v| !v._deleted)
+            .map(|v| ("EtcItem", &v.base_info))
+    }
+}
+
+fn existence(server: bool, client: bool) -> Vec<StatDifference> {
+    if server == client {
+        return vec![];
+    }
+
+    let state = |v: bool| if v { "exists" } else { "missing" }.to_string();
+
+    vec![StatDifference {
+        field: "Exists",
+        server: state(server),
+        client: state(client),
+    }]
+}
+
+fn text(field: &'static str, server: &str, client: &str) -> Option<StatDifference> {
+    (server != client).then(|| StatDifference {
+        field,
+        server: server.to_string(),
+        client: client.to_string(),
+    })
+}
+
+/**Client keeps most of the stats as floats, so tiny differences are ignored
+ */
+fn number(field: &'static str, server: Option<f64>, client: f64) -> Option<StatDifference> {
+    let server = server?;
+
+    ((server - client).abs() > 0.01).then(|| StatDifference {
+        field,
+        server: server.to_string(),
+        client: client.to_string(),
+    })
+}
+
+fn npc_differences(server: &ServerNpc, client: &Npc) -> Vec<StatDifference> {
+    let mesh = &client.mesh_params.inner;
+
+    [
+        text("Name", &server.name, &client.name),
+        text("Title", &server.title, &client.title),
+        number("HP", server.hp, client.org_hp),
+        number("MP", server.mp, client.org_mp),
+        number(
+            "Collision Radius",
+            server.collision_radius,
+            mesh.collision_radius_1 as f64,
+        ),
+        number(
+            "Collision Height",
+            server.collision_height,
+            mesh.collision_height_1 as f64,
+        ),
+        number("Walk Speed", server.walk_speed, mesh.walk_speed as f64),
+        number("Run Speed", server.run_speed, mesh.run_speed as f64),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn item_differences(
+    server: &ServerItem,
+    item_type: &str,
+    client: &ItemBaseInfo,
+) -> Vec<StatDifference> {
+    [
+        text("Name", &server.name, &client.name),
+        text("Type", &server.item_type, item_type),
+        number(
+            "Weight",
+            server.weight.map(|v| v as f64),
+            client.weight as f64,
+        ),
+        number(
+            "Price",
+            server.price.map(|v| v as f64),
+            client.default_price as f64,
+        ),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn escape(val: &str) -> String {
+    val.replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/**Skeleton server xml of client npc or item, only fields known to client are filled.
+Returns file name relative to stats folder and file content
+ */
+pub fn skeleton_xml(
+    entity: EntityT,
+    holder: &GameDataHolder,
+    server: &ServerStatsHolder,
+) -> Option<(PathBuf, String)> {
+    match entity {
+        EntityT::Npc(id) => holder.npc_holder.get(&id).map(|v| {
+            (
+                Path::new("npcs").join(format!("{}.xml", id.0)),
+                npc_skeleton_xml(v, server.npcs.get(&id)),
+            )
+        }),
+
+        EntityT::Weapon(id) | EntityT::Armor(id) | EntityT::EtcItem(id) => client_item(holder, id)
+            .map(|(item_type, v)| {
+                (
+                    Path::new("items").join(format!("{}.xml", id.0)),
+                    item_skeleton_xml(item_type, v),
+                )
+            }),
+
+        _ => None,
+    }
+}
+
+/**Client has no npc level and its npc type numbers don't match server types, so level and
+type are kept from server version of the npc. New npcs get no level and are `Monster` if client
+shows their hp, `Folk` otherwise
+ */
+fn npc_skeleton_xml(npc: &Npc, server: Option<&ServerNpc>) -> String {
+    let mesh = &npc.mesh_params.inner;
+
+    let level = server
+        .and_then(|v| v.level)
+        .map(|v| format!(r#" level="{v}""#))
+        .unwrap_or_default();
+
+    let npc_type = match server {
+        Some(v) if !v.npc_type.is_empty() => v.npc_type.as_str(),
+        _ if npc.show_hp => "Monster",
+        _ => "Folk",
+    };
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<list xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:noNamespaceSchemaLocation="../../xsd/npcs.xsd">
+	<npc id="{id}"{level} type="{npc_type}" name="{name}" title="{title}">
+		<stats>
+			<vitals hp="{hp}" mp="{mp}" />
+			<speed>
+				<walk ground="{walk}" />
+				<run ground="{run}" />
+			</speed>
+		</stats>
+		<collision>
+			<radius normal="{radius}" />
+			<height normal="{height}" />
+		</collision>
+	</npc>
+</list>
+"#,
+        id = npc.id.0,
+        npc_type = escape(npc_type),
+        name = escape(&npc.name),
+        title = escape(&npc.title),
+        hp = npc.org_hp,
+        mp = npc.org_mp,
+        walk = mesh.walk_speed,
+        run = mesh.run_speed,
+        radius = mesh.collision_radius_1,
+        height = mesh.collision_height_1,
+    )
+}
+
+fn item_skeleton_xml(item_type: &str, info: &ItemBaseInfo) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<list xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:noNamespaceSchemaLocation="../../xsd/items.xsd">
+	<item id="{id}" type="{item_type}" name="{name}">
+		<set name="weight" val="{weight}" />
+		<set name="price" val="{price}" />
+	</item>
+</list>
+"#,
+        id = info.id.0,
+        name = escape(&info.name),
+        weight = info.weight,
+        price = info.default_price,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    /**Writes `files` (path relative to the folder, content) into a fresh temp stats folder
+     */
+    fn stats_dir(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("l2w_stats_{}_{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        for (file, content) in files {
+            let path = dir.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+
+        dir
+    }
+
+    fn load(name: &str, files: &[(&str, &str)]) -> (ServerStatsHolder, Vec<Log>) {
+        let dir = stats_dir(name, files);
+        let res = ServerStatsHolder::load(dir.to_str().unwrap());
+
+        fs::remove_dir_all(dir).unwrap();
+
+        res
+    }
+
+    const MOBIUS_NPC: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<list>
+	<npc id="20241" level="34" type="Monster" name="Gremlin &amp; Co" title="Pest">
+		<stats>
+			<vitals hp="1200.5" mp="300" />
+			<speed>
+				<walk ground="50" />
+				<run ground="120" />
+			</speed>
+		</stats>
+		<collision>
+			<radius normal="10" />
+			<height normal="22.5" />
+		</collision>
+		<dropLists>
+			<drop>
+				<item id="57" min="1" max="10" chance="70" />
+			</drop>
+		</dropLists>
+	</npc>
+</list>
+"#;
+
+    const L2J_NPC: &str = r#"<list>
+	<npc id="30001" name="Lector">
+		<set name="level" val="20" />
+		<set name="baseHpMax" val="2444" />
+		<set name="baseMpMax" val="1000" />
+		<set name="collision_radius" val="8" />
+		<set name="collision_height" val="23" />
+		<set name="baseWalkSpd" val="40" />
+		<set name="baseRunSpd" val="100" />
+		<set name="title" val="Trader" />
+	</npc>
+</list>
+"#;
+
+    const ITEMS: &str = r#"<list>
+	<item id="57" type="EtcItem" name="Adena">
+		<set name="weight" val="0" />
+		<set name="price" val="1" />
+	</item>
+	<item id="1" type="Weapon" name="Short Sword">
+		<set name="weight" val="1600" />
+	</item>
+</list>
+"#;
+
+    #[test]
+    fn parses_mobius_npcs_without_drop_items() {
+        let (holder, _) = load("mobius", &[("npcs/20200-20299.xml", MOBIUS_NPC)]);
+        let npc = &holder.npcs[&NpcId(20241)];
+
+        assert_eq!(npc.name, "Gremlin & Co");
+        assert_eq!(npc.title, "Pest");
+        assert_eq!(npc.level, Some(34));
+        assert_eq!(npc.npc_type, "Monster");
+        assert_eq!(npc.hp, Some(1200.5));
+        assert_eq!(npc.mp, Some(300.0));
+        assert_eq!(npc.walk_speed, Some(50.0));
+        assert_eq!(npc.run_speed, Some(120.0));
+        assert_eq!(npc.collision_radius, Some(10.0));
+        assert_eq!(npc.collision_height, Some(22.5));
+        assert!(holder.items.is_empty());
+    }
+
+    #[test]
+    fn parses_old_l2j_set_stats() {
+        let (holder, _) = load(
+            "l2j",
+            &[
+                ("npcs/custom/30001.xml", L2J_NPC),
+                ("items/00000-00099.xml", ITEMS),
+            ],
+        );
+        let npc = &holder.npcs[&NpcId(30001)];
+
+        assert_eq!(npc.title, "Trader");
+        assert_eq!(npc.level, Some(20));
+        assert_eq!(npc.hp, Some(2444.0));
+        assert_eq!(npc.mp, Some(1000.0));
+        assert_eq!(npc.walk_speed, Some(40.0));
+        assert_eq!(npc.run_speed, Some(100.0));
+        assert_eq!(npc.collision_radius, Some(8.0));
+        assert_eq!(npc.collision_height, Some(23.0));
+
+        let adena = &holder.items[&ItemId(57)];
+
+        assert_eq!(adena.name, "Adena");
+        assert_eq!(adena.item_type, "EtcItem");
+        assert_eq!(adena.weight, Some(0));
+        assert_eq!(adena.price, Some(1));
+        assert_eq!(holder.items[&ItemId(1)].price, None);
+    }
+
+    #[test]
+    fn broken_files_are_skipped_with_warning() {
+        let (holder, logs) = load(
+            "broken",
+            &[
+                ("npcs/good.xml", L2J_NPC),
+                ("npcs/broken.xml", "<list><npc id=\"1\">"),
+                ("npcs/readme.txt", "<not xml"),
+            ],
+        );
+
+        assert_eq!(holder.npcs.len(), 1);
+
+        let warnings: Vec<_> = logs
+            .iter()
+            .filter(|v| v.level == LogLevel::Warning)
+            .collect();
+
+        assert_eq!(warnings.len(), 1);
+        assert!(
+            warnings[0].log.contains("broken.xml"),
+            "{}",
+            warnings[0].log
+        );
+    }
+
+    fn client_npc(show_hp: bool) -> Npc {
+        let mut npc = Npc {
+            id: NpcId(20241),
+            name: "Gremlin & Co".to_string(),
+            title: "<Pest>".to_string(),
+            show_hp,
+            org_hp: 1200.5,
+            org_mp: 300.0,
+            ..Default::default()
+        };
+
+        let mesh = &mut npc.mesh_params.inner;
+        mesh.walk_speed = 50;
+        mesh.run_speed = 120;
+        mesh.collision_radius_1 = 10.0;
+        mesh.collision_height_1 = 22.5;
+
+        npc
+    }
+
+    fn parse_npc_skeleton(xml: &str) -> ServerNpc {
+        let (holder, _) = load("skeleton", &[("npcs/skeleton.xml", xml)]);
+
+        holder.npcs.into_values().next().unwrap()
+    }
+
+    #[test]
+    fn npc_skeleton_reads_back_without_differences() {
+        let client = client_npc(true);
+        let server = parse_npc_skeleton(&npc_skeleton_xml(&client, None));
+
+        assert!(npc_differences(&server, &client).is_empty());
+        assert_eq!(server.level, None);
+        assert_eq!(server.npc_type, "Monster");
+
+        assert_eq!(
+            parse_npc_skeleton(&npc_skeleton_xml(&client_npc(false), None)).npc_type,
+            "Folk"
+        );
+    }
+
+    #[test]
+    fn npc_skeleton_keeps_server_level_and_type() {
+        let known = ServerNpc {
+            level: Some(40),
+            npc_type: "Guard".to_string(),
+            ..Default::default()
+        };
+
+        let server = parse_npc_skeleton(&npc_skeleton_xml(&client_npc(true), Some(&known)));
+
+        assert_eq!(server.level, Some(40));
+        assert_eq!(server.npc_type, "Guard");
+    }
+
+    #[test]
+    fn item_skeleton_reads_back_without_differences() {
+        let client = ItemBaseInfo {
+            id: ItemId(1),
+            name: "Short \"Sword\"".to_string(),
+            weight: 1600,
+            default_price: 590,
+            ..Default::default()
+        };
+
+        let (holder, _) = load(
+            "item_skeleton",
+            &[("items/1.xml", &item_skeleton_xml("Weapon", &client))],
+        );
+
+        assert!(item_differences(&holder.items[&ItemId(1)], "Weapon", &client).is_empty());
+    }
+}
@@ -0,0 +1,1446 @@
+use crate::backend::holder::HolderMapOps;
+use crate::backend::log_holder::{Log, LogLevel};
+use crate::backend::{Backend, Dialog};
+use crate::entity::{CommonEntity, Entity};
+use crate::logs_mut;
+use l2_rw::table::{field_path, item_path, Table, TableError, TableReader};
+use serde::de::value::{StrDeserializer, U32Deserializer};
+use serde::de::{
+    DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+use serde::ser::{
+    Impossible, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+    SerializeTupleStruct, SerializeTupleVariant,
+};
+use serde::{Deserializer, Serialize, Serializer};
+use std::collections::HashSet;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::Hash;
+use std::path::Path;
+use std::str::FromStr;
+
+/**Only `inner` of [`crate::backend::entity_editor::WindowParams`] goes to the table, without its
+own column segment. The rest is editor state and gets default values on import
+ */
+const WINDOW_PARAMS: &str = "WindowParams";
+
+/**Editor state of every entity, skipped and set to default on import. Other fields starting with
+`_` (like quest faction ones) are dat data and go to the table
+ */
+const EDITOR_FLAGS: [&str; 2] = ["_changed", "_deleted"];
+
+/**Entities flattened into [`Table`] rows, columns are named like [`Table`] describes.
+
+[`EDITOR_FLAGS`] are skipped and get default values on import.
+`None` leaves its columns empty, enums are written as variant names with variant data in
+`field.Variant` columns
+ */
+pub fn to_table<'a, V: Serialize + 'a>(values: impl IntoIterator<Item = &'a V>) -> Table {
+    Table::from_flat_rows(
+        values
+            .into_iter()
+            .map(|v| {
+                let mut row = vec![];
+
+                v.serialize(FlatSerializer {
+                    path: String::new(),
+                    out: &mut row,
+                })
+                .expect("entities have no maps");
+
+                row
+            })
+            .collect(),
+    )
+}
+
+pub fn from_table<V: DeserializeOwned>(table: &Table) -> Result<Vec<V>, TableError> {
+    let mut reader = TableReader::new(table)?;
+    let mut res = Vec::with_capacity(reader.len());
+
+    for row in 0..reader.len() {
+        let v = V::deserialize(FlatDeserializer {
+            reader: &mut reader,
+            row,
+            path: String::new(),
+        })
+        .map_err(|e| TableError::invalid(Some(row), &e.column.unwrap_or_default(), e.message))?;
+
+        res.push(v);
+    }
+
+    reader.check_unused()?;
+
+    Ok(res)
+}
+
+#[derive(Debug)]
+pub struct FlatError {
+    column: Option<String>,
+    message: String,
+}
+
+impl FlatError {
+    fn in_column(mut self, column: &str) -> Self {
+        if self.column.is_none() {
+            self.column = Some(column.to_string());
+        }
+
+        self
+    }
+}
+
+impl From<TableError> for FlatError {
+    fn from(value: TableError) -> Self {
+        match value {
+            TableError::Invalid {
+                column, message, ..
+            } => Self {
+                column: Some(column),
+                message,
+            },
+            e => Self {
+                column: None,
+                message: e.to_string(),
+            },
+        }
+    }
+}
+
+impl Display for FlatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for FlatError {}
+
+impl serde::ser::Error for FlatError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self {
+            column: None,
+            message: msg.to_string(),
+        }
+    }
+}
+
+impl serde::de::Error for FlatError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self {
+            column: None,
+            message: msg.to_string(),
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+//                                           Serialize
+//--------------------------------------------------------------------------------------------------
+
+struct FlatSerializer<'a> {
+    path: String,
+    out: &'a mut Vec<(String, String)>,
+}
+
+impl FlatSerializer<'_> {
+    fn leaf(self, value: impl ToString) -> Result<(), FlatError> {
+        self.out.push((self.path, value.to_string()));
+
+        Ok(())
+    }
+}
+
+/**Lists get `path[i]` columns, tuples and tuple variants get `path.i`
+ */
+struct FlatSeqSerializer<'a> {
+    path: String,
+    out: &'a mut Vec<(String, String)>,
+    index: usize,
+    is_list: bool,
+}
+
+impl FlatSeqSerializer<'_> {
+    fn element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), FlatError> {
+        let path = if self.is_list {
+            item_path(&self.path, self.index)
+        } else {
+            field_path(&self.path, &self.index.to_string())
+        };
+
+        self.index += 1;
+
+        value.serialize(FlatSerializer {
+            path,
+            out: self.out,
+        })
+    }
+}
+
+struct FlatStructSerializer<'a> {
+    path: String,
+    out: &'a mut Vec<(String, String)>,
+    window_params: bool,
+}
+
+impl FlatStructSerializer<'_> {
+    fn field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), FlatError> {
+        let path = if self.window_params {
+            if key != "inner" {
+                return Ok(());
+            }
+
+            self.path.clone()
+        } else if EDITOR_FLAGS.contains(&key) {
+            return Ok(());
+        } else {
+            field_path(&self.path, key)
+        };
+
+        value.serialize(FlatSerializer {
+            path,
+            out: self.out,
+        })
+    }
+}
+
+impl<'a> Serializer for FlatSerializer<'a> {
+    type Ok = ();
+    type Error = FlatError;
+    type SerializeSeq = FlatSeqSerializer<'a>;
+    type SerializeTuple = FlatSeqSerializer<'a>;
+    type SerializeTupleStruct = FlatSeqSerializer<'a>;
+    type SerializeTupleVariant = FlatSeqSerializer<'a>;
+    type SerializeMap = Impossible<(), FlatError>;
+    type SerializeStruct = FlatStructSerializer<'a>;
+    type SerializeStructVariant = FlatStructSerializer<'a>;
+
+    fn serialize_bool(self, v: bool) -> Result<(), FlatError> {
+        self.leaf(v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<(), FlatError> {
+        self.leaf(v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<(), FlatError> {
+        self.leaf(v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<(), FlatError> {
+        self.leaf(v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<(), FlatError> {
+        self.leaf(v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<(), FlatError> {
+        self.leaf(v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<(), FlatError> {
+        self.leaf(v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<(), FlatError> {
+        self.leaf(v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<(), FlatError> {
+        self.leaf(v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<(), FlatError> {
+        self.leaf(v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<(), FlatError> {
+        self.leaf(v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<(), FlatError> {
+        self.leaf(v)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), FlatError> {
+        self.leaf(v)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), FlatError> {
+        let mut seq = self.serialize_seq(Some(v.len()))?;
+
+        for b in v {
+            seq.element(b)?;
+        }
+
+        Ok(())
+    }
+
+    fn serialize_none(self) -> Result<(), FlatError> {
+        Ok(())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<(), FlatError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), FlatError> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), FlatError> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<(), FlatError> {
+        self.leaf(variant)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), FlatError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), FlatError> {
+        let path = field_path(&self.path, variant);
+        self.out.push((self.path, variant.to_string()));
+
+        value.serialize(FlatSerializer {
+            path,
+            out: self.out,
+        })
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<FlatSeqSerializer<'a>, FlatError> {
+        Ok(FlatSeqSerializer {
+            path: self.path,
+            out: self.out,
+            index: 0,
+            is_list: true,
+        })
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<FlatSeqSerializer<'a>, FlatError> {
+        Ok(FlatSeqSerializer {
+            path: self.path,
+            out: self.out,
+            index: 0,
+            is_list: false,
+        })
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<FlatSeqSerializer<'a>, FlatError> {
+        self.serialize_tuple(len)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<FlatSeqSerializer<'a>, FlatError> {
+        let path = field_path(&self.path, variant);
+        self.out.push((self.path, variant.to_string()));
+
+        Ok(FlatSeqSerializer {
+            path,
+            out: self.out,
+            index: 0,
+            is_list: false,
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, FlatError> {
+        Err(serde::ser::Error::custom(format!(
+            "{}: maps are not supported",
+            self.path
+        )))
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        _len: usize,
+    ) -> Result<FlatStructSerializer<'a>, FlatError> {
+        Ok(FlatStructSerializer {
+            path: self.path,
+            out: self.out,
+            window_params: name == WINDOW_PARAMS,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<FlatStructSerializer<'a>, FlatError> {
+        let path = field_path(&self.path, variant);
+        self.out.push((self.path, variant.to_string()));
+
+        Ok(FlatStructSerializer {
+            path,
+            out: self.out,
+            window_params: false,
+        })
+    }
+}
+
+impl SerializeSeq for FlatSeqSerializer<'_> {
+    type Ok = ();
+    type Error = FlatError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), FlatError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), FlatError> {
+        Ok(())
+    }
+}
+
+impl SerializeTuple for FlatSeqSerializer<'_> {
+    type Ok = ();
+    type Error = FlatError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), FlatError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), FlatError> {
+        Ok(())
+    }
+}
+
+impl SerializeTupleStruct for FlatSeqSerializer<'_> {
+    type Ok = ();
+    type Error = FlatError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), FlatError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), FlatError> {
+        Ok(())
+    }
+}
+
+impl SerializeTupleVariant for FlatSeqSerializer<'_> {
+    type Ok = ();
+    type Error = FlatError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), FlatError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), FlatError> {
+        Ok(())
+    }
+}
+
+impl SerializeStruct for FlatStructSerializer<'_> {
+    type Ok = ();
+    type Error = FlatError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), FlatError> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), FlatError> {
+        Ok(())
+    }
+}
+
+impl SerializeStructVariant for FlatStructSerializer<'_> {
+    type Ok = ();
+    type Error = FlatError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), FlatError> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), FlatError> {
+        Ok(())
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+//                                          Deserialize
+//--------------------------------------------------------------------------------------------------
+
+struct FlatDeserializer<'r, 't> {
+    reader: &'r mut TableReader<'t>,
+    row: usize,
+    path: String,
+}
+
+impl<'t> FlatDeserializer<'_, 't> {
+    fn nested(&mut self, path: String) -> FlatDeserializer<'_, 't> {
+        FlatDeserializer {
+            reader: self.reader,
+            row: self.row,
+            path,
+        }
+    }
+
+    fn cell(&mut self) -> Result<&'t str, FlatError> {
+        Ok(self.reader.cell(self.row, &self.path)?)
+    }
+
+    fn parse<T: FromStr>(&mut self, type_name: &str) -> Result<T, FlatError> {
+        let cell = self.cell()?.trim();
+
+        cell.parse().map_err(|_| FlatError {
+            column: Some(self.path.clone()),
+            message: format!("{cell:?} is not {type_name}"),
+        })
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($name:ident => $ty:ty, $visit:ident;)*) => {
+        $(
+            fn $name<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value, FlatError> {
+                visitor.$visit(self.parse::<$ty>(stringify!($ty))?)
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for FlatDeserializer<'_, '_> {
+    type Error = FlatError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, FlatError> {
+        Err(FlatError {
+            column: Some(self.path),
+            message: "value type is unknown".to_string(),
+        })
+    }
+
+    deserialize_parsed! {
+        deserialize_bool => bool, visit_bool;
+        deserialize_i8 => i8, visit_i8;
+        deserialize_i16 => i16, visit_i16;
+        deserialize_i32 => i32, visit_i32;
+        deserialize_i64 => i64, visit_i64;
+        deserialize_u8 => u8, visit_u8;
+        deserialize_u16 => u16, visit_u16;
+        deserialize_u32 => u32, visit_u32;
+        deserialize_u64 => u64, visit_u64;
+        deserialize_f32 => f32, visit_f32;
+        deserialize_f64 => f64, visit_f64;
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value, FlatError> {
+        let mut chars = self.cell()?.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => visitor.visit_char(c),
+            _ => Err(FlatError {
+                column: Some(self.path),
+                message: "expected single character".to_string(),
+            }),
+        }
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value, FlatError> {
+        visitor.visit_str(self.cell()?)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value, FlatError> {
+        visitor.visit_string(self.cell()?.to_string())
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        if self.reader.has_value(self.row, &self.path) {
+            visitor.visit_some(self)
+        } else {
+            visitor.visit_none()
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, FlatError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, FlatError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        let len = self.reader.list_len(self.row, &self.path);
+        let path = self.path.clone();
+
+        visitor
+            .visit_seq(FlatSeqAccess {
+                de: self,
+                index: 0,
+                len,
+                is_list: true,
+            })
+            .map_err(|e| e.in_column(&path))
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, FlatError> {
+        let path = self.path.clone();
+
+        visitor
+            .visit_seq(FlatSeqAccess {
+                de: self,
+                index: 0,
+                len,
+                is_list: false,
+            })
+            .map_err(|e| e.in_column(&path))
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, FlatError> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, FlatError> {
+        Err(FlatError {
+            column: Some(self.path),
+            message: "maps are not supported".to_string(),
+        })
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, FlatError> {
+        let path = self.path.clone();
+
+        visitor
+            .visit_map(FlatStructAccess {
+                de: self,
+                fields: fields.iter(),
+                current: "",
+                window_params: name == WINDOW_PARAMS,
+            })
+            .map_err(|e| e.in_column(&path))
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        mut self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, FlatError> {
+        let variant = self.cell()?.trim();
+        let path = self.path.clone();
+
+        visitor
+            .visit_enum(FlatEnumAccess { de: self, variant })
+            .map_err(|e| e.in_column(&path))
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        visitor.visit_unit()
+    }
+}
+
+struct FlatSeqAccess<'r, 't> {
+    de: FlatDeserializer<'r, 't>,
+    index: usize,
+    len: usize,
+    is_list: bool,
+}
+
+impl<'de> SeqAccess<'de> for FlatSeqAccess<'_, '_> {
+    type Error = FlatError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, FlatError> {
+        if self.index == self.len {
+            return Ok(None);
+        }
+
+        let path = if self.is_list {
+            item_path(&self.de.path, self.index)
+        } else {
+            field_path(&self.de.path, &self.index.to_string())
+        };
+
+        self.index += 1;
+
+        seed.deserialize(self.de.nested(path)).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len - self.index)
+    }
+}
+
+struct FlatStructAccess<'r, 't> {
+    de: FlatDeserializer<'r, 't>,
+    fields: std::slice::Iter<'static, &'static str>,
+    current: &'static str,
+    window_params: bool,
+}
+
+impl<'de> MapAccess<'de> for FlatStructAccess<'_, '_> {
+    type Error = FlatError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, FlatError> {
+        let Some(field) = self.fields.next() else {
+            return Ok(None);
+        };
+
+        self.current = field;
+
+        seed.deserialize(StrDeserializer::new(field)).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, FlatError> {
+        if self.window_params {
+            if self.current == "inner" {
+                let path = self.de.path.clone();
+
+                return seed.deserialize(self.de.nested(path));
+            }
+
+            return seed.deserialize(DefaultDeserializer);
+        }
+
+        if EDITOR_FLAGS.contains(&self.current) {
+            return seed.deserialize(DefaultDeserializer);
+        }
+
+        let path = field_path(&self.de.path, self.current);
+
+        seed.deserialize(self.de.nested(path))
+    }
+}
+
+struct FlatEnumAccess<'r, 't> {
+    de: FlatDeserializer<'r, 't>,
+    variant: &'t str,
+}
+
+impl<'de, 'r, 't> EnumAccess<'de> for FlatEnumAccess<'r, 't> {
+    type Error = FlatError;
+    type Variant = FlatDeserializer<'r, 't>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        mut self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), FlatError> {
+        let variant = seed.deserialize(StrDeserializer::<FlatError>::new(self.variant))?;
+        self.de.path = field_path(&self.de.path, self.variant);
+
+        Ok((variant, self.de))
+    }
+}
+
+/**After [`FlatEnumAccess`] path already points to `field.Variant`
+ */
+impl<'de> VariantAccess<'de> for FlatDeserializer<'_, '_> {
+    type Error = FlatError;
+
+    fn unit_variant(self) -> Result<(), FlatError> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, FlatError> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, FlatError> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, FlatError> {
+        self.deserialize_struct("", fields, visitor)
+    }
+}
+
+/**Zero values, empty lists and first enum variants for everything that isn't in the table.
+Editor actions always have `None` as the first variant
+ */
+struct DefaultDeserializer;
+
+impl<'de> Deserializer<'de> for DefaultDeserializer {
+    type Error = FlatError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        visitor.visit_bool(false)
+    }
+
+    fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        visitor.visit_i8(0)
+    }
+
+    fn deserialize_i16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        visitor.visit_i16(0)
+    }
+
+    fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        visitor.visit_i32(0)
+    }
+
+    fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        visitor.visit_i64(0)
+    }
+
+    fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        visitor.visit_u8(0)
+    }
+
+    fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        visitor.visit_u16(0)
+    }
+
+    fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        visitor.visit_u32(0)
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        visitor.visit_u64(0)
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        visitor.visit_f32(0.)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        visitor.visit_f64(0.)
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        visitor.visit_char('\0')
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        visitor.visit_str("")
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        visitor.visit_string(String::new())
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        visitor.visit_none()
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, FlatError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, FlatError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        visitor.visit_seq(DefaultSeqAccess(0))
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, FlatError> {
+        visitor.visit_seq(DefaultSeqAccess(len))
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, FlatError> {
+        visitor.visit_seq(DefaultSeqAccess(len))
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        visitor.visit_map(DefaultStructAccess([].iter()))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, FlatError> {
+        visitor.visit_map(DefaultStructAccess(fields.iter()))
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, FlatError> {
+        visitor.visit_enum(self)
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        visitor.visit_u32(0)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, FlatError> {
+        visitor.visit_unit()
+    }
+}
+
+struct DefaultSeqAccess(usize);
+
+impl<'de> SeqAccess<'de> for DefaultSeqAccess {
+    type Error = FlatError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, FlatError> {
+        if self.0 == 0 {
+            return Ok(None);
+        }
+
+        self.0 -= 1;
+
+        seed.deserialize(DefaultDeserializer).map(Some)
+    }
+}
+
+struct DefaultStructAccess(std::slice::Iter<'static, &'static str>);
+
+impl<'de> MapAccess<'de> for DefaultStructAccess {
+    type Error = FlatError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, FlatError> {
+        let Some(field) = self.0.next() else {
+            return Ok(None);
+        };
+
+        seed.deserialize(StrDeserializer::new(field)).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, FlatError> {
+        seed.deserialize(DefaultDeserializer)
+    }
+}
+
+impl<'de> EnumAccess<'de> for DefaultDeserializer {
+    type Error = FlatError;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self), FlatError> {
+        let index: U32Deserializer<FlatError> = 0u32.into_deserializer();
+
+        Ok((seed.deserialize(index)?, self))
+    }
+}
+
+impl<'de> VariantAccess<'de> for DefaultDeserializer {
+    type Error = FlatError;
+
+    fn unit_variant(self) -> Result<(), FlatError> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, FlatError> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, FlatError> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, FlatError> {
+        self.deserialize_struct("", fields, visitor)
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+//                                            Backend
+//--------------------------------------------------------------------------------------------------
+
+fn export_holder<K, V>(holder: &impl HolderMapOps<K, V>, path: &Path) -> Result<usize, TableError>
+where
+    K: Hash + Eq + Copy + Clone + Ord,
+    V: Clone + CommonEntity<K> + Serialize,
+{
+    let mut values: Vec<_> = holder.values().filter(|v| !v.deleted()).collect();
+    values.sort_by_key(|v| v.id());
+
+    to_table(values.iter().copied()).write(path)?;
+
+    Ok(values.len())
+}
+
+/**All rows are checked before anything is saved, so a bad table changes nothing
+ */
+fn read_entities<K, V>(path: &Path) -> Result<Vec<V>, String>
+where
+    K: Hash + Eq + Copy + Default + Debug,
+    V: CommonEntity<K> + DeserializeOwned,
+{
+    let table = Table::read(path).map_err(|e| e.to_string())?;
+    let values: Vec<V> = from_table(&table).map_err(|e| format!("{path:?}: {e}"))?;
+
+    let mut ids = HashSet::new();
+
+    for (row, v) in values.iter().enumerate() {
+        if v.id() == K::default() {
+            return Err(format!("{path:?}: row {}: ID can't be 0!", row + 1));
+        }
+
+        if !ids.insert(v.id()) {
+            return Err(format!(
+                "{path:?}: row {}: ID {:?} is duplicated",
+                row + 1,
+                v.id()
+            ));
+        }
+    }
+
+    Ok(values)
+}
+
+impl Backend {
+    pub fn export_holder_to_table(&mut self, entity: Entity, path: &Path) {
+        let h = &self.holders.game_data_holder;
+
+        let res = match entity {
+            Entity::Npc => export_holder(&h.npc_holder, path),
+            Entity::Quest => export_holder(&h.quest_holder, path),
+            Entity::Skill => export_holder(&h.skill_holder, path),
+            Entity::Weapon => export_holder(&h.weapon_holder, path),
+            Entity::Armor => export_holder(&h.armor_holder, path),
+            Entity::EtcItem => export_holder(&h.etc_item_holder, path),
+            Entity::ItemSet => export_holder(&h.item_set_holder, path),
+            Entity::Recipe => export_holder(&h.recipe_holder, path),
+            Entity::HuntingZone => export_holder(&h.hunting_zone_holder, path),
+            Entity::Region => export_holder(&h.region_holder, path),
+            Entity::RaidInfo => export_holder(&h.raid_info_holder, path),
+            Entity::DailyMission => export_holder(&h.daily_mission_holder, path),
+            Entity::AnimationCombo => export_holder(&h.animation_combo_holder, path),
+            Entity::Residence => export_holder(&h.residence_holder, path),
+            Entity::GameString => export_holder(&h.game_string_holder, path),
+            Entity::WeaponEnchantEffect => export_holder(&h.weapon_enchant_effect_holder, path),
+            Entity::ArmorEnchantEffect => export_holder(&h.armor_enchant_effect_holder, path),
+            Entity::EnsoulOption => export_holder(&h.ensoul_option_holder, path),
+            Entity::VariationOption => export_holder(&h.variation_option_holder, path),
+            Entity::InstantZone => export_holder(&h.instant_zone_holder, path),
+        };
+
+        match res {
+            Ok(count) => logs_mut().add(Log {
+                level: LogLevel::Info,
+                producer: "Table Export".to_string(),
+                log: format!("{count} {entity} entities exported to {path:?}"),
+                entity: None,
+            }),
+            Err(e) => self.show_dialog(Dialog::ShowWarning(e.to_string())),
+        }
+    }
+
+    /**Rows equal to current entities are skipped, the rest are saved like from the editor.
+    Entities missing from the table are left as they are
+     */
+    pub fn import_holder_from_table(&mut self, entity: Entity, path: &Path) {
+        let chronicle = self.holders.game_data_holder.chronicle;
+
+        if !chronicle.supports(entity) {
+            self.show_dialog(Dialog::ShowWarning(format!(
+                "{entity} is not loaded for {chronicle}"
+            )));
+
+            return;
+        }
+
+        let res = match entity {
+            Entity::Npc => self.import_entities(path, Self::save_npc_force),
+            Entity::Quest => self.import_entities(path, Self::save_quest_force),
+            Entity::Skill => self.import_entities(path, Self::save_skill_force),
+            Entity::Weapon => self.import_entities(path, Self::save_weapon_force),
+            Entity::Armor => self.import_entities(path, Self::save_armor_force),
+            Entity::EtcItem => self.import_entities(path, Self::save_etc_item_force),
+            Entity::ItemSet => self.import_entities(path, Self::save_item_set_force),
+            Entity::Recipe => self.import_entities(path, Self::save_recipe_force),
+            Entity::HuntingZone => self.import_entities(path, Self::save_hunting_zone_object_force),
+            Entity::Region => self.import_entities(path, Self::save_region_object_force),
+            Entity::RaidInfo => self.import_entities(path, Self::save_raid_info_object_force),
+            Entity::DailyMission => {
+                self.import_entities(path, Self::save_daily_mission_object_force)
+            }
+            Entity::AnimationCombo => {
+                self.import_entities(path, Self::save_animation_combo_object_force)
+            }
+            Entity::Residence => self.import_entities(path, Self::save_residence_force),
+            Entity::GameString => self.import_entities(path, Self::save_game_string_force),
+            Entity::WeaponEnchantEffect => {
+                self.import_entities(path, Self::save_weapon_enchant_effect_force)
+            }
+            Entity::ArmorEnchantEffect => {
+                self.import_entities(path, Self::save_armor_enchant_effect_force)
+            }
+            Entity::EnsoulOption => self.import_entities(path, Self::save_ensoul_option_force),
+            Entity::VariationOption => {
+                self.import_entities(path, Self::save_variation_option_force)
+            }
+            Entity::InstantZone => self.import_entities(path, Self::save_instant_zone_force),
+        };
+
+        match res {
+            Ok(count) => logs_mut().add(Log {
+                level: LogLevel::Info,
+                producer: "Table Import".to_string(),
+                log: format!("{count} {entity} rows imported from {path:?}"),
+                entity: None,
+            }),
+            Err(e) => self.show_dialog(Dialog::ShowWarning(e)),
+        }
+    }
+
+    fn import_entities<K, V>(
+        &mut self,
+        path: &Path,
+        save: fn(&mut Self, V),
+    ) -> Result<usize, String>
+    where
+        K: Hash + Eq + Copy + Default + Debug,
+        V: CommonEntity<K> + DeserializeOwned,
+    {
+        let values = read_entities::<K, V>(path)?;
+        let count = values.len();
+
+        for v in values {
+            save(self, v);
+        }
+
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::entity_editor::WindowParams;
+    use crate::data::{ItemId, QuestId};
+    use crate::entity::quest::{Quest, QuestReward, QuestStep, QuestTranslation};
+    use serde::Deserialize;
+    use std::path::PathBuf;
+
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+    struct Loc {
+        x: i32,
+        y: f32,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+    struct Part {
+        name: String,
+        loc: Loc,
+        tags: Vec<u8>,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+    enum Kind {
+        #[default]
+        Plain,
+        Pair(u32, u32),
+        Named {
+            text: String,
+        },
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+    struct Row {
+        id: u32,
+        name: String,
+        part: Part,
+        parts: Vec<Part>,
+        ids: Vec<u32>,
+        extra: Option<Loc>,
+        kind: Kind,
+        _changed: bool,
+    }
+
+    fn rows() -> Vec<Row> {
+        vec![
+            Row {
+                id: 1,
+                name: "comma, \"quoted\"\tand tab".to_string(),
+                part: Part {
+                    name: "line\nbreak".to_string(),
+                    loc: Loc { x: -5, y: 1.5 },
+                    tags: vec![1, 2],
+                },
+                parts: vec![
+                    Part {
+                        name: "first".to_string(),
+                        loc: Loc { x: 1, y: 0.25 },
+                        tags: vec![],
+                    },
+                    Part {
+                        name: "second;third".to_string(),
+                        loc: Loc { x: 2, y: -0.5 },
+                        tags: vec![7, 8, 9],
+                    },
+                ],
+                ids: vec![10, 20, 30],
+                extra: Some(Loc { x: 3, y: 4.0 }),
+                kind: Kind::Pair(5, 6),
+                _changed: false,
+            },
+            Row {
+                id: 2,
+                name: String::new(),
+                parts: vec![Part::default()],
+                kind: Kind::Named {
+                    text: "a\tb,c".to_string(),
+                },
+                ..Default::default()
+            },
+            Row {
+                id: 3,
+                ..Default::default()
+            },
+        ]
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("l2w_table_{}_{name}", std::process::id()))
+    }
+
+    fn round_trip<V: Serialize + DeserializeOwned>(values: &[V], file_name: &str) -> Vec<V> {
+        let path = temp_path(file_name);
+
+        to_table(values).write(&path).unwrap();
+        let table = Table::read(&path);
+
+        std::fs::remove_file(&path).unwrap();
+
+        from_table(&table.unwrap()).unwrap()
+    }
+
+    #[test]
+    fn csv_round_trip() {
+        assert_eq!(round_trip(&rows(), "rows.csv"), rows());
+    }
+
+    #[test]
+    fn tsv_round_trip() {
+        assert_eq!(round_trip(&rows(), "rows.tsv"), rows());
+    }
+
+    #[test]
+    fn editor_flags_are_not_written() {
+        let mut row = rows().remove(0);
+        row._changed = true;
+
+        let table = to_table([&row]);
+
+        assert!(!table.columns.iter().any(|v| v.contains("_changed")));
+        assert_eq!(round_trip(&[row], "flags.csv"), rows()[..1]);
+    }
+
+    #[test]
+    fn list_columns_follow_longest_row() {
+        let table = to_table(&rows());
+
+        assert!(table.columns.contains(&"parts[1].tags[2]".to_string()));
+        assert!(table.columns.contains(&"kind.Named.text".to_string()));
+
+        let column = table.columns.iter().position(|v| v == "ids[2]").unwrap();
+
+        assert_eq!(table.rows[0][column], "30");
+        assert_eq!(table.rows[1][column], "");
+    }
+
+    #[test]
+    fn bad_cell_names_row_and_column() {
+        let mut table = to_table(&rows());
+        let column = table
+            .columns
+            .iter()
+            .position(|v| v == "part.loc.x")
+            .unwrap();
+        table.rows[1][column] = "west".to_string();
+
+        let e = from_table::<Row>(&table).unwrap_err().to_string();
+
+        assert!(e.starts_with("row 2, column part.loc.x:"), "{e}");
+    }
+
+    #[test]
+    fn entity_round_trip() {
+        let quest = Quest {
+            id: QuestId(10),
+            title: "Letters, \"Love\"".to_string(),
+            steps: vec![WindowParams {
+                inner: QuestStep {
+                    title: "Step\tone".to_string(),
+                    level: 1,
+                    prev_steps: vec![0],
+                    ..Default::default()
+                },
+                opened: true,
+                initial_id: (),
+                action: Default::default(),
+                params: (),
+            }],
+            rewards: vec![QuestReward {
+                reward_id: ItemId(57),
+                count: 1000,
+            }],
+            translations: vec![QuestTranslation {
+                locale: "ru".to_string(),
+                title: "Письма".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let quests = [quest];
+
+        for file_name in ["quest.csv", "quest.tsv"] {
+            assert_eq!(round_trip(&quests, file_name), quests);
+        }
+    }
+
+    #[test]
+    fn underscored_dat_fields_are_kept() {
+        let mut quest = Quest {
+            id: QuestId(11),
+            _is_clan_pet_quest: true,
+            _faction_id: 3,
+            _faction_level_min: 2,
+            _faction_level_max: 5,
+            steps: vec![WindowParams {
+                inner: QuestStep {
+                    _get_item_in_step: true,
+                    ..Default::default()
+                },
+                opened: false,
+                initial_id: (),
+                action: Default::default(),
+                params: (),
+            }],
+            ..Default::default()
+        };
+
+        let table = to_table([&quest]);
+
+        assert!(table.columns.contains(&"_faction_id".to_string()));
+        assert!(table
+            .columns
+            .contains(&"steps[0]._get_item_in_step".to_string()));
+
+        quest._changed = true;
+        let quests = [quest];
+        let mut expected = quests.clone();
+        expected[0]._changed = false;
+
+        assert_eq!(round_trip(&quests, "faction.csv"), expected);
+    }
+}
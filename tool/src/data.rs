@@ -212,6 +212,15 @@ impl From<u8> for SetEnchantEffectId {
     }
 }
 
+/**Editor uses `u32::MAX` as "no id", it and other values out of byte range become `u8::MAX`
+instead of wrapping. Dat loader checks the range itself
+ */
+impl From<u32> for SetEnchantEffectId {
+    fn from(value: u32) -> Self {
+        Self(u8::try_from(value).unwrap_or(u8::MAX))
+    }
+}
+
 #[derive(
     Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Hash, Default, PartialOrd, Ord,
 )]
@@ -280,6 +289,83 @@ impl From<u32> for ResidenceId {
     }
 }
 
+#[derive(
+    Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Hash, Default, PartialOrd, Ord,
+)]
+pub struct WeaponEnchantEffectId(pub u32);
+
+impl From<u32> for WeaponEnchantEffectId {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(
+    Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Hash, Default, PartialOrd, Ord,
+)]
+pub struct EnsoulOptionId(pub u32);
+
+impl From<u32> for EnsoulOptionId {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(
+    Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Hash, Default, PartialOrd, Ord,
+)]
+pub struct VariationOptionId(pub u32);
+
+impl From<u32> for VariationOptionId {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+/**String dat the [`GameStringId`] points into
+ */
+#[derive(
+    Serialize,
+    Deserialize,
+    Display,
+    Debug,
+    EnumIter,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    Default,
+    PartialOrd,
+    Ord,
+)]
+pub enum StringTable {
+    #[default]
+    NpcString,
+    SysString,
+    SystemMsg,
+    GameDataName,
+}
+
+/**Ids of different string dats overlap, so table is a part of the id
+ */
+#[derive(
+    Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Hash, Default, PartialOrd, Ord,
+)]
+pub struct GameStringId {
+    pub table: StringTable,
+    pub id: u32,
+}
+
+impl From<u32> for GameStringId {
+    fn from(value: u32) -> Self {
+        Self {
+            table: StringTable::NpcString,
+            id: value,
+        }
+    }
+}
+
 
 #[derive(
     Serialize, Deserialize, Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash,
@@ -0,0 +1,52 @@
+use crate::data::SetEnchantEffectId;
+use crate::entity::weapon_enchant_effect::EnchantEffectLevel;
+use crate::entity::{CommonEntity, GetEditParams};
+use serde::{Deserialize, Serialize};
+
+impl GetEditParams<()> for ArmorEnchantEffect {
+    fn edit_params(&self) {}
+}
+
+impl CommonEntity<SetEnchantEffectId> for ArmorEnchantEffect {
+    fn name(&self) -> String {
+        format!("Set Effect {}", self.id.0)
+    }
+
+    fn desc(&self) -> String {
+        format!("Levels: {}", self.levels.len())
+    }
+
+    fn id(&self) -> SetEnchantEffectId {
+        self.id
+    }
+
+    fn changed(&self) -> bool {
+        self._changed
+    }
+
+    fn deleted(&self) -> bool {
+        self._deleted
+    }
+
+    fn new(id: SetEnchantEffectId) -> Self {
+        ArmorEnchantEffect {
+            id,
+            levels: vec![EnchantEffectLevel::default()],
+
+            _changed: false,
+            _deleted: false,
+        }
+    }
+}
+
+/**Glow of fully enchanted armor set, linked from [`crate::entity::item::armor::Armor`] by
+`set_enchant_effect_id`
+ */
+#[derive(Clone, Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct ArmorEnchantEffect {
+    pub id: SetEnchantEffectId,
+    pub levels: Vec<EnchantEffectLevel>,
+
+    pub _changed: bool,
+    pub _deleted: bool,
+}
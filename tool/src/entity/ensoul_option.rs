@@ -0,0 +1,77 @@
+use crate::data::{EnsoulOptionId, ItemId, SkillId};
+use crate::entity::{CommonEntity, GetEditParams};
+use serde::{Deserialize, Serialize};
+
+impl GetEditParams<()> for EnsoulOption {
+    fn edit_params(&self) {}
+}
+
+impl CommonEntity<EnsoulOptionId> for EnsoulOption {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn desc(&self) -> String {
+        self.desc.clone()
+    }
+
+    fn id(&self) -> EnsoulOptionId {
+        self.id
+    }
+
+    fn changed(&self) -> bool {
+        self._changed
+    }
+
+    fn deleted(&self) -> bool {
+        self._deleted
+    }
+
+    fn new(id: EnsoulOptionId) -> Self {
+        EnsoulOption {
+            id,
+            name: "New Ensoul Option".to_string(),
+            desc: "".to_string(),
+            option_type: 1,
+            step: 1,
+            skill_id: SkillId(0),
+            skill_level: 1,
+            extraction_item_id: ItemId(0),
+            icon: "".to_string(),
+            icon_panel: "".to_string(),
+            stones: vec![],
+
+            _changed: false,
+            _deleted: false,
+        }
+    }
+}
+
+/**Soul crystal option, gives `skill_id` to the ensouled weapon.
+
+Stone dat lists options per soul crystal, here it is turned around: each option keeps the stones
+which can roll it, so a new option is attached to crystals from its own editor
+ */
+#[derive(Clone, Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct EnsoulOption {
+    pub id: EnsoulOptionId,
+    pub name: String,
+    pub desc: String,
+    pub option_type: u32,
+    pub step: u32,
+    pub skill_id: SkillId,
+    pub skill_level: u32,
+    pub extraction_item_id: ItemId,
+    pub icon: String,
+    pub icon_panel: String,
+    pub stones: Vec<EnsoulStone>,
+
+    pub _changed: bool,
+    pub _deleted: bool,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct EnsoulStone {
+    pub item_id: ItemId,
+    pub slot_type: u32,
+}
@@ -0,0 +1,104 @@
+use crate::data::{GameStringId, StringTable};
+use crate::entity::{CommonEntity, GetEditParams};
+use eframe::egui::Color32;
+use serde::{Deserialize, Serialize};
+
+impl GetEditParams<()> for GameString {
+    fn edit_params(&self) {}
+}
+
+impl CommonEntity<GameStringId> for GameString {
+    fn name(&self) -> String {
+        self.value.clone()
+    }
+
+    fn desc(&self) -> String {
+        self.id.table.to_string()
+    }
+
+    fn id(&self) -> GameStringId {
+        self.id
+    }
+
+    fn changed(&self) -> bool {
+        self._changed
+    }
+
+    fn deleted(&self) -> bool {
+        self._deleted
+    }
+
+    fn new(id: GameStringId) -> Self {
+        GameString {
+            id,
+            value: "New String".to_string(),
+            system_message: Default::default(),
+
+            _changed: false,
+            _deleted: false,
+        }
+    }
+}
+
+/**Single record of npcstring, sysstring, systemmsg or l2gamedataname.
+
+Entities keep their own copies of l2gamedataname strings and find indexes by value on save, so
+editing those records only matters for dats the tool doesn't map
+ */
+#[derive(Clone, Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct GameString {
+    pub id: GameStringId,
+    pub value: String,
+    /**Used only by [`StringTable::SystemMsg`] records
+     */
+    pub system_message: SystemMessageParams,
+
+    pub _changed: bool,
+    pub _deleted: bool,
+}
+
+impl GameString {
+    pub fn from_table(table: StringTable, id: u32, value: String) -> Self {
+        Self {
+            id: GameStringId { table, id },
+            value,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct SystemMessageParams {
+    pub group: u32,
+    pub color: Color32,
+    pub sound: String,
+    pub reference: String,
+    pub window: u32,
+    pub font: u32,
+    pub lifetime: u32,
+    pub background: u32,
+    pub animation: u32,
+    pub sub_message: String,
+    pub message_type: String,
+
+    pub unk1: u32,
+}
+
+impl Default for SystemMessageParams {
+    fn default() -> Self {
+        Self {
+            group: 0,
+            color: Color32::WHITE,
+            sound: "".to_string(),
+            reference: "".to_string(),
+            window: 0,
+            font: 0,
+            lifetime: 0,
+            background: 0,
+            animation: 0,
+            sub_message: "".to_string(),
+            message_type: "".to_string(),
+            unk1: 0,
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use crate::data::InstantZoneId;
+use crate::entity::{CommonEntity, GetEditParams};
+use serde::{Deserialize, Serialize};
+
+impl GetEditParams<()> for InstantZone {
+    fn edit_params(&self) {}
+}
+
+impl CommonEntity<InstantZoneId> for InstantZone {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn desc(&self) -> String {
+        self.desc.clone()
+    }
+
+    fn id(&self) -> InstantZoneId {
+        self.id
+    }
+
+    fn changed(&self) -> bool {
+        self._changed
+    }
+
+    fn deleted(&self) -> bool {
+        self._deleted
+    }
+
+    fn new(id: InstantZoneId) -> Self {
+        InstantZone {
+            id,
+            name: "New Instant Zone".to_string(),
+            desc: "".to_string(),
+            icon: "".to_string(),
+            level_min: 1,
+            level_max: 1,
+            max_members: 1,
+            reuse_time: 0,
+
+            _changed: false,
+            _deleted: false,
+        }
+    }
+}
+
+/**Instance dungeon, referenced by [`crate::entity::hunting_zone::HuntingZone`]
+ */
+#[derive(Clone, Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct InstantZone {
+    pub id: InstantZoneId,
+    pub name: String,
+    pub desc: String,
+    pub icon: String,
+    pub level_min: u32,
+    pub level_max: u32,
+    pub max_members: u32,
+    /**Minutes
+     */
+    pub reuse_time: u32,
+
+    pub _changed: bool,
+    pub _deleted: bool,
+}
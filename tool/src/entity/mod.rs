@@ -1,8 +1,9 @@
-use crate::data::{AnimationComboId, DailyMissionId, HuntingZoneId, ItemId, ItemSetId, NpcId, QuestId, RaidInfoId, RecipeId, RegionId, ResidenceId, SkillId};
+use crate::data::{AnimationComboId, DailyMissionId, EnsoulOptionId, GameStringId, HuntingZoneId, InstantZoneId, ItemId, ItemSetId, NpcId, QuestId, RaidInfoId, RecipeId, RegionId, ResidenceId, SetEnchantEffectId, SkillId, VariationOptionId, WeaponEnchantEffectId};
 use strum_macros::{Display, EnumIter};
 
 pub mod animation_combo;
 pub mod daily_mission;
+pub mod game_string;
 pub mod hunting_zone;
 pub mod item;
 pub mod item_set;
@@ -13,6 +14,11 @@ pub mod recipe;
 pub mod region;
 pub mod skill;
 pub mod residence;
+pub mod weapon_enchant_effect;
+pub mod armor_enchant_effect;
+pub mod ensoul_option;
+pub mod variation_option;
+pub mod instant_zone;
 
 #[derive(Display, Debug, EnumIter, Eq, PartialEq, Copy, Clone)]
 pub enum Entity {
@@ -30,9 +36,15 @@ pub enum Entity {
     DailyMission,
     AnimationCombo,
     Residence,
+    GameString,
+    WeaponEnchantEffect,
+    ArmorEnchantEffect,
+    EnsoulOption,
+    VariationOption,
+    InstantZone,
 }
 
-#[derive(Display, Debug, EnumIter, Eq, PartialEq, Copy, Clone)]
+#[derive(Display, Debug, EnumIter, Eq, PartialEq, Copy, Clone, Hash, PartialOrd, Ord)]
 pub enum EntityT {
     Quest(QuestId),
     Skill(SkillId),
@@ -48,6 +60,41 @@ pub enum EntityT {
     DailyMission(DailyMissionId),
     AnimationCombo(AnimationComboId),
     Residence(ResidenceId),
+    GameString(GameStringId),
+    WeaponEnchantEffect(WeaponEnchantEffectId),
+    ArmorEnchantEffect(SetEnchantEffectId),
+    EnsoulOption(EnsoulOptionId),
+    VariationOption(VariationOptionId),
+    InstantZone(InstantZoneId),
+}
+
+impl EntityT {
+    /**Strings are identified by table too, only their index is returned
+     */
+    pub fn raw_id(&self) -> u32 {
+        match self {
+            EntityT::Quest(id) => id.0,
+            EntityT::Skill(id) => id.0,
+            EntityT::Npc(id) => id.0,
+            EntityT::Weapon(id) => id.0,
+            EntityT::Armor(id) => id.0,
+            EntityT::EtcItem(id) => id.0,
+            EntityT::ItemSet(id) => id.0,
+            EntityT::Recipe(id) => id.0,
+            EntityT::HuntingZone(id) => id.0,
+            EntityT::Region(id) => id.0,
+            EntityT::RaidInfo(id) => id.0,
+            EntityT::DailyMission(id) => id.0,
+            EntityT::AnimationCombo(id) => id.0,
+            EntityT::Residence(id) => id.0,
+            EntityT::GameString(id) => id.id,
+            EntityT::WeaponEnchantEffect(id) => id.0,
+            EntityT::ArmorEnchantEffect(id) => id.0 as u32,
+            EntityT::EnsoulOption(id) => id.0,
+            EntityT::VariationOption(id) => id.0,
+            EntityT::InstantZone(id) => id.0,
+        }
+    }
 }
 
 pub trait GetEditParams<EditParams> {
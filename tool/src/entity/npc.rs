@@ -54,6 +54,7 @@ impl CommonEntity<NpcId> for Npc {
             icon: "".to_string(),
             additional_parts: Default::default(),
             quest_infos: Default::default(),
+            translations: vec![],
 
             _changed: false,
             _deleted: false,
@@ -96,6 +97,8 @@ pub struct Npc {
     pub(crate) icon: String,
     pub(crate) properties: Vec<NpcProperty>,
     pub(crate) quest_infos: Vec<NpcQuestInfo>,
+    #[serde(default)]
+    pub(crate) translations: Vec<NpcTranslation>,
 
     pub(crate) mesh_params: WindowParams<NpcMeshParams, (), NpcMeshAction, ()>,
     pub(crate) sound_params: WindowParams<NpcSoundParams, (), NpcSoundAction, ()>,
@@ -115,6 +118,15 @@ pub struct NpcSkillAnimation {
     pub(crate) animation: String,
 }
 
+/**Strings of one of additional locales, main locale is kept in [`Npc::name`] and [`Npc::title`]
+ */
+#[derive(Clone, Serialize, Deserialize, Default, Debug, PartialEq)]
+pub struct NpcTranslation {
+    pub(crate) locale: String,
+    pub(crate) name: String,
+    pub(crate) title: String,
+}
+
 #[derive(Clone, Serialize, Deserialize, Default, Debug, PartialEq)]
 pub struct NpcQuestInfo {
     pub(crate) id: QuestId,
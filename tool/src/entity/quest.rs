@@ -57,6 +57,7 @@ impl CommonEntity<QuestId> for Quest {
             _faction_level_min: 0,
             _faction_level_max: 0,
             java_class: None,
+            translations: vec![],
 
             _changed: false,
             _deleted: false,
@@ -167,6 +168,8 @@ pub struct Quest {
     pub(crate) _faction_level_max: u32,
 
     pub java_class: Option<WindowParams<String, (), (), ()>>,
+    #[serde(default)]
+    pub translations: Vec<QuestTranslation>,
 
     pub _changed: bool,
     pub _deleted: bool,
@@ -190,6 +193,7 @@ impl Quest {
                 label: "".to_string(),
                 prev_steps: vec![self.steps.len() as u32 - 2],
                 level: self.last_finish_step_id,
+                translations: vec![],
             },
 
             initial_id: (),
@@ -214,6 +218,7 @@ impl Quest {
                 label: "Step Label".to_string(),
                 prev_steps: vec![self.steps.len() as u32 - 2],
                 level: self.steps.len() as u32 - 1,
+                translations: vec![],
             },
 
             initial_id: (),
@@ -307,6 +312,47 @@ pub struct QuestStep {
     pub unk_2: Unk2,
     pub prev_steps: Vec<u32>,
     pub level: u32,
+    #[serde(default)]
+    pub translations: Vec<QuestStepTranslation>,
+}
+
+impl QuestStep {
+    /**Finish steps have levels counting down from `u32::MAX`, they keep no title of their own
+     */
+    pub fn is_finish_level(level: u32) -> bool {
+        level > 1_000
+    }
+
+    /**Levels of linked previous steps, `0` in [`QuestStep::prev_steps`] is quest start and
+    never links to a step, even to one with level `0`
+     */
+    pub fn linked_prev_steps(&self) -> impl Iterator<Item = u32> + '_ {
+        self.prev_steps.iter().copied().filter(|v| *v != 0)
+    }
+
+    /**Quest can begin with this step: it has no previous steps or one of them is `0`
+     */
+    pub fn is_start(&self) -> bool {
+        self.prev_steps.is_empty() || self.prev_steps.contains(&0)
+    }
+}
+
+/**Strings of one of additional locales, main locale is kept in quest fields
+ */
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct QuestTranslation {
+    pub locale: String,
+    pub title: String,
+    pub intro: String,
+    pub requirements: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct QuestStepTranslation {
+    pub locale: String,
+    pub title: String,
+    pub label: String,
+    pub desc: String,
 }
 
 #[derive(Serialize, Deserialize, Default, Debug, Copy, Clone, PartialEq)]
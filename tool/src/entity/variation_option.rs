@@ -0,0 +1,59 @@
+use crate::data::{SkillId, VariationOptionId};
+use crate::entity::{CommonEntity, GetEditParams};
+use serde::{Deserialize, Serialize};
+
+impl GetEditParams<()> for VariationOption {
+    fn edit_params(&self) {}
+}
+
+impl CommonEntity<VariationOptionId> for VariationOption {
+    fn name(&self) -> String {
+        self.desc.lines().next().unwrap_or_default().to_string()
+    }
+
+    fn desc(&self) -> String {
+        format!("Type {}", self.option_type)
+    }
+
+    fn id(&self) -> VariationOptionId {
+        self.id
+    }
+
+    fn changed(&self) -> bool {
+        self._changed
+    }
+
+    fn deleted(&self) -> bool {
+        self._deleted
+    }
+
+    fn new(id: VariationOptionId) -> Self {
+        VariationOption {
+            id,
+            option_type: 0,
+            skill_id: SkillId(0),
+            skill_level: 1,
+            desc: "New Option".to_string(),
+
+            _changed: false,
+            _deleted: false,
+        }
+    }
+}
+
+/**Lifestone augmentation option. Everything it does is done by `skill_id`, `desc` is the line
+shown on augmented item
+ */
+#[derive(Clone, Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct VariationOption {
+    pub id: VariationOptionId,
+    /**0 stat, 1 passive, 2 active, 3 chance in known clients, other values are kept as is
+     */
+    pub option_type: u32,
+    pub skill_id: SkillId,
+    pub skill_level: u32,
+    pub desc: String,
+
+    pub _changed: bool,
+    pub _deleted: bool,
+}
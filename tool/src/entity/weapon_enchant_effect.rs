@@ -0,0 +1,79 @@
+use crate::data::{Position, WeaponEnchantEffectId};
+use crate::entity::item::CrystalType;
+use crate::entity::{CommonEntity, GetEditParams};
+use eframe::egui::Color32;
+use serde::{Deserialize, Serialize};
+
+impl GetEditParams<()> for WeaponEnchantEffect {
+    fn edit_params(&self) {}
+}
+
+impl CommonEntity<WeaponEnchantEffectId> for WeaponEnchantEffect {
+    fn name(&self) -> String {
+        format!("{} Grade", self.crystal_type)
+    }
+
+    fn desc(&self) -> String {
+        format!("Levels: {}", self.levels.len())
+    }
+
+    fn id(&self) -> WeaponEnchantEffectId {
+        self.id
+    }
+
+    fn changed(&self) -> bool {
+        self._changed
+    }
+
+    fn deleted(&self) -> bool {
+        self._deleted
+    }
+
+    fn new(id: WeaponEnchantEffectId) -> Self {
+        WeaponEnchantEffect {
+            id,
+            crystal_type: CrystalType::NG,
+            levels: vec![EnchantEffectLevel::default()],
+
+            _changed: false,
+            _deleted: false,
+        }
+    }
+}
+
+/**Glow of enchanted weapons, weapons point to it by effect names of its levels
+ */
+#[derive(Clone, Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct WeaponEnchantEffect {
+    pub id: WeaponEnchantEffectId,
+    pub crystal_type: CrystalType,
+    pub levels: Vec<EnchantEffectLevel>,
+
+    pub _changed: bool,
+    pub _deleted: bool,
+}
+
+/**Effect shown starting from `enchant_level`, same record is used by armor set effects
+ */
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct EnchantEffectLevel {
+    pub enchant_level: u32,
+    pub effect: String,
+    pub bone: String,
+    pub offset: Position,
+    pub scale: f32,
+    pub color: Color32,
+}
+
+impl Default for EnchantEffectLevel {
+    fn default() -> Self {
+        Self {
+            enchant_level: 0,
+            effect: "".to_string(),
+            bone: "".to_string(),
+            offset: Position::default(),
+            scale: 1.0,
+            color: Color32::WHITE,
+        }
+    }
+}
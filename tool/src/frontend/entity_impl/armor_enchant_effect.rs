@@ -0,0 +1,219 @@
+use crate::backend::entity_editor::{CurrentEntity, EditParamsCommonOps};
+use crate::backend::entity_impl::weapon_enchant_effect::EnchantEffectAction;
+use crate::backend::holder::{DataHolder, HolderMapOps, HolderOps};
+use crate::backend::Backend;
+use crate::entity::armor_enchant_effect::ArmorEnchantEffect;
+use crate::entity::{CommonEntity, EntityT};
+use crate::frontend::entity_impl::EntityInfoState;
+use crate::frontend::util::{
+    close_entity_button, format_button_text, num_row, DrawAsTooltip, DrawUtils,
+};
+use crate::frontend::{DrawEntity, Frontend};
+use eframe::egui::{Button, Color32, Context, ScrollArea, Stroke, Ui};
+use std::sync::RwLock;
+
+impl DrawEntity<EnchantEffectAction, ()> for ArmorEnchantEffect {
+    fn draw_entity(
+        &mut self,
+        ui: &mut Ui,
+        _ctx: &Context,
+        action: &RwLock<EnchantEffectAction>,
+        holders: &mut DataHolder,
+        _params: &mut (),
+    ) {
+        ui.horizontal(|ui| {
+            ui.set_height(400.);
+
+            ui.vertical(|ui| {
+                ui.set_width(300.);
+
+                ui.horizontal(|ui| {
+                    num_row(ui, &mut self.id.0, "Id").on_hover_ui(|ui| {
+                        holders
+                            .game_data_holder
+                            .armor_enchant_effect_holder
+                            .get(&self.id)
+                            .draw_as_tooltip(ui)
+                    });
+                });
+            });
+
+            ui.separator();
+
+            ui.vertical(|ui| {
+                ui.set_width(450.);
+
+                self.levels.draw_vertical(
+                    ui,
+                    "Levels",
+                    |v| {
+                        *action.write().unwrap() = EnchantEffectAction::RemoveLevel(v);
+                    },
+                    holders,
+                    true,
+                    true,
+                );
+            });
+
+            ui.separator();
+        });
+
+        ui.separator();
+    }
+}
+
+impl Frontend {
+    pub fn draw_armor_enchant_effect_tabs(&mut self, ui: &mut Ui) {
+        for (i, (title, id, is_changed)) in self
+            .backend
+            .edit_params
+            .get_opened_armor_enchant_effects_info()
+            .iter()
+            .enumerate()
+        {
+            let mut button = Button::new(format_button_text(&format!(
+                "{}[{}] {}",
+                if *is_changed { "*" } else { "" },
+                id.0,
+                title
+            )))
+            .fill(Color32::from_rgb(64, 78, 110))
+            .min_size([150., 10.].into());
+
+            let is_current =
+                CurrentEntity::ArmorEnchantEffect(i) == self.backend.edit_params.current_entity;
+
+            if is_current {
+                button = button.stroke(Stroke::new(1.0, Color32::LIGHT_GRAY));
+            }
+
+            if ui
+                .add(button)
+                .on_hover_text(format!(
+                    "Armor Enchant Effect: [{}] {}{}",
+                    id.0,
+                    title,
+                    if *is_changed { "\nModified!" } else { "" },
+                ))
+                .clicked()
+                && !self.backend.dialog_showing
+            {
+                self.backend.edit_params.set_current_armor_enchant_effect(i);
+            }
+
+            close_entity_button(
+                ui,
+                CurrentEntity::ArmorEnchantEffect(i),
+                &mut self.backend,
+                *is_changed,
+            );
+
+            ui.separator();
+        }
+    }
+
+    pub(crate) fn draw_armor_enchant_effect_selector(
+        backend: &mut Backend,
+        ui: &mut Ui,
+        width: f32,
+    ) {
+        ui.vertical(|ui| {
+            ui.set_width(width);
+
+            let holder = &mut backend.holders.game_data_holder.armor_enchant_effect_holder;
+            let catalog = &mut backend.entity_catalogs.armor_enchant_effect;
+            let filter_mode = &mut backend.entity_catalogs.filter_mode;
+            let edit_params = &mut backend.edit_params;
+
+            if catalog
+                .draw_search_and_add_buttons(ui, holder, filter_mode, catalog.len())
+                .clicked()
+            {
+                edit_params.create_new_armor_enchant_effect();
+            }
+
+            ui.separator();
+
+            let mut changed = None;
+
+            ui.push_id(ui.next_auto_id(), |ui| {
+                ScrollArea::vertical().show_rows(ui, 36., catalog.catalog.len(), |ui, range| {
+                    ui.set_width(width - 5.);
+
+                    for v in range {
+                        let q = &catalog.catalog[v];
+
+                        let mut has_unsaved_changes = false;
+
+                        let info_state = if let Some((ind, v)) = edit_params
+                            .armor_enchant_effects
+                            .opened
+                            .iter()
+                            .enumerate()
+                            .find(|(_, v)| v.inner.initial_id == q.id)
+                        {
+                            has_unsaved_changes = v.is_changed();
+
+                            if edit_params.current_entity == CurrentEntity::ArmorEnchantEffect(ind)
+                            {
+                                EntityInfoState::Current
+                            } else {
+                                EntityInfoState::Opened
+                            }
+                        } else {
+                            EntityInfoState::Nothing
+                        };
+
+                        ui.horizontal(|ui| {
+                            if q.draw_catalog_buttons(
+                                ui,
+                                &mut changed,
+                                info_state,
+                                has_unsaved_changes,
+                            )
+                            .clicked()
+                                && backend.dialog.is_none()
+                                && !q.deleted
+                            {
+                                if ui.input(|i| i.modifiers.ctrl) && !has_unsaved_changes {
+                                    edit_params.close_if_opened(EntityT::ArmorEnchantEffect(q.id));
+                                } else {
+                                    edit_params.open_armor_enchant_effect(q.id, holder);
+                                }
+                            }
+                        });
+                    }
+                });
+            });
+
+            if let Some(id) = changed {
+                if let Some(v) = holder.get_mut(&id) {
+                    v._deleted = !v._deleted;
+
+                    if v._deleted {
+                        edit_params.close_if_opened(EntityT::ArmorEnchantEffect(id));
+                        holder.inc_deleted();
+                    } else {
+                        holder.dec_deleted();
+                    }
+
+                    catalog.filter(holder, *filter_mode);
+
+                    backend.check_for_unwrote_changed(EntityT::ArmorEnchantEffect(id));
+                    backend.warn_if_deleted_entity_used(EntityT::ArmorEnchantEffect(id));
+                }
+            }
+        });
+    }
+}
+
+impl DrawAsTooltip for ArmorEnchantEffect {
+    fn draw_as_tooltip(&self, ui: &mut Ui) {
+        ui.label(format!(
+            "ID: {}\n{}\n{}",
+            self.id.0,
+            self.name(),
+            self.desc()
+        ));
+    }
+}
@@ -319,7 +319,8 @@ impl Frontend {
 
                     catalog.filter(holder, *filter_mode);
 
-                    backend.check_for_unwrote_changed();
+                    backend.check_for_unwrote_changed(EntityT::DailyMission(id));
+                    backend.warn_if_deleted_entity_used(EntityT::DailyMission(id));
                 }
             }
         });
@@ -0,0 +1,230 @@
+use crate::backend::entity_editor::{CurrentEntity, EditParamsCommonOps};
+use crate::backend::entity_impl::game_string::GameStringAction;
+use crate::backend::holder::{DataHolder, HolderMapOps};
+use crate::backend::Backend;
+use crate::data::StringTable;
+use crate::entity::game_string::GameString;
+use crate::entity::EntityT;
+use crate::frontend::entity_impl::EntityInfoState;
+use crate::frontend::util::{
+    close_entity_button, combo_box_row, format_button_text, num_row, text_row, text_row_multiline,
+    DrawAsTooltip,
+};
+use crate::frontend::{DrawEntity, Frontend};
+use eframe::egui;
+use eframe::egui::color_picker::{color_edit_button_srgba, Alpha};
+use eframe::egui::{Button, Color32, Context, ScrollArea, Stroke, Ui};
+use std::sync::RwLock;
+
+impl DrawEntity<GameStringAction, ()> for GameString {
+    fn draw_entity(
+        &mut self,
+        ui: &mut Ui,
+        _ctx: &Context,
+        _action: &RwLock<GameStringAction>,
+        holders: &mut DataHolder,
+        _params: &mut (),
+    ) {
+        ui.horizontal(|ui| {
+            ui.set_height(400.);
+
+            ui.vertical(|ui| {
+                ui.set_width(300.);
+
+                ui.horizontal(|ui| {
+                    combo_box_row(ui, &mut self.id.table, "Table");
+                    num_row(ui, &mut self.id.id, "Id").on_hover_ui(|ui| {
+                        holders
+                            .game_data_holder
+                            .game_string_holder
+                            .get(&self.id)
+                            .draw_as_tooltip(ui)
+                    });
+                });
+
+                text_row_multiline(ui, &mut self.value, "Value");
+
+                if self.id.table == StringTable::GameDataName
+                    && self.id.id < holders.game_data_holder.game_string_table.next_index()
+                {
+                    ui.label("Read only, entities keep this text and add it again on save");
+                }
+            });
+
+            if self.id.table == StringTable::SystemMsg {
+                ui.separator();
+
+                let params = &mut self.system_message;
+
+                ui.vertical(|ui| {
+                    ui.set_width(300.);
+
+                    ui.horizontal(|ui| {
+                        num_row(ui, &mut params.group, "Group");
+
+                        ui.add(egui::Label::new("Color"));
+                        color_edit_button_srgba(ui, &mut params.color, Alpha::OnlyBlend);
+                    });
+
+                    text_row(ui, &mut params.sound, "Sound");
+                    text_row(ui, &mut params.reference, "Reference");
+                    text_row(ui, &mut params.sub_message, "Sub Message");
+                    text_row(ui, &mut params.message_type, "Type");
+                });
+
+                ui.separator();
+
+                ui.vertical(|ui| {
+                    ui.set_width(150.);
+
+                    num_row(ui, &mut params.window, "Window");
+                    num_row(ui, &mut params.font, "Font");
+                    num_row(ui, &mut params.lifetime, "Lifetime");
+                    num_row(ui, &mut params.background, "Background");
+                    num_row(ui, &mut params.animation, "Animation");
+                    num_row(ui, &mut params.unk1, "Unk1");
+                });
+            }
+
+            ui.separator();
+        });
+
+        ui.separator();
+    }
+}
+
+impl Frontend {
+    pub fn draw_game_string_tabs(&mut self, ui: &mut Ui) {
+        for (i, (title, id, is_changed)) in self
+            .backend
+            .edit_params
+            .get_opened_game_strings_info()
+            .iter()
+            .enumerate()
+        {
+            let mut button = Button::new(format_button_text(&format!(
+                "{}[{}] {}",
+                if *is_changed { "*" } else { "" },
+                id.id,
+                title
+            )))
+            .fill(Color32::from_rgb(110, 93, 64))
+            .min_size([150., 10.].into());
+
+            let is_current =
+                CurrentEntity::GameString(i) == self.backend.edit_params.current_entity;
+
+            if is_current {
+                button = button.stroke(Stroke::new(1.0, Color32::LIGHT_GRAY));
+            }
+
+            if ui
+                .add(button)
+                .on_hover_text(format!(
+                    "{}: [{}] {}{}",
+                    id.table,
+                    id.id,
+                    title,
+                    if *is_changed { "\nModified!" } else { "" },
+                ))
+                .clicked()
+                && !self.backend.dialog_showing
+            {
+                self.backend.edit_params.set_current_game_string(i);
+            }
+
+            close_entity_button(
+                ui,
+                CurrentEntity::GameString(i),
+                &mut self.backend,
+                *is_changed,
+            );
+
+            ui.separator();
+        }
+    }
+
+    pub(crate) fn draw_game_string_selector(backend: &mut Backend, ui: &mut Ui, width: f32) {
+        ui.vertical(|ui| {
+            ui.set_width(width);
+
+            let holder = &mut backend.holders.game_data_holder.game_string_holder;
+            let catalog = &mut backend.entity_catalogs.game_string;
+            let filter_mode = &mut backend.entity_catalogs.filter_mode;
+            let edit_params = &mut backend.edit_params;
+
+            if catalog
+                .draw_search_and_add_buttons(ui, holder, filter_mode, catalog.len())
+                .clicked()
+            {
+                edit_params.create_new_game_string();
+            }
+
+            ui.separator();
+
+            let mut changed = None;
+
+            ui.push_id(ui.next_auto_id(), |ui| {
+                ScrollArea::vertical().show_rows(ui, 36., catalog.catalog.len(), |ui, range| {
+                    ui.set_width(width - 5.);
+
+                    for v in range {
+                        let q = &catalog.catalog[v];
+
+                        let mut has_unsaved_changes = false;
+
+                        let info_state = if let Some((ind, v)) = edit_params
+                            .game_strings
+                            .opened
+                            .iter()
+                            .enumerate()
+                            .find(|(_, v)| v.inner.initial_id == q.id)
+                        {
+                            has_unsaved_changes = v.is_changed();
+
+                            if edit_params.current_entity == CurrentEntity::GameString(ind) {
+                                EntityInfoState::Current
+                            } else {
+                                EntityInfoState::Opened
+                            }
+                        } else {
+                            EntityInfoState::Nothing
+                        };
+
+                        ui.horizontal(|ui| {
+                            if q.draw_catalog_buttons(
+                                ui,
+                                &mut changed,
+                                info_state,
+                                has_unsaved_changes,
+                            )
+                            .clicked()
+                                && backend.dialog.is_none()
+                                && !q.deleted
+                            {
+                                if ui.input(|i| i.modifiers.ctrl) && !has_unsaved_changes {
+                                    edit_params.close_if_opened(EntityT::GameString(q.id));
+                                } else {
+                                    edit_params.open_game_string(q.id, holder);
+                                }
+                            }
+                        });
+                    }
+                });
+            });
+
+            if let Some(id) = changed {
+                backend.toggle_game_string_deleted(id);
+            }
+        });
+    }
+}
+
+impl DrawAsTooltip for GameString {
+    fn draw_as_tooltip(&self, ui: &mut Ui) {
+        ui.label(format!(
+            "{} ID: {}\n{}",
+            self.id.table, self.id.id, self.value
+        ));
+    }
+}
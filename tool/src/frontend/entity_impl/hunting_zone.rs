@@ -6,8 +6,9 @@ use crate::entity::hunting_zone::{HuntingZone, MapObject};
 use crate::entity::EntityT;
 use crate::frontend::entity_impl::EntityInfoState;
 use crate::frontend::util::{
-    close_entity_button, combo_box_row, format_button_text, num_row, num_row_2d, num_row_optional,
-    text_row, text_row_multiline, Draw, DrawActioned, DrawAsTooltip, DrawUtils,
+    close_entity_button, combo_box_row, entity_link_button, format_button_text, num_row,
+    num_row_2d, num_row_optional, text_row, text_row_multiline, Draw, DrawActioned, DrawAsTooltip,
+    DrawUtils,
 };
 use crate::frontend::{DrawEntity, Frontend, ADD_ICON, DELETE_ICON};
 use eframe::egui::{Button, Color32, Context, DragValue, ScrollArea, Stroke, Ui};
@@ -110,7 +111,22 @@ impl DrawEntity<HuntingZoneAction, ()> for HuntingZone {
                     .on_hover_text("Used for linking with World Map Objects (minimapregion)");
                 num_row(ui, &mut self.search_zone_id.0, "Region Id")
                     .on_hover_text("Used for search by region in map interface");
-                num_row_optional(ui, &mut self.instant_zone_id.0, "Instant Zone", "Id", 0);
+                ui.horizontal(|ui| {
+                    num_row_optional(ui, &mut self.instant_zone_id.0, "Instant Zone", "Id", 0);
+
+                    if self.instant_zone_id.0 != 0 {
+                        entity_link_button(
+                            ui,
+                            "Open",
+                            holders
+                                .game_data_holder
+                                .instant_zone_holder
+                                .get(&self.instant_zone_id),
+                            EntityT::InstantZone(self.instant_zone_id),
+                            &mut holders.entity_link,
+                        );
+                    }
+                });
             });
 
             ui.separator();
@@ -288,7 +304,8 @@ impl Frontend {
 
                     catalog.filter(holder, *filter_mode);
 
-                    backend.check_for_unwrote_changed();
+                    backend.check_for_unwrote_changed(EntityT::HuntingZone(id));
+                    backend.warn_if_deleted_entity_used(EntityT::HuntingZone(id));
                 }
             }
         });
@@ -0,0 +1,214 @@
+use crate::backend::entity_editor::{CurrentEntity, EditParamsCommonOps};
+use crate::backend::entity_impl::instant_zone::InstantZoneAction;
+use crate::backend::holder::{DataHolder, HolderMapOps, HolderOps};
+use crate::backend::Backend;
+use crate::entity::instant_zone::InstantZone;
+use crate::entity::EntityT;
+use crate::frontend::entity_impl::EntityInfoState;
+use crate::frontend::util::{
+    close_entity_button, format_button_text, num_row, text_row, text_row_multiline, DrawAsTooltip,
+};
+use crate::frontend::{DrawEntity, Frontend};
+use eframe::egui::{Button, Color32, Context, DragValue, ScrollArea, Stroke, Ui};
+use std::sync::RwLock;
+
+impl DrawEntity<InstantZoneAction, ()> for InstantZone {
+    fn draw_entity(
+        &mut self,
+        ui: &mut Ui,
+        _ctx: &Context,
+        _action: &RwLock<InstantZoneAction>,
+        holders: &mut DataHolder,
+        _params: &mut (),
+    ) {
+        ui.horizontal(|ui| {
+            ui.set_height(400.);
+
+            ui.vertical(|ui| {
+                ui.set_width(300.);
+
+                ui.horizontal(|ui| {
+                    text_row(ui, &mut self.name, "Name");
+                    num_row(ui, &mut self.id.0, "Id").on_hover_ui(|ui| {
+                        holders
+                            .game_data_holder
+                            .instant_zone_holder
+                            .get(&self.id)
+                            .draw_as_tooltip(ui)
+                    });
+                });
+
+                text_row_multiline(ui, &mut self.desc, "Description");
+                text_row(ui, &mut self.icon, "Icon");
+            });
+
+            ui.separator();
+
+            ui.vertical(|ui| {
+                ui.set_width(200.);
+
+                ui.horizontal(|ui| {
+                    ui.label("Level Range");
+                    ui.add(DragValue::new(&mut self.level_min));
+                    ui.add(DragValue::new(&mut self.level_max));
+                });
+
+                num_row(ui, &mut self.max_members, "Max Members");
+                num_row(ui, &mut self.reuse_time, "Reuse Time").on_hover_text("Minutes");
+            });
+
+            ui.separator();
+        });
+
+        ui.separator();
+    }
+}
+
+impl Frontend {
+    pub fn draw_instant_zone_tabs(&mut self, ui: &mut Ui) {
+        for (i, (title, id, is_changed)) in self
+            .backend
+            .edit_params
+            .get_opened_instant_zones_info()
+            .iter()
+            .enumerate()
+        {
+            let mut button = Button::new(format_button_text(&format!(
+                "{}[{}] {}",
+                if *is_changed { "*" } else { "" },
+                id.0,
+                title
+            )))
+            .fill(Color32::from_rgb(47, 84, 99))
+            .min_size([150., 10.].into());
+
+            let is_current =
+                CurrentEntity::InstantZone(i) == self.backend.edit_params.current_entity;
+
+            if is_current {
+                button = button.stroke(Stroke::new(1.0, Color32::LIGHT_GRAY));
+            }
+
+            if ui
+                .add(button)
+                .on_hover_text(format!(
+                    "Instant Zone: [{}] {}{}",
+                    id.0,
+                    title,
+                    if *is_changed { "\nModified!" } else { "" },
+                ))
+                .clicked()
+                && !self.backend.dialog_showing
+            {
+                self.backend.edit_params.set_current_instant_zone(i);
+            }
+
+            close_entity_button(
+                ui,
+                CurrentEntity::InstantZone(i),
+                &mut self.backend,
+                *is_changed,
+            );
+
+            ui.separator();
+        }
+    }
+
+    pub(crate) fn draw_instant_zone_selector(backend: &mut Backend, ui: &mut Ui, width: f32) {
+        ui.vertical(|ui| {
+            ui.set_width(width);
+
+            let holder = &mut backend.holders.game_data_holder.instant_zone_holder;
+            let catalog = &mut backend.entity_catalogs.instant_zone;
+            let filter_mode = &mut backend.entity_catalogs.filter_mode;
+            let edit_params = &mut backend.edit_params;
+
+            if catalog
+                .draw_search_and_add_buttons(ui, holder, filter_mode, catalog.len())
+                .clicked()
+            {
+                edit_params.create_new_instant_zone();
+            }
+
+            ui.separator();
+
+            let mut changed = None;
+
+            ui.push_id(ui.next_auto_id(), |ui| {
+                ScrollArea::vertical().show_rows(ui, 36., catalog.catalog.len(), |ui, range| {
+                    ui.set_width(width - 5.);
+
+                    for v in range {
+                        let q = &catalog.catalog[v];
+
+                        let mut has_unsaved_changes = false;
+
+                        let info_state = if let Some((ind, v)) = edit_params
+                            .instant_zones
+                            .opened
+                            .iter()
+                            .enumerate()
+                            .find(|(_, v)| v.inner.initial_id == q.id)
+                        {
+                            has_unsaved_changes = v.is_changed();
+
+                            if edit_params.current_entity == CurrentEntity::InstantZone(ind) {
+                                EntityInfoState::Current
+                            } else {
+                                EntityInfoState::Opened
+                            }
+                        } else {
+                            EntityInfoState::Nothing
+                        };
+
+                        ui.horizontal(|ui| {
+                            if q.draw_catalog_buttons(
+                                ui,
+                                &mut changed,
+                                info_state,
+                                has_unsaved_changes,
+                            )
+                            .clicked()
+                                && backend.dialog.is_none()
+                                && !q.deleted
+                            {
+                                if ui.input(|i| i.modifiers.ctrl) && !has_unsaved_changes {
+                                    edit_params.close_if_opened(EntityT::InstantZone(q.id));
+                                } else {
+                                    edit_params.open_instant_zone(q.id, holder);
+                                }
+                            }
+                        });
+                    }
+                });
+            });
+
+            if let Some(id) = changed {
+                if let Some(v) = holder.get_mut(&id) {
+                    v._deleted = !v._deleted;
+
+                    if v._deleted {
+                        edit_params.close_if_opened(EntityT::InstantZone(id));
+                        holder.inc_deleted();
+                    } else {
+                        holder.dec_deleted();
+                    }
+
+                    catalog.filter(holder, *filter_mode);
+
+                    backend.check_for_unwrote_changed(EntityT::InstantZone(id));
+                    backend.warn_if_deleted_entity_used(EntityT::InstantZone(id));
+                }
+            }
+        });
+    }
+}
+
+impl DrawAsTooltip for InstantZone {
+    fn draw_as_tooltip(&self, ui: &mut Ui) {
+        ui.label(format!(
+            "ID: {}\n{}\nLevel: {}-{}\n{}",
+            self.id.0, self.name, self.level_min, self.level_max, self.desc
+        ));
+    }
+}
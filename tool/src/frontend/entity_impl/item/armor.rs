@@ -8,8 +8,8 @@ use crate::entity::item::armor::{
 use crate::entity::EntityT;
 use crate::frontend::entity_impl::EntityInfoState;
 use crate::frontend::util::{
-    bool_row, close_entity_button, combo_box_row, format_button_text, num_row, num_row_optional,
-    text_row, Draw, DrawActioned, DrawCtx, DrawUtils,
+    bool_row, close_entity_button, combo_box_row, entity_link_button, format_button_text, num_row,
+    num_row_optional, text_row, Draw, DrawActioned, DrawCtx, DrawUtils,
 };
 use crate::frontend::{DrawEntity, Frontend};
 use eframe::egui::{Button, Color32, Context, Response, ScrollArea, Stroke, Ui};
@@ -43,13 +43,28 @@ impl DrawEntity<ArmorAction, ()> for Armor {
                 num_row(ui, &mut self.mp_bonus, "Mp Bonus");
                 num_row(ui, &mut self.hide_mask, "Hide Mask");
 
-                num_row_optional(
-                    ui,
-                    &mut self.set_enchant_effect_id.0,
-                    "Enchanted Set Effect Id",
-                    "",
-                    u8::MAX,
-                );
+                ui.horizontal(|ui| {
+                    num_row_optional(
+                        ui,
+                        &mut self.set_enchant_effect_id.0,
+                        "Enchanted Set Effect Id",
+                        "",
+                        u8::MAX,
+                    );
+
+                    if self.set_enchant_effect_id.0 != u8::MAX {
+                        entity_link_button(
+                            ui,
+                            "Open",
+                            holders
+                                .game_data_holder
+                                .armor_enchant_effect_holder
+                                .get(&self.set_enchant_effect_id),
+                            EntityT::ArmorEnchantEffect(self.set_enchant_effect_id),
+                            &mut holders.entity_link,
+                        );
+                    }
+                });
 
                 combo_box_row(ui, &mut self.underwater_body_type1, "Underwater 1");
                 combo_box_row(ui, &mut self.underwater_body_type2, "Underwater 2");
@@ -271,7 +286,8 @@ impl Frontend {
 
                     catalog.filter(holder, *filter_mode);
 
-                    backend.check_for_unwrote_changed();
+                    backend.check_for_unwrote_changed(EntityT::Armor(id));
+                    backend.warn_if_deleted_entity_used(EntityT::Armor(id));
                 }
             }
         });
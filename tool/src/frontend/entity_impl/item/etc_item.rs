@@ -195,7 +195,8 @@ impl Frontend {
 
                     catalog.filter(holder, *filter_mode);
 
-                    backend.check_for_unwrote_changed();
+                    backend.check_for_unwrote_changed(EntityT::EtcItem(id));
+                    backend.warn_if_deleted_entity_used(EntityT::EtcItem(id));
                 }
             }
         });
@@ -11,8 +11,8 @@ use crate::entity::item::weapon::{
 use crate::entity::EntityT;
 use crate::frontend::entity_impl::EntityInfoState;
 use crate::frontend::util::{
-    bool_row, close_entity_button, combo_box_row, format_button_text, num_row, text_row, Draw,
-    DrawActioned, DrawCtx, DrawUtils,
+    bool_row, close_entity_button, combo_box_row, entity_link_button, format_button_text, num_row,
+    text_row, Draw, DrawActioned, DrawCtx, DrawUtils,
 };
 use crate::frontend::{DrawEntity, Frontend};
 use eframe::egui::{Button, Color32, Context, Response, ScrollArea, Stroke, Ui};
@@ -92,6 +92,35 @@ impl DrawEntity<WeaponAction, ()> for Weapon {
 
                 ui.separator();
 
+                ui.horizontal(|ui| {
+                    ui.label("Enchant Effects");
+
+                    let holder = &holders.game_data_holder.weapon_enchant_effect_holder;
+
+                    let effects: Vec<_> = holders
+                        .game_data_holder
+                        .weapon_enchant_effects(self)
+                        .into_iter()
+                        .filter(|v| holder.get(v).is_some_and(|v| !v._deleted))
+                        .collect();
+
+                    if effects.is_empty() {
+                        ui.label("None referenced");
+                    }
+
+                    for id in effects {
+                        entity_link_button(
+                            ui,
+                            &format!("{}", id.0),
+                            holder.get(&id),
+                            EntityT::WeaponEnchantEffect(id),
+                            &mut holders.entity_link,
+                        );
+                    }
+                });
+
+                ui.separator();
+
                 ui.horizontal(|ui| {
                     self.enchant_info.draw_as_button(
                         ui,
@@ -414,7 +443,8 @@ impl Frontend {
 
                     catalog.filter(holder, *filter_mode);
 
-                    backend.check_for_unwrote_changed();
+                    backend.check_for_unwrote_changed(EntityT::Weapon(id));
+                    backend.warn_if_deleted_entity_used(EntityT::Weapon(id));
                 }
             }
         });
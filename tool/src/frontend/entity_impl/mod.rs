@@ -4,6 +4,7 @@ use std::cmp::PartialEq;
 
 pub mod animation_combo;
 pub mod daily_mission;
+pub mod game_string;
 pub mod hunting_zone;
 pub mod item;
 pub mod item_set;
@@ -14,6 +15,11 @@ pub mod recipe;
 pub mod region;
 pub mod skill;
 pub mod residence;
+pub mod weapon_enchant_effect;
+pub mod armor_enchant_effect;
+pub mod ensoul_option;
+pub mod variation_option;
+pub mod instant_zone;
 
 #[derive(Clone, Copy, Eq, PartialEq)]
 enum EntityInfoState {
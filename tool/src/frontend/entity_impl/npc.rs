@@ -6,7 +6,7 @@ use crate::backend::holder::{DataHolder, HolderMapOps, HolderOps};
 use crate::backend::Backend;
 use crate::entity::npc::{
     Npc, NpcAdditionalParts, NpcDecorationEffect, NpcEquipParams, NpcMeshParams, NpcProperty,
-    NpcSkillAnimation, NpcSoundParams, NpcSummonParams,
+    NpcSkillAnimation, NpcSoundParams, NpcSummonParams, NpcTranslation,
 };
 use crate::entity::EntityT;
 use crate::frontend::entity_impl::EntityInfoState;
@@ -62,6 +62,28 @@ impl DrawEntity<NpcAction, ()> for Npc {
                     color_edit_button_srgba(ui, &mut self.title_color, Alpha::Opaque);
                 });
 
+                if holders.game_data_holder.locales.len() > 1 || !self.translations.is_empty() {
+                    ui.collapsing("Translations", |ui| {
+                        for v in &mut self.translations {
+                            ui.label(format!("[{}]", v.locale));
+                            text_row(ui, &mut v.name, "Name");
+                            text_row(ui, &mut v.title, "Title");
+                        }
+
+                        for locale in holders.game_data_holder.locales.iter().skip(1) {
+                            if !self.translations.iter().any(|v| v.locale == *locale)
+                                && ui.button(format!("{ADD_ICON} {locale}")).clicked()
+                            {
+                                self.translations.push(NpcTranslation {
+                                    locale: locale.clone(),
+                                    name: self.name.clone(),
+                                    title: self.title.clone(),
+                                });
+                            }
+                        }
+                    });
+                }
+
                 ui.horizontal(|ui| {
                     num_row_optional(ui, &mut self.npc_type, "Npc Type", "", u16::MAX);
 
@@ -738,7 +760,8 @@ impl Frontend {
 
                     catalog.filter(holder, *filter_mode);
 
-                    backend.check_for_unwrote_changed();
+                    backend.check_for_unwrote_changed(EntityT::Npc(id));
+                    backend.warn_if_deleted_entity_used(EntityT::Npc(id));
                 }
             }
         });
@@ -1,17 +1,22 @@
 use crate::backend::entity_editor::{CurrentEntity, EditParamsCommonOps, WindowParams};
 use crate::backend::entity_impl::quest::{QuestAction, StepAction};
 use crate::backend::holder::{DataHolder, HolderMapOps, HolderOps};
+use crate::backend::server_side::quest_script::ParsedQuestScript;
 use crate::backend::Backend;
-use crate::data::{ItemId, NpcId, PlayerClass};
-use crate::entity::quest::{GoalType, Quest, QuestReward, QuestStep, StepGoal, UnkQLevel};
+use crate::data::{GameStringId, ItemId, NpcId, PlayerClass, QuestId};
+use crate::entity::quest::{
+    GoalType, Quest, QuestReward, QuestStep, QuestStepTranslation, QuestTranslation, StepGoal,
+    UnkQLevel,
+};
 use crate::entity::EntityT;
 use crate::frontend::entity_impl::EntityInfoState;
+use crate::frontend::quest_step_graph::draw_quest_step_graph;
 use crate::frontend::util::num_value::NumberValue;
 use crate::frontend::util::{
     close_entity_button, combo_box_row, format_button_text, num_row, text_row, text_row_multiline,
     Draw, DrawUtils,
 };
-use crate::frontend::{DrawAsTooltip, DrawEntity, Frontend, DELETE_ICON};
+use crate::frontend::{DrawAsTooltip, DrawEntity, Frontend, ADD_ICON, DELETE_ICON};
 use eframe::egui;
 use eframe::egui::{Button, Color32, Context, Response, ScrollArea, Stroke, Ui};
 use std::sync::RwLock;
@@ -44,6 +49,30 @@ impl DrawEntity<QuestAction, ()> for Quest {
 
                 text_row_multiline(ui, &mut self.intro, "Intro");
                 text_row_multiline(ui, &mut self.requirements, "Requirements");
+
+                if holders.game_data_holder.locales.len() > 1 || !self.translations.is_empty() {
+                    ui.collapsing("Translations", |ui| {
+                        for v in &mut self.translations {
+                            ui.label(format!("[{}]", v.locale));
+                            text_row(ui, &mut v.title, "Name");
+                            text_row_multiline(ui, &mut v.intro, "Intro");
+                            text_row_multiline(ui, &mut v.requirements, "Requirements");
+                        }
+
+                        for locale in holders.game_data_holder.locales.iter().skip(1) {
+                            if !self.translations.iter().any(|v| v.locale == *locale)
+                                && ui.button(format!("{ADD_ICON} {locale}")).clicked()
+                            {
+                                self.translations.push(QuestTranslation {
+                                    locale: locale.clone(),
+                                    title: self.title.clone(),
+                                    intro: self.intro.clone(),
+                                    requirements: self.requirements.clone(),
+                                });
+                            }
+                        }
+                    });
+                }
             });
 
             ui.separator();
@@ -140,6 +169,24 @@ impl DrawEntity<QuestAction, ()> for Quest {
                     }
                 }
 
+                if ui
+                    .button("Check JAVA Class")
+                    .on_hover_text("Compares script registrations, checks and rewards with quest")
+                    .clicked()
+                {
+                    if self.java_class.is_none() {
+                        holders.set_java_class(self);
+                    }
+
+                    if let Some(v) = &self.java_class {
+                        let script = ParsedQuestScript::parse(&v.inner);
+
+                        ctx.data_mut(|d| d.insert_temp(script_check_id(self.id), script));
+                    }
+                }
+
+                draw_script_check(self, ctx, holders);
+
                 if let Some(class) = &mut self.java_class {
                     let theme = egui_extras::syntax_highlighting::CodeTheme::from_memory(ui.ctx());
 
@@ -301,12 +348,103 @@ impl DrawEntity<QuestAction, ()> for Quest {
                         });
                 }
             }
+
+            ui.separator();
+
+            let graph_id = egui::Id::new(("_quest_step_graph_opened_", self.id.0));
+            let mut graph_opened = ui
+                .data_mut(|d| d.get_temp::<bool>(graph_id))
+                .unwrap_or_default();
+
+            if ui.button("Step Graph").clicked() {
+                graph_opened = true;
+            }
+
+            if graph_opened {
+                egui::Window::new(format!("{} Steps", self.title))
+                    .id(graph_id)
+                    .open(&mut graph_opened)
+                    .show(ctx, |ui| {
+                        draw_quest_step_graph(ui, self, holders);
+                    });
+            }
+
+            ui.data_mut(|d| d.insert_temp(graph_id, graph_opened));
         });
 
         ui.separator();
     }
 }
 
+fn script_check_id(id: QuestId) -> egui::Id {
+    egui::Id::new(3_000_000 + id.0)
+}
+
+/**Differences between parsed quest script and quest, each one can be replaced by server value
+ */
+fn draw_script_check(quest: &mut Quest, ctx: &Context, holders: &DataHolder) {
+    let id = script_check_id(quest.id);
+
+    let Some(script) = ctx.data_mut(|d| d.get_temp::<ParsedQuestScript>(id)) else {
+        return;
+    };
+
+    let mut opened = true;
+
+    egui::Window::new(format!("{} Script Check", quest.title))
+        .id(id)
+        .open(&mut opened)
+        .show(ctx, |ui| {
+            let differences = script.differences(quest);
+            let mut pull = vec![];
+
+            if differences.is_empty() {
+                ui.label("Script matches the quest");
+            }
+
+            ScrollArea::vertical().max_height(400.).show(ui, |ui| {
+                for v in &differences {
+                    ui.horizontal(|ui| {
+                        ui.label(egui::RichText::new(v.field.to_string()).strong());
+
+                        if ui
+                            .add_enabled(v.field.can_pull(), Button::new("Use Server"))
+                            .on_disabled_hover_text("Kill targets are set in step goals")
+                            .clicked()
+                        {
+                            pull.push(v.field);
+                        }
+                    });
+
+                    ui.label(format!(
+                        "Server: {}",
+                        v.server.describe(&holders.game_data_holder)
+                    ));
+                    ui.label(format!(
+                        "Client: {}",
+                        v.client.describe(&holders.game_data_holder)
+                    ));
+
+                    ui.separator();
+                }
+            });
+
+            if differences.iter().any(|v| v.field.can_pull())
+                && ui.button("Use All Server Values").clicked()
+            {
+                pull = differences.iter().map(|v| v.field).collect();
+            }
+
+            for field in pull {
+                script.pull(field, quest);
+            }
+        });
+
+    if !opened {
+        ctx.data_mut(|d| d.remove::<ParsedQuestScript>(id));
+    }
+}
+
 impl DrawAsTooltip for Quest {
     fn draw_as_tooltip(&self, ui: &mut Ui) {
         ui.label(format!("[{}]\n{}", self.id.0, self.title));
@@ -341,8 +479,8 @@ impl Draw for StepGoal {
                     num_row(ui, &mut self.target_id, "Npc String Id").on_hover_ui(|ui| {
                         holders
                             .game_data_holder
-                            .npc_strings
-                            .get(&self.target_id)
+                            .game_string_holder
+                            .get(&GameStringId::from(self.target_id))
                             .draw_as_tooltip(ui);
                     });
                 }
@@ -369,6 +507,30 @@ impl QuestStep {
             text_row(ui, &mut self.title, "Title");
             text_row(ui, &mut self.label, "Label");
             text_row_multiline(ui, &mut self.desc, "Description");
+
+            if holders.game_data_holder.locales.len() > 1 || !self.translations.is_empty() {
+                ui.collapsing("Translations", |ui| {
+                    for v in &mut self.translations {
+                        ui.label(format!("[{}]", v.locale));
+                        text_row(ui, &mut v.title, "Title");
+                        text_row(ui, &mut v.label, "Label");
+                        text_row_multiline(ui, &mut v.desc, "Description");
+                    }
+
+                    for locale in holders.game_data_holder.locales.iter().skip(1) {
+                        if !self.translations.iter().any(|v| v.locale == *locale)
+                            && ui.button(format!("{ADD_ICON} {locale}")).clicked()
+                        {
+                            self.translations.push(QuestStepTranslation {
+                                locale: locale.clone(),
+                                title: self.title.clone(),
+                                label: self.label.clone(),
+                                desc: self.desc.clone(),
+                            });
+                        }
+                    }
+                });
+            }
         });
 
         ui.separator();
@@ -637,7 +799,8 @@ impl Frontend {
 
                     catalog.filter(holder, *filter_mode);
 
-                    backend.check_for_unwrote_changed();
+                    backend.check_for_unwrote_changed(EntityT::Quest(id));
+                    backend.warn_if_deleted_entity_used(EntityT::Quest(id));
                 }
             }
         });
@@ -232,7 +232,8 @@ impl Frontend {
 
                     catalog.filter(holder, *filter_mode);
 
-                    backend.check_for_unwrote_changed();
+                    backend.check_for_unwrote_changed(EntityT::Recipe(id));
+                    backend.warn_if_deleted_entity_used(EntityT::Recipe(id));
                 }
             }
         });
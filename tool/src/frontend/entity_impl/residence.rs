@@ -186,7 +186,8 @@ impl Frontend {
 
                     catalog.filter(holder, *filter_mode);
 
-                    backend.check_for_unwrote_changed();
+                    backend.check_for_unwrote_changed(EntityT::Residence(id));
+                    backend.warn_if_deleted_entity_used(EntityT::Residence(id));
                 }
             }
         });
@@ -0,0 +1,245 @@
+use crate::backend::entity_editor::{CurrentEntity, EditParamsCommonOps};
+use crate::backend::entity_impl::variation_option::VariationOptionAction;
+use crate::backend::holder::{DataHolder, HolderMapOps, HolderOps};
+use crate::backend::Backend;
+use crate::entity::variation_option::VariationOption;
+use crate::entity::{CommonEntity, EntityT};
+use crate::frontend::entity_impl::EntityInfoState;
+use crate::frontend::util::{
+    close_entity_button, entity_link_button, format_button_text, num_row, text_row_multiline,
+    DrawAsTooltip,
+};
+use crate::frontend::{DrawEntity, Frontend};
+use eframe::egui::{Button, Color32, Context, RichText, ScrollArea, Stroke, Ui};
+use std::sync::RwLock;
+
+impl DrawEntity<VariationOptionAction, ()> for VariationOption {
+    fn draw_entity(
+        &mut self,
+        ui: &mut Ui,
+        _ctx: &Context,
+        _action: &RwLock<VariationOptionAction>,
+        holders: &mut DataHolder,
+        _params: &mut (),
+    ) {
+        ui.horizontal(|ui| {
+            ui.set_height(400.);
+
+            ui.vertical(|ui| {
+                ui.set_width(300.);
+
+                ui.horizontal(|ui| {
+                    num_row(ui, &mut self.id.0, "Id").on_hover_ui(|ui| {
+                        holders
+                            .game_data_holder
+                            .variation_option_holder
+                            .get(&self.id)
+                            .draw_as_tooltip(ui)
+                    });
+                    num_row(ui, &mut self.option_type, "Type");
+                });
+
+                ui.horizontal(|ui| {
+                    num_row(ui, &mut self.skill_id.0, "Skill");
+                    num_row(ui, &mut self.skill_level, "Level");
+
+                    entity_link_button(
+                        ui,
+                        "Open",
+                        holders.game_data_holder.skill_holder.get(&self.skill_id),
+                        EntityT::Skill(self.skill_id),
+                        &mut holders.entity_link,
+                    );
+                });
+
+                text_row_multiline(ui, &mut self.desc, "Description");
+            });
+
+            ui.separator();
+
+            ui.vertical(|ui| {
+                ui.set_width(300.);
+
+                let skill_text = holders.game_data_holder.variation_option_skill_text(self);
+
+                ui.horizontal(|ui| {
+                    ui.label("Skill Effect");
+
+                    if ui
+                        .add_enabled(skill_text.is_some(), Button::new("Use as Description"))
+                        .clicked()
+                    {
+                        if let Some(text) = &skill_text {
+                            self.desc = text.clone();
+                        }
+                    }
+                });
+
+                ui.add_space(5.);
+
+                if let Some(text) = skill_text {
+                    ui.label(text);
+                } else {
+                    ui.label(RichText::new("Skill not found").color(Color32::RED));
+                }
+            });
+
+            ui.separator();
+        });
+
+        ui.separator();
+    }
+}
+
+impl Frontend {
+    pub fn draw_variation_option_tabs(&mut self, ui: &mut Ui) {
+        for (i, (title, id, is_changed)) in self
+            .backend
+            .edit_params
+            .get_opened_variation_options_info()
+            .iter()
+            .enumerate()
+        {
+            let mut button = Button::new(format_button_text(&format!(
+                "{}[{}] {}",
+                if *is_changed { "*" } else { "" },
+                id.0,
+                title
+            )))
+            .fill(Color32::from_rgb(110, 64, 86))
+            .min_size([150., 10.].into());
+
+            let is_current =
+                CurrentEntity::VariationOption(i) == self.backend.edit_params.current_entity;
+
+            if is_current {
+                button = button.stroke(Stroke::new(1.0, Color32::LIGHT_GRAY));
+            }
+
+            if ui
+                .add(button)
+                .on_hover_text(format!(
+                    "Variation Option: [{}] {}{}",
+                    id.0,
+                    title,
+                    if *is_changed { "\nModified!" } else { "" },
+                ))
+                .clicked()
+                && !self.backend.dialog_showing
+            {
+                self.backend.edit_params.set_current_variation_option(i);
+            }
+
+            close_entity_button(
+                ui,
+                CurrentEntity::VariationOption(i),
+                &mut self.backend,
+                *is_changed,
+            );
+
+            ui.separator();
+        }
+    }
+
+    pub(crate) fn draw_variation_option_selector(backend: &mut Backend, ui: &mut Ui, width: f32) {
+        ui.vertical(|ui| {
+            ui.set_width(width);
+
+            let holder = &mut backend.holders.game_data_holder.variation_option_holder;
+            let catalog = &mut backend.entity_catalogs.variation_option;
+            let filter_mode = &mut backend.entity_catalogs.filter_mode;
+            let edit_params = &mut backend.edit_params;
+
+            if catalog
+                .draw_search_and_add_buttons(ui, holder, filter_mode, catalog.len())
+                .clicked()
+            {
+                edit_params.create_new_variation_option();
+            }
+
+            ui.separator();
+
+            let mut changed = None;
+
+            ui.push_id(ui.next_auto_id(), |ui| {
+                ScrollArea::vertical().show_rows(ui, 36., catalog.catalog.len(), |ui, range| {
+                    ui.set_width(width - 5.);
+
+                    for v in range {
+                        let q = &catalog.catalog[v];
+
+                        let mut has_unsaved_changes = false;
+
+                        let info_state = if let Some((ind, v)) = edit_params
+                            .variation_options
+                            .opened
+                            .iter()
+                            .enumerate()
+                            .find(|(_, v)| v.inner.initial_id == q.id)
+                        {
+                            has_unsaved_changes = v.is_changed();
+
+                            if edit_params.current_entity == CurrentEntity::VariationOption(ind) {
+                                EntityInfoState::Current
+                            } else {
+                                EntityInfoState::Opened
+                            }
+                        } else {
+                            EntityInfoState::Nothing
+                        };
+
+                        ui.horizontal(|ui| {
+                            if q.draw_catalog_buttons(
+                                ui,
+                                &mut changed,
+                                info_state,
+                                has_unsaved_changes,
+                            )
+                            .clicked()
+                                && backend.dialog.is_none()
+                                && !q.deleted
+                            {
+                                if ui.input(|i| i.modifiers.ctrl) && !has_unsaved_changes {
+                                    edit_params.close_if_opened(EntityT::VariationOption(q.id));
+                                } else {
+                                    edit_params.open_variation_option(q.id, holder);
+                                }
+                            }
+                        });
+                    }
+                });
+            });
+
+            if let Some(id) = changed {
+                if let Some(v) = holder.get_mut(&id) {
+                    v._deleted = !v._deleted;
+
+                    if v._deleted {
+                        edit_params.close_if_opened(EntityT::VariationOption(id));
+                        holder.inc_deleted();
+                    } else {
+                        holder.dec_deleted();
+                    }
+
+                    catalog.filter(holder, *filter_mode);
+
+                    backend.check_for_unwrote_changed(EntityT::VariationOption(id));
+                    backend.warn_if_deleted_entity_used(EntityT::VariationOption(id));
+                }
+            }
+        });
+    }
+}
+
+impl DrawAsTooltip for VariationOption {
+    fn draw_as_tooltip(&self, ui: &mut Ui) {
+        ui.label(format!(
+            "ID: {}\nType: {}\n{}\nSkill: {} Lv. {}",
+            self.id.0,
+            self.option_type,
+            self.name(),
+            self.skill_id.0,
+            self.skill_level
+        ));
+    }
+}
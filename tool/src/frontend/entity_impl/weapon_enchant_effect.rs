@@ -0,0 +1,252 @@
+use crate::backend::entity_editor::{CurrentEntity, EditParamsCommonOps};
+use crate::backend::entity_impl::weapon_enchant_effect::EnchantEffectAction;
+use crate::backend::holder::{DataHolder, HolderMapOps, HolderOps};
+use crate::backend::Backend;
+use crate::entity::weapon_enchant_effect::{EnchantEffectLevel, WeaponEnchantEffect};
+use crate::entity::{CommonEntity, EntityT};
+use crate::frontend::entity_impl::EntityInfoState;
+use crate::frontend::util::{
+    close_entity_button, combo_box_row, format_button_text, num_row, text_row, Draw, DrawAsTooltip,
+    DrawUtils,
+};
+use crate::frontend::{DrawEntity, Frontend};
+use eframe::egui;
+use eframe::egui::color_picker::{color_edit_button_srgba, Alpha};
+use eframe::egui::{Button, Color32, Context, Response, ScrollArea, Stroke, Ui};
+use std::sync::RwLock;
+
+impl DrawEntity<EnchantEffectAction, ()> for WeaponEnchantEffect {
+    fn draw_entity(
+        &mut self,
+        ui: &mut Ui,
+        _ctx: &Context,
+        action: &RwLock<EnchantEffectAction>,
+        holders: &mut DataHolder,
+        _params: &mut (),
+    ) {
+        ui.horizontal(|ui| {
+            ui.set_height(400.);
+
+            ui.vertical(|ui| {
+                ui.set_width(300.);
+
+                ui.horizontal(|ui| {
+                    num_row(ui, &mut self.id.0, "Id").on_hover_ui(|ui| {
+                        holders
+                            .game_data_holder
+                            .weapon_enchant_effect_holder
+                            .get(&self.id)
+                            .draw_as_tooltip(ui)
+                    });
+                    combo_box_row(ui, &mut self.crystal_type, "Grade");
+                });
+            });
+
+            ui.separator();
+
+            ui.vertical(|ui| {
+                ui.set_width(450.);
+
+                self.levels.draw_vertical(
+                    ui,
+                    "Levels",
+                    |v| {
+                        *action.write().unwrap() = EnchantEffectAction::RemoveLevel(v);
+                    },
+                    holders,
+                    true,
+                    true,
+                );
+            });
+
+            ui.separator();
+        });
+
+        ui.separator();
+    }
+}
+
+impl Draw for EnchantEffectLevel {
+    fn draw(&mut self, ui: &mut Ui, holders: &DataHolder) -> Response {
+        ui.vertical(|ui| {
+            ui.horizontal(|ui| {
+                num_row(ui, &mut self.enchant_level, "Enchant Level");
+                num_row(ui, &mut self.scale, "Scale");
+
+                ui.add(egui::Label::new("Color"));
+                color_edit_button_srgba(ui, &mut self.color, Alpha::OnlyBlend);
+            });
+
+            text_row(ui, &mut self.effect, "Effect");
+            text_row(ui, &mut self.bone, "Bone");
+
+            ui.horizontal(|ui| {
+                ui.label("Offset");
+                ui.add_space(5.);
+                self.offset.draw(ui, holders);
+            });
+        })
+        .response
+    }
+}
+
+impl Frontend {
+    pub fn draw_weapon_enchant_effect_tabs(&mut self, ui: &mut Ui) {
+        for (i, (title, id, is_changed)) in self
+            .backend
+            .edit_params
+            .get_opened_weapon_enchant_effects_info()
+            .iter()
+            .enumerate()
+        {
+            let mut button = Button::new(format_button_text(&format!(
+                "{}[{}] {}",
+                if *is_changed { "*" } else { "" },
+                id.0,
+                title
+            )))
+            .fill(Color32::from_rgb(94, 64, 110))
+            .min_size([150., 10.].into());
+
+            let is_current =
+                CurrentEntity::WeaponEnchantEffect(i) == self.backend.edit_params.current_entity;
+
+            if is_current {
+                button = button.stroke(Stroke::new(1.0, Color32::LIGHT_GRAY));
+            }
+
+            if ui
+                .add(button)
+                .on_hover_text(format!(
+                    "Weapon Enchant Effect: [{}] {}{}",
+                    id.0,
+                    title,
+                    if *is_changed { "\nModified!" } else { "" },
+                ))
+                .clicked()
+                && !self.backend.dialog_showing
+            {
+                self.backend
+                    .edit_params
+                    .set_current_weapon_enchant_effect(i);
+            }
+
+            close_entity_button(
+                ui,
+                CurrentEntity::WeaponEnchantEffect(i),
+                &mut self.backend,
+                *is_changed,
+            );
+
+            ui.separator();
+        }
+    }
+
+    pub(crate) fn draw_weapon_enchant_effect_selector(
+        backend: &mut Backend,
+        ui: &mut Ui,
+        width: f32,
+    ) {
+        ui.vertical(|ui| {
+            ui.set_width(width);
+
+            let holder = &mut backend
+                .holders
+                .game_data_holder
+                .weapon_enchant_effect_holder;
+            let catalog = &mut backend.entity_catalogs.weapon_enchant_effect;
+            let filter_mode = &mut backend.entity_catalogs.filter_mode;
+            let edit_params = &mut backend.edit_params;
+
+            if catalog
+                .draw_search_and_add_buttons(ui, holder, filter_mode, catalog.len())
+                .clicked()
+            {
+                edit_params.create_new_weapon_enchant_effect();
+            }
+
+            ui.separator();
+
+            let mut changed = None;
+
+            ui.push_id(ui.next_auto_id(), |ui| {
+                ScrollArea::vertical().show_rows(ui, 36., catalog.catalog.len(), |ui, range| {
+                    ui.set_width(width - 5.);
+
+                    for v in range {
+                        let q = &catalog.catalog[v];
+
+                        let mut has_unsaved_changes = false;
+
+                        let info_state = if let Some((ind, v)) = edit_params
+                            .weapon_enchant_effects
+                            .opened
+                            .iter()
+                            .enumerate()
+                            .find(|(_, v)| v.inner.initial_id == q.id)
+                        {
+                            has_unsaved_changes = v.is_changed();
+
+                            if edit_params.current_entity == CurrentEntity::WeaponEnchantEffect(ind)
+                            {
+                                EntityInfoState::Current
+                            } else {
+                                EntityInfoState::Opened
+                            }
+                        } else {
+                            EntityInfoState::Nothing
+                        };
+
+                        ui.horizontal(|ui| {
+                            if q.draw_catalog_buttons(
+                                ui,
+                                &mut changed,
+                                info_state,
+                                has_unsaved_changes,
+                            )
+                            .clicked()
+                                && backend.dialog.is_none()
+                                && !q.deleted
+                            {
+                                if ui.input(|i| i.modifiers.ctrl) && !has_unsaved_changes {
+                                    edit_params.close_if_opened(EntityT::WeaponEnchantEffect(q.id));
+                                } else {
+                                    edit_params.open_weapon_enchant_effect(q.id, holder);
+                                }
+                            }
+                        });
+                    }
+                });
+            });
+
+            if let Some(id) = changed {
+                if let Some(v) = holder.get_mut(&id) {
+                    v._deleted = !v._deleted;
+
+                    if v._deleted {
+                        edit_params.close_if_opened(EntityT::WeaponEnchantEffect(id));
+                        holder.inc_deleted();
+                    } else {
+                        holder.dec_deleted();
+                    }
+
+                    catalog.filter(holder, *filter_mode);
+
+                    backend.check_for_unwrote_changed(EntityT::WeaponEnchantEffect(id));
+                    backend.warn_if_deleted_entity_used(EntityT::WeaponEnchantEffect(id));
+                }
+            }
+        });
+    }
+}
+
+impl DrawAsTooltip for WeaponEnchantEffect {
+    fn draw_as_tooltip(&self, ui: &mut Ui) {
+        ui.label(format!(
+            "ID: {}\n{}\n{}",
+            self.id.0,
+            self.name(),
+            self.desc()
+        ));
+    }
+}
@@ -1,15 +1,19 @@
 mod entity_impl;
 mod map_icons_editor;
+mod quest_step_graph;
 mod spawn_editor;
 mod util;
 
+use crate::backend::dat_loader::Chronicle;
 use crate::backend::entity_catalog::{EntityCatalog, EntityInfo, FilterMode};
 use crate::backend::entity_editor::{ChangeTrackedParams, CurrentEntity, WindowParams};
 use crate::backend::holder::{DataHolder, HolderMapOps};
-use crate::backend::log_holder::{LogHolder, LogHolderParams, LogLevel, LogLevelFilter};
+use crate::backend::log_holder::{LogHolder, LogHolderAction, LogHolderParams, LogLevel, LogLevelFilter};
+use crate::backend::server_side::stats::skeleton_xml;
+use crate::backend::server_side::ServerEmulator;
 use crate::backend::{Backend, Dialog, DialogAnswer};
 use crate::data::{ItemId, Location, NpcId, Position, QuestId};
-use crate::entity::{CommonEntity, Entity};
+use crate::entity::{CommonEntity, Entity, EntityT};
 use crate::frontend::map_icons_editor::MapIconsEditor;
 use crate::frontend::spawn_editor::SpawnEditor;
 use crate::frontend::util::num_value::NumberValue;
@@ -20,7 +24,7 @@ use eframe::egui::{Align2, Button, Color32, FontFamily, Image, Key, Modifiers, R
 use eframe::{egui, glow};
 use std::collections::HashMap;
 use std::hash::Hash;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::RwLock;
 use strum::IntoEnumIterator;
@@ -39,6 +43,10 @@ const RAID_INFO_ICON: &[u8] = include_bytes!("../../../files/raid_info.png");
 const DAILY_MISSION_ICON: &[u8] = include_bytes!("../../../files/daily_mission.png");
 const ANIMATION_COMBO_ICON: &[u8] = include_bytes!("../../../files/animation_combo.png");
 const RESIDENCE_ICON: &[u8] = include_bytes!("../../../files/residence.png");
+const WEAPON_ENCHANT_ICON: &[u8] = include_bytes!("../../../files/weapon_enchant.png");
+const ARMOR_ENCHANT_ICON: &[u8] = include_bytes!("../../../files/armor_enchant.png");
+const ENSOUL_ICON: &[u8] = include_bytes!("../../../files/ensoul.png");
+const VARIATION_ICON: &[u8] = include_bytes!("../../../files/variation.png");
 
 pub const NOT_FOUND: &[u8] = include_bytes!("../../../files/none.png");
 
@@ -49,6 +57,7 @@ const DELETE_ICON: &str = "🗑";
 const ADD_ICON: &str = "➕";
 
 pub(crate) static IS_SAVING: AtomicBool = AtomicBool::new(false);
+pub(crate) static IS_VERIFYING: AtomicBool = AtomicBool::new(false);
 
 struct GlobalSearchParams {
     pub search_showing: bool,
@@ -139,8 +148,137 @@ impl Frontend {
                 [index]
                 .draw_window(ui, ctx, &mut self.backend.holders),
 
+            CurrentEntity::GameString(index) => self.backend.edit_params.game_strings.opened
+                [index]
+                .draw_window(ui, ctx, &mut self.backend.holders),
+
+            CurrentEntity::WeaponEnchantEffect(index) => self
+                .backend
+                .edit_params
+                .weapon_enchant_effects
+                .opened[index]
+                .draw_window(ui, ctx, &mut self.backend.holders),
+
+            CurrentEntity::ArmorEnchantEffect(index) => self
+                .backend
+                .edit_params
+                .armor_enchant_effects
+                .opened[index]
+                .draw_window(ui, ctx, &mut self.backend.holders),
+
+            CurrentEntity::EnsoulOption(index) => self.backend.edit_params.ensoul_options.opened
+                [index]
+                .draw_window(ui, ctx, &mut self.backend.holders),
+
+            CurrentEntity::VariationOption(index) => self
+                .backend
+                .edit_params
+                .variation_options
+                .opened[index]
+                .draw_window(ui, ctx, &mut self.backend.holders),
+
+            CurrentEntity::InstantZone(index) => self.backend.edit_params.instant_zones.opened
+                [index]
+                .draw_window(ui, ctx, &mut self.backend.holders),
+
             CurrentEntity::None => {}
         }
+
+        self.draw_used_by(ui);
+        self.draw_server_stats(ui);
+    }
+
+    /**Differences of current npc or item with GS stats xml
+     */
+    fn draw_server_stats(&mut self, ui: &mut Ui) {
+        let Some(entity) = self.backend.edit_params.current_entity_t() else {
+            return;
+        };
+
+        if !matches!(
+            entity,
+            EntityT::Npc(_) | EntityT::Weapon(_) | EntityT::Armor(_) | EntityT::EtcItem(_)
+        ) {
+            return;
+        }
+
+        let Some(stats_path) = self.backend.config.server_stats_folder_path.clone() else {
+            return;
+        };
+
+        let holders = &self.backend.holders;
+        let differences = holders
+            .server_stats_holder
+            .differences(entity, &holders.game_data_holder);
+
+        let mut create = None;
+
+        egui::CollapsingHeader::new(format!("Server Stats ({})", differences.len()))
+            .id_source("_server_stats_")
+            .show(ui, |ui| {
+                if differences.is_empty() {
+                    ui.label("Same as server");
+                }
+
+                for v in &differences {
+                    ui.label(format!(
+                        "{}: server {}, client {}",
+                        v.field, v.server, v.client
+                    ));
+                }
+
+                if let Some((file, _)) =
+                    skeleton_xml(entity, &holders.game_data_holder, &holders.server_stats_holder)
+                {
+                    if ui
+                        .button("Create Server XML")
+                        .on_hover_text("Skeleton xml with stats known to client")
+                        .clicked()
+                    {
+                        let file = Path::new(&stats_path).join(file);
+
+                        create = rfd::FileDialog::new()
+                            .set_directory(file.parent().unwrap())
+                            .set_file_name(file.file_name().unwrap().to_str().unwrap())
+                            .save_file();
+                    }
+                }
+            });
+
+        if let Some(path) = create {
+            self.backend.create_server_xml(entity, path);
+        }
+    }
+
+    /**Entities referencing the one in current tab, click opens them
+     */
+    fn draw_used_by(&mut self, ui: &mut Ui) {
+        let Some(entity) = self.backend.edit_params.current_entity_t() else {
+            return;
+        };
+
+        let holders = &mut self.backend.holders;
+        let used_by = holders.references.used_by(entity);
+
+        egui::CollapsingHeader::new(format!("Used by ({})", used_by.len()))
+            .id_source("_used_by_")
+            .show(ui, |ui| {
+                ScrollArea::vertical()
+                    .id_source("_used_by_scroll_")
+                    .max_height(150.)
+                    .show(ui, |ui| {
+                        ui.horizontal_wrapped(|ui| {
+                            for v in used_by {
+                                if ui
+                                    .button(holders.game_data_holder.entity_title(v))
+                                    .clicked()
+                                {
+                                    holders.entity_link = Some(v);
+                                }
+                            }
+                        });
+                    });
+            });
     }
 
     fn draw_tabs(&mut self, ui: &mut Ui, _ctx: &egui::Context) {
@@ -205,6 +343,12 @@ impl Frontend {
                                 self.draw_daily_missions_tabs(ui);
                                 self.draw_animation_combo_tabs(ui);
                                 self.draw_residence_tabs(ui);
+                                self.draw_game_string_tabs(ui);
+                                self.draw_weapon_enchant_effect_tabs(ui);
+                                self.draw_armor_enchant_effect_tabs(ui);
+                                self.draw_ensoul_option_tabs(ui);
+                                self.draw_variation_option_tabs(ui);
+                                self.draw_instant_zone_tabs(ui);
                             });
                         });
                     });
@@ -253,11 +397,87 @@ impl Frontend {
                             self.backend.update_quests_java_path(path)
                         }
                     }
+                    ui.menu_button("Quest template", |ui| {
+                        for emulator in ServerEmulator::iter() {
+                            let selected = self.backend.config.server_emulator == emulator;
+
+                            if ui
+                                .radio(selected, emulator.to_string())
+                                .on_hover_text(format!(
+                                    "Edited in {}, delete the file to restore built-in one",
+                                    emulator.quest_template_path().display()
+                                ))
+                                .clicked()
+                            {
+                                self.backend.update_server_emulator(emulator);
+                                ui.close_menu();
+                            }
+                        }
+                    });
                     if ui.button("Select GS spawn folder").clicked() {
                         if let Some(path) = rfd::FileDialog::new().pick_folder() {
                             self.update_npc_spawn_path(path)
                         }
                     }
+                    if ui.button("Select GS stats folder").clicked() {
+                        if let Some(path) = rfd::FileDialog::new().pick_folder() {
+                            self.backend.update_server_stats_path(path)
+                        }
+                    }
+                    ui.menu_button("Chronicle", |ui| {
+                        if ui
+                            .radio(self.backend.config.chronicle.is_none(), "Auto")
+                            .clicked()
+                        {
+                            self.backend.update_chronicle(None);
+                            ui.close_menu();
+                        }
+
+                        for chronicle in Chronicle::iter() {
+                            let selected = self.backend.config.chronicle == Some(chronicle);
+
+                            if ui.radio(selected, chronicle.to_string()).clicked() {
+                                self.backend.update_chronicle(Some(chronicle));
+                                ui.close_menu();
+                            }
+                        }
+                    });
+                    if self.backend.holders.game_data_holder.locales.len() > 1 {
+                        ui.menu_button("Main locale", |ui| {
+                            for locale in self.backend.holders.game_data_holder.locales.clone() {
+                                if ui.button(&locale).clicked() {
+                                    self.backend.update_main_locale(locale);
+                                    ui.close_menu();
+                                }
+                            }
+                        });
+                    }
+                    if ui
+                        .button("Verify dats")
+                        .on_hover_text(
+                            "Serializes loaded dats without writing them and compares with files",
+                        )
+                        .clicked()
+                    {
+                        self.backend.verify_dats();
+                        ui.close_menu();
+                    }
+                    if ui
+                        .button("Validate references")
+                        .on_hover_text("Logs ids pointing to missing or deleted entities")
+                        .clicked()
+                    {
+                        self.backend.validate_references();
+                        ui.close_menu();
+                    }
+                    if ui
+                        .button("Compare server stats")
+                        .on_hover_text("Logs npcs and items which differ from GS stats xml")
+                        .clicked()
+                    {
+                        self.backend.compare_server_stats();
+                        ui.close_menu();
+                    }
                 },
             )
             .response
@@ -295,6 +515,41 @@ impl Frontend {
                 self.search_params.search_showing = true;
             }
 
+            ui.menu_button(
+                RichText::new(" \u{f0ce} ").family(FontFamily::Name("icons".into())),
+                |ui| {
+                    let chronicle = self.backend.holders.game_data_holder.chronicle;
+
+                    for entity in Entity::iter().filter(|v| chronicle.supports(*v)) {
+                        ui.menu_button(entity.to_string(), |ui| {
+                            if ui.button("Export to CSV/TSV").clicked() {
+                                if let Some(path) = rfd::FileDialog::new()
+                                    .add_filter("Table", &["csv", "tsv"])
+                                    .set_file_name(format!("{entity}.csv"))
+                                    .save_file()
+                                {
+                                    self.backend.export_holder_to_table(entity, &path);
+                                }
+
+                                ui.close_menu();
+                            }
+                            if ui.button("Import from CSV/TSV").clicked() {
+                                if let Some(path) = rfd::FileDialog::new()
+                                    .add_filter("Table", &["csv", "tsv"])
+                                    .pick_file()
+                                {
+                                    self.backend.import_holder_from_table(entity, &path);
+                                }
+
+                                ui.close_menu();
+                            }
+                        });
+                    }
+                },
+            )
+            .response
+            .on_hover_text("Tables");
+
             if let Some(p) = &self.backend.config.server_spawn_root_folder_path {
                 if ui
                     .button(RichText::new(" \u{f279} ").family(FontFamily::Name("icons".into())))
@@ -366,19 +621,24 @@ impl Frontend {
     fn draw_entity_library(&mut self, ctx: &egui::Context) {
         const LIBRARY_WIDTH: f32 = 392.;
 
+        let chronicle = self.backend.holders.game_data_holder.chronicle;
+
         egui::Window::new("📚")
             .id(egui::Id::new("_search_"))
             .open(&mut self.search_params.search_showing)
             .show(ctx, |ui| {
                 ui.set_width(LIBRARY_WIDTH);
 
-                ui.horizontal(|ui| {
-                    ui.set_height(32.);
+                ui.horizontal_wrapped(|ui| {
+                    ui.set_min_height(32.);
                     if ui
-                        .add(egui::ImageButton::new(Image::from_bytes(
-                            "bytes://quest.png",
-                            QUEST_ICON,
-                        )))
+                        .add_enabled(
+                            chronicle.supports(Entity::Quest),
+                            egui::ImageButton::new(Image::from_bytes(
+                                "bytes://quest.png",
+                                QUEST_ICON,
+                            )),
+                        )
                         .on_hover_text("Quests")
                         .clicked()
                     {
@@ -386,10 +646,13 @@ impl Frontend {
                     };
 
                     if ui
-                        .add(egui::ImageButton::new(Image::from_bytes(
-                            "bytes://skill.png",
-                            SKILL_ICON,
-                        )))
+                        .add_enabled(
+                            chronicle.supports(Entity::Skill),
+                            egui::ImageButton::new(Image::from_bytes(
+                                "bytes://skill.png",
+                                SKILL_ICON,
+                            )),
+                        )
                         .on_hover_text("Skills")
                         .clicked()
                     {
@@ -397,10 +660,10 @@ impl Frontend {
                     };
 
                     if ui
-                        .add(egui::ImageButton::new(Image::from_bytes(
-                            "bytes://npc.png",
-                            NPC_ICON,
-                        )))
+                        .add_enabled(
+                            chronicle.supports(Entity::Npc),
+                            egui::ImageButton::new(Image::from_bytes("bytes://npc.png", NPC_ICON)),
+                        )
                         .on_hover_text("Npcs")
                         .clicked()
                     {
@@ -408,10 +671,13 @@ impl Frontend {
                     };
 
                     if ui
-                        .add(egui::ImageButton::new(Image::from_bytes(
-                            "bytes://weapon.png",
-                            WEAPON_ICON,
-                        )))
+                        .add_enabled(
+                            chronicle.supports(Entity::Weapon),
+                            egui::ImageButton::new(Image::from_bytes(
+                                "bytes://weapon.png",
+                                WEAPON_ICON,
+                            )),
+                        )
                         .on_hover_text("Weapon")
                         .clicked()
                     {
@@ -419,10 +685,13 @@ impl Frontend {
                     };
 
                     if ui
-                        .add(egui::ImageButton::new(Image::from_bytes(
-                            "bytes://armor.png",
-                            ARMOR_ICON,
-                        )))
+                        .add_enabled(
+                            chronicle.supports(Entity::Armor),
+                            egui::ImageButton::new(Image::from_bytes(
+                                "bytes://armor.png",
+                                ARMOR_ICON,
+                            )),
+                        )
                         .on_hover_text("Armor/Jewelry")
                         .clicked()
                     {
@@ -430,10 +699,10 @@ impl Frontend {
                     };
 
                     if ui
-                        .add(egui::ImageButton::new(Image::from_bytes(
-                            "bytes://etc.png",
-                            ETC_ICON,
-                        )))
+                        .add_enabled(
+                            chronicle.supports(Entity::EtcItem),
+                            egui::ImageButton::new(Image::from_bytes("bytes://etc.png", ETC_ICON)),
+                        )
                         .on_hover_text("Etc Items")
                         .clicked()
                     {
@@ -441,10 +710,10 @@ impl Frontend {
                     };
 
                     if ui
-                        .add(egui::ImageButton::new(Image::from_bytes(
-                            "bytes://set.png",
-                            SET_ICON,
-                        )))
+                        .add_enabled(
+                            chronicle.supports(Entity::ItemSet),
+                            egui::ImageButton::new(Image::from_bytes("bytes://set.png", SET_ICON)),
+                        )
                         .on_hover_text("Sets")
                         .clicked()
                     {
@@ -452,10 +721,13 @@ impl Frontend {
                     };
 
                     if ui
-                        .add(egui::ImageButton::new(Image::from_bytes(
-                            "bytes://recipe.png",
-                            RECIPE_ICON,
-                        )))
+                        .add_enabled(
+                            chronicle.supports(Entity::Recipe),
+                            egui::ImageButton::new(Image::from_bytes(
+                                "bytes://recipe.png",
+                                RECIPE_ICON,
+                            )),
+                        )
                         .on_hover_text("Recipes")
                         .clicked()
                     {
@@ -463,10 +735,13 @@ impl Frontend {
                     };
 
                     if ui
-                        .add(egui::ImageButton::new(Image::from_bytes(
-                            "bytes://hunting_zone.png",
-                            HUNTING_ZONE_ICON,
-                        )))
+                        .add_enabled(
+                            chronicle.supports(Entity::HuntingZone),
+                            egui::ImageButton::new(Image::from_bytes(
+                                "bytes://hunting_zone.png",
+                                HUNTING_ZONE_ICON,
+                            )),
+                        )
                         .on_hover_text("Hunting Zones")
                         .clicked()
                     {
@@ -474,10 +749,13 @@ impl Frontend {
                     };
 
                     if ui
-                        .add(egui::ImageButton::new(Image::from_bytes(
-                            "bytes://region.png",
-                            REGION_ICON,
-                        )))
+                        .add_enabled(
+                            chronicle.supports(Entity::Region),
+                            egui::ImageButton::new(Image::from_bytes(
+                                "bytes://region.png",
+                                REGION_ICON,
+                            )),
+                        )
                         .on_hover_text("Regions")
                         .clicked()
                     {
@@ -489,10 +767,13 @@ impl Frontend {
                     ui.set_height(32.);
 
                     if ui
-                        .add(egui::ImageButton::new(Image::from_bytes(
-                            "bytes://raid_info.png",
-                            RAID_INFO_ICON,
-                        )))
+                        .add_enabled(
+                            chronicle.supports(Entity::RaidInfo),
+                            egui::ImageButton::new(Image::from_bytes(
+                                "bytes://raid_info.png",
+                                RAID_INFO_ICON,
+                            )),
+                        )
                         .on_hover_text("Raid Info")
                         .clicked()
                     {
@@ -500,10 +781,13 @@ impl Frontend {
                     };
 
                     if ui
-                        .add(egui::ImageButton::new(Image::from_bytes(
-                            "bytes://daily_mission.png",
-                            DAILY_MISSION_ICON,
-                        )))
+                        .add_enabled(
+                            chronicle.supports(Entity::DailyMission),
+                            egui::ImageButton::new(Image::from_bytes(
+                                "bytes://daily_mission.png",
+                                DAILY_MISSION_ICON,
+                            )),
+                        )
                         .on_hover_text("Daily Missions")
                         .clicked()
                     {
@@ -511,10 +795,13 @@ impl Frontend {
                     };
 
                     if ui
-                        .add(egui::ImageButton::new(Image::from_bytes(
-                            "bytes://animation_combo.png",
-                            ANIMATION_COMBO_ICON,
-                        )))
+                        .add_enabled(
+                            chronicle.supports(Entity::AnimationCombo),
+                            egui::ImageButton::new(Image::from_bytes(
+                                "bytes://animation_combo.png",
+                                ANIMATION_COMBO_ICON,
+                            )),
+                        )
                         .on_hover_text("Animation Combo")
                         .clicked()
                     {
@@ -522,19 +809,119 @@ impl Frontend {
                     };
 
                     if ui
-                        .add(egui::ImageButton::new(Image::from_bytes(
-                            "bytes://residence.png",
-                            RESIDENCE_ICON,
-                        )))
+                        .add_enabled(
+                            chronicle.supports(Entity::Residence),
+                            egui::ImageButton::new(Image::from_bytes(
+                                "bytes://residence.png",
+                                RESIDENCE_ICON,
+                            )),
+                        )
                         .on_hover_text("Residence")
                         .clicked()
                     {
                         self.search_params.current_entity = Entity::Residence;
                     };
+
+                    if ui
+                        .add_enabled(
+                            chronicle.supports(Entity::WeaponEnchantEffect),
+                            egui::ImageButton::new(Image::from_bytes(
+                                "bytes://weapon_enchant.png",
+                                WEAPON_ENCHANT_ICON,
+                            )),
+                        )
+                        .on_hover_text("Weapon Enchant Effect")
+                        .clicked()
+                    {
+                        self.search_params.current_entity = Entity::WeaponEnchantEffect;
+                    };
+
+                    if ui
+                        .add_enabled(
+                            chronicle.supports(Entity::ArmorEnchantEffect),
+                            egui::ImageButton::new(Image::from_bytes(
+                                "bytes://armor_enchant.png",
+                                ARMOR_ENCHANT_ICON,
+                            )),
+                        )
+                        .on_hover_text("Armor Enchant Effect")
+                        .clicked()
+                    {
+                        self.search_params.current_entity = Entity::ArmorEnchantEffect;
+                    };
+
+                    if ui
+                        .add_enabled(
+                            chronicle.supports(Entity::EnsoulOption),
+                            egui::ImageButton::new(Image::from_bytes(
+                                "bytes://ensoul.png",
+                                ENSOUL_ICON,
+                            )),
+                        )
+                        .on_hover_text("Ensoul Option")
+                        .clicked()
+                    {
+                        self.search_params.current_entity = Entity::EnsoulOption;
+                    };
+
+                    if ui
+                        .add_enabled(
+                            chronicle.supports(Entity::VariationOption),
+                            egui::ImageButton::new(Image::from_bytes(
+                                "bytes://variation.png",
+                                VARIATION_ICON,
+                            )),
+                        )
+                        .on_hover_text("Lifestone Option")
+                        .clicked()
+                    {
+                        self.search_params.current_entity = Entity::VariationOption;
+                    };
+
+                    if ui
+                        .add_enabled(
+                            chronicle.supports(Entity::InstantZone),
+                            Button::new(
+                                RichText::new("\u{f6d9}")
+                                    .family(FontFamily::Name("icons".into()))
+                                    .size(20.),
+                            )
+                            .min_size([32., 32.].into()),
+                        )
+                        .on_hover_text("Instant Zones")
+                        .clicked()
+                    {
+                        self.search_params.current_entity = Entity::InstantZone;
+                    };
+
+                    if ui
+                        .add_enabled(
+                            chronicle.supports(Entity::GameString),
+                            Button::new(
+                                RichText::new("\u{f031}")
+                                    .family(FontFamily::Name("icons".into()))
+                                    .size(20.),
+                            )
+                            .min_size([32., 32.].into()),
+                        )
+                        .on_hover_text("Strings")
+                        .clicked()
+                    {
+                        self.search_params.current_entity = Entity::GameString;
+                    };
                 });
 
                 ui.separator();
 
+                if !chronicle.supports(self.search_params.current_entity) {
+                    ui.label(format!(
+                        "{} is not loaded for {chronicle}",
+                        self.search_params.current_entity
+                    ));
+
+                    return;
+                }
+
                 match self.search_params.current_entity {
                     Entity::Npc => Self::draw_npc_selector(&mut self.backend, ui, LIBRARY_WIDTH),
 
@@ -589,6 +976,34 @@ impl Frontend {
                     Entity::Residence => {
                         Self::draw_residence_selector(&mut self.backend, ui, LIBRARY_WIDTH)
                     }
+
+                    Entity::GameString => {
+                        Self::draw_game_string_selector(&mut self.backend, ui, LIBRARY_WIDTH)
+                    }
+
+                    Entity::WeaponEnchantEffect => Self::draw_weapon_enchant_effect_selector(
+                        &mut self.backend,
+                        ui,
+                        LIBRARY_WIDTH,
+                    ),
+
+                    Entity::ArmorEnchantEffect => Self::draw_armor_enchant_effect_selector(
+                        &mut self.backend,
+                        ui,
+                        LIBRARY_WIDTH,
+                    ),
+
+                    Entity::EnsoulOption => {
+                        Self::draw_ensoul_option_selector(&mut self.backend, ui, LIBRARY_WIDTH)
+                    }
+
+                    Entity::VariationOption => {
+                        Self::draw_variation_option_selector(&mut self.backend, ui, LIBRARY_WIDTH)
+                    }
+
+                    Entity::InstantZone => {
+                        Self::draw_instant_zone_selector(&mut self.backend, ui, LIBRARY_WIDTH)
+                    }
                 }
             });
     }
@@ -646,6 +1061,18 @@ impl Frontend {
                         })
                     });
             }
+
+            if IS_VERIFYING.load(Ordering::Relaxed) {
+                egui::Window::new("VERIFYING DATS")
+                    .id(egui::Id::new("_verifying_"))
+                    .resizable(false)
+                    .collapsible(false)
+                    .show(ctx, |ui| {
+                        ui.vertical_centered(|ui| {
+                            ui.spinner();
+                        })
+                    });
+            }
         });
     }
 
@@ -706,6 +1133,13 @@ impl Frontend {
             | Dialog::ConfirmDailyMissionSave { message, .. }
             | Dialog::ConfirmAnimationComboSave { message, .. }
             | Dialog::ConfirmResidenceSave { message, .. }
+            | Dialog::ConfirmGameStringSave { message, .. }
+            | Dialog::ConfirmWeaponEnchantEffectSave { message, .. }
+            | Dialog::ConfirmArmorEnchantEffectSave { message, .. }
+            | Dialog::ConfirmEnsoulOptionSave { message, .. }
+            | Dialog::ConfirmVariationOptionSave { message, .. }
+            | Dialog::ConfirmInstantZoneSave { message, .. }
+            | Dialog::ConfirmDatSave { message }
             | Dialog::ConfirmSkillSave { message, .. } => {
                 let m = message.clone();
 
@@ -935,12 +1369,12 @@ impl From<LogLevel> for Color32 {
     }
 }
 
-impl DrawActioned<(), ()> for LogHolderParams {
+impl DrawActioned<LogHolderAction, ()> for LogHolderParams {
     fn draw_with_action(
         &mut self,
         ui: &mut Ui,
-        _holders: &DataHolder,
-        _action: &RwLock<()>,
+        holders: &DataHolder,
+        action: &RwLock<LogHolderAction>,
         _params: &mut (),
     ) {
         ui.horizontal(|ui| {
@@ -976,6 +1410,17 @@ impl DrawActioned<(), ()> for LogHolderParams {
                     }) {
                         ui.horizontal(|ui| {
                             ui.label(&log.producer);
+
+                            if let Some(entity) = log.entity {
+                                if ui
+                                    .button(format!("{entity} {}", entity.raw_id()))
+                                    .on_hover_text(holders.game_data_holder.entity_title(entity))
+                                    .clicked()
+                                {
+                                    *action.write().unwrap() = LogHolderAction::OpenEntity(entity);
+                                }
+                            }
+
                             ui.label(RichText::new(&log.log).color(log.level));
                         });
 
@@ -0,0 +1,298 @@
+use crate::backend::holder::DataHolder;
+use crate::data::{ItemId, NpcId};
+use crate::entity::quest::{GoalType, Quest, StepGoal};
+use eframe::egui::{Align2, Color32, FontId, Id, Pos2, Rect, ScrollArea, Sense, Stroke, Ui, Vec2};
+
+const NODE_SIZE: Vec2 = Vec2::new(190., 90.);
+const COLUMN_SPACING: f32 = 250.;
+const ROW_SPACING: f32 = 115.;
+const PORT_RADIUS: f32 = 6.;
+const MARGIN: f32 = 20.;
+
+const NODE_FILL: Color32 = Color32::from_rgb(36, 40, 46);
+const NODE_STROKE: Color32 = Color32::from_rgb(120, 120, 120);
+const FINISH_STROKE: Color32 = Color32::from_rgb(70, 160, 90);
+const CYCLE_STROKE: Color32 = Color32::from_rgb(238, 62, 62);
+const UNREACHABLE_STROKE: Color32 = Color32::from_rgb(238, 146, 62);
+
+/**Node graph of [`Quest::steps`], edges come from
+[`crate::entity::quest::QuestStep::prev_steps`]. Links are edited right in the quest, so form
+editor stays in sync, only node positions are kept in egui memory.
+
+Drag node to move it, drag from its right port to another node to link them, click the dot in
+the middle of a link to remove it, click node to open step form
+ */
+pub fn draw_quest_step_graph(ui: &mut Ui, quest: &mut Quest, holders: &DataHolder) {
+    let positions_id = Id::new(("_quest_step_graph_", quest.id.0));
+
+    let mut positions: Vec<Pos2> = ui
+        .data_mut(|d| d.get_temp(positions_id))
+        .unwrap_or_default();
+
+    if positions.len() != quest.steps.len() {
+        positions = initial_layout(quest);
+    }
+
+    let issues = quest.step_graph_issues();
+
+    ui.horizontal(|ui| {
+        if ui.button("Reset Layout").clicked() {
+            positions = initial_layout(quest);
+        }
+
+        ui.label("Drag from the right port to link steps, click the dot on a link to remove it");
+    });
+
+    ui.separator();
+
+    let canvas_size = positions.iter().fold(Vec2::new(600., 300.), |acc, v| {
+        acc.max(v.to_vec2() + NODE_SIZE + Vec2::splat(MARGIN))
+    });
+
+    let mut link = None;
+    let mut unlink = None;
+
+    ui.push_id(positions_id, |ui| {
+        ScrollArea::both().max_height(500.).show(ui, |ui| {
+            let (canvas, painter) = ui.allocate_painter(canvas_size, Sense::hover());
+            let origin = canvas.rect.min;
+
+            let node_rect =
+                |i: usize| Rect::from_min_size(origin + positions[i].to_vec2(), NODE_SIZE);
+
+            for (from, to) in quest.step_edges() {
+                let a = node_rect(from).right_center();
+                let b = node_rect(to).left_center();
+                let color = if issues.in_cycle.contains(&from) && issues.in_cycle.contains(&to) {
+                    CYCLE_STROKE
+                } else {
+                    NODE_STROKE
+                };
+
+                painter.arrow(a, b - a, Stroke::new(1.5, color));
+
+                let middle = a + (b - a) / 2.;
+                let dot = ui
+                    .interact(
+                        Rect::from_center_size(middle, Vec2::splat(PORT_RADIUS * 2.)),
+                        positions_id.with(("link", from, to)),
+                        Sense::click(),
+                    )
+                    .on_hover_text(format!("Unlink [{from}] -> [{to}]"));
+
+                painter.circle_filled(
+                    middle,
+                    PORT_RADIUS / 2.,
+                    if dot.hovered() { CYCLE_STROKE } else { color },
+                );
+
+                if dot.clicked() {
+                    unlink = Some((from, to));
+                }
+            }
+
+            let mut moved = vec![];
+
+            for (i, step) in quest.steps.iter_mut().enumerate() {
+                let rect = node_rect(i);
+
+                let stroke = if issues.in_cycle.contains(&i) {
+                    CYCLE_STROKE
+                } else if issues.unreachable.contains(&i) {
+                    UNREACHABLE_STROKE
+                } else if step.inner.level == quest.last_finish_step_id {
+                    FINISH_STROKE
+                } else {
+                    NODE_STROKE
+                };
+
+                let node = ui.interact(rect, positions_id.with(i), Sense::click_and_drag());
+
+                if node.dragged() {
+                    moved.push((i, node.drag_delta()));
+                }
+                if node.clicked() {
+                    step.opened = true;
+                }
+
+                painter.rect(rect, 4., NODE_FILL, Stroke::new(1.5, stroke));
+                painter.text(
+                    rect.left_top() + Vec2::new(6., 4.),
+                    Align2::LEFT_TOP,
+                    format!("[{i}] {}", step.inner.title),
+                    FontId::proportional(13.),
+                    Color32::WHITE,
+                );
+                painter.text(
+                    rect.left_top() + Vec2::new(6., 22.),
+                    Align2::LEFT_TOP,
+                    goals_text(&step.inner.goals, holders),
+                    FontId::proportional(11.),
+                    Color32::LIGHT_GRAY,
+                );
+                painter.text(
+                    rect.right_bottom() - Vec2::new(6., 4.),
+                    Align2::RIGHT_BOTTOM,
+                    format!("Level {}", step.inner.level),
+                    FontId::proportional(10.),
+                    Color32::GRAY,
+                );
+
+                painter.circle_filled(rect.left_center(), PORT_RADIUS / 2., stroke);
+
+                let port_center = rect.right_center();
+                let port = ui.interact(
+                    Rect::from_center_size(port_center, Vec2::splat(PORT_RADIUS * 2.)),
+                    positions_id.with(("port", i)),
+                    Sense::drag(),
+                );
+
+                painter.circle_filled(
+                    port_center,
+                    PORT_RADIUS,
+                    if port.hovered() || port.dragged() {
+                        FINISH_STROKE
+                    } else {
+                        stroke
+                    },
+                );
+
+                let pointer = ui.ctx().pointer_latest_pos();
+
+                if port.dragged() {
+                    if let Some(pointer) = pointer {
+                        painter.arrow(
+                            port_center,
+                            pointer - port_center,
+                            Stroke::new(1.5, FINISH_STROKE),
+                        );
+                    }
+                }
+
+                if port.drag_stopped() {
+                    if let Some(pointer) = pointer {
+                        link = (0..positions.len())
+                            .find(|v| node_rect(*v).contains(pointer))
+                            .map(|to| (i, to));
+                    }
+                }
+            }
+
+            for (i, delta) in moved {
+                positions[i] = (positions[i] + delta).max(Pos2::ZERO);
+            }
+        });
+    });
+
+    if let Some((from, to)) = link {
+        quest.link_steps(from, to);
+    }
+    if let Some((from, to)) = unlink {
+        quest.unlink_steps(from, to);
+    }
+
+    ui.data_mut(|d| d.insert_temp(positions_id, positions));
+
+    ui.separator();
+
+    if issues.is_empty() {
+        ui.label("No issues");
+    }
+    for i in &issues.in_cycle {
+        ui.colored_label(CYCLE_STROKE, format!("[{i}] is part of a cycle"));
+    }
+    for i in &issues.unreachable {
+        ui.colored_label(
+            UNREACHABLE_STROKE,
+            format!("[{i}] can't be reached from any start step"),
+        );
+    }
+    for (i, level) in &issues.unknown_prev_steps {
+        ui.colored_label(
+            UNREACHABLE_STROKE,
+            format!("[{i}] follows missing step with level {level}"),
+        );
+    }
+    if issues.finish_not_linked {
+        ui.colored_label(
+            CYCLE_STROKE,
+            format!(
+                "Nothing leads to finish step (level {})",
+                quest.last_finish_step_id
+            ),
+        );
+    }
+}
+
+/**Columns by distance from start steps, unreachable steps go to the last one
+ */
+fn initial_layout(quest: &Quest) -> Vec<Pos2> {
+    let edges = quest.step_edges();
+    let mut depth = vec![None; quest.steps.len()];
+    let mut queue = quest.start_steps();
+
+    for i in &queue {
+        depth[*i] = Some(0);
+    }
+
+    while !queue.is_empty() {
+        let mut next = vec![];
+
+        for from in queue {
+            for (_, to) in edges.iter().filter(|v| v.0 == from) {
+                if depth[*to].is_none() {
+                    depth[*to] = Some(depth[from].unwrap() + 1);
+                    next.push(*to);
+                }
+            }
+        }
+
+        queue = next;
+    }
+
+    let last_column = depth.iter().flatten().max().map(|v| v + 1).unwrap_or(0);
+    let mut rows = vec![0; last_column + 1];
+
+    depth
+        .iter()
+        .map(|v| {
+            let column = v.unwrap_or(last_column);
+            let row = rows[column];
+
+            rows[column] += 1;
+
+            Pos2::new(
+                MARGIN + column as f32 * COLUMN_SPACING,
+                MARGIN + row as f32 * ROW_SPACING,
+            )
+        })
+        .collect()
+}
+
+fn goals_text(goals: &[StepGoal], holders: &DataHolder) -> String {
+    let holder = &holders.game_data_holder;
+
+    let mut lines: Vec<_> = goals
+        .iter()
+        .take(3)
+        .map(|v| match v.goal_type {
+            GoalType::KillNpc => format!(
+                "Kill {} x{}",
+                holder.get_npc_name(&NpcId(v.target_id)),
+                v.count
+            ),
+            GoalType::CollectItem => format!(
+                "Collect {} x{}",
+                holder.get_item_name(&ItemId(v.target_id)),
+                v.count
+            ),
+            GoalType::Other => format!("String {}", v.target_id),
+        })
+        .collect();
+
+    if goals.len() > 3 {
+        lines.push(format!("...and {} more", goals.len() - 3));
+    }
+
+    lines.join("\n")
+}
@@ -3,9 +3,10 @@
 use crate::backend::entity_editor::{CurrentEntity, WindowParams};
 use crate::backend::holder::DataHolder;
 use crate::backend::Backend;
+use crate::entity::EntityT;
 use crate::frontend::util::num_value::NumberValue;
 use crate::frontend::{ADD_ICON, DELETE_ICON};
-use eframe::egui::{Align2, Color32, Response, RichText, ScrollArea, TextWrapMode, Ui, Vec2, WidgetText};
+use eframe::egui::{Align2, Button, Color32, Response, RichText, ScrollArea, TextWrapMode, Ui, Vec2, WidgetText};
 use eframe::{egui, emath};
 use std::fmt::Display;
 use std::sync::RwLock;
@@ -486,3 +487,31 @@ pub fn close_entity_button(
         backend.close_entity(entity, ui.ctx().input(|i| i.modifiers.ctrl));
     }
 }
+
+/**Opens `link` in its own editor on click. Disabled if `target` doesn't exist, so dangling ids
+are visible right in the field
+ */
+pub fn entity_link_button<T: DrawAsTooltip>(
+    ui: &mut Ui,
+    label: &str,
+    target: Option<&T>,
+    link: EntityT,
+    entity_link: &mut Option<EntityT>,
+) -> Response {
+    let text = if target.is_some() {
+        RichText::new(label)
+    } else {
+        RichText::new(label).color(Color32::RED)
+    };
+
+    let response = ui
+        .add_enabled(target.is_some(), Button::new(text))
+        .on_hover_ui(|ui| target.draw_as_tooltip(ui))
+        .on_disabled_hover_ui(|ui| target.draw_as_tooltip(ui));
+
+    if response.clicked() {
+        *entity_link = Some(link);
+    }
+
+    response
+}